@@ -1,7 +1,9 @@
 use anyhow::{Context, Result};
-use anytype_rs::api::AnytypeClient;
+use anytype_rs::api::{AnytypeClient, AnytypeError, ClientConfig};
 use clap::{Args, Subcommand};
-use std::io::{self, Write};
+
+/// How many times a wrong or malformed code may be entered before login gives up
+const MAX_CODE_ATTEMPTS: usize = 3;
 
 #[derive(Debug, Args)]
 pub struct AuthArgs {
@@ -12,25 +14,79 @@ pub struct AuthArgs {
 #[derive(Debug, Subcommand)]
 pub enum AuthCommand {
     /// Start the authentication process
-    Login,
+    Login {
+        /// Name the API key is registered under in the Anytype app (prompted if omitted)
+        #[arg(long)]
+        app_name: Option<String>,
+    },
     /// Remove stored credentials
     Logout,
     /// Show current authentication status
     Status,
+    /// Manage issued API keys
+    Keys {
+        #[command(subcommand)]
+        command: KeysCommand,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum KeysCommand {
+    /// List the API keys issued by the Anytype app
+    List,
+    /// Revoke an issued API key
+    Revoke {
+        /// API key ID
+        id: String,
+    },
 }
 
 pub async fn handle_auth_command(args: AuthArgs) -> Result<()> {
     match args.command {
-        AuthCommand::Login => login().await,
+        AuthCommand::Login { app_name } => login(app_name).await,
         AuthCommand::Logout => logout().await,
         AuthCommand::Status => status().await,
+        AuthCommand::Keys { command } => {
+            let action = match command {
+                KeysCommand::List => "Listing",
+                KeysCommand::Revoke { .. } => "Revoking",
+            };
+            // The local API only has endpoints for creating keys (`/v1/auth/challenges` and
+            // `/v1/auth/api_keys`), so there is nothing to call yet
+            anyhow::bail!(
+                "{action} API keys is not supported by the Anytype API; manage issued keys in the Anytype app settings instead"
+            )
+        }
     }
 }
 
-async fn login() -> Result<()> {
+async fn login(app_name: Option<String>) -> Result<()> {
     println!("🔐 Starting authentication with local Anytype app...");
 
-    let client = AnytypeClient::new()?;
+    let defaults = ClientConfig::default();
+    let app_name = match app_name {
+        Some(app_name) => app_name,
+        None => {
+            let app_name = super::prompt(&format!("📛 App name [{}]: ", defaults.app_name))?;
+            if app_name.is_empty() {
+                defaults.app_name
+            } else {
+                app_name
+            }
+        }
+    };
+    let client = AnytypeClient::with_config(ClientConfig {
+        app_name,
+        ..ClientConfig::default()
+    })?;
+
+    // Make sure the app is running before asking it for a code
+    if !client.ping().await.is_reachable() {
+        return Err(anyhow::anyhow!(
+            "Could not reach the Anytype app at {}. Is it running with the API enabled?",
+            defaults.base_url
+        ));
+    }
 
     // Step 1: Create challenge
     println!("📱 Creating authentication challenge...");
@@ -42,24 +98,39 @@ async fn login() -> Result<()> {
     println!("✅ Challenge created with ID: {}", challenge.challenge_id);
     println!("📧 Please check your local Anytype app for the 4-digit authentication code.");
 
-    // Step 2: Get code from user
-    print!("🔢 Enter the 4-digit code: ");
-    io::stdout().flush()?;
-
-    let mut code = String::new();
-    io::stdin().read_line(&mut code)?;
-    let code = code.trim().to_string();
-
-    if code.len() != 4 || !code.chars().all(|c| c.is_ascii_digit()) {
-        return Err(anyhow::anyhow!("Invalid code format. Expected 4 digits."));
-    }
+    // Steps 2 and 3: Get the code from the user and exchange it for an API key,
+    // asking again when the code is malformed or rejected
+    let mut attempt = 0;
+    let api_key_response = loop {
+        attempt += 1;
+        let code = super::prompt("🔢 Enter the 4-digit code: ")?;
+
+        if !is_valid_code(&code) {
+            println!("❌ Invalid code format. Expected 4 digits.");
+        } else {
+            println!("🔑 Creating API key...");
+            match client
+                .create_api_key(challenge.challenge_id.clone(), code)
+                .await
+            {
+                Ok(response) => break response,
+                // Anything other than a rejected code (e.g. the app went away) won't be fixed
+                // by typing the code again
+                Err(e @ (AnytypeError::Auth { .. } | AnytypeError::Api { .. })) => {
+                    println!("❌ Code rejected: {e}");
+                }
+                Err(e) => {
+                    return Err(e).context("Failed to create API key");
+                }
+            }
+        }
 
-    // Step 3: Create API key
-    println!("🔑 Creating API key...");
-    let api_key_response = client
-        .create_api_key(challenge.challenge_id, code)
-        .await
-        .context("Failed to create API key. Please check your code and try again.")?;
+        if attempt >= MAX_CODE_ATTEMPTS {
+            return Err(anyhow::anyhow!(
+                "No valid code after {MAX_CODE_ATTEMPTS} attempts. Run 'anytype auth login' to start over."
+            ));
+        }
+    };
 
     // Step 4: Save API key
     crate::config::save_api_key(&api_key_response.api_key).context("Failed to save API key")?;
@@ -70,12 +141,25 @@ async fn login() -> Result<()> {
     Ok(())
 }
 
+/// Codes shown by the Anytype app are exactly four digits
+fn is_valid_code(code: &str) -> bool {
+    code.len() == 4 && code.chars().all(|c| c.is_ascii_digit())
+}
+
 async fn logout() -> Result<()> {
     println!("🔐 Logging out...");
 
-    crate::config::remove_api_key().context("Failed to remove API key")?;
-
-    println!("✅ Logged out successfully. API key removed.");
+    match crate::config::remove_api_key().context("Failed to remove API key")? {
+        Some(key_file) => {
+            println!(
+                "✅ Logged out successfully. Removed API key file {}",
+                key_file.display()
+            );
+        }
+        None => println!("ℹ️  No stored API key to remove."),
+    }
+    // The local API has no endpoint for revoking keys, so the key stays registered in the app
+    println!("💡 The key is still registered in Anytype; revoke it in the app settings if needed.");
 
     Ok(())
 }
@@ -0,0 +1,104 @@
+use anyhow::Result;
+use anytype_rs::api::ClientConfig;
+use clap::{Args, Subcommand};
+
+/// Number of trailing API key characters shown by `config show`
+const VISIBLE_KEY_CHARS: usize = 4;
+
+#[derive(Debug, Args)]
+pub struct ConfigArgs {
+    #[command(subcommand)]
+    pub command: ConfigCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ConfigCommand {
+    /// Show the current settings (the API key is masked)
+    Show,
+    /// Print the location of the configuration directory
+    Path,
+    /// Show or set the default number of results for list and search commands
+    ///
+    /// Used when a command is given no --limit. ANYTYPE_PAGE_SIZE overrides it.
+    PageSize {
+        /// New default page size
+        #[arg(value_parser = clap::value_parser!(u32).range(1..))]
+        size: Option<u32>,
+        /// Remove the saved page size, going back to each command's own default
+        #[arg(long, conflicts_with = "size")]
+        unset: bool,
+    },
+}
+
+pub async fn handle_config_command(args: ConfigArgs) -> Result<()> {
+    match args.command {
+        ConfigCommand::Show => show(),
+        ConfigCommand::Path => path(),
+        ConfigCommand::PageSize { size, unset } => page_size(size, unset),
+    }
+}
+
+fn show() -> Result<()> {
+    let defaults = ClientConfig::default();
+    let key_file = crate::config::api_key_file()?;
+
+    println!("⚙️  Configuration:");
+    println!(
+        "   📁 Config directory: {}",
+        crate::config::config_dir()?.display()
+    );
+    println!("   🔑 API key file: {}", key_file.display());
+    match crate::config::load_api_key()? {
+        Some(api_key) => println!("   🔑 API key: {}", mask_api_key(&api_key)),
+        None => println!("   🔑 API key: not set (run 'anytype auth login')"),
+    }
+    println!("   🌐 API URL: {}", defaults.base_url);
+    println!("   ⏱️  Timeout: {}s", defaults.timeout_seconds);
+    println!("   🔀 Max concurrency: {}", defaults.max_concurrency);
+    match crate::config::default_page_size()? {
+        Some(page_size) => println!("   📄 Page size: {page_size}"),
+        None => println!("   📄 Page size: command default"),
+    }
+
+    Ok(())
+}
+
+fn path() -> Result<()> {
+    // Printed without decoration so it can be used in scripts, e.g. `cd $(atc config path)`
+    println!("{}", crate::config::config_dir()?.display());
+    Ok(())
+}
+
+fn page_size(size: Option<u32>, unset: bool) -> Result<()> {
+    if unset {
+        crate::config::save_page_size(None)?;
+        println!("✅ Page size unset; commands use their own default");
+    } else if let Some(size) = size {
+        crate::config::save_page_size(Some(size))?;
+        println!("✅ Page size set to {size}");
+    } else {
+        match crate::config::default_page_size()? {
+            Some(page_size) => println!("📄 Page size: {page_size}"),
+            None => println!("📄 Page size: not set (commands use their own default)"),
+        }
+        return Ok(());
+    }
+
+    if std::env::var_os(crate::config::PAGE_SIZE_ENV).is_some() {
+        println!(
+            "⚠️  {} is set and takes precedence",
+            crate::config::PAGE_SIZE_ENV
+        );
+    }
+    Ok(())
+}
+
+/// Hide all but the last few characters of an API key
+fn mask_api_key(api_key: &str) -> String {
+    let chars: Vec<char> = api_key.chars().collect();
+    if chars.len() <= VISIBLE_KEY_CHARS * 2 {
+        return "*".repeat(chars.len());
+    }
+    let visible: String = chars[chars.len() - VISIBLE_KEY_CHARS..].iter().collect();
+    format!("{}{visible}", "*".repeat(8))
+}
@@ -0,0 +1,71 @@
+use anyhow::{Context, Result};
+use anytype_rs::api::{AnytypeClient, PageStart};
+use clap::{Args, Subcommand};
+use std::io::{BufWriter, Write};
+
+/// Number of objects fetched per request while exporting
+const EXPORT_PAGE_SIZE: usize = 100;
+
+#[derive(Debug, Args)]
+pub struct ExportArgs {
+    #[command(subcommand)]
+    pub command: ExportCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ExportCommand {
+    /// Export all objects in a space as JSON lines (one object per line) to stdout
+    Jsonl {
+        /// Space ID to export
+        #[arg(short, long)]
+        space: String,
+    },
+}
+
+pub async fn handle_export_command(args: ExportArgs) -> Result<()> {
+    let api_key = crate::config::load_api_key()?
+        .ok_or_else(|| anyhow::anyhow!("Not authenticated. Run 'anytype auth login' first."))?;
+
+    let mut client = AnytypeClient::new()?;
+    client.set_api_key(api_key);
+
+    match args.command {
+        ExportCommand::Jsonl { space } => export_jsonl(&client, &space).await,
+    }
+}
+
+async fn export_jsonl(client: &AnytypeClient, space_id: &str) -> Result<()> {
+    // Objects go to stdout, so progress is reported on stderr
+    let mut out = BufWriter::new(std::io::stdout().lock());
+    let mut start = PageStart::default();
+    let mut exported = 0;
+
+    loop {
+        let page = client
+            .list_objects_from(space_id, &start, EXPORT_PAGE_SIZE)
+            .await
+            .context("Failed to fetch objects")?;
+
+        // Listed objects have no body, so each one is fetched again in full
+        let ids: Vec<String> = page.data.iter().map(|object| object.id.clone()).collect();
+        let objects = client
+            .get_objects(space_id, &ids)
+            .await
+            .context("Failed to fetch object bodies")?;
+        for object in &objects {
+            serde_json::to_writer(&mut out, object).context("Failed to serialize object")?;
+            writeln!(out).context("Failed to write object")?;
+        }
+        exported += page.data.len();
+
+        match page.pagination.next_page(exported) {
+            Some(next) if !page.data.is_empty() => start = next,
+            _ => break,
+        }
+    }
+
+    out.flush().context("Failed to write objects")?;
+    eprintln!("✅ Exported {exported} objects from space '{space_id}'");
+
+    Ok(())
+}
@@ -1,8 +1,14 @@
+use super::{BatchErrorArgs, Interrupt, print_not_sent, record_failure};
 use anyhow::{Context, Result, bail};
-use anytype_rs::api::{AnytypeClient, CreateObjectRequest};
+use anytype_rs::api::{
+    AnytypeClient, BatchErrorPolicy, ClientConfig, CreateObjectRequest, UpdateObjectRequest,
+};
 use clap::{Args, Subcommand};
 use gray_matter::Matter;
 use gray_matter::engine::YAML;
+use orgize::elements::{Datetime, Table, TableRow, Timestamp};
+use orgize::{Element, Event, Org};
+use serde::Deserialize;
 use serde_json::Value as JsonValue;
 use std::collections::HashMap;
 use std::path::Path;
@@ -11,6 +17,47 @@ use std::path::Path;
 pub struct ImportArgs {
     #[command(subcommand)]
     pub command: ImportCommand,
+
+    /// Maximum number of concurrent requests for batch operations (default 8)
+    #[arg(long, global = true)]
+    pub concurrency: Option<usize>,
+
+    #[command(flatten)]
+    pub batch: BatchErrorArgs,
+}
+
+/// Flags choosing what happens to objects that were already imported
+#[derive(Debug, Clone, Copy, Args)]
+pub struct ExistingArgs {
+    /// Skip objects whose name and type already exist in the space, e.g. when re-running an
+    /// import after a partial failure
+    #[arg(long, conflicts_with = "update_existing")]
+    pub skip_existing: bool,
+
+    /// Update objects whose name and type already exist in the space instead of creating
+    /// duplicates
+    #[arg(long)]
+    pub update_existing: bool,
+}
+
+/// What to do with an object that already exists in the target space
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum OnExisting {
+    Create,
+    Skip,
+    Update,
+}
+
+impl ExistingArgs {
+    fn on_existing(&self) -> OnExisting {
+        if self.skip_existing {
+            OnExisting::Skip
+        } else if self.update_existing {
+            OnExisting::Update
+        } else {
+            OnExisting::Create
+        }
+    }
 }
 
 #[derive(Debug, Subcommand)]
@@ -35,6 +82,82 @@ pub enum ImportCommand {
         /// Show detailed mapping information
         #[arg(short, long)]
         verbose: bool,
+
+        #[command(flatten)]
+        existing: ExistingArgs,
+    },
+    /// Import a CSV file into Anytype, creating one object per row
+    Csv {
+        /// Path to the CSV file to import
+        file: String,
+
+        /// Target space ID
+        #[arg(short, long)]
+        space: String,
+
+        /// Type key for the new objects
+        #[arg(short = 't', long)]
+        type_key: String,
+
+        /// Column holding the object names
+        #[arg(long, default_value = "name")]
+        name_column: String,
+
+        /// Preview the column mapping and row count without creating objects
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Show detailed mapping information
+        #[arg(short, long)]
+        verbose: bool,
+
+        #[command(flatten)]
+        existing: ExistingArgs,
+    },
+    /// Import objects from a JSON lines file (one object per line, e.g. from `export jsonl`)
+    Jsonl {
+        /// Path to the JSON lines file to import
+        file: String,
+
+        /// Target space ID
+        #[arg(short, long)]
+        space: String,
+
+        /// Type key for lines that don't specify one
+        #[arg(short = 't', long)]
+        type_key: Option<String>,
+
+        /// Update the existing object when a line has an `id`, instead of creating a copy
+        #[arg(long)]
+        upsert: bool,
+
+        /// Count the objects that would be created and updated without sending anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Import an Org-mode file into Anytype
+    Org {
+        /// Path to the Org file to import
+        file: String,
+
+        /// Target space ID
+        #[arg(short, long)]
+        space: String,
+
+        /// Type key for the new object
+        #[arg(short = 't', long)]
+        type_key: String,
+
+        /// Preview the mapping without creating the object
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Show detailed mapping information
+        #[arg(short, long)]
+        verbose: bool,
+
+        #[command(flatten)]
+        existing: ExistingArgs,
     },
 }
 
@@ -42,8 +165,15 @@ pub async fn handle_import_command(args: ImportArgs) -> Result<()> {
     let api_key = crate::config::load_api_key()?
         .ok_or_else(|| anyhow::anyhow!("Not authenticated. Run 'anytype auth login' first."))?;
 
-    let mut client = AnytypeClient::new()?;
+    let mut client = match args.concurrency {
+        Some(max_concurrency) => AnytypeClient::with_config(ClientConfig {
+            max_concurrency,
+            ..ClientConfig::default()
+        })?,
+        None => AnytypeClient::new()?,
+    };
     client.set_api_key(api_key);
+    let policy = args.batch.policy();
 
     match args.command {
         ImportCommand::Markdown {
@@ -52,17 +182,90 @@ pub async fn handle_import_command(args: ImportArgs) -> Result<()> {
             type_key,
             dry_run,
             verbose,
-        } => import_markdown(&client, &file, &space, &type_key, dry_run, verbose).await,
+            existing,
+        } => {
+            let options = ImportOptions {
+                dry_run,
+                verbose,
+                on_existing: existing.on_existing(),
+            };
+            import_markdown(&client, &file, &space, &type_key, options).await
+        }
+        ImportCommand::Csv {
+            file,
+            space,
+            type_key,
+            name_column,
+            dry_run,
+            verbose,
+            existing,
+        } => {
+            let options = ImportOptions {
+                dry_run,
+                verbose,
+                on_existing: existing.on_existing(),
+            };
+            import_csv(
+                &client,
+                &file,
+                &space,
+                &type_key,
+                &name_column,
+                options,
+                policy,
+            )
+            .await
+        }
+        ImportCommand::Jsonl {
+            file,
+            space,
+            type_key,
+            upsert,
+            dry_run,
+        } => {
+            import_jsonl(
+                &client,
+                &file,
+                &space,
+                type_key.as_deref(),
+                upsert,
+                dry_run,
+                policy,
+            )
+            .await
+        }
+        ImportCommand::Org {
+            file,
+            space,
+            type_key,
+            dry_run,
+            verbose,
+            existing,
+        } => {
+            let options = ImportOptions {
+                dry_run,
+                verbose,
+                on_existing: existing.on_existing(),
+            };
+            import_org(&client, &file, &space, &type_key, options).await
+        }
     }
 }
 
+/// Settings shared by the markdown, Org and CSV importers
+#[derive(Debug, Clone, Copy)]
+pub(super) struct ImportOptions {
+    pub(super) dry_run: bool,
+    pub(super) verbose: bool,
+    pub(super) on_existing: OnExisting,
+}
+
 async fn import_markdown(
     client: &AnytypeClient,
     file_path: &str,
     space_id: &str,
     type_key: &str,
-    dry_run: bool,
-    verbose: bool,
+    options: ImportOptions,
 ) -> Result<()> {
     // Read the markdown file
     println!("📄 Reading markdown file: {}", file_path);
@@ -72,10 +275,486 @@ async fn import_markdown(
     // Parse frontmatter and content
     let (frontmatter, markdown_body) = parse_frontmatter(&content)?;
 
-    if verbose || dry_run {
+    if options.verbose || options.dry_run {
         println!("✓ Parsed frontmatter: {} fields found", frontmatter.len());
     }
 
+    let document = ParsedDocument {
+        file_path,
+        frontmatter,
+        markdown_body,
+    };
+    import_document(client, &document, space_id, type_key, options).await
+}
+
+async fn import_org(
+    client: &AnytypeClient,
+    file_path: &str,
+    space_id: &str,
+    type_key: &str,
+    options: ImportOptions,
+) -> Result<()> {
+    // Read the Org file
+    println!("📄 Reading Org file: {}", file_path);
+    let content = std::fs::read_to_string(file_path)
+        .with_context(|| format!("Failed to read file: {}", file_path))?;
+
+    // Parse #+KEYWORD headers and convert the body to markdown
+    let (metadata, markdown_body) = parse_org(&content);
+
+    if options.verbose || options.dry_run {
+        println!("✓ Parsed Org headers: {} fields found", metadata.len());
+    }
+
+    let document = ParsedDocument {
+        file_path,
+        frontmatter: metadata,
+        markdown_body,
+    };
+    import_document(client, &document, space_id, type_key, options).await
+}
+
+async fn import_csv(
+    client: &AnytypeClient,
+    file_path: &str,
+    space_id: &str,
+    type_key: &str,
+    name_column: &str,
+    options: ImportOptions,
+    policy: BatchErrorPolicy,
+) -> Result<()> {
+    let ImportOptions {
+        dry_run,
+        verbose,
+        on_existing,
+    } = options;
+    // Read the CSV file
+    println!("📄 Reading CSV file: {}", file_path);
+    let content = std::fs::read_to_string(file_path)
+        .with_context(|| format!("Failed to read file: {}", file_path))?;
+
+    let (headers, rows) =
+        parse_csv(&content).with_context(|| format!("Failed to parse CSV: {}", file_path))?;
+    if !headers.iter().any(|header| header == name_column) {
+        bail!(
+            "Name column '{}' not found. Available columns: {}",
+            name_column,
+            headers.join(", ")
+        );
+    }
+
+    if verbose || dry_run {
+        println!(
+            "✓ Parsed CSV: {} columns, {} rows",
+            headers.len(),
+            rows.len()
+        );
+    }
+
+    // Fetch type definition
+    let type_data = client.get_type(space_id, type_key).await.with_context(|| {
+        format!(
+            "Failed to fetch type '{}' in space '{}'",
+            type_key, space_id
+        )
+    })?;
+
+    if verbose || dry_run {
+        println!("✓ Fetched type definition: {}", type_data.name);
+        println!("\n📋 Column Mapping:");
+        println!("  {} → name (object name)", name_column);
+        for header in headers.iter().filter(|header| *header != name_column) {
+            match type_data
+                .properties
+                .iter()
+                .find(|p| p.key.eq_ignore_ascii_case(header))
+            {
+                Some(prop) => println!("  {} → {} ({})", header, prop.name, &prop.format),
+                None => println!("  {} → (unmapped)", header),
+            }
+        }
+    }
+
+    // Build one create request per row, using the same mapping as frontmatter
+    let mut names = Vec::new();
+    let mut requests = Vec::new();
+    let mut skipped = 0;
+    for (index, mut row) in rows.into_iter().enumerate() {
+        let Some(name) = row
+            .remove(name_column)
+            .and_then(|value| value.as_str().map(str::to_string))
+        else {
+            // Line 1 is the header row
+            eprintln!(
+                "⚠️  Warning: Row {} has no '{}', skipping",
+                index + 2,
+                name_column
+            );
+            skipped += 1;
+            continue;
+        };
+
+        let (properties, _unmapped) = map_frontmatter_to_properties(&row, &type_data.properties)?;
+        names.push(name.clone());
+        requests.push(CreateObjectRequest {
+            properties: to_property_list(properties, &type_data.properties),
+            ..CreateObjectRequest::new(type_key).name(name)
+        });
+    }
+
+    // Set objects that already exist aside, to be skipped or updated instead of created
+    let mut existing = Vec::new();
+    if on_existing != OnExisting::Create {
+        let mut new_names = Vec::new();
+        let mut new_requests = Vec::new();
+        for (name, request) in names.into_iter().zip(requests) {
+            match find_existing(client, space_id, &name, type_key, on_existing).await? {
+                Some(id) => existing.push((name, id, request)),
+                None => {
+                    new_names.push(name);
+                    new_requests.push(request);
+                }
+            }
+        }
+        names = new_names;
+        requests = new_requests;
+    }
+
+    if dry_run {
+        println!("\n🔍 Dry-run mode - no objects created");
+        println!("  📝 Would create {} objects", requests.len());
+        if !existing.is_empty() {
+            let action = match on_existing {
+                OnExisting::Update => "update",
+                _ => "skip",
+            };
+            println!("  📝 Would {} {} existing objects", action, existing.len());
+        }
+        println!("    Type: {}", type_key);
+        println!("    Space: {}", space_id);
+        if skipped > 0 {
+            println!("    Skipped rows: {}", skipped);
+        }
+        return Ok(());
+    }
+
+    let interrupt = Interrupt::watch();
+    let mut updated = 0;
+    let mut failed = 0;
+    let mut not_sent = 0;
+    for (name, id, request) in existing {
+        if interrupt.is_set() {
+            not_sent += 1;
+            continue;
+        }
+        if on_existing == OnExisting::Skip {
+            if verbose {
+                println!("  ⏭️  {} already exists ({})", name, id);
+            }
+            skipped += 1;
+            continue;
+        }
+        let request = UpdateObjectRequest {
+            name: None,
+            body: request.body,
+            properties: request.properties,
+        };
+        match client.update_object(space_id, &id, request).await {
+            Ok(_) => {
+                if verbose {
+                    println!("  ✓ {} ({}, updated)", name, id);
+                }
+                updated += 1;
+            }
+            Err(e) => {
+                println!("  ❌ Failed to update '{}': {e}", name);
+                record_failure(&mut failed, policy)?;
+            }
+        }
+    }
+
+    let results = client
+        .create_objects_until_stopped(space_id, requests, policy, interrupt.flag())
+        .await;
+
+    let mut created = 0;
+    for (name, result) in names.iter().zip(results) {
+        match result {
+            Some(Ok(response)) => {
+                if verbose {
+                    println!("  ✓ {} ({})", name, response.object.id);
+                }
+                created += 1;
+            }
+            Some(Err(e)) => {
+                println!("  ❌ Failed to create '{}': {e}", name);
+                record_failure(&mut failed, policy)?;
+            }
+            None => not_sent += 1,
+        }
+    }
+
+    println!(
+        "\n✓ Imported CSV into space {}: {created} created, {updated} updated, {skipped} skipped, {failed} failed",
+        space_id
+    );
+    print_not_sent(not_sent);
+
+    interrupt.check()
+}
+
+/// One line of a JSON lines import, in the format written by `export jsonl`
+#[derive(Debug, Deserialize)]
+struct JsonlObject {
+    id: Option<String>,
+    name: Option<String>,
+    /// Type key of the object
+    object: Option<String>,
+    #[serde(default)]
+    properties: JsonValue,
+    markdown: Option<String>,
+}
+
+async fn import_jsonl(
+    client: &AnytypeClient,
+    file_path: &str,
+    space_id: &str,
+    type_key: Option<&str>,
+    upsert: bool,
+    dry_run: bool,
+    policy: BatchErrorPolicy,
+) -> Result<()> {
+    println!("📄 Reading JSON lines file: {}", file_path);
+    let content = std::fs::read_to_string(file_path)
+        .with_context(|| format!("Failed to read file: {}", file_path))?;
+
+    let mut names = Vec::new();
+    let mut creates = Vec::new();
+    let mut updates = Vec::new();
+    let mut failed = 0;
+
+    for (index, line) in content.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let line_number = index + 1;
+
+        let object: JsonlObject = match serde_json::from_str(line) {
+            Ok(object) => object,
+            Err(e) => {
+                println!("  ❌ Line {}: invalid JSON: {e}", line_number);
+                record_failure(&mut failed, policy)?;
+                continue;
+            }
+        };
+        let properties = properties_from_export(object.properties);
+
+        if upsert && let Some(id) = object.id {
+            let request = UpdateObjectRequest {
+                name: object.name,
+                body: object.markdown,
+                properties,
+            };
+            updates.push((line_number, id, request));
+            continue;
+        }
+
+        let Some(object_type) = object.object.or_else(|| type_key.map(str::to_string)) else {
+            println!(
+                "  ❌ Line {}: no type key (set `object` or pass --type-key)",
+                line_number
+            );
+            record_failure(&mut failed, policy)?;
+            continue;
+        };
+        names.push(format!("line {}", line_number));
+        creates.push(CreateObjectRequest {
+            name: object.name,
+            body: object.markdown,
+            properties,
+            ..CreateObjectRequest::new(object_type)
+        });
+    }
+
+    if dry_run {
+        println!("\n🔍 Dry-run mode - no objects created");
+        println!("  📝 Would create {} objects", creates.len());
+        if !updates.is_empty() {
+            println!("  📝 Would update {} existing objects", updates.len());
+        }
+        println!("    Space: {}", space_id);
+        if failed > 0 {
+            println!("    Invalid lines: {}", failed);
+        }
+        return Ok(());
+    }
+
+    let interrupt = Interrupt::watch();
+    let mut updated = 0;
+    let mut not_sent = 0;
+    for (line_number, id, request) in updates {
+        if interrupt.is_set() {
+            not_sent += 1;
+            continue;
+        }
+        match client.update_object(space_id, &id, request).await {
+            Ok(_) => updated += 1,
+            Err(e) => {
+                println!("  ❌ Line {}: failed to update '{}': {e}", line_number, id);
+                record_failure(&mut failed, policy)?;
+            }
+        }
+    }
+
+    let mut created = 0;
+    for (name, result) in names.iter().zip(
+        client
+            .create_objects_until_stopped(space_id, creates, policy, interrupt.flag())
+            .await,
+    ) {
+        match result {
+            Some(Ok(_)) => created += 1,
+            Some(Err(e)) => {
+                println!("  ❌ Failed to create object from {}: {e}", name);
+                record_failure(&mut failed, policy)?;
+            }
+            None => not_sent += 1,
+        }
+    }
+
+    println!(
+        "\n✓ Imported JSON lines into space {}: {created} created, {updated} updated, {failed} failed",
+        space_id
+    );
+    print_not_sent(not_sent);
+
+    interrupt.check()
+}
+
+/// Convert exported object properties to the list format of create/update requests
+///
+/// `export jsonl` writes properties the way the API returns them, e.g. `{"id": "..", "key":
+/// "status", "name": "Status", "format": "select", "select": {"id": "tag1", ..}}`, while
+/// requests take the key and the value under the format name, with tags given by ID:
+/// `{"key": "status", "select": "tag1"}`. Entries already in request form are kept, and
+/// entries without a key or a value are dropped.
+fn properties_from_export(properties: JsonValue) -> Option<Vec<JsonValue>> {
+    let properties: Vec<JsonValue> = properties
+        .as_array()?
+        .iter()
+        .filter_map(property_from_export)
+        .collect();
+    (!properties.is_empty()).then_some(properties)
+}
+
+/// Convert one exported property to its request form, see [`properties_from_export`]
+fn property_from_export(property: &JsonValue) -> Option<JsonValue> {
+    let key = property.get("key")?.as_str()?;
+    let (format, value) = match property.get("format").and_then(JsonValue::as_str) {
+        Some(format) => (format, property.get(format)?),
+        None => property
+            .as_object()?
+            .iter()
+            .find(|(field, _)| field.as_str() != "key")
+            .map(|(field, value)| (field.as_str(), value))?,
+    };
+
+    // Tags come back as objects, and are set by ID
+    let tag_id = |tag: &JsonValue| tag.get("id").cloned().unwrap_or_else(|| tag.clone());
+    let value = match value {
+        JsonValue::Null => return None,
+        JsonValue::Object(_) => tag_id(value),
+        JsonValue::Array(items) => items.iter().map(tag_id).collect(),
+        value => value.clone(),
+    };
+
+    let mut entry = serde_json::Map::new();
+    entry.insert("key".to_string(), key.into());
+    entry.insert(format.to_string(), value);
+    Some(entry.into())
+}
+
+/// Field values of one CSV data row, keyed by column header
+type CsvRow = HashMap<String, JsonValue>;
+
+/// Parse CSV content into its header row and one field map per data row
+///
+/// Cells are trimmed and empty cells are left out of the row map.
+fn parse_csv(content: &str) -> Result<(Vec<String>, Vec<CsvRow>)> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(content.as_bytes());
+
+    let headers: Vec<String> = reader.headers()?.iter().map(str::to_string).collect();
+
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let record = record?;
+        let row = headers
+            .iter()
+            .zip(record.iter())
+            .filter(|(_, cell)| !cell.is_empty())
+            .map(|(header, cell)| (header.clone(), JsonValue::String(cell.to_string())))
+            .collect();
+        rows.push(row);
+    }
+
+    Ok((headers, rows))
+}
+
+/// Convert mapped properties to the list format of create/update requests
+///
+/// Each `key: value` pair becomes a `{"key": key, "<format>": value}` entry, with the
+/// format taken from the type's property of that key.
+pub(crate) fn to_property_list(
+    properties: JsonValue,
+    type_properties: &[anytype_rs::api::TypeProperty],
+) -> Option<Vec<JsonValue>> {
+    let JsonValue::Object(properties) = properties else {
+        return None;
+    };
+    let entries: Vec<JsonValue> = properties
+        .into_iter()
+        .filter_map(|(key, value)| {
+            let format = &type_properties.iter().find(|p| p.key == key)?.format;
+            let mut entry = serde_json::Map::new();
+            entry.insert("key".to_string(), key.into());
+            entry.insert(format.clone(), value);
+            Some(entry.into())
+        })
+        .collect();
+    (!entries.is_empty()).then_some(entries)
+}
+
+/// A file parsed into metadata and a markdown body, ready to import
+pub(super) struct ParsedDocument<'a> {
+    pub(super) file_path: &'a str,
+    pub(super) frontmatter: HashMap<String, JsonValue>,
+    pub(super) markdown_body: String,
+}
+
+/// Create an object from a parsed document
+///
+/// Shared by the markdown and Org importers: the frontmatter is mapped onto the type's
+/// properties, with `title` used as the object name.
+pub(super) async fn import_document(
+    client: &AnytypeClient,
+    document: &ParsedDocument<'_>,
+    space_id: &str,
+    type_key: &str,
+    options: ImportOptions,
+) -> Result<()> {
+    let ImportOptions {
+        dry_run,
+        verbose,
+        on_existing,
+    } = options;
+    let ParsedDocument {
+        file_path,
+        frontmatter,
+        markdown_body,
+    } = document;
+
     // Fetch type definition
     if verbose || dry_run {
         println!("✓ Fetching type definition: {}", type_key);
@@ -92,11 +771,11 @@ async fn import_markdown(
     }
 
     // Extract title from frontmatter or use filename
-    let object_name = extract_object_name(&frontmatter, file_path);
+    let object_name = extract_object_name(frontmatter, file_path);
 
     // Map frontmatter to properties
     let (properties, unmapped_fields) =
-        map_frontmatter_to_properties(&frontmatter, &type_data.properties)?;
+        map_frontmatter_to_properties(frontmatter, &type_data.properties)?;
 
     // Display mapping information
     if verbose || dry_run {
@@ -133,9 +812,24 @@ async fn import_markdown(
         }
     }
 
+    let existing = find_existing(client, space_id, &object_name, type_key, on_existing).await?;
+    if let Some(id) = &existing
+        && on_existing == OnExisting::Skip
+    {
+        println!(
+            "\n⏭️  Skipped '{}': it already exists in space {}",
+            object_name, space_id
+        );
+        println!("  🆔 ID: {}", id);
+        return Ok(());
+    }
+
     if dry_run {
         println!("\n🔍 Dry-run mode - no object created");
-        println!("  📝 Would create object with:");
+        match &existing {
+            Some(id) => println!("  📝 Would update existing object {} with:", id),
+            None => println!("  📝 Would create object with:"),
+        }
         println!("    Name: {}", object_name);
         println!("    Type: {}", type_key);
         println!("    Space: {}", space_id);
@@ -147,28 +841,34 @@ async fn import_markdown(
         return Ok(());
     }
 
+    let body = if markdown_body.trim().is_empty() {
+        None
+    } else {
+        Some(markdown_body.to_string())
+    };
+
+    if let Some(id) = existing {
+        let request = UpdateObjectRequest {
+            name: None,
+            body,
+            properties: to_property_list(properties, &type_data.properties),
+        };
+        client
+            .update_object(space_id, &id, request)
+            .await
+            .with_context(|| format!("Failed to update object '{}'", id))?;
+
+        println!("\n✓ Updated existing object in space {}", space_id);
+        println!("  🆔 ID: {}", id);
+        println!("  📝 Name: {}", object_name);
+        return Ok(());
+    }
+
     // Create the object with body content if available
     let request = CreateObjectRequest {
-        type_key: type_key.to_string(),
-        name: Some(object_name.clone()),
-        body: if markdown_body.trim().is_empty() {
-            None
-        } else {
-            Some(markdown_body.clone())
-        },
-        icon: None,
-        template_id: None,
-        properties: if let Some(props_obj) = properties.as_object() {
-            if props_obj.is_empty() {
-                None
-            } else {
-                // Convert properties object to array format expected by API
-                // For now, we'll keep it as a single-element vec with the object
-                Some(vec![properties])
-            }
-        } else {
-            None
-        },
+        body,
+        properties: to_property_list(properties, &type_data.properties),
+        ..CreateObjectRequest::new(type_key).name(object_name.clone())
     };
 
     let response = client
@@ -195,9 +895,28 @@ async fn import_markdown(
     Ok(())
 }
 
+/// ID of an object with the same name and type, looked up only under `--skip-existing` or
+/// `--update-existing`
+async fn find_existing(
+    client: &AnytypeClient,
+    space_id: &str,
+    name: &str,
+    type_key: &str,
+    on_existing: OnExisting,
+) -> Result<Option<String>> {
+    if on_existing == OnExisting::Create {
+        return Ok(None);
+    }
+    let object = client
+        .find_object_by_name(space_id, name, type_key)
+        .await
+        .with_context(|| format!("Failed to look up existing object '{}'", name))?;
+    Ok(object.map(|object| object.id))
+}
+
 /// Parse frontmatter from markdown content
 /// Returns (frontmatter_map, markdown_body)
-fn parse_frontmatter(content: &str) -> Result<(HashMap<String, JsonValue>, String)> {
+pub(super) fn parse_frontmatter(content: &str) -> Result<(HashMap<String, JsonValue>, String)> {
     let matter = Matter::<YAML>::new();
 
     let result: gray_matter::ParsedEntity = matter.parse(content)?;
@@ -258,6 +977,253 @@ fn pod_to_json_value(pod: &gray_matter::Pod) -> Result<JsonValue> {
     Ok(JsonValue::Null)
 }
 
+/// Parse an Org-mode document
+/// Returns (metadata_map, markdown_body)
+///
+/// `#+KEYWORD: value` lines become metadata with lowercase keys (so `#+TITLE` maps to
+/// `title`), `#+FILETAGS` is split into a `tags` list, and the body is converted to markdown.
+fn parse_org(content: &str) -> (HashMap<String, JsonValue>, String) {
+    let org = Org::parse(content);
+    let mut metadata = HashMap::new();
+
+    for keyword in org.keywords() {
+        let key = keyword.key.to_lowercase();
+        let value = keyword.value.trim();
+        if key == "filetags" {
+            let tags = value
+                .split(|c: char| c == ':' || c.is_whitespace())
+                .filter(|tag| !tag.is_empty())
+                .map(|tag| JsonValue::String(tag.to_string()))
+                .collect();
+            metadata.insert("tags".to_string(), JsonValue::Array(tags));
+        } else if !value.is_empty() {
+            metadata.insert(key, JsonValue::String(value.to_string()));
+        }
+    }
+
+    let mut markdown = OrgMarkdown::default();
+    for event in org.iter() {
+        match event {
+            Event::Start(element) => markdown.start(element),
+            Event::End(element) => markdown.end(element),
+        }
+    }
+
+    (metadata, markdown.finish())
+}
+
+/// Markdown renderer for the element tree orgize parses out of an Org document
+///
+/// Keywords, comments and drawers have no markdown equivalent and are dropped. List
+/// items and quotes are rendered into their own buffer first, so nested content can be
+/// indented or quoted as a whole when the element ends.
+#[derive(Default)]
+struct OrgMarkdown {
+    /// Output of the document, followed by one buffer per open list item or quote
+    buffers: Vec<String>,
+    /// Number of open drawers, whose contents are skipped
+    hidden: usize,
+    /// Cells seen in the current table row, for the rule under the header
+    columns: usize,
+}
+
+impl OrgMarkdown {
+    fn out(&mut self) -> &mut String {
+        if self.buffers.is_empty() {
+            self.buffers.push(String::new());
+        }
+        self.buffers.last_mut().expect("buffer was just pushed")
+    }
+
+    fn start(&mut self, element: &Element) {
+        if self.hidden > 0 {
+            if matches!(element, Element::Drawer(_)) {
+                self.hidden += 1;
+            }
+            return;
+        }
+
+        match element {
+            Element::Drawer(_) => self.hidden = 1,
+            Element::Title(title) => {
+                let hashes = "#".repeat(title.level);
+                self.out().push_str(&format!("{hashes} "));
+            }
+            Element::ListItem(_) | Element::QuoteBlock(_) => {
+                self.out();
+                self.buffers.push(String::new());
+            }
+            Element::Bold => self.out().push_str("**"),
+            Element::Italic => self.out().push('*'),
+            Element::Strike => self.out().push_str("~~"),
+            Element::Text { value } => self.out().push_str(value),
+            Element::Code { value } | Element::Verbatim { value } => {
+                self.out().push_str(&format!("`{value}`"))
+            }
+            Element::Link(link) => {
+                let text = match &link.desc {
+                    Some(desc) => format!("[{desc}]({})", link.path),
+                    None if link.path.contains("://") => format!("<{}>", link.path),
+                    None => link.path.to_string(),
+                };
+                self.out().push_str(&text);
+            }
+            Element::Timestamp(timestamp) => {
+                let text = match timestamp {
+                    Timestamp::Active { start, .. } | Timestamp::Inactive { start, .. } => {
+                        format_org_datetime(start)
+                    }
+                    Timestamp::ActiveRange { start, end, .. }
+                    | Timestamp::InactiveRange { start, end, .. } => {
+                        format!(
+                            "{} – {}",
+                            format_org_datetime(start),
+                            format_org_datetime(end)
+                        )
+                    }
+                    Timestamp::Diary { value } => value.to_string(),
+                };
+                self.out().push_str(&text);
+            }
+            Element::SourceBlock(block) => {
+                self.fence(&block.language, &block.contents, block.post_blank)
+            }
+            Element::ExampleBlock(block) => self.fence("", &block.contents, block.post_blank),
+            Element::FixedWidth(fixed) => {
+                // Each line starts with the `:` marker
+                let contents: String = fixed
+                    .value
+                    .lines()
+                    .map(|line| {
+                        let line = line.trim_start().trim_start_matches(':');
+                        format!("{}\n", line.strip_prefix(' ').unwrap_or(line))
+                    })
+                    .collect();
+                self.fence("", &contents, fixed.post_blank);
+            }
+            Element::Table(Table::TableEl { value, post_blank }) => {
+                self.fence("", value, *post_blank)
+            }
+            Element::Rule(rule) => {
+                let blanks = "\n".repeat(rule.post_blank);
+                self.out().push_str(&format!("---\n{blanks}"));
+            }
+            Element::TableRow(TableRow::Header | TableRow::Body) => {
+                self.columns = 0;
+                self.out().push('|');
+            }
+            Element::TableRow(TableRow::HeaderRule) => {
+                let rule = "---|".repeat(self.columns);
+                self.out().push_str(&format!("|{rule}\n"));
+            }
+            Element::TableCell(_) => self.out().push(' '),
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, element: &Element) {
+        if self.hidden > 0 {
+            if matches!(element, Element::Drawer(_)) {
+                self.hidden -= 1;
+            }
+            return;
+        }
+
+        match element {
+            Element::Title(title) => self.line_end(title.post_blank),
+            Element::Paragraph { post_blank } => self.line_end(*post_blank),
+            Element::List(list) => self.out().push_str(&"\n".repeat(list.post_blank)),
+            Element::ListItem(item) => {
+                let content = self.buffers.pop().unwrap_or_default();
+                let marker = if item.ordered {
+                    let number: String = item
+                        .bullet
+                        .chars()
+                        .take_while(char::is_ascii_digit)
+                        .collect();
+                    format!("{number}. ")
+                } else {
+                    "- ".to_string()
+                };
+                let indent = " ".repeat(marker.len());
+                for (i, line) in content.lines().enumerate() {
+                    let prefix = if i == 0 {
+                        marker.as_str()
+                    } else {
+                        indent.as_str()
+                    };
+                    let line = if line.is_empty() {
+                        String::new()
+                    } else {
+                        format!("{prefix}{line}")
+                    };
+                    self.out().push_str(&format!("{line}\n"));
+                }
+            }
+            Element::QuoteBlock(block) => {
+                let content = self.buffers.pop().unwrap_or_default();
+                for line in content.lines() {
+                    let line = if line.is_empty() {
+                        ">".to_string()
+                    } else {
+                        format!("> {line}")
+                    };
+                    self.out().push_str(&format!("{line}\n"));
+                }
+                self.out().push_str(&"\n".repeat(block.post_blank));
+            }
+            Element::Bold => self.out().push_str("**"),
+            Element::Italic => self.out().push('*'),
+            Element::Strike => self.out().push_str("~~"),
+            Element::TableCell(_) => {
+                self.columns += 1;
+                self.out().push_str(" |");
+            }
+            Element::TableRow(TableRow::Header | TableRow::Body) => self.out().push('\n'),
+            Element::Table(Table::Org { post_blank, .. }) => {
+                self.out().push_str(&"\n".repeat(*post_blank))
+            }
+            _ => {}
+        }
+    }
+
+    /// End the current line, keeping the blank lines that followed it in the Org file
+    fn line_end(&mut self, post_blank: usize) {
+        let out = self.out();
+        if !out.ends_with('\n') {
+            out.push('\n');
+        }
+        out.push_str(&"\n".repeat(post_blank));
+    }
+
+    /// Write a fenced code block
+    fn fence(&mut self, language: &str, contents: &str, post_blank: usize) {
+        let out = self.out();
+        out.push_str(&format!("```{language}\n{contents}"));
+        if !contents.is_empty() && !contents.ends_with('\n') {
+            out.push('\n');
+        }
+        out.push_str("```\n");
+        out.push_str(&"\n".repeat(post_blank));
+    }
+
+    fn finish(mut self) -> String {
+        self.out().trim_end().to_string()
+    }
+}
+
+/// Format an Org timestamp's date (and time, when it has one)
+fn format_org_datetime(datetime: &Datetime) -> String {
+    let date = format!(
+        "{:04}-{:02}-{:02}",
+        datetime.year, datetime.month, datetime.day
+    );
+    match (datetime.hour, datetime.minute) {
+        (Some(hour), Some(minute)) => format!("{date} {hour:02}:{minute:02}"),
+        _ => date,
+    }
+}
+
 /// Extract object name from frontmatter or filename
 fn extract_object_name(frontmatter: &HashMap<String, JsonValue>, file_path: &str) -> String {
     // Try to get title from frontmatter
@@ -314,7 +1280,7 @@ fn map_frontmatter_to_properties(
 }
 
 /// Convert a JSON value to match the expected property format (string-based)
-fn convert_value_to_format_str(value: &JsonValue, format: &str) -> Result<JsonValue> {
+pub(crate) fn convert_value_to_format_str(value: &JsonValue, format: &str) -> Result<JsonValue> {
     let format_lower = format.to_lowercase();
 
     match format_lower.as_str() {
@@ -571,21 +1537,21 @@ This is the body."#;
                 id: "prop1".to_string(),
                 key: "status".to_string(),
                 name: "Status".to_string(),
-                object: "property".to_string(),
+                object: "property".into(),
             },
             anytype_rs::api::TypeProperty {
                 format: "number".to_string(),
                 id: "prop2".to_string(),
                 key: "priority".to_string(),
                 name: "Priority".to_string(),
-                object: "property".to_string(),
+                object: "property".into(),
             },
             anytype_rs::api::TypeProperty {
                 format: "checkbox".to_string(),
                 id: "prop3".to_string(),
                 key: "published".to_string(),
                 name: "Published".to_string(),
-                object: "property".to_string(),
+                object: "property".into(),
             },
         ];
 
@@ -597,6 +1563,11 @@ This is the body."#;
         assert!(props_obj.contains_key("priority"));
         assert!(props_obj.contains_key("published"));
 
+        // Requests take one `{"key": .., "<format>": value}` entry per property
+        let list = to_property_list(properties, &type_properties).unwrap();
+        assert_eq!(list.len(), 3);
+        assert!(list.contains(&serde_json::json!({ "key": "published", "checkbox": true })));
+
         // Title should be unmapped (used for object name)
         // unknown_field should be unmapped (no matching property)
         assert_eq!(unmapped.len(), 1);
@@ -621,14 +1592,14 @@ This is the body."#;
                 id: "prop1".to_string(),
                 key: "status".to_string(),
                 name: "Status".to_string(),
-                object: "property".to_string(),
+                object: "property".into(),
             },
             anytype_rs::api::TypeProperty {
                 format: "number".to_string(),
                 id: "prop2".to_string(),
                 key: "priority".to_string(),
                 name: "Priority".to_string(),
-                object: "property".to_string(),
+                object: "property".into(),
             },
         ];
 
@@ -640,4 +1611,135 @@ This is the body."#;
         assert!(props_obj.contains_key("priority"));
         assert_eq!(unmapped.len(), 0);
     }
+
+    #[test]
+    fn test_parse_org_headers() {
+        let content = "#+TITLE: Weekly Review\n#+FILETAGS: :work:planning:\n#+DATE: 2025-01-15\n\n* Done\nShipped the release.";
+
+        let (metadata, body) = parse_org(content);
+
+        assert_eq!(
+            metadata.get("title").and_then(|v| v.as_str()),
+            Some("Weekly Review")
+        );
+        assert_eq!(
+            metadata.get("date").and_then(|v| v.as_str()),
+            Some("2025-01-15")
+        );
+        assert_eq!(
+            metadata.get("tags"),
+            Some(&serde_json::json!(["work", "planning"]))
+        );
+        assert_eq!(body.trim(), "# Done\nShipped the release.");
+    }
+
+    #[test]
+    fn test_org_to_markdown_structure() {
+        let content = r#"* Heading
+** Subheading
+:PROPERTIES:
+:ID: 1234
+:END:
++ first
+  + nested
+1. numbered
+# a comment
+#+BEGIN_SRC rust
+let x = *y*;
+#+END_SRC
+#+BEGIN_QUOTE
+Quoted text
+#+END_QUOTE"#;
+
+        let (_, body) = parse_org(content);
+
+        assert_eq!(
+            body,
+            "# Heading\n## Subheading\n- first\n  - nested\n1. numbered\n```rust\nlet x = *y*;\n```\n> Quoted text"
+        );
+    }
+
+    #[test]
+    fn test_org_inline_markup() {
+        let content = "some *bold*, /italic/ and +struck+ text\n\nrun =cargo test= now\n\nsee [[https://anytype.io][Anytype]] or [[https://example.com]]\n\na/b/c and 2*3*4";
+
+        let (_, body) = parse_org(content);
+
+        assert_eq!(
+            body,
+            "some **bold**, *italic* and ~~struck~~ text\n\nrun `cargo test` now\n\nsee [Anytype](https://anytype.io) or <https://example.com>\n\na/b/c and 2*3*4"
+        );
+    }
+
+    #[test]
+    fn test_parse_csv() {
+        let content = "title,status,priority\nFirst, active ,1\nSecond,,2\n";
+
+        let (headers, rows) = parse_csv(content).unwrap();
+
+        assert_eq!(headers, vec!["title", "status", "priority"]);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(
+            rows[0].get("status").and_then(|v| v.as_str()),
+            Some("active")
+        );
+        // Empty cells are not mapped
+        assert!(!rows[1].contains_key("status"));
+        assert_eq!(rows[1].get("priority").and_then(|v| v.as_str()), Some("2"));
+    }
+
+    #[test]
+    fn test_parse_csv_quoted_fields() {
+        let content = "name,notes\n\"Smith, Jane\",\"said \"\"hi\"\"\"\n";
+
+        let (_, rows) = parse_csv(content).unwrap();
+
+        assert_eq!(
+            rows[0].get("name").and_then(|v| v.as_str()),
+            Some("Smith, Jane")
+        );
+        assert_eq!(
+            rows[0].get("notes").and_then(|v| v.as_str()),
+            Some("said \"hi\"")
+        );
+    }
+
+    #[test]
+    fn test_parse_jsonl_object() {
+        let line = r##"{"id":"obj1","name":"Note","object":"ot-page","properties":[{"key":"status","text":"done"}],"markdown":"# Hi"}"##;
+
+        let object: JsonlObject = serde_json::from_str(line).unwrap();
+
+        assert_eq!(object.id.as_deref(), Some("obj1"));
+        assert_eq!(object.object.as_deref(), Some("ot-page"));
+        assert_eq!(object.markdown.as_deref(), Some("# Hi"));
+        assert_eq!(
+            properties_from_export(object.properties).map(|p| p.len()),
+            Some(1)
+        );
+
+        // Properties as `export jsonl` writes them are turned into their request form
+        let exported = serde_json::json!([
+            { "id": "p1", "key": "status", "name": "Status", "format": "select", "object": "property",
+              "select": { "id": "tag1", "key": "done", "name": "Done", "color": "green" } },
+            { "id": "p2", "key": "tags", "name": "Tags", "format": "multi_select", "object": "property",
+              "multi_select": [{ "id": "tag2", "name": "a" }, { "id": "tag3", "name": "b" }] },
+            { "id": "p3", "key": "pages", "name": "Pages", "format": "number", "object": "property",
+              "number": 12 },
+            { "id": "p4", "key": "due", "name": "Due", "format": "date", "object": "property" }
+        ]);
+        assert_eq!(
+            properties_from_export(exported),
+            Some(vec![
+                serde_json::json!({ "key": "status", "select": "tag1" }),
+                serde_json::json!({ "key": "tags", "multi_select": ["tag2", "tag3"] }),
+                serde_json::json!({ "key": "pages", "number": 12 }),
+            ])
+        );
+
+        // Minimal lines only need what the caller can't default
+        let object: JsonlObject = serde_json::from_str(r#"{"name":"Bare"}"#).unwrap();
+        assert!(object.id.is_none());
+        assert!(properties_from_export(object.properties).is_none());
+    }
 }
@@ -1,5 +1,5 @@
 use anyhow::Result;
-use anytype_rs::api::AnytypeClient;
+use anytype_rs::api::{AddListObjectsRequest, AnytypeClient};
 use clap::{Args, Subcommand};
 
 #[derive(Debug, Args)]
@@ -23,6 +23,10 @@ pub enum ListCommand {
         /// Object IDs to add to the list (comma-separated or multiple --object-id flags)
         #[arg(long, value_delimiter = ',')]
         object_ids: Vec<String>,
+
+        /// Print the request that would be sent without sending it
+        #[arg(long)]
+        dry_run: bool,
     },
     /// Get views for a list
     Views {
@@ -44,9 +48,9 @@ pub enum ListCommand {
         #[arg(short, long)]
         list_id: String,
 
-        /// Limit the number of results
-        #[arg(long, default_value = "20")]
-        limit: u32,
+        /// Limit the number of results (default 20, or the configured page size)
+        #[arg(long)]
+        limit: Option<u32>,
     },
     /// Remove objects from a list (collection)
     Remove {
@@ -61,6 +65,10 @@ pub enum ListCommand {
         /// Object ID to remove from the list
         #[arg(long)]
         object_id: String,
+
+        /// Print the request that would be sent without sending it
+        #[arg(long)]
+        dry_run: bool,
     },
 }
 
@@ -76,7 +84,8 @@ pub async fn handle_list_command(args: ListArgs) -> Result<()> {
             space_id,
             list_id,
             object_ids,
-        } => add_objects_to_list(&client, &space_id, &list_id, object_ids).await,
+            dry_run,
+        } => add_objects_to_list(&client, &space_id, &list_id, object_ids, dry_run).await,
         ListCommand::Views { space_id, list_id } => {
             get_list_views(&client, &space_id, &list_id).await
         }
@@ -84,12 +93,16 @@ pub async fn handle_list_command(args: ListArgs) -> Result<()> {
             space_id,
             list_id,
             limit,
-        } => get_list_objects(&client, &space_id, &list_id, limit).await,
+        } => {
+            let limit = super::page_size(limit, 20)?;
+            get_list_objects(&client, &space_id, &list_id, limit).await
+        }
         ListCommand::Remove {
             space_id,
             list_id,
             object_id,
-        } => remove_object_from_list(&client, &space_id, &list_id, &object_id).await,
+            dry_run,
+        } => remove_object_from_list(&client, &space_id, &list_id, &object_id, dry_run).await,
     }
 }
 
@@ -98,12 +111,22 @@ async fn add_objects_to_list(
     space_id: &str,
     list_id: &str,
     object_ids: Vec<String>,
+    dry_run: bool,
 ) -> Result<()> {
     if object_ids.is_empty() {
         println!("❌ Error: No object IDs provided");
         return Ok(());
     }
 
+    if dry_run {
+        let path = format!("/v1/spaces/{space_id}/lists/{list_id}/objects");
+        return super::print_dry_run_with_body(
+            "POST",
+            &path,
+            &AddListObjectsRequest { object_ids },
+        );
+    }
+
     println!(
         "📝 Adding {} objects to list '{}' in space '{}'...",
         object_ids.len(),
@@ -208,24 +231,14 @@ async fn get_list_objects(
     println!("✅ Found {total_objects} objects in list (showing first {display_count}):");
 
     for (i, object) in response.data.iter().take(display_count).enumerate() {
-        println!("   {}. 📄 {} ({})", i + 1, object.name, object.id);
+        println!("   {}. 📄 {} ({})", i + 1, object.display_name(), object.id);
         println!(
             "      🏷️  Type: {} ({})",
             object.object_type.name, object.object_type.key
         );
         println!("      📐 Layout: {}", object.layout);
 
-        match &object.icon {
-            anytype_rs::api::Icon::Emoji { emoji } => {
-                println!("      🎨 Icon: {emoji}");
-            }
-            anytype_rs::api::Icon::File { file } => {
-                println!("      🎨 Icon: {file}");
-            }
-            anytype_rs::api::Icon::Icon { name, color } => {
-                println!("      🎨 Icon: {name} ({color:?})");
-            }
-        }
+        println!("      🎨 Icon: {}", object.icon.display());
 
         if let Some(snippet) = &object.snippet
             && !snippet.is_empty()
@@ -256,7 +269,16 @@ async fn remove_object_from_list(
     space_id: &str,
     list_id: &str,
     object_id: &str,
+    dry_run: bool,
 ) -> Result<()> {
+    if dry_run {
+        super::print_dry_run(
+            "DELETE",
+            &format!("/v1/spaces/{space_id}/lists/{list_id}/objects/{object_id}"),
+        );
+        return Ok(());
+    }
+
     println!("🗑️ Removing {object_id} object from list '{list_id}' in space '{space_id}'...");
 
     let response = client
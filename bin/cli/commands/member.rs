@@ -1,8 +1,9 @@
 //! Member management commands
 
 use anyhow::{Context, Result};
-use anytype_rs::api::AnytypeClient;
-use clap::{Args, Subcommand};
+use anytype_rs::api::{AnytypeClient, Member, MemberFilter, MemberRole, MemberStatus, Space};
+use clap::{Args, Subcommand, ValueEnum};
+use serde::Serialize;
 use tracing::debug;
 
 #[derive(Debug, Args)]
@@ -19,9 +20,22 @@ pub enum MemberCommand {
         #[arg(short, long)]
         space_id: String,
 
-        /// Enable pagination (returns full response with pagination info)
-        #[arg(short, long)]
+        /// Enable pagination (returns full response with pagination info). With a filter,
+        /// only the matching members are returned, as the totals would count every member.
+        #[arg(long)]
         pagination: bool,
+
+        /// Only show members with this role (viewer, editor, owner, no_permission)
+        #[arg(long)]
+        role: Option<MemberRole>,
+
+        /// Only show members with this status (joining, active, removed, declined, removing, canceled)
+        #[arg(long)]
+        status: Option<MemberStatus>,
+
+        /// Only show members with a pending join request
+        #[arg(long)]
+        pending: bool,
     },
     /// Get a specific member by ID
     Get {
@@ -33,6 +47,66 @@ pub enum MemberCommand {
         #[arg(short, long)]
         member_id: String,
     },
+
+    /// Get the profile object of a member
+    Profile {
+        /// Space ID
+        #[arg(short, long)]
+        space_id: String,
+
+        /// Member ID
+        #[arg(short, long)]
+        member_id: String,
+    },
+
+    /// Export members with their space, role and status, for access audits
+    Export {
+        /// Space ID to export members from
+        #[arg(short, long, required_unless_present = "all_spaces")]
+        space_id: Option<String>,
+
+        /// Export the members of every space
+        #[arg(long, conflicts_with = "space_id")]
+        all_spaces: bool,
+
+        /// Output format
+        #[arg(long, value_enum, default_value = "csv")]
+        format: MemberExportFormat,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum MemberExportFormat {
+    Csv,
+    Json,
+}
+
+/// One exported member, flattened with the space it belongs to
+#[derive(Debug, Serialize)]
+struct MemberRow {
+    space_id: String,
+    space_name: String,
+    member_id: String,
+    name: String,
+    global_name: String,
+    identity: String,
+    role: String,
+    status: String,
+}
+
+impl MemberRow {
+    fn new(space: &Space, member: Member) -> Self {
+        Self {
+            space_id: space.id.clone(),
+            space_name: space.name.clone(),
+            member_id: member.id,
+            name: member.name.unwrap_or_default(),
+            global_name: member.global_name.unwrap_or_default(),
+            identity: member.identity.unwrap_or_default(),
+            role: member.role.to_string(),
+            status: member.status.to_string(),
+        }
+    }
 }
 
 pub async fn handle_member_command(args: MemberArgs) -> Result<()> {
@@ -48,16 +122,32 @@ pub async fn handle_member_command(args: MemberArgs) -> Result<()> {
         MemberCommand::List {
             space_id,
             pagination,
+            role,
+            status,
+            pending,
         } => {
+            let filter = MemberFilter {
+                role,
+                status,
+                pending,
+            };
             if pagination {
-                let response = client
+                let mut response = client
                     .list_members_with_pagination(&space_id)
                     .await
                     .context("Failed to list members with pagination")?;
-                println!("{}", serde_json::to_string_pretty(&response)?);
+                if filter.is_empty() {
+                    println!("{}", serde_json::to_string_pretty(&response)?);
+                } else {
+                    // The totals count every member, and other pages can't be filtered
+                    // here, so only the matching members of this page are printed
+                    response.data.retain(|member| filter.matches(member));
+                    let filtered = serde_json::json!({ "data": response.data });
+                    println!("{}", serde_json::to_string_pretty(&filtered)?);
+                }
             } else {
                 let members = client
-                    .list_members(&space_id)
+                    .list_members_filtered(&space_id, &filter)
                     .await
                     .context("Failed to list members")?;
                 println!("{}", serde_json::to_string_pretty(&members)?);
@@ -73,6 +163,67 @@ pub async fn handle_member_command(args: MemberArgs) -> Result<()> {
                 .context("Failed to get member")?;
             println!("{}", serde_json::to_string_pretty(&member)?);
         }
+        MemberCommand::Profile {
+            space_id,
+            member_id,
+        } => {
+            let profile = client
+                .get_member_profile(&space_id, &member_id)
+                .await
+                .context("Failed to get member profile")?;
+            println!("{}", serde_json::to_string_pretty(&profile)?);
+        }
+        MemberCommand::Export {
+            space_id,
+            all_spaces: _,
+            format,
+        } => export_members(&client, space_id.as_deref(), format).await?,
+    }
+
+    Ok(())
+}
+
+/// Print members of one space, or of every space when `space_id` is `None`
+async fn export_members(
+    client: &AnytypeClient,
+    space_id: Option<&str>,
+    format: MemberExportFormat,
+) -> Result<()> {
+    let spaces = match space_id {
+        Some(space_id) => {
+            let space = client
+                .get_space(space_id)
+                .await
+                .context("Failed to get space")?;
+            let members = client
+                .list_members(space_id)
+                .await
+                .context("Failed to list members")?;
+            vec![(space, members)]
+        }
+        None => client
+            .list_members_in_all_spaces()
+            .await
+            .context("Failed to list members")?,
+    };
+    let rows: Vec<MemberRow> = spaces
+        .into_iter()
+        .flat_map(|(space, members)| {
+            members
+                .into_iter()
+                .map(move |member| MemberRow::new(&space, member))
+        })
+        .collect();
+
+    match format {
+        MemberExportFormat::Json => println!("{}", serde_json::to_string_pretty(&rows)?),
+        MemberExportFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(std::io::stdout().lock());
+            for row in &rows {
+                writer.serialize(row).context("Failed to write CSV")?;
+            }
+            writer.flush().context("Failed to write CSV")?;
+        }
     }
 
     Ok(())
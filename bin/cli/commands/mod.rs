@@ -1,4 +1,6 @@
 pub mod auth;
+pub mod config;
+pub mod export;
 pub mod import;
 pub mod list;
 pub mod member;
@@ -8,4 +10,231 @@ pub mod search;
 pub mod space;
 pub mod tag;
 pub mod template;
+pub mod template_local;
 pub mod r#type;
+pub mod version;
+
+use anyhow::{Context, Result, bail};
+use anytype_rs::api::{AnytypeClient, BatchErrorPolicy, Method};
+use clap::Args;
+use serde::Serialize;
+use std::io::{IsTerminal, Read, Write};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+/// Flags choosing what commands that send many requests do when one fails
+#[derive(Debug, Clone, Copy, Args)]
+pub struct BatchErrorArgs {
+    /// Keep going after a failed request and report a summary at the end (default)
+    #[arg(long, global = true, conflicts_with = "fail_fast")]
+    pub continue_on_error: bool,
+
+    /// Stop at the first failed request and exit with an error
+    #[arg(long, global = true)]
+    pub fail_fast: bool,
+}
+
+impl BatchErrorArgs {
+    pub fn policy(&self) -> BatchErrorPolicy {
+        if self.fail_fast {
+            BatchErrorPolicy::FailFast
+        } else {
+            BatchErrorPolicy::ContinueOnError
+        }
+    }
+}
+
+/// Flag re-running a listing command on an interval
+#[derive(Debug, Clone, Copy, Args)]
+pub struct WatchArgs {
+    /// Re-run every SECONDS seconds, redrawing the output, until Ctrl-C
+    #[arg(long, value_name = "SECONDS", value_parser = clap::value_parser!(u64).range(1..))]
+    pub watch: Option<u64>,
+}
+
+impl WatchArgs {
+    /// Run `tick` once, or on every interval under `--watch`
+    ///
+    /// In a terminal the screen is cleared before each run, unless `json` is set: JSON
+    /// output is left as one document per run so it can be piped. A failed run is reported
+    /// and retried on the next interval rather than ending the watch.
+    pub async fn run<F, Fut>(&self, json: bool, mut tick: F) -> Result<()>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<()>>,
+    {
+        let Some(seconds) = self.watch else {
+            return tick().await;
+        };
+        let clear = !json && std::io::stdout().is_terminal();
+
+        loop {
+            if clear {
+                print!("\x1b[2J\x1b[H");
+                println!(
+                    "🔄 Every {seconds}s, last run {} (Ctrl-C to stop)\n",
+                    chrono::Local::now().format("%H:%M:%S")
+                );
+            }
+            if let Err(e) = tick().await {
+                eprintln!("❌ {e:#}");
+            }
+
+            tokio::select! {
+                _ = tokio::time::sleep(Duration::from_secs(seconds)) => {}
+                _ = tokio::signal::ctrl_c() => return Ok(()),
+            }
+        }
+    }
+}
+
+/// Send a request body read verbatim from a file, or stdin for `-` (`--raw-json`)
+///
+/// The content is only checked to be well-formed JSON. Prints the raw JSON response.
+pub async fn send_raw_json(
+    client: &AnytypeClient,
+    method: Method,
+    path: &str,
+    source: &str,
+    dry_run: bool,
+) -> Result<()> {
+    let content = if source == "-" {
+        let mut content = String::new();
+        std::io::stdin()
+            .read_to_string(&mut content)
+            .context("Failed to read request body from stdin")?;
+        content
+    } else {
+        std::fs::read_to_string(source)
+            .with_context(|| format!("Failed to read request body from '{source}'"))?
+    };
+    let body: serde_json::Value =
+        serde_json::from_str(&content).context("--raw-json is not valid JSON")?;
+
+    if dry_run {
+        return print_dry_run_with_body(method.as_str(), path, &body);
+    }
+
+    println!("⚠️  Sending raw request body (unstable, not validated)...");
+    let response = client
+        .raw_request(method, path, Some(&body))
+        .await
+        .context("Raw request failed")?;
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&response).context("Failed to format response")?
+    );
+
+    Ok(())
+}
+
+/// Number of results a list or search command shows
+///
+/// An explicit `--limit` wins, then `ANYTYPE_PAGE_SIZE` or the page size saved with
+/// `config page-size`, then the command's own `fallback`.
+pub fn page_size(limit: Option<u32>, fallback: u32) -> Result<u32> {
+    match limit {
+        Some(limit) => Ok(limit),
+        None => Ok(crate::config::default_page_size()?.unwrap_or(fallback)),
+    }
+}
+
+/// Ctrl-C watcher for commands that send many requests
+///
+/// After the first Ctrl-C, [`Interrupt::is_set`] is true: the command stops starting new
+/// requests, waits for those in flight, prints what it got done and then fails through
+/// [`Interrupt::check`]. A second Ctrl-C quits straight away.
+pub struct Interrupt {
+    flag: Arc<AtomicBool>,
+    watcher: tokio::task::JoinHandle<()>,
+}
+
+impl Interrupt {
+    /// Start watching for Ctrl-C
+    pub fn watch() -> Self {
+        let flag = Arc::new(AtomicBool::new(false));
+        let watcher = tokio::spawn({
+            let flag = Arc::clone(&flag);
+            async move {
+                if tokio::signal::ctrl_c().await.is_err() {
+                    return;
+                }
+                flag.store(true, Ordering::SeqCst);
+                eprintln!(
+                    "\n⚠️  Interrupted: finishing requests in flight (Ctrl-C again to quit now)"
+                );
+                if tokio::signal::ctrl_c().await.is_ok() {
+                    std::process::exit(130);
+                }
+            }
+        });
+        Self { flag, watcher }
+    }
+
+    /// Whether Ctrl-C has been pressed
+    pub fn is_set(&self) -> bool {
+        self.flag.load(Ordering::SeqCst)
+    }
+
+    /// The flag set by Ctrl-C, for library batch calls that stop early
+    pub fn flag(&self) -> &AtomicBool {
+        &self.flag
+    }
+
+    /// Fail if Ctrl-C was pressed; call after printing the summary of what was done
+    pub fn check(&self) -> Result<()> {
+        if self.is_set() {
+            bail!("Interrupted before every request was sent; see the summary above");
+        }
+        Ok(())
+    }
+}
+
+impl Drop for Interrupt {
+    fn drop(&mut self) {
+        self.watcher.abort();
+    }
+}
+
+/// Report the requests of a batch left unsent after Ctrl-C, if any
+pub fn print_not_sent(not_sent: usize) {
+    if not_sent > 0 {
+        println!("⏹️  Interrupted: {not_sent} not sent");
+    }
+}
+
+/// Count a failed request of a batch, aborting the command under `--fail-fast`
+pub fn record_failure(failed: &mut usize, policy: BatchErrorPolicy) -> Result<()> {
+    *failed += 1;
+    if policy == BatchErrorPolicy::FailFast {
+        bail!("Stopped at the first failure (--fail-fast)");
+    }
+    Ok(())
+}
+
+/// Print the request a mutating command would send instead of sending it (`--dry-run`)
+pub fn print_dry_run(method: &str, path: &str) {
+    println!("🔍 Dry-run mode - no request sent");
+    println!("  {method} {path}");
+}
+
+/// Like [`print_dry_run`], followed by the request body as pretty JSON
+pub fn print_dry_run_with_body<B: Serialize>(method: &str, path: &str, body: &B) -> Result<()> {
+    print_dry_run(method, path);
+    let json = serde_json::to_string_pretty(body).context("Failed to serialize request")?;
+    println!("{json}");
+    Ok(())
+}
+
+/// Print a prompt and read a trimmed line from stdin
+pub fn prompt(label: &str) -> Result<String> {
+    print!("{label}");
+    std::io::stdout().flush()?;
+
+    let mut input = String::new();
+    if std::io::stdin().read_line(&mut input)? == 0 {
+        bail!("No input: stdin was closed");
+    }
+    Ok(input.trim().to_string())
+}
@@ -1,6 +1,17 @@
-use anyhow::{Context, Result};
-use anytype_rs::api::{AnytypeClient, CreateObjectRequest, UpdateObjectRequest};
+use anyhow::{Context, Result, bail};
+use anytype_rs::api::{
+    AnytypeClient, Change, CreateObjectRequest, Method, SearchObject, SearchSpaceRequest,
+    SyncToken, TemplateOverrides, UpdateObjectRequest,
+};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 use clap::{Args, Subcommand};
+use std::io::{IsTerminal, Read};
+use std::time::SystemTime;
+
+/// Number of search results checked when resolving an object by name
+const NAME_SEARCH_LIMIT: usize = 20;
+/// Number of search results fetched per request when collecting every match
+const SEARCH_PAGE_SIZE: usize = 100;
 
 #[derive(Debug, Args)]
 pub struct ObjectArgs {
@@ -14,20 +25,96 @@ pub enum ObjectCommand {
     List {
         /// Space ID
         space_id: String,
-        /// Limit the number of results
-        #[arg(short, long, default_value = "10")]
-        limit: u32,
+        /// Limit the number of results (default 10, or the configured page size)
+        #[arg(short, long)]
+        limit: Option<u32>,
+        /// Only list objects modified at or after this date (YYYY-MM-DD, UTC) or RFC 3339 time
+        #[arg(long)]
+        since: Option<String>,
+        /// Print the objects as a JSON array with all their fields
+        #[arg(long)]
+        json: bool,
+        #[command(flatten)]
+        watch: super::WatchArgs,
+    },
+    /// List objects created, updated or deleted since a sync token
+    Changes {
+        /// Space ID
+        space_id: String,
+        /// Token printed by the previous run; without it every object is listed as created
+        #[arg(long)]
+        since_token: Option<String>,
+    },
+    /// Show an object with its properties and markdown body
+    Get {
+        /// Space ID
+        space_id: String,
+        /// Object ID
+        object_id: String,
+        /// Pretty-print the markdown body (ignored when output is not a terminal)
+        #[arg(long)]
+        render: bool,
     },
     /// Create a new object in a space
     Create {
         /// Space ID
         space_id: String,
         /// Name of the object
-        #[arg(short, long)]
-        name: String,
+        #[arg(short, long, required_unless_present = "raw_json")]
+        name: Option<String>,
         /// Object type key (required)
         #[arg(short = 't', long, default_value = "page")]
         type_key: String,
+        /// Body/content for the object (supports Markdown, use - to read from stdin)
+        #[arg(short, long)]
+        body: Option<String>,
+        /// Property to set, as key=value (repeatable). Values are converted to the property's
+        /// format; select values are tag names, multi-select values are comma-separated.
+        #[arg(short = 'p', long = "property", value_name = "KEY=VALUE")]
+        properties: Vec<String>,
+        /// Template (name or ID) of the type to start from; its body and property values
+        /// are used unless overridden by --body or --property
+        #[arg(long)]
+        template: Option<String>,
+        /// Collection (name or ID) to add the new object to. The API has no other way of
+        /// nesting objects, so the parent must be a collection.
+        #[arg(long, value_name = "NAME|ID")]
+        parent: Option<String>,
+        /// [Advanced, unstable] Send this JSON request body (file path, or - for stdin)
+        /// verbatim instead of building the request from the other flags
+        #[arg(
+            long,
+            value_name = "FILE|-",
+            conflicts_with_all = ["name", "body", "properties", "template", "parent"]
+        )]
+        raw_json: Option<String>,
+        /// Print the request that would be sent without sending it
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Create a new object from a local markdown template file
+    ///
+    /// The template's frontmatter holds default property values and may name the type
+    /// with `type`. `{{date}}` and `{{time}}` are replaced with the current date and
+    /// time, and fields left empty are asked for.
+    New {
+        /// Space ID
+        space_id: String,
+        /// Path to the template file
+        #[arg(long, value_name = "FILE")]
+        template: String,
+        /// Object type key (defaults to the template's `type`, then "page")
+        #[arg(short = 't', long)]
+        type_key: Option<String>,
+        /// Name of the object, overriding the template's `title`
+        #[arg(short, long)]
+        name: Option<String>,
+        /// Property to set, as key=value (repeatable), overriding the template's value
+        #[arg(short = 'p', long = "property", value_name = "KEY=VALUE")]
+        properties: Vec<String>,
+        /// Show what would be created without creating it
+        #[arg(long)]
+        dry_run: bool,
     },
     /// Update an existing object in a space
     Update {
@@ -38,9 +125,98 @@ pub enum ObjectCommand {
         /// New name for the object
         #[arg(short, long)]
         name: Option<String>,
-        /// New body/content for the object (supports Markdown)
+        /// New body/content for the object (supports Markdown, use - to read from stdin)
         #[arg(short, long)]
         body: Option<String>,
+        /// Print the request that would be sent without sending it
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Append markdown to the end of an object's body, keeping the existing content
+    Append {
+        /// Space ID
+        space_id: String,
+        /// Object ID to append to
+        object_id: String,
+        /// Markdown text to append (use - to read from stdin)
+        text: String,
+        /// Print the request that would be sent without sending it
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Clear the value of a property on an object
+    UnsetProperty {
+        /// Space ID
+        space_id: String,
+        /// Object ID to update
+        object_id: String,
+        /// Key or name of the property to clear
+        #[arg(short, long)]
+        property: String,
+        /// Print the request that would be sent without sending it
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Set a property on an object, or on every object matching a search
+    SetProperty {
+        /// Object ID to update (omit when using --search)
+        #[arg(required_unless_present = "search", conflicts_with = "search")]
+        object_id: Option<String>,
+        /// Space ID
+        #[arg(short, long)]
+        space: String,
+        /// Update every object matching this search query
+        #[arg(long)]
+        search: Option<String>,
+        /// Key or name of the property to set
+        #[arg(short, long)]
+        property: String,
+        /// New value; tag names for select properties, comma-separated for multi-select
+        #[arg(long)]
+        value: String,
+        /// Update the objects matching --search; without it they are only listed
+        #[arg(long)]
+        yes: bool,
+        /// Check the property against each object's type before updating anything
+        #[arg(long)]
+        validate: bool,
+        /// Print the requests that would be sent without sending them
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Link an object to another through a relation property, keeping existing links
+    Link {
+        /// Space ID
+        space_id: String,
+        /// ID of the object whose property is updated
+        from: String,
+        /// ID of the object to link to
+        to: String,
+        /// Key of the relation (objects-format) property
+        #[arg(short, long)]
+        property: String,
+        /// Print the request that would be sent without sending it
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Open an object in the Anytype desktop app
+    Open {
+        /// Object name or ID
+        object: String,
+        /// Space ID
+        #[arg(short, long)]
+        space: String,
+        /// Print the anytype:// link instead of opening it
+        #[arg(long)]
+        print: bool,
+    },
+    /// Print the URL of an object on its space's gateway
+    Url {
+        /// Object name or ID
+        object: String,
+        /// Space ID
+        #[arg(short, long)]
+        space: String,
     },
     /// Delete an object in a space (archives it)
     Delete {
@@ -48,6 +224,9 @@ pub enum ObjectCommand {
         space_id: String,
         /// Object ID to delete
         object_id: String,
+        /// Print the request that would be sent without sending it
+        #[arg(long)]
+        dry_run: bool,
     },
 }
 
@@ -59,22 +238,190 @@ pub async fn handle_object_command(args: ObjectArgs) -> Result<()> {
     client.set_api_key(api_key);
 
     match args.command {
-        ObjectCommand::List { space_id, limit } => list_objects(&client, &space_id, limit).await,
+        ObjectCommand::List {
+            space_id,
+            limit,
+            since,
+            json,
+            watch,
+        } => {
+            let since = since.as_deref().map(parse_since).transpose()?;
+            let limit = super::page_size(limit, 10)?;
+            watch
+                .run(json, || {
+                    show_objects(&client, &space_id, since, limit, json)
+                })
+                .await
+        }
+        ObjectCommand::Changes {
+            space_id,
+            since_token,
+        } => list_changes(&client, &space_id, since_token.as_deref()).await,
+        ObjectCommand::Get {
+            space_id,
+            object_id,
+            render,
+        } => get_object(&client, &space_id, &object_id, render).await,
+        ObjectCommand::Create {
+            space_id,
+            raw_json: Some(raw_json),
+            dry_run,
+            ..
+        } => {
+            let path = format!("/v1/spaces/{space_id}/objects");
+            super::send_raw_json(&client, Method::POST, &path, &raw_json, dry_run).await
+        }
         ObjectCommand::Create {
             space_id,
             name,
             type_key,
-        } => create_object(&client, &space_id, &name, &type_key).await,
+            body,
+            properties,
+            template,
+            parent,
+            dry_run,
+            ..
+        } => {
+            let name = name.context("--name is required")?;
+            let body = read_body(body)?;
+            let parent = match parent {
+                Some(parent) => Some(resolve_parent(&client, &space_id, &parent).await?),
+                None => None,
+            };
+            let parent = parent.as_deref();
+            match template {
+                Some(template) => {
+                    create_object_from_template(
+                        &client,
+                        &space_id,
+                        &name,
+                        &type_key,
+                        &template,
+                        body,
+                        &properties,
+                        parent,
+                        dry_run,
+                    )
+                    .await
+                }
+                None => {
+                    create_object(
+                        &client,
+                        &space_id,
+                        &name,
+                        &type_key,
+                        body,
+                        &properties,
+                        parent,
+                        dry_run,
+                    )
+                    .await
+                }
+            }
+        }
+        ObjectCommand::New {
+            space_id,
+            template,
+            type_key,
+            name,
+            properties,
+            dry_run,
+        } => {
+            let properties = parse_property_assignments(&properties).map_err(anyhow::Error::msg)?;
+            let args = super::template_local::LocalTemplateArgs {
+                space_id: &space_id,
+                template: &template,
+                type_key: type_key.as_deref(),
+                name: name.as_deref(),
+                properties: &properties,
+                dry_run,
+            };
+            super::template_local::create_from_local_template(&client, args).await
+        }
         ObjectCommand::Update {
             space_id,
             object_id,
             name,
             body,
-        } => update_object(&client, &space_id, &object_id, name, body).await,
+            dry_run,
+        } => {
+            update_object(
+                &client,
+                &space_id,
+                &object_id,
+                name,
+                read_body(body)?,
+                dry_run,
+            )
+            .await
+        }
+        ObjectCommand::Append {
+            space_id,
+            object_id,
+            text,
+            dry_run,
+        } => {
+            let text = read_body(Some(text))?.unwrap_or_default();
+            append_object(&client, &space_id, &object_id, &text, dry_run).await
+        }
+        ObjectCommand::UnsetProperty {
+            space_id,
+            object_id,
+            property,
+            dry_run,
+        } => unset_property(&client, &space_id, &object_id, &property, dry_run).await,
+        ObjectCommand::SetProperty {
+            object_id,
+            space,
+            search,
+            property,
+            value,
+            yes,
+            validate,
+            dry_run,
+        } => {
+            let target = match (object_id, search) {
+                (Some(object_id), _) => PropertyTarget::Object(object_id),
+                (None, Some(query)) => PropertyTarget::Search { query, yes },
+                (None, None) => unreachable!("clap requires an object ID or --search"),
+            };
+            set_property(
+                &client, &space, target, &property, &value, validate, dry_run,
+            )
+            .await
+        }
+        ObjectCommand::Link {
+            space_id,
+            from,
+            to,
+            property,
+            dry_run,
+        } => link_objects(&client, &space_id, &from, &to, &property, dry_run).await,
         ObjectCommand::Delete {
             space_id,
             object_id,
-        } => delete_object(&client, &space_id, &object_id).await,
+            dry_run,
+        } => delete_object(&client, &space_id, &object_id, dry_run).await,
+        ObjectCommand::Open {
+            object,
+            space,
+            print,
+        } => open_object(&client, &space, &object, print).await,
+        ObjectCommand::Url { object, space } => print_gateway_url(&client, &space, &object).await,
+    }
+}
+
+/// Resolve a body argument, reading it from stdin when given as `-`
+fn read_body(body: Option<String>) -> Result<Option<String>> {
+    match body.as_deref() {
+        Some("-") => {
+            let mut content = String::new();
+            std::io::stdin()
+                .read_to_string(&mut content)
+                .context("Failed to read body from stdin")?;
+            Ok(Some(content))
+        }
+        _ => Ok(body),
     }
 }
 
@@ -97,21 +444,21 @@ async fn list_objects(client: &AnytypeClient, space_id: &str, limit: u32) -> Res
 
     for object in objects.into_iter().take(display_count) {
         println!(
-            "  📄 {} (Space: {})",
+            "  📄 {} - {} (Space: {})",
             object.id,
+            object.display_name(),
             object.space_id.as_deref().unwrap_or("Unknown")
         );
-        if let Some(properties) = object.properties.as_object() {
-            for (key, value) in properties.iter().take(3) {
-                println!(
-                    "    🔑 {}: {}",
-                    key,
-                    serde_json::to_string(value).unwrap_or_else(|_| "N/A".to_string())
-                );
-            }
-            if properties.len() > 3 {
-                println!("    ... and {} more properties", properties.len() - 3);
-            }
+        let properties: Vec<_> = object.properties_iter().collect();
+        for (key, value) in properties.iter().take(3) {
+            println!(
+                "    🔑 {}: {}",
+                key,
+                serde_json::to_string(value).unwrap_or_else(|_| "N/A".to_string())
+            );
+        }
+        if properties.len() > 3 {
+            println!("    ... and {} more properties", properties.len() - 3);
         }
         println!();
     }
@@ -123,27 +470,219 @@ async fn list_objects(client: &AnytypeClient, space_id: &str, limit: u32) -> Res
     Ok(())
 }
 
+/// `object list`, once per run under `--watch`
+async fn show_objects(
+    client: &AnytypeClient,
+    space_id: &str,
+    since: Option<SystemTime>,
+    limit: u32,
+    json: bool,
+) -> Result<()> {
+    match (json, since) {
+        (true, _) => list_objects_json(client, space_id, since, limit).await,
+        (false, Some(since)) => list_modified_objects(client, space_id, since, limit).await,
+        (false, None) => list_objects(client, space_id, limit).await,
+    }
+}
+
+async fn list_objects_json(
+    client: &AnytypeClient,
+    space_id: &str,
+    since: Option<SystemTime>,
+    limit: u32,
+) -> Result<()> {
+    let objects = match since {
+        Some(since) => client.objects_modified_since(space_id, since).await,
+        None => client.list_objects(space_id).await,
+    }
+    .context("Failed to fetch objects")?;
+
+    let shown = &objects[..(limit as usize).min(objects.len())];
+    let json = serde_json::to_string_pretty(shown).context("Failed to serialize objects")?;
+    println!("{json}");
+
+    Ok(())
+}
+
+async fn list_changes(
+    client: &AnytypeClient,
+    space_id: &str,
+    since_token: Option<&str>,
+) -> Result<()> {
+    let token = since_token
+        .map(SyncToken::parse)
+        .transpose()
+        .context("Invalid --since-token")?;
+    println!("🔄 Fetching changes in space '{space_id}'...");
+
+    let (changes, next) = client
+        .changes_since(space_id, token.as_ref())
+        .await
+        .context("Failed to fetch changes")?;
+
+    if changes.is_empty() {
+        println!("📭 No changes.");
+    } else {
+        println!("✅ Found {} changes:", changes.len());
+    }
+    for change in &changes {
+        let icon = match change {
+            Change::Created(_) => "➕",
+            Change::Updated(_) => "✏️ ",
+            Change::Deleted(_) => "🗑️ ",
+        };
+        let object = change.object();
+        println!(
+            "  {icon} {} {} - {}",
+            change.kind(),
+            object.id,
+            object.display_name()
+        );
+    }
+    println!("🔖 Next token: {next}");
+
+    Ok(())
+}
+
+async fn get_object(
+    client: &AnytypeClient,
+    space_id: &str,
+    object_id: &str,
+    render: bool,
+) -> Result<()> {
+    let object = client
+        .get_object(space_id, object_id)
+        .await
+        .context("Failed to fetch object")?;
+
+    println!("📄 {}", object.display_name());
+    println!("   🆔 ID: {}", object.id);
+    if let Some(object_type) = &object.object {
+        println!("   🏷️  Type: {object_type}");
+    }
+    for (key, value) in object.properties_iter() {
+        println!(
+            "   🔑 {}: {}",
+            key,
+            serde_json::to_string(value).unwrap_or_else(|_| "N/A".to_string())
+        );
+    }
+
+    if let Some(markdown) = object.markdown.as_deref().filter(|m| !m.trim().is_empty()) {
+        println!();
+        // Escape codes would end up in files and pipes, so only render for a terminal
+        if render && std::io::stdout().is_terminal() {
+            print!("{}", crate::render::render_markdown(markdown));
+        } else {
+            println!("{markdown}");
+        }
+    }
+
+    Ok(())
+}
+
+/// Parse a `--since` value: a plain date (midnight UTC) or a full RFC 3339 timestamp
+fn parse_since(since: &str) -> Result<SystemTime> {
+    if let Ok(date) = NaiveDate::parse_from_str(since, "%Y-%m-%d") {
+        let midnight = date.and_time(NaiveTime::MIN).and_utc();
+        return Ok(midnight.into());
+    }
+
+    DateTime::parse_from_rfc3339(since)
+        .map(SystemTime::from)
+        .with_context(|| {
+            format!("Invalid --since '{since}'. Expected YYYY-MM-DD or an RFC 3339 timestamp")
+        })
+}
+
+async fn list_modified_objects(
+    client: &AnytypeClient,
+    space_id: &str,
+    since: SystemTime,
+    limit: u32,
+) -> Result<()> {
+    let since_label = DateTime::<Utc>::from(since).to_rfc3339();
+    println!("📄 Fetching objects modified since {since_label} from space '{space_id}'...");
+
+    let objects = client
+        .objects_modified_since(space_id, since)
+        .await
+        .context("Failed to fetch objects")?;
+
+    if objects.is_empty() {
+        println!("📭 No objects modified since {since_label}.");
+        return Ok(());
+    }
+
+    let display_count = (limit as usize).min(objects.len());
+    let total_objects = objects.len();
+    println!("✅ Found {total_objects} modified objects (showing first {display_count}):");
+
+    for object in objects.into_iter().take(display_count) {
+        let modified = object
+            .updated_at()
+            .map(|time| DateTime::<Utc>::from(time).to_rfc3339())
+            .unwrap_or_default();
+        println!(
+            "  📄 {} - {} (modified {modified})",
+            object.id,
+            object.display_name()
+        );
+    }
+
+    if total_objects > display_count {
+        println!("💡 Use --limit {total_objects} to see more results");
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
 async fn create_object(
     client: &AnytypeClient,
     space_id: &str,
     name: &str,
     type_key: &str,
+    body: Option<String>,
+    assignments: &[String],
+    parent: Option<&str>,
+    dry_run: bool,
 ) -> Result<()> {
-    println!("📝 Creating object '{name}' in space '{space_id}'...");
+    let assignments = match parse_property_assignments(assignments) {
+        Ok(assignments) => assignments,
+        Err(e) => {
+            println!("❌ {e}");
+            return Ok(());
+        }
+    };
 
-    let request = CreateObjectRequest {
-        name: Some(name.to_string()),
-        type_key: type_key.to_string(),
-        body: None,
-        icon: None,
-        template_id: None,
-        properties: None,
+    let mut request = CreateObjectRequest {
+        body,
+        ..CreateObjectRequest::new(type_key).name(name)
     };
+    if !assignments.is_empty() {
+        let properties = resolve_property_values(client, space_id, type_key, &assignments).await?;
+        request = request.properties(properties);
+    }
 
-    let response = client
-        .create_object(space_id, request)
-        .await
-        .context("Failed to create object")?;
+    if dry_run {
+        let path = format!("/v1/spaces/{space_id}/objects");
+        super::print_dry_run_with_body("POST", &path, &request)?;
+        print_parent_dry_run(space_id, parent);
+        return Ok(());
+    }
+
+    println!("📝 Creating object '{name}' in space '{space_id}'...");
+
+    let response = match parent {
+        Some(parent) => {
+            client
+                .create_object_in_list(space_id, parent, request)
+                .await
+        }
+        None => client.create_object(space_id, request).await,
+    }
+    .context("Failed to create object")?;
 
     println!("✅ Object created successfully!");
     println!("   📄 Object ID: {}", response.object.id);
@@ -151,23 +690,313 @@ async fn create_object(
         "   🏠 Space ID: {}",
         response.object.space_id.as_deref().unwrap_or("Unknown")
     );
-    println!(
-        "   📝 Name: {}",
-        response.object.name.as_deref().unwrap_or("Unnamed")
-    );
+    println!("   📝 Name: {}", response.object.display_name());
     if let Some(object_type) = &response.object.object {
         println!("   🏷️  Type: {object_type}");
     }
+    if let Some(parent) = parent {
+        println!("   📁 Collection: {parent}");
+    }
+
+    Ok(())
+}
+
+/// Create an object from one of its type's templates
+#[allow(clippy::too_many_arguments)]
+async fn create_object_from_template(
+    client: &AnytypeClient,
+    space_id: &str,
+    name: &str,
+    type_key: &str,
+    template: &str,
+    body: Option<String>,
+    assignments: &[String],
+    parent: Option<&str>,
+    dry_run: bool,
+) -> Result<()> {
+    let assignments = match parse_property_assignments(assignments) {
+        Ok(assignments) => assignments,
+        Err(e) => {
+            println!("❌ {e}");
+            return Ok(());
+        }
+    };
+
+    let type_id = client
+        .type_id_for_key(space_id, type_key)
+        .await
+        .with_context(|| format!("Failed to find type '{type_key}' in space '{space_id}'"))?;
+    let templates = client
+        .list_templates(space_id, &type_id)
+        .await
+        .context("Failed to list templates")?;
+    let Some(found) = templates.into_iter().find(|t| {
+        t.id == template
+            || t.name
+                .as_deref()
+                .is_some_and(|name| name.eq_ignore_ascii_case(template))
+    }) else {
+        println!("❌ Type '{type_key}' has no template named '{template}'");
+        return Ok(());
+    };
+
+    let properties = if assignments.is_empty() {
+        Vec::new()
+    } else {
+        resolve_property_values(client, space_id, type_key, &assignments).await?
+    };
+    let overrides = TemplateOverrides {
+        name: Some(name.to_string()),
+        body,
+        properties,
+    };
+
+    if dry_run {
+        let path = format!("/v1/spaces/{space_id}/objects");
+        let request = found
+            .object_request(overrides)
+            .context("Failed to apply template")?;
+        super::print_dry_run_with_body("POST", &path, &request)?;
+        print_parent_dry_run(space_id, parent);
+        return Ok(());
+    }
+
+    println!(
+        "📝 Creating object '{name}' from template '{}' in space '{space_id}'...",
+        found.name.as_deref().unwrap_or("Unnamed")
+    );
+
+    let response = client
+        .create_object_from_template(space_id, &type_id, &found.id, overrides)
+        .await
+        .context("Failed to create object from template")?;
+
+    println!("✅ Object created successfully!");
+    println!("   📄 Object ID: {}", response.object.id);
+    println!("   📝 Name: {}", response.object.display_name());
+    println!("   📋 Template: {}", found.id);
+
+    if let Some(parent) = parent {
+        client
+            .add_list_objects(space_id, parent, vec![response.object.id.clone()])
+            .await
+            .with_context(|| {
+                format!("Object created, but failed to add it to collection '{parent}'")
+            })?;
+        println!("   📁 Collection: {parent}");
+    }
+
+    Ok(())
+}
+
+/// Resolve `--parent` to a collection ID
+///
+/// The name is looked up with a space search; a value that matches no object by name is
+/// taken to be an ID. Objects found by name that are not collections are rejected before
+/// anything is created.
+async fn resolve_parent(client: &AnytypeClient, space_id: &str, parent: &str) -> Result<String> {
+    let request = SearchSpaceRequest {
+        query: Some(parent.to_string()),
+        limit: Some(NAME_SEARCH_LIMIT),
+        offset: None,
+        sort: None,
+        include_archived: false,
+    };
+    let results = client
+        .search_space_objects(space_id, request)
+        .await
+        .with_context(|| format!("Failed to look up parent '{parent}'"))?;
+
+    let Some(found) = results
+        .into_iter()
+        .find(|object| object.id == parent || object.name.eq_ignore_ascii_case(parent))
+    else {
+        return Ok(parent.to_string());
+    };
+    if found
+        .r#type
+        .as_ref()
+        .is_some_and(|t| t.layout != anytype_rs::api::types::Layout::Collection)
+    {
+        bail!(
+            "'{}' is not a collection; only collections can contain other objects",
+            found.name
+        );
+    }
+
+    Ok(found.id)
+}
+
+/// Resolve an object given by name or ID to its ID
+///
+/// The name is looked up with a space search; a value that matches no object by name is
+/// taken to be an ID.
+async fn resolve_object_id(client: &AnytypeClient, space_id: &str, object: &str) -> Result<String> {
+    let request = SearchSpaceRequest {
+        query: Some(object.to_string()),
+        limit: Some(NAME_SEARCH_LIMIT),
+        offset: None,
+        sort: None,
+        include_archived: false,
+    };
+    Ok(client
+        .search_space_objects(space_id, request)
+        .await
+        .with_context(|| format!("Failed to look up object '{object}'"))?
+        .into_iter()
+        .find(|found| found.id == object || found.name.eq_ignore_ascii_case(object))
+        .map_or_else(|| object.to_string(), |found| found.id))
+}
+
+/// Open an object by name or ID through its `anytype://` link
+async fn open_object(
+    client: &AnytypeClient,
+    space_id: &str,
+    object: &str,
+    print: bool,
+) -> Result<()> {
+    let object_id = resolve_object_id(client, space_id, object).await?;
+    let found = client
+        .get_object(space_id, &object_id)
+        .await
+        .with_context(|| format!("Failed to get object '{object}'"))?;
+
+    let link = found.deep_link(space_id);
+    if print {
+        println!("{link}");
+        return Ok(());
+    }
+    open::that_detached(&link).with_context(|| format!("Failed to open {link}"))?;
+    println!("🔗 Opened {} in Anytype", found.display_name());
 
     Ok(())
 }
 
+async fn print_gateway_url(client: &AnytypeClient, space_id: &str, object: &str) -> Result<()> {
+    let object_id = resolve_object_id(client, space_id, object).await?;
+    let space = client
+        .get_space(space_id)
+        .await
+        .context("Failed to get space")?;
+
+    match space.gateway_object_url(&object_id) {
+        Some(url) => println!("{url}"),
+        None => bail!("Space '{}' has no gateway URL", space.name),
+    }
+
+    Ok(())
+}
+
+/// Note the follow-up request `--parent` adds to a dry run
+fn print_parent_dry_run(space_id: &str, parent: Option<&str>) {
+    if let Some(parent) = parent {
+        println!("  POST /v1/spaces/{space_id}/lists/{parent}/objects (with the new object's ID)");
+    }
+}
+
+/// Split `key=value` flags into pairs, rejecting entries without a key
+fn parse_property_assignments(
+    assignments: &[String],
+) -> std::result::Result<Vec<(String, String)>, String> {
+    assignments
+        .iter()
+        .map(|assignment| match assignment.split_once('=') {
+            Some((key, value)) if !key.trim().is_empty() => {
+                Ok((key.trim().to_string(), value.trim().to_string()))
+            }
+            _ => Err(format!(
+                "Invalid property '{assignment}': expected key=value"
+            )),
+        })
+        .collect()
+}
+
+/// Convert `key=value` pairs to the formats of the type's properties
+///
+/// Select and multi-select values are given as tag names (or IDs) and sent as tag IDs.
+/// Returns a `{"key": ..., "<format>": value}` entry for each assignment.
+async fn resolve_property_values(
+    client: &AnytypeClient,
+    space_id: &str,
+    type_key: &str,
+    assignments: &[(String, String)],
+) -> Result<Vec<serde_json::Value>> {
+    let type_data = client
+        .get_type_by_key(space_id, type_key)
+        .await
+        .with_context(|| format!("Failed to fetch type '{type_key}' from space '{space_id}'"))?;
+
+    let mut properties = Vec::new();
+    for (key, value) in assignments {
+        let prop = type_data
+            .properties
+            .iter()
+            .find(|p| p.key.eq_ignore_ascii_case(key))
+            .ok_or_else(|| anyhow::anyhow!("Type '{type_key}' has no property '{key}'"))?;
+
+        let value =
+            convert_property_value(client, space_id, &prop.id, key, &prop.format, value).await?;
+        properties.push(serde_json::json!({ "key": prop.key, prop.format.clone(): value }));
+    }
+
+    Ok(properties)
+}
+
+/// Convert a value given on the command line to the format of a property
+///
+/// Select and multi-select values are given as tag names (or IDs) and sent as tag IDs.
+async fn convert_property_value(
+    client: &AnytypeClient,
+    space_id: &str,
+    property_id: &str,
+    key: &str,
+    format: &str,
+    value: &str,
+) -> Result<serde_json::Value> {
+    match format {
+        "select" | "multi_select" => {
+            let tags = client
+                .list_tags(space_id, property_id)
+                .await
+                .with_context(|| format!("Failed to fetch tags of property '{key}'"))?;
+            let tag_ids = value
+                .split(',')
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .map(|name| {
+                    tags.iter()
+                        .find(|tag| tag.name.eq_ignore_ascii_case(name) || tag.id == name)
+                        .map(|tag| serde_json::Value::String(tag.id.clone()))
+                        .ok_or_else(|| {
+                            anyhow::anyhow!("Property '{key}' has no tag named '{name}'")
+                        })
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            if format == "select" {
+                match tag_ids.as_slice() {
+                    [tag_id] => Ok(tag_id.clone()),
+                    _ => anyhow::bail!("Property '{key}' takes exactly one tag"),
+                }
+            } else {
+                Ok(serde_json::Value::Array(tag_ids))
+            }
+        }
+        format => crate::commands::import::convert_value_to_format_str(
+            &serde_json::Value::String(value.to_string()),
+            format,
+        )
+        .with_context(|| format!("Invalid value for property '{key}'")),
+    }
+}
+
 async fn update_object(
     client: &AnytypeClient,
     space_id: &str,
     object_id: &str,
     name: Option<String>,
     body: Option<String>,
+    dry_run: bool,
 ) -> Result<()> {
     // Check if at least one field is provided for update
     if name.is_none() && body.is_none() {
@@ -176,18 +1005,33 @@ async fn update_object(
         ));
     }
 
-    println!("🔄 Updating object '{object_id}' in space '{space_id}'...");
-
     let request = UpdateObjectRequest {
         name,
         body,
         properties: None, // For now, we don't support updating properties via CLI
     };
 
-    let response = client
-        .update_object(space_id, object_id, request)
-        .await
-        .context("Failed to update object")?;
+    if dry_run {
+        let path = format!("/v1/spaces/{space_id}/objects/{object_id}");
+        return super::print_dry_run_with_body("PATCH", &path, &request);
+    }
+
+    println!("🔄 Updating object '{object_id}' in space '{space_id}'...");
+
+    let response = match request {
+        UpdateObjectRequest {
+            name: Some(name),
+            body: None,
+            ..
+        } => client.rename_object(space_id, object_id, &name).await,
+        UpdateObjectRequest {
+            name: None,
+            body: Some(body),
+            ..
+        } => client.set_object_markdown(space_id, object_id, &body).await,
+        request => client.update_object(space_id, object_id, request).await,
+    }
+    .context("Failed to update object")?;
 
     println!("✅ Object updated successfully!");
     println!("   📄 Object ID: {}", response.object.id);
@@ -195,10 +1039,7 @@ async fn update_object(
         "   🏠 Space ID: {}",
         response.object.space_id.as_deref().unwrap_or("Unknown")
     );
-    println!(
-        "   📝 Name: {}",
-        response.object.name.as_deref().unwrap_or("Unnamed")
-    );
+    println!("   📝 Name: {}", response.object.display_name());
     if let Some(object_type) = &response.object.object {
         println!("   🏷️  Type: {object_type}");
     }
@@ -209,7 +1050,257 @@ async fn update_object(
     Ok(())
 }
 
-async fn delete_object(client: &AnytypeClient, space_id: &str, object_id: &str) -> Result<()> {
+async fn append_object(
+    client: &AnytypeClient,
+    space_id: &str,
+    object_id: &str,
+    text: &str,
+    dry_run: bool,
+) -> Result<()> {
+    if dry_run {
+        let path = format!("/v1/spaces/{space_id}/objects/{object_id}");
+        super::print_dry_run("PATCH", &path);
+        println!("  ➕ Would append {} characters to the body", text.len());
+        return Ok(());
+    }
+
+    println!("➕ Appending to object '{object_id}' in space '{space_id}'...");
+
+    let response = client
+        .append_markdown(space_id, object_id, text)
+        .await
+        .context("Failed to append to object")?;
+
+    println!("✅ Text appended successfully!");
+    println!("   📄 Object ID: {}", response.object.id);
+    if let Some(body) = &response.body {
+        println!("   📄 Body: {} characters", body.len());
+    }
+
+    Ok(())
+}
+
+async fn unset_property(
+    client: &AnytypeClient,
+    space_id: &str,
+    object_id: &str,
+    property: &str,
+    dry_run: bool,
+) -> Result<()> {
+    if dry_run {
+        let path = format!("/v1/spaces/{space_id}/objects/{object_id}");
+        super::print_dry_run("PATCH", &path);
+        println!("  🧹 Would clear '{property}'");
+        return Ok(());
+    }
+
+    println!("🧹 Clearing property '{property}' on object '{object_id}' in space '{space_id}'...");
+
+    let response = client
+        .unset_object_property(space_id, object_id, property)
+        .await
+        .context("Failed to clear property")?;
+
+    println!("✅ Property cleared successfully!");
+    println!("   📄 Object ID: {}", response.object.id);
+
+    Ok(())
+}
+
+/// Objects `object set-property` updates
+enum PropertyTarget {
+    /// A single object, by ID
+    Object(String),
+    /// Every object matching a search; without `yes` they are only listed
+    Search { query: String, yes: bool },
+}
+
+async fn set_property(
+    client: &AnytypeClient,
+    space_id: &str,
+    target: PropertyTarget,
+    property: &str,
+    value: &str,
+    validate: bool,
+    dry_run: bool,
+) -> Result<()> {
+    let properties = client
+        .list_properties(space_id)
+        .await
+        .context("Failed to fetch properties")?;
+    let prop = properties
+        .iter()
+        .find(|p| p.key == property)
+        .or_else(|| properties.iter().find(|p| p.name == property))
+        .ok_or_else(|| anyhow::anyhow!("No property '{property}' found in space '{space_id}'"))?;
+    let value =
+        convert_property_value(client, space_id, &prop.id, &prop.key, &prop.format, value).await?;
+    let entry = serde_json::json!({ "key": prop.key, prop.format.clone(): value });
+
+    let targets = match target {
+        PropertyTarget::Object(object_id) => vec![(object_id.clone(), object_id)],
+        PropertyTarget::Search { query, yes } => {
+            let matches = search_all(client, space_id, &query).await?;
+            println!("🔎 {} objects match '{query}':", matches.len());
+            for object in &matches {
+                println!("  📄 {} ({})", object.display_name(), object.id);
+            }
+            if matches.is_empty() {
+                return Ok(());
+            }
+            if !yes && !dry_run {
+                bail!(
+                    "Pass --yes to set '{}' on these {} objects",
+                    prop.key,
+                    matches.len()
+                );
+            }
+            matches
+                .into_iter()
+                .map(|object| (object.id.clone(), object.display_name()))
+                .collect()
+        }
+    };
+
+    let (ids, names): (Vec<String>, Vec<String>) = targets.into_iter().unzip();
+    let updates: Vec<(String, UpdateObjectRequest)> = ids
+        .into_iter()
+        .map(|object_id| {
+            let request = UpdateObjectRequest {
+                name: None,
+                body: None,
+                properties: Some(vec![entry.clone()]),
+            };
+            (object_id, request)
+        })
+        .collect();
+
+    if dry_run {
+        for (object_id, request) in &updates {
+            let path = format!("/v1/spaces/{space_id}/objects/{object_id}");
+            super::print_dry_run_with_body("PATCH", &path, request)?;
+        }
+        return Ok(());
+    }
+
+    let interrupt = super::Interrupt::watch();
+    if validate {
+        println!(
+            "🔍 Validating '{}' against {} objects...",
+            prop.key,
+            updates.len()
+        );
+        for ((object_id, request), name) in updates.iter().zip(&names) {
+            if interrupt.is_set() {
+                break;
+            }
+            client
+                .validate_object_update(space_id, object_id, request)
+                .await
+                .with_context(|| format!("Validation failed for '{name}'"))?;
+        }
+    }
+
+    println!("✏️  Setting '{}' on {} objects...", prop.key, updates.len());
+
+    let mut updated = 0;
+    let mut failed = 0;
+    let mut not_sent = 0;
+    for (name, result) in names.iter().zip(
+        client
+            .update_objects_until_stopped(space_id, updates, interrupt.flag())
+            .await,
+    ) {
+        match result {
+            Some(Ok(_)) => updated += 1,
+            Some(Err(e)) => {
+                println!("  ❌ Failed to update '{name}': {e}");
+                failed += 1;
+            }
+            None => not_sent += 1,
+        }
+    }
+
+    println!("✅ Property set: {updated} updated, {failed} failed");
+    super::print_not_sent(not_sent);
+    interrupt.check()?;
+    if failed > 0 {
+        bail!("{failed} of {} updates failed", names.len());
+    }
+
+    Ok(())
+}
+
+/// Every object matching a space search, fetched page by page
+async fn search_all(
+    client: &AnytypeClient,
+    space_id: &str,
+    query: &str,
+) -> Result<Vec<SearchObject>> {
+    let mut objects = Vec::new();
+    let mut offset = 0;
+    loop {
+        let request = SearchSpaceRequest {
+            query: Some(query.to_string()),
+            limit: Some(SEARCH_PAGE_SIZE),
+            offset: Some(offset),
+            sort: None,
+            include_archived: false,
+        };
+        let response = client
+            .search_space(space_id, request)
+            .await
+            .with_context(|| format!("Failed to search for '{query}'"))?;
+        objects.extend(response.data);
+
+        if !response.pagination.has_more {
+            return Ok(objects);
+        }
+        offset += SEARCH_PAGE_SIZE;
+    }
+}
+
+async fn link_objects(
+    client: &AnytypeClient,
+    space_id: &str,
+    from: &str,
+    to: &str,
+    property: &str,
+    dry_run: bool,
+) -> Result<()> {
+    if dry_run {
+        super::print_dry_run("PATCH", &format!("/v1/spaces/{space_id}/objects/{from}"));
+        println!("  🔗 Would add '{to}' to '{property}'");
+        return Ok(());
+    }
+
+    println!("🔗 Linking object '{from}' to '{to}' through '{property}' in space '{space_id}'...");
+
+    let response = client
+        .add_object_relation(space_id, from, property, to)
+        .await
+        .context("Failed to link objects")?;
+
+    println!("✅ Objects linked successfully!");
+    println!("   📄 Object ID: {}", response.object.id);
+
+    Ok(())
+}
+
+async fn delete_object(
+    client: &AnytypeClient,
+    space_id: &str,
+    object_id: &str,
+    dry_run: bool,
+) -> Result<()> {
+    if dry_run {
+        super::print_dry_run(
+            "DELETE",
+            &format!("/v1/spaces/{space_id}/objects/{object_id}"),
+        );
+        return Ok(());
+    }
+
     println!("🗑️  Deleting object '{object_id}' in space '{space_id}'...");
 
     let response = client
@@ -223,10 +1314,7 @@ async fn delete_object(client: &AnytypeClient, space_id: &str, object_id: &str)
         "   🏠 Space ID: {}",
         response.object.space_id.as_deref().unwrap_or("Unknown")
     );
-    println!(
-        "   📝 Name: {}",
-        response.object.name.as_deref().unwrap_or("Unnamed")
-    );
+    println!("   📝 Name: {}", response.object.display_name());
     if let Some(object_type) = &response.object.object {
         println!("   🏷️  Type: {object_type}");
     }
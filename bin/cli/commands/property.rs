@@ -16,9 +16,9 @@ pub enum PropertyCommand {
     List {
         /// Space ID
         space_id: String,
-        /// Limit the number of results
-        #[arg(short, long, default_value = "20")]
-        limit: u32,
+        /// Limit the number of results (default 20, or the configured page size)
+        #[arg(short, long)]
+        limit: Option<u32>,
     },
     /// Get details of a specific property
     Get {
@@ -37,6 +37,9 @@ pub enum PropertyCommand {
         /// Property format
         #[arg(short, long, default_value = "text")]
         format: String,
+        /// Print the request that would be sent without sending it
+        #[arg(long)]
+        dry_run: bool,
     },
     /// Update an existing property in a space
     Update {
@@ -50,6 +53,22 @@ pub enum PropertyCommand {
         /// Property key (optional, snake_case)
         #[arg(short, long)]
         key: Option<String>,
+        /// Print the request that would be sent without sending it
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Change the format of a property, keeping the values objects have for it
+    Migrate {
+        /// Space ID
+        space_id: String,
+        /// Property ID to migrate
+        property_id: String,
+        /// New property format
+        #[arg(short, long)]
+        format: String,
+        /// List the tags that would be created and the objects that would be updated
+        #[arg(long)]
+        dry_run: bool,
     },
     /// Delete a property in a space
     Delete {
@@ -57,6 +76,9 @@ pub enum PropertyCommand {
         space_id: String,
         /// Property ID to delete
         property_id: String,
+        /// Print the request that would be sent without sending it
+        #[arg(long)]
+        dry_run: bool,
     },
 }
 
@@ -69,6 +91,7 @@ pub async fn handle_property_command(args: PropertyArgs) -> Result<()> {
 
     match args.command {
         PropertyCommand::List { space_id, limit } => {
+            let limit = super::page_size(limit, 20)?;
             list_properties(&client, &space_id, limit).await
         }
         PropertyCommand::Get {
@@ -79,17 +102,26 @@ pub async fn handle_property_command(args: PropertyArgs) -> Result<()> {
             space_id,
             name,
             format,
-        } => create_property(&client, &space_id, &name, &format).await,
+            dry_run,
+        } => create_property(&client, &space_id, &name, &format, dry_run).await,
         PropertyCommand::Update {
             space_id,
             property_id,
             name,
             key,
-        } => update_property(&client, &space_id, &property_id, &name, key).await,
+            dry_run,
+        } => update_property(&client, &space_id, &property_id, &name, key, dry_run).await,
+        PropertyCommand::Migrate {
+            space_id,
+            property_id,
+            format,
+            dry_run,
+        } => migrate_property(&client, &space_id, &property_id, &format, dry_run).await,
         PropertyCommand::Delete {
             space_id,
             property_id,
-        } => delete_property(&client, &space_id, &property_id).await,
+            dry_run,
+        } => delete_property(&client, &space_id, &property_id, dry_run).await,
     }
 }
 
@@ -142,14 +174,13 @@ async fn get_property(client: &AnytypeClient, space_id: &str, property_id: &str)
     Ok(())
 }
 
-async fn create_property(
-    client: &AnytypeClient,
-    space_id: &str,
-    name: &str,
-    format_str: &str,
-) -> Result<()> {
-    // Parse the format string to PropertyFormat enum
-    let format = match format_str.to_lowercase().as_str() {
+/// Formats accepted by `--format`, for error messages
+const VALID_FORMATS: &str =
+    "text, number, select, multi_select, date, files, checkbox, url, email, phone, objects";
+
+/// Parse a `--format` value
+fn parse_format(format_str: &str) -> Option<PropertyFormat> {
+    Some(match format_str.to_lowercase().as_str() {
         "text" => PropertyFormat::Text,
         "number" => PropertyFormat::Number,
         "select" => PropertyFormat::Select,
@@ -161,12 +192,20 @@ async fn create_property(
         "email" => PropertyFormat::Email,
         "phone" => PropertyFormat::Phone,
         "objects" => PropertyFormat::Objects,
-        _ => {
-            println!(
-                "❌ Invalid format: {format_str}. Valid options: text, number, select, multi_select, date, files, checkbox, url, email, phone, objects"
-            );
-            return Ok(());
-        }
+        _ => return None,
+    })
+}
+
+async fn create_property(
+    client: &AnytypeClient,
+    space_id: &str,
+    name: &str,
+    format_str: &str,
+    dry_run: bool,
+) -> Result<()> {
+    let Some(format) = parse_format(format_str) else {
+        println!("❌ Invalid format: {format_str}. Valid options: {VALID_FORMATS}");
+        return Ok(());
     };
 
     let request = CreatePropertyRequest {
@@ -175,6 +214,11 @@ async fn create_property(
         key: None,
     };
 
+    if dry_run {
+        let path = format!("/v1/spaces/{space_id}/properties");
+        return super::print_dry_run_with_body("POST", &path, &request);
+    }
+
     println!("🔧 Creating property '{name}' in space '{space_id}'...");
 
     let response = client
@@ -200,12 +244,18 @@ async fn update_property(
     property_id: &str,
     name: &str,
     key: Option<String>,
+    dry_run: bool,
 ) -> Result<()> {
     let request = UpdatePropertyRequest {
         name: name.to_string(),
         key,
     };
 
+    if dry_run {
+        let path = format!("/v1/spaces/{space_id}/properties/{property_id}");
+        return super::print_dry_run_with_body("PATCH", &path, &request);
+    }
+
     println!("🔧 Updating property '{property_id}' in space '{space_id}'...");
 
     let response = client
@@ -225,7 +275,87 @@ async fn update_property(
     Ok(())
 }
 
-async fn delete_property(client: &AnytypeClient, space_id: &str, property_id: &str) -> Result<()> {
+async fn migrate_property(
+    client: &AnytypeClient,
+    space_id: &str,
+    property_id: &str,
+    format_str: &str,
+    dry_run: bool,
+) -> Result<()> {
+    let format = parse_format(format_str)
+        .with_context(|| format!("Invalid format: {format_str}. Valid options: {VALID_FORMATS}"))?;
+
+    if dry_run {
+        println!("🔍 Planning migration of property '{property_id}' to {format_str}...");
+        let plan = client
+            .plan_property_migration(space_id, property_id, format)
+            .await
+            .context("Failed to plan property migration")?;
+
+        println!(
+            "📐 {} ({}): {} → {}",
+            plan.property.name,
+            plan.property.key,
+            plan.property.format,
+            plan.new_format.as_str()
+        );
+        println!("🏷️  {} tags to create:", plan.tags.len());
+        for tag in &plan.tags {
+            println!("  🏷️  {tag}");
+        }
+        println!("📄 {} objects to update:", plan.objects.len());
+        for object in &plan.objects {
+            println!(
+                "  📄 {} - {}: {}",
+                object.id,
+                object.name,
+                object.values.join(", ")
+            );
+        }
+        println!("🧩 {} types to update:", plan.types.len());
+        for type_data in &plan.types {
+            println!(
+                "  🧩 {} - {} ({})",
+                type_data.id, type_data.name, type_data.key
+            );
+        }
+        println!(
+            "🗑️  Property {} would be deleted and its key '{}' given to the new property",
+            plan.property.id, plan.property.key
+        );
+        println!("💡 Run without --dry-run to migrate");
+        return Ok(());
+    }
+
+    println!("🔧 Migrating property '{property_id}' to {format_str} in space '{space_id}'...");
+
+    let property = client
+        .migrate_property_format(space_id, property_id, format)
+        .await
+        .context("Failed to migrate property")?;
+
+    println!("✅ Property migrated successfully!");
+    println!("  🔧 {} ({})", property.name, property.key);
+    println!("  🆔 New ID: {}", property.id);
+    println!("  📐 Format: {}", property.format);
+
+    Ok(())
+}
+
+async fn delete_property(
+    client: &AnytypeClient,
+    space_id: &str,
+    property_id: &str,
+    dry_run: bool,
+) -> Result<()> {
+    if dry_run {
+        super::print_dry_run(
+            "DELETE",
+            &format!("/v1/spaces/{space_id}/properties/{property_id}"),
+        );
+        return Ok(());
+    }
+
     println!("🗑️  Deleting property '{property_id}' from space '{space_id}'...");
 
     let response = client
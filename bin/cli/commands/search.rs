@@ -1,17 +1,18 @@
 use anyhow::{Context, Result};
 use anytype_rs::api::{
-    AnytypeClient, SearchRequest, SearchSpaceRequest, Sort, SortDirection, SortProperty,
+    AnytypeClient, SearchOrder, SearchRequest, SearchSpaceRequest, Sort, SortDirection,
+    SortProperty,
 };
-use clap::Args;
+use clap::{Args, ValueEnum};
 
 #[derive(Debug, Args)]
 pub struct SearchArgs {
     /// Search query
     pub query: String,
 
-    /// Limit the number of results
-    #[arg(short, long, default_value = "10")]
-    pub limit: usize,
+    /// Limit the number of results (default 10, or the configured page size)
+    #[arg(short, long)]
+    pub limit: Option<u32>,
 
     /// Offset for pagination
     #[arg(short, long, default_value = "0")]
@@ -21,6 +22,10 @@ pub struct SearchArgs {
     #[arg(short, long)]
     pub space_id: Option<String>,
 
+    /// Order of results: best matches first, most recently modified first, or by name
+    #[arg(long, value_enum, default_value_t, conflicts_with_all = ["sort_by", "sort_direction"])]
+    pub sort: SearchSort,
+
     /// Sort by property (created_date, last_modified_date, last_opened_date, name)
     #[arg(long)]
     pub sort_by: Option<String>,
@@ -28,6 +33,36 @@ pub struct SearchArgs {
     /// Sort direction (asc, desc)
     #[arg(long)]
     pub sort_direction: Option<String>,
+
+    /// Match body content as well as names. The API always does, so this has no effect
+    /// and is accepted for clarity in scripts.
+    #[arg(long)]
+    pub full_text: bool,
+
+    /// Include archived objects, which are left out by default
+    #[arg(long)]
+    pub include_archived: bool,
+
+    #[command(flatten)]
+    pub watch: super::WatchArgs,
+}
+
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+pub enum SearchSort {
+    #[default]
+    Relevance,
+    Recent,
+    Name,
+}
+
+impl From<SearchSort> for SearchOrder {
+    fn from(sort: SearchSort) -> Self {
+        match sort {
+            SearchSort::Relevance => SearchOrder::Relevance,
+            SearchSort::Recent => SearchOrder::Recent,
+            SearchSort::Name => SearchOrder::Name,
+        }
+    }
 }
 
 pub async fn handle_search_command(args: SearchArgs) -> Result<()> {
@@ -37,7 +72,10 @@ pub async fn handle_search_command(args: SearchArgs) -> Result<()> {
     let mut client = AnytypeClient::new()?;
     client.set_api_key(api_key);
 
-    search(&client, args).await
+    let limit = super::page_size(args.limit, 10)? as usize;
+    args.watch
+        .run(false, || search(&client, &args, limit))
+        .await
 }
 
 fn parse_sort_options(sort_by: Option<&str>, sort_direction: Option<&str>) -> Result<Option<Sort>> {
@@ -82,25 +120,32 @@ fn parse_sort_options(sort_by: Option<&str>, sort_direction: Option<&str>) -> Re
     }
 }
 
-async fn search(client: &AnytypeClient, args: SearchArgs) -> Result<()> {
+async fn search(client: &AnytypeClient, args: &SearchArgs, limit: usize) -> Result<()> {
     let space_info = match &args.space_id {
         Some(space_id) => format!(" in space '{space_id}'"),
         None => " globally".to_string(),
     };
 
     println!("🔍 Searching for '{}'{}...", args.query, space_info);
+    if args.full_text {
+        println!("ℹ️  Search always matches body content; --full-text has no effect");
+    }
 
-    // Parse sort options
-    let sort = parse_sort_options(args.sort_by.as_deref(), args.sort_direction.as_deref())?;
+    // Parse sort options; --sort-by/--sort-direction are used instead of --sort
+    let sort = match parse_sort_options(args.sort_by.as_deref(), args.sort_direction.as_deref())? {
+        Some(sort) => Some(sort),
+        None => SearchOrder::from(args.sort).sort(),
+    };
 
     let response = match &args.space_id {
         Some(space_id) => {
             // Use space-specific search endpoint
             let request = SearchSpaceRequest {
                 query: Some(args.query.clone()),
-                limit: Some(args.limit),
+                limit: Some(limit),
                 offset: Some(args.offset),
                 sort,
+                include_archived: args.include_archived,
             };
             client
                 .search_space(space_id, request)
@@ -111,10 +156,11 @@ async fn search(client: &AnytypeClient, args: SearchArgs) -> Result<()> {
             // Use global search endpoint
             let request = SearchRequest {
                 query: Some(args.query.clone()),
-                limit: Some(args.limit),
+                limit: Some(limit),
                 offset: Some(args.offset),
                 space_id: None,
                 sort,
+                include_archived: args.include_archived,
             };
             client
                 .search(request)
@@ -134,22 +180,13 @@ async fn search(client: &AnytypeClient, args: SearchArgs) -> Result<()> {
 
     for (i, object) in response.data.iter().enumerate() {
         let index = args.offset + i + 1;
-        println!("{}. 📄 Object ID: {}", index, object.id);
+        println!("{}. 📄 {}", index, object.display_name());
+        println!("   🆔 Object ID: {}", object.id);
 
         // Display icon
         match &object.icon {
-            Some(anytype_rs::api::Icon::Emoji { emoji }) => {
-                println!("   🎨 Icon: {emoji}");
-            }
-            Some(anytype_rs::api::Icon::File { file }) => {
-                println!("   🎨 Icon: 📁 File ({file})");
-            }
-            Some(anytype_rs::api::Icon::Icon { name, color }) => {
-                println!("   🎨 Icon: {name} ({color:?})");
-            }
-            None => {
-                println!("   🎨 Icon: (none)");
-            }
+            Some(icon) => println!("   🎨 Icon: {}", icon.display()),
+            None => println!("   🎨 Icon: (none)"),
         }
 
         println!("   🏠 Space: {}", object.space_id);
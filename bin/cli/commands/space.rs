@@ -1,17 +1,42 @@
-use anyhow::{Context, Result};
-use anytype_rs::api::{AnytypeClient, CreateSpaceRequest, UpdateSpaceRequest};
-use clap::{Args, Subcommand};
+use super::{BatchErrorArgs, Interrupt, print_not_sent, record_failure};
+use anyhow::{Context, Result, bail};
+use anytype_rs::api::{
+    AnytypeClient, BatchErrorPolicy, ClientConfig, CreatePropertyRequest, CreateSpaceRequest,
+    CreateTagRequest, CreateTypeRequest, MemberRole, Object, PropertyFormat, Space, SpaceSort, Tag,
+    UpdateSpaceRequest, snake_case_key,
+};
+use clap::{Args, Subcommand, ValueEnum};
+use std::collections::{HashMap, HashSet};
+use yaml_rust2::{Yaml, YamlEmitter};
 
 #[derive(Debug, Args)]
 pub struct SpaceArgs {
     #[command(subcommand)]
     pub command: SpaceCommand,
+
+    /// Maximum number of concurrent requests for batch operations (default 8)
+    #[arg(long, global = true)]
+    pub concurrency: Option<usize>,
+
+    #[command(flatten)]
+    pub batch: BatchErrorArgs,
 }
 
 #[derive(Debug, Subcommand)]
 pub enum SpaceCommand {
     /// List all spaces
-    List,
+    List {
+        /// Sort spaces (server order if omitted)
+        #[arg(long, value_enum)]
+        sort: Option<SpaceSortKey>,
+        /// Print the spaces as a JSON array with all their fields
+        #[arg(long)]
+        json: bool,
+        /// Show how many objects each space holds (one extra request per space, run
+        /// concurrently up to --concurrency)
+        #[arg(long)]
+        with_counts: bool,
+    },
     /// Get details of a specific space
     Get {
         /// Space ID
@@ -25,6 +50,9 @@ pub enum SpaceCommand {
         /// Description of the space
         #[arg(long)]
         description: Option<String>,
+        /// Print the request that would be sent without sending it
+        #[arg(long)]
+        dry_run: bool,
     },
     /// Update an existing space
     Update {
@@ -36,51 +64,243 @@ pub enum SpaceCommand {
         /// New description for the space
         #[arg(long)]
         description: Option<String>,
+        /// Print the request that would be sent without sending it
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Create a new space with the types, properties and tags of an existing one
+    Clone {
+        /// Space ID to copy the structure from
+        #[arg(long)]
+        from: String,
+        /// Name of the new space
+        #[arg(long)]
+        to: String,
+        /// Description of the new space (defaults to the source space description)
+        #[arg(long)]
+        description: Option<String>,
+        /// Show what would be copied without creating anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Export the full schema of a space (types, their properties and select tags)
+    Schema {
+        /// Space ID
+        space_id: String,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = SchemaFormat::Json)]
+        format: SchemaFormat,
+        /// File to write the schema to (prints to stdout if omitted)
+        #[arg(short, long)]
+        out: Option<String>,
+    },
+    /// Export every object in a space as a single markdown document, one section per object
+    ExportDoc {
+        /// Space ID
+        space_id: String,
+        /// File to write the document to (prints to stdout if omitted)
+        #[arg(short, long)]
+        out: Option<String>,
+        /// Order of the sections
+        #[arg(long, value_enum, default_value_t = DocOrder::Name)]
+        order: DocOrder,
+        /// Start the document with a table of contents
+        #[arg(long)]
+        toc: bool,
+    },
+    /// Share a space with another Anytype user by global name
+    Share {
+        /// Space ID
+        space_id: String,
+        /// Global name of the user to share with (e.g. jane.any)
+        #[arg(long = "with")]
+        with: String,
+        /// Role to give the user (viewer, editor)
+        #[arg(long, default_value = "viewer")]
+        role: MemberRole,
     },
 }
 
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum SpaceSortKey {
+    Name,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum SchemaFormat {
+    Json,
+    Yaml,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum DocOrder {
+    /// Alphabetically by name
+    Name,
+    /// Oldest first
+    Created,
+}
+
 pub async fn handle_space_command(args: SpaceArgs) -> Result<()> {
     let api_key = crate::config::load_api_key()?
         .ok_or_else(|| anyhow::anyhow!("Not authenticated. Run 'anytype auth login' first."))?;
 
-    let mut client = AnytypeClient::new()?;
+    let mut client = match args.concurrency {
+        Some(max_concurrency) => AnytypeClient::with_config(ClientConfig {
+            max_concurrency,
+            ..ClientConfig::default()
+        })?,
+        None => AnytypeClient::new()?,
+    };
     client.set_api_key(api_key);
 
     match args.command {
-        SpaceCommand::List => list_spaces(&client).await,
-        SpaceCommand::Get { space_id } => get_space(&client, &space_id).await,
-        SpaceCommand::Create { name, description } => {
-            create_space(&client, &name, description).await
+        SpaceCommand::List {
+            sort,
+            json,
+            with_counts,
+        } => {
+            let sort = match sort {
+                Some(SpaceSortKey::Name) => SpaceSort::Name,
+                None => SpaceSort::Server,
+            };
+            if json {
+                list_spaces_json(&client, sort, with_counts).await
+            } else {
+                list_spaces(&client, sort, with_counts).await
+            }
         }
+        SpaceCommand::Get { space_id } => get_space(&client, &space_id).await,
+        SpaceCommand::Create {
+            name,
+            description,
+            dry_run,
+        } => create_space(&client, &name, description, dry_run).await,
         SpaceCommand::Update {
             space_id,
             name,
             description,
-        } => update_space(&client, &space_id, name, description).await,
+            dry_run,
+        } => update_space(&client, &space_id, name, description, dry_run).await,
+        SpaceCommand::Clone {
+            from,
+            to,
+            description,
+            dry_run,
+        } => {
+            clone_space(
+                &client,
+                &from,
+                &to,
+                description,
+                dry_run,
+                args.batch.policy(),
+            )
+            .await
+        }
+        SpaceCommand::Schema {
+            space_id,
+            format,
+            out,
+        } => export_schema(&client, &space_id, format, out.as_deref()).await,
+        SpaceCommand::ExportDoc {
+            space_id,
+            out,
+            order,
+            toc,
+        } => export_doc(&client, &space_id, out.as_deref(), order, toc).await,
+        SpaceCommand::Share {
+            space_id,
+            with,
+            role,
+        } => share_space(&client, &space_id, &with, role).await,
     }
 }
 
-async fn list_spaces(client: &AnytypeClient) -> Result<()> {
+async fn list_spaces(client: &AnytypeClient, sort: SpaceSort, with_counts: bool) -> Result<()> {
     println!("🏠 Fetching spaces...");
 
-    let spaces = client
-        .list_spaces()
+    let response = client
+        .list_spaces_sorted(sort)
         .await
         .context("Failed to fetch spaces")?;
+    let spaces = response.data;
 
     if spaces.is_empty() {
         println!("📭 No spaces found.");
         return Ok(());
     }
 
-    println!("✅ Found {} spaces:", spaces.len());
-    for space in spaces {
-        println!("  🏠 {} - {}", space.id, space.name);
+    if response.pagination.total > spaces.len() {
+        println!(
+            "✅ Showing {} of {} spaces:",
+            spaces.len(),
+            response.pagination.total
+        );
+    } else {
+        println!("✅ Found {} spaces:", spaces.len());
+    }
+
+    if !with_counts {
+        for space in spaces {
+            println!("  🏠 {} - {}", space.id, space.name);
+        }
+        return Ok(());
+    }
+
+    let counts = count_objects(client, &spaces).await;
+    for (space, count) in spaces.iter().zip(counts) {
+        let count = count.map_or_else(|| "?".to_string(), |count| count.to_string());
+        println!("  🏠 {} - {} ({count} objects)", space.id, space.name);
     }
 
     Ok(())
 }
 
+async fn list_spaces_json(
+    client: &AnytypeClient,
+    sort: SpaceSort,
+    with_counts: bool,
+) -> Result<()> {
+    let response = client
+        .list_spaces_sorted(sort)
+        .await
+        .context("Failed to fetch spaces")?;
+
+    let mut spaces = serde_json::to_value(&response.data).context("Failed to serialize spaces")?;
+    if with_counts {
+        let counts = count_objects(client, &response.data).await;
+        if let Some(entries) = spaces.as_array_mut() {
+            for (entry, count) in entries.iter_mut().zip(counts) {
+                entry["object_count"] = serde_json::json!(count);
+            }
+        }
+    }
+    let json = serde_json::to_string_pretty(&spaces).context("Failed to serialize spaces")?;
+    println!("{json}");
+
+    Ok(())
+}
+
+/// Number of objects in each space, or `None` where it could not be counted
+///
+/// A failed count is reported as a warning rather than failing the whole listing.
+async fn count_objects(client: &AnytypeClient, spaces: &[Space]) -> Vec<Option<usize>> {
+    let space_ids: Vec<String> = spaces.iter().map(|space| space.id.clone()).collect();
+    let counts = client.count_objects_in_spaces(&space_ids).await;
+
+    spaces
+        .iter()
+        .zip(counts)
+        .map(|(space, count)| match count {
+            Ok(count) => Some(count),
+            Err(e) => {
+                eprintln!("⚠️  Could not count objects in '{}': {e}", space.name);
+                None
+            }
+        })
+        .collect()
+}
+
 async fn get_space(client: &AnytypeClient, space_id: &str) -> Result<()> {
     println!("🔍 Fetching space details for '{space_id}'...");
 
@@ -100,14 +320,19 @@ async fn create_space(
     client: &AnytypeClient,
     name: &str,
     description: Option<String>,
+    dry_run: bool,
 ) -> Result<()> {
-    println!("🏗️  Creating space '{name}'...");
-
     let request = CreateSpaceRequest {
         name: name.to_string(),
         description,
     };
 
+    if dry_run {
+        return super::print_dry_run_with_body("POST", "/v1/spaces", &request);
+    }
+
+    println!("🏗️  Creating space '{name}'...");
+
     let response = client
         .create_space(request)
         .await
@@ -134,6 +359,7 @@ async fn update_space(
     space_id: &str,
     name: Option<String>,
     description: Option<String>,
+    dry_run: bool,
 ) -> Result<()> {
     // Check if at least one field is provided for update
     if name.is_none() && description.is_none() {
@@ -142,10 +368,15 @@ async fn update_space(
         ));
     }
 
-    println!("🔄 Updating space '{space_id}'...");
-
     let request = UpdateSpaceRequest { name, description };
 
+    if dry_run {
+        let path = format!("/v1/spaces/{space_id}");
+        return super::print_dry_run_with_body("PATCH", &path, &request);
+    }
+
+    println!("🔄 Updating space '{space_id}'...");
+
     let response = client
         .update_space(space_id, request)
         .await
@@ -166,3 +397,518 @@ async fn update_space(
 
     Ok(())
 }
+
+/// Returns true for property formats whose values are tags
+fn has_tags(format: &str) -> bool {
+    matches!(format, "select" | "multi_select")
+}
+
+async fn clone_space(
+    client: &AnytypeClient,
+    from: &str,
+    to: &str,
+    description: Option<String>,
+    dry_run: bool,
+    policy: BatchErrorPolicy,
+) -> Result<()> {
+    println!("🔍 Reading structure of space '{from}'...");
+
+    let source = client
+        .get_space(from)
+        .await
+        .context("Failed to fetch source space")?;
+    let types = client
+        .list_types(from)
+        .await
+        .context("Failed to fetch types")?;
+    let properties = client
+        .list_properties(from)
+        .await
+        .context("Failed to fetch properties")?;
+
+    let mut tags: HashMap<String, Vec<Tag>> = HashMap::new();
+    for property in properties.iter().filter(|p| has_tags(&p.format)) {
+        let property_tags = client
+            .list_tags(from, &property.id)
+            .await
+            .with_context(|| format!("Failed to fetch tags for property '{}'", property.key))?;
+        tags.insert(property.key.clone(), property_tags);
+    }
+
+    if dry_run {
+        println!("📋 Dry run - nothing will be created");
+        println!("  🏠 New space: {to} (copied from '{}')", source.name);
+        println!("  🔑 Properties: {}", properties.len());
+        for property in &properties {
+            println!(
+                "    • {} ({}) - {}",
+                property.name, property.format, property.key
+            );
+            for tag in tags.get(&property.key).into_iter().flatten() {
+                println!("      🏷️  {}", tag.name);
+            }
+        }
+        println!("  🏷️  Types: {}", types.len());
+        for type_obj in &types {
+            println!(
+                "    • {} ({}) - {} properties",
+                type_obj.name,
+                type_obj.key,
+                type_obj.properties.len()
+            );
+        }
+        println!("💡 Types and properties that already exist in the new space will be skipped");
+        return Ok(());
+    }
+
+    println!("🏗️  Creating space '{to}'...");
+    let response = client
+        .create_space(CreateSpaceRequest {
+            name: to.to_string(),
+            description: description.or(source.description),
+        })
+        .await
+        .context("Failed to create space")?;
+    let target_id = response.space.id;
+    println!("✅ Space created: {target_id}");
+
+    // Built-in types and properties already exist in a new space, so only copy missing ones
+    let existing_properties: HashMap<String, String> = client
+        .list_properties(&target_id)
+        .await
+        .context("Failed to fetch properties of the new space")?
+        .into_iter()
+        .map(|p| (snake_case_key(&p.key), p.id))
+        .collect();
+    let existing_types: HashSet<String> = client
+        .list_types(&target_id)
+        .await
+        .context("Failed to fetch types of the new space")?
+        .into_iter()
+        .map(|t| snake_case_key(&t.key))
+        .collect();
+
+    let interrupt = Interrupt::watch();
+    let mut created = 0;
+    let mut skipped = 0;
+    let mut failed = 0;
+    let mut not_sent = 0;
+
+    for property in &properties {
+        if interrupt.is_set() {
+            not_sent += 1 + tags.get(&property.key).map_or(0, Vec::len);
+            continue;
+        }
+        // Keys the server assigned may not be snake_case, which the API rejects on create
+        let key = snake_case_key(&property.key);
+        let target_property_id = match existing_properties.get(&key) {
+            Some(id) => {
+                skipped += 1;
+                id.clone()
+            }
+            None => {
+                let format: PropertyFormat = match serde_json::from_value(
+                    serde_json::Value::String(property.format.clone()),
+                ) {
+                    Ok(format) => format,
+                    Err(e) => {
+                        println!("  ⚠️  Skipping property '{}': {e}", property.key);
+                        record_failure(&mut failed, policy)?;
+                        continue;
+                    }
+                };
+                let request = CreatePropertyRequest {
+                    name: property.name.clone(),
+                    format,
+                    key: Some(key.clone()),
+                };
+                match client.create_property(&target_id, request).await {
+                    Ok(response) => {
+                        println!("  🔑 Property: {} ({key})", property.name);
+                        created += 1;
+                        response.property.id
+                    }
+                    Err(e) => {
+                        println!("  ❌ Failed to create property '{}': {e}", property.key);
+                        record_failure(&mut failed, policy)?;
+                        continue;
+                    }
+                }
+            }
+        };
+
+        let Some(source_tags) = tags.get(&property.key) else {
+            continue;
+        };
+        let existing_tags: HashSet<String> =
+            match client.list_tags(&target_id, &target_property_id).await {
+                Ok(existing) => existing.into_iter().map(|t| t.name).collect(),
+                Err(e) => {
+                    println!(
+                        "  ❌ Failed to fetch tags of property '{}': {e}",
+                        property.key
+                    );
+                    record_failure(&mut failed, policy)?;
+                    continue;
+                }
+            };
+        for tag in source_tags {
+            if interrupt.is_set() {
+                not_sent += 1;
+                continue;
+            }
+            if existing_tags.contains(&tag.name) {
+                skipped += 1;
+                continue;
+            }
+            let request = CreateTagRequest {
+                name: tag.name.clone(),
+                color: tag.color.clone(),
+            };
+            match client
+                .create_tag(&target_id, &target_property_id, request)
+                .await
+            {
+                Ok(_) => {
+                    println!("    🏷️  Tag: {}", tag.name);
+                    created += 1;
+                }
+                Err(e) => {
+                    println!("    ❌ Failed to create tag '{}': {e}", tag.name);
+                    record_failure(&mut failed, policy)?;
+                }
+            }
+        }
+    }
+
+    for type_obj in &types {
+        if interrupt.is_set() {
+            not_sent += 1;
+            continue;
+        }
+        let request = match CreateTypeRequest::from_exported_type(type_obj) {
+            Ok(request) => request,
+            Err(e) => {
+                println!("  ❌ Failed to create type '{}': {e}", type_obj.key);
+                record_failure(&mut failed, policy)?;
+                continue;
+            }
+        };
+        if existing_types.contains(&request.key) {
+            skipped += 1;
+            continue;
+        }
+        let key = request.key.clone();
+        match client.create_type(&target_id, request).await {
+            Ok(_) => {
+                println!("  🏷️  Type: {} ({key})", type_obj.name);
+                created += 1;
+            }
+            Err(e) => {
+                println!("  ❌ Failed to create type '{}': {e}", type_obj.key);
+                record_failure(&mut failed, policy)?;
+            }
+        }
+    }
+
+    println!("✅ Space cloned: {created} created, {skipped} already present, {failed} failed");
+    println!("   🆔 Space ID: {target_id}");
+    print_not_sent(not_sent);
+    interrupt.check()?;
+    if failed > 0 {
+        bail!("{failed} requests failed while cloning into space '{target_id}'");
+    }
+
+    Ok(())
+}
+
+async fn export_schema(
+    client: &AnytypeClient,
+    space_id: &str,
+    format: SchemaFormat,
+    out: Option<&str>,
+) -> Result<()> {
+    let schema = client
+        .export_schema(space_id)
+        .await
+        .context("Failed to export schema")?;
+
+    let value = serde_json::to_value(&schema).context("Failed to serialize schema")?;
+    let document = match format {
+        SchemaFormat::Json => {
+            serde_json::to_string_pretty(&value).context("Failed to serialize schema")?
+        }
+        SchemaFormat::Yaml => {
+            let mut yaml = String::new();
+            YamlEmitter::new(&mut yaml)
+                .dump(&json_to_yaml(value))
+                .context("Failed to serialize schema")?;
+            yaml
+        }
+    };
+
+    match out {
+        Some(path) => {
+            std::fs::write(path, document)
+                .with_context(|| format!("Failed to write file: {path}"))?;
+            println!("✅ Schema exported successfully!");
+            println!("  🏷️  Types: {}", schema.types.len());
+            println!("  💾 File: {path}");
+        }
+        None => println!("{document}"),
+    }
+
+    Ok(())
+}
+
+async fn export_doc(
+    client: &AnytypeClient,
+    space_id: &str,
+    out: Option<&str>,
+    order: DocOrder,
+    toc: bool,
+) -> Result<()> {
+    let mut objects = client
+        .list_all_objects(space_id)
+        .await
+        .context("Failed to list objects")?;
+    match order {
+        DocOrder::Name => objects.sort_by_cached_key(|object| object.display_name().to_lowercase()),
+        // Objects without a creation date go last
+        DocOrder::Created => {
+            objects.sort_by_key(|object| (object.created_at().is_none(), object.created_at()))
+        }
+    }
+
+    let ids: Vec<String> = objects.into_iter().map(|object| object.id).collect();
+    let objects = client
+        .get_objects(space_id, &ids)
+        .await
+        .context("Failed to fetch objects")?;
+    let document = markdown_document(&objects, toc);
+
+    match out {
+        Some(path) => {
+            std::fs::write(path, document)
+                .with_context(|| format!("Failed to write file: {path}"))?;
+            println!("✅ Space exported successfully!");
+            println!("  📄 Objects: {}", objects.len());
+            println!("  💾 File: {path}");
+        }
+        None => print!("{document}"),
+    }
+
+    Ok(())
+}
+
+/// Join object bodies into one document with a `# Name` section per object
+///
+/// Headings inside the bodies are moved down a level so they nest under their object.
+fn markdown_document(objects: &[Object], toc: bool) -> String {
+    let names: Vec<String> = objects.iter().map(|object| object.display_name()).collect();
+    let mut document = String::new();
+
+    if toc {
+        document.push_str("# Contents\n\n");
+        // Repeated headings get numbered anchors; `# Contents` takes the first "contents"
+        let mut anchors = HashMap::from([("contents".to_string(), 1)]);
+        for name in &names {
+            let anchor = heading_anchor(name);
+            let count = anchors.entry(anchor.clone()).or_insert(0);
+            let anchor = match *count {
+                0 => anchor,
+                n => format!("{anchor}-{n}"),
+            };
+            *count += 1;
+            document.push_str(&format!("- [{name}](#{anchor})\n"));
+        }
+        document.push('\n');
+    }
+
+    for (object, name) in objects.iter().zip(&names) {
+        document.push_str(&format!("# {name}\n\n"));
+        let body = object.markdown.as_deref().unwrap_or_default().trim();
+        if !body.is_empty() {
+            let mut in_code_block = false;
+            for line in body.lines() {
+                if line.trim_start().starts_with("```") {
+                    in_code_block = !in_code_block;
+                }
+                if !in_code_block && line.starts_with('#') {
+                    document.push('#');
+                }
+                document.push_str(line);
+                document.push('\n');
+            }
+            document.push('\n');
+        }
+    }
+
+    document
+}
+
+/// The anchor markdown renderers give a heading: lowercase, punctuation dropped, spaces
+/// turned into dashes
+fn heading_anchor(heading: &str) -> String {
+    heading
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric() || matches!(c, ' ' | '-' | '_'))
+        .map(|c| if c == ' ' { '-' } else { c })
+        .collect()
+}
+
+/// Resolve a global name to an identity and check whether it can be added to the space
+///
+/// The API has no endpoint for adding members or creating invite links, so a user who is
+/// not in the space yet has to be invited from the Anytype app; the resolved identity is
+/// printed to make that easier.
+async fn share_space(
+    client: &AnytypeClient,
+    space_id: &str,
+    global_name: &str,
+    role: MemberRole,
+) -> Result<()> {
+    println!("🔍 Looking up {global_name}...");
+
+    let member = client
+        .find_member_by_global_name(global_name)
+        .await
+        .context("Failed to look up member")?
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "No member named '{global_name}' found in your spaces; the API can only resolve users you already share a space with"
+            )
+        })?;
+    let identity = member.identity.as_deref().unwrap_or("unknown");
+    println!("  👤 {global_name} → {identity}");
+
+    let members = client
+        .list_members(space_id)
+        .await
+        .context("Failed to list members")?;
+    if let Some(existing) = members
+        .iter()
+        .find(|m| m.identity.is_some() && m.identity == member.identity)
+    {
+        println!(
+            "✅ {global_name} is already a member of this space ({}, {})",
+            existing.role, existing.status
+        );
+        if existing.role != role {
+            println!(
+                "  ⚠️  Requested role {role} was not applied: the API cannot change member roles"
+            );
+        }
+        return Ok(());
+    }
+
+    anyhow::bail!(
+        "The Anytype API cannot add members or create invite links; invite {global_name} (identity {identity}) as {role} from the Anytype app"
+    )
+}
+
+/// Convert a JSON value into the equivalent YAML node
+fn json_to_yaml(value: serde_json::Value) -> Yaml {
+    match value {
+        serde_json::Value::Null => Yaml::Null,
+        serde_json::Value::Bool(b) => Yaml::Boolean(b),
+        serde_json::Value::Number(n) => match n.as_i64() {
+            Some(i) => Yaml::Integer(i),
+            None => Yaml::Real(n.to_string()),
+        },
+        serde_json::Value::String(s) => Yaml::String(s),
+        serde_json::Value::Array(items) => {
+            Yaml::Array(items.into_iter().map(json_to_yaml).collect())
+        }
+        serde_json::Value::Object(map) => Yaml::Hash(
+            map.into_iter()
+                .map(|(key, value)| (Yaml::String(key), json_to_yaml(value)))
+                .collect(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anytype_rs::testing::{self, TEST_SPACE_ID, fixtures};
+
+    const TARGET_SPACE_ID: &str = "bafyreitarget";
+
+    #[tokio::test]
+    async fn test_clone_space_rewrites_hyphenated_keys() {
+        let mock = testing::mock_client().await;
+        let source = format!("/v1/spaces/{TEST_SPACE_ID}");
+        let target = format!("/v1/spaces/{TARGET_SPACE_ID}");
+        let empty_list = serde_json::json!({
+            "data": [],
+            "pagination": { "has_more": false, "limit": 50, "offset": 0, "total": 0 }
+        });
+
+        mock.server.mock(|when, then| {
+            when.method("GET")
+                .path(format!("{source}/properties/prop-status-456/tags"));
+            then.status(200)
+                .json_body(fixtures::tags::list_tags_response());
+        });
+        mock.server.mock(|when, then| {
+            when.method("POST").path("/v1/spaces");
+            let mut space = fixtures::spaces::space();
+            space["id"] = TARGET_SPACE_ID.into();
+            then.status(200)
+                .json_body(serde_json::json!({ "space": space }));
+        });
+        for path in ["properties", "types", "properties/prop-new-789/tags"] {
+            mock.server.mock(|when, then| {
+                when.method("GET").path(format!("{target}/{path}"));
+                then.status(200).json_body(empty_list.clone());
+            });
+        }
+        // The fixtures' `custom-field` property and `ot-page` type are created snake_case
+        let property_mock = mock.server.mock(|when, then| {
+            when.method("POST")
+                .path(format!("{target}/properties"))
+                .body_includes("\"key\":\"custom_field\"");
+            then.status(200)
+                .json_body(fixtures::properties::create_property_response());
+        });
+        mock.server.mock(|when, then| {
+            when.method("POST").path(format!("{target}/properties"));
+            then.status(200)
+                .json_body(fixtures::properties::create_property_response());
+        });
+        mock.server.mock(|when, then| {
+            when.method("POST")
+                .path(format!("{target}/properties/prop-new-789/tags"));
+            then.status(200)
+                .json_body(fixtures::tags::create_tag_response());
+        });
+        let type_mock = mock.server.mock(|when, then| {
+            when.method("POST")
+                .path(format!("{target}/types"))
+                .body_includes("\"key\":\"ot_page\"");
+            then.status(200)
+                .json_body(fixtures::types::create_type_response());
+        });
+        mock.server.mock(|when, then| {
+            when.method("POST").path(format!("{target}/types"));
+            then.status(200)
+                .json_body(fixtures::types::create_type_response());
+        });
+
+        let result = clone_space(
+            &mock,
+            TEST_SPACE_ID,
+            "Copy",
+            None,
+            false,
+            BatchErrorPolicy::FailFast,
+        )
+        .await;
+
+        assert!(result.is_ok(), "Clone failed: {result:?}");
+        property_mock.assert();
+        type_mock.assert();
+    }
+}
@@ -15,10 +15,17 @@ pub enum TagCommand {
         /// Space ID
         space_id: String,
         /// Property ID (the property for which to list tags)
-        property_id: String,
-        /// Limit the number of results
-        #[arg(short, long, default_value = "20")]
-        limit: u32,
+        #[arg(required_unless_present = "all_properties")]
+        property_id: Option<String>,
+        /// List the tags of every select and multi-select property of a type instead
+        #[arg(long, requires = "type_id", conflicts_with = "property_id")]
+        all_properties: bool,
+        /// Type ID whose properties are listed with --all-properties
+        #[arg(long)]
+        type_id: Option<String>,
+        /// Limit the number of results (default 20, or the configured page size)
+        #[arg(short, long)]
+        limit: Option<u32>,
     },
     /// Create a new tag for a property in a space
     Create {
@@ -32,6 +39,9 @@ pub enum TagCommand {
         /// Tag color
         #[arg(short, long, default_value = "grey")]
         color: String,
+        /// Print the request that would be sent without sending it
+        #[arg(long)]
+        dry_run: bool,
     },
     /// Get details of a specific tag
     Get {
@@ -56,6 +66,9 @@ pub enum TagCommand {
         /// Tag color
         #[arg(short, long, default_value = "grey")]
         color: String,
+        /// Print the request that would be sent without sending it
+        #[arg(long)]
+        dry_run: bool,
     },
     /// Delete a tag from a property in a space
     Delete {
@@ -65,6 +78,9 @@ pub enum TagCommand {
         property_id: String,
         /// Tag ID to delete
         tag_id: String,
+        /// Print the request that would be sent without sending it
+        #[arg(long)]
+        dry_run: bool,
     },
 }
 
@@ -79,14 +95,25 @@ pub async fn handle_tag_command(args: TagArgs) -> Result<()> {
         TagCommand::List {
             space_id,
             property_id,
+            all_properties,
+            type_id,
             limit,
-        } => list_tags(&client, &space_id, &property_id, limit).await,
+        } => match (all_properties, type_id, property_id) {
+            (true, Some(type_id), _) => list_type_tags(&client, &space_id, &type_id).await,
+            (_, _, Some(property_id)) => {
+                let limit = super::page_size(limit, 20)?;
+                list_tags(&client, &space_id, &property_id, limit).await
+            }
+            // clap requires a property ID unless --all-properties (with --type-id) is given
+            _ => unreachable!("clap enforces property_id or --all-properties --type-id"),
+        },
         TagCommand::Create {
             space_id,
             property_id,
             name,
             color,
-        } => create_tag(&client, &space_id, &property_id, &name, &color).await,
+            dry_run,
+        } => create_tag(&client, &space_id, &property_id, &name, &color, dry_run).await,
         TagCommand::Get {
             space_id,
             property_id,
@@ -98,12 +125,25 @@ pub async fn handle_tag_command(args: TagArgs) -> Result<()> {
             tag_id,
             name,
             color,
-        } => update_tag(&client, &space_id, &property_id, &tag_id, &name, &color).await,
+            dry_run,
+        } => {
+            update_tag(
+                &client,
+                &space_id,
+                &property_id,
+                &tag_id,
+                &name,
+                &color,
+                dry_run,
+            )
+            .await
+        }
         TagCommand::Delete {
             space_id,
             property_id,
             tag_id,
-        } => delete_tag(&client, &space_id, &property_id, &tag_id).await,
+            dry_run,
+        } => delete_tag(&client, &space_id, &property_id, &tag_id, dry_run).await,
     }
 }
 
@@ -148,33 +188,54 @@ async fn list_tags(
     Ok(())
 }
 
+async fn list_type_tags(client: &AnytypeClient, space_id: &str, type_id: &str) -> Result<()> {
+    println!("🏷️  Fetching tags for all properties of type '{type_id}' from space '{space_id}'...");
+
+    let grouped = client
+        .list_type_tags(space_id, type_id)
+        .await
+        .context("Failed to fetch tags")?;
+
+    if grouped.is_empty() {
+        println!("📭 This type has no select or multi-select properties.");
+        return Ok(());
+    }
+
+    for (property, tags) in grouped {
+        println!(
+            "📋 {} ({}, {}) - {} tags",
+            property.name,
+            property.key,
+            property.format,
+            tags.len()
+        );
+        for tag in tags {
+            match &tag.color {
+                Some(color) => println!("  🏷️  {} ({}) 🎨 {color}", tag.name, tag.id),
+                None => println!("  🏷️  {} ({})", tag.name, tag.id),
+            }
+        }
+        println!();
+    }
+
+    Ok(())
+}
+
 async fn create_tag(
     client: &AnytypeClient,
     space_id: &str,
     property_id: &str,
     name: &str,
     color_str: &str,
+    dry_run: bool,
 ) -> Result<()> {
-    println!("🏗️  Creating tag '{name}' for property '{property_id}' in space '{space_id}'...");
-
     // Parse color
-    let color = match color_str.to_lowercase().as_str() {
-        "grey" => Color::Grey,
-        "yellow" => Color::Yellow,
-        "orange" => Color::Orange,
-        "red" => Color::Red,
-        "pink" => Color::Pink,
-        "purple" => Color::Purple,
-        "blue" => Color::Blue,
-        "ice" => Color::Ice,
-        "teal" => Color::Teal,
-        "lime" => Color::Lime,
-        _ => {
-            println!(
-                "❌ Invalid color: {color_str}. Valid options: grey, yellow, orange, red, pink, purple, blue, ice, teal, lime"
-            );
-            return Ok(());
-        }
+    let Some(color) = Color::from_name(color_str) else {
+        println!(
+            "❌ Invalid color: {color_str}. Valid options: {}",
+            Color::names()
+        );
+        return Ok(());
     };
 
     let request = CreateTagRequest {
@@ -182,6 +243,13 @@ async fn create_tag(
         color: Some(color),
     };
 
+    if dry_run {
+        let path = format!("/v1/spaces/{space_id}/properties/{property_id}/tags");
+        return super::print_dry_run_with_body("POST", &path, &request);
+    }
+
+    println!("🏗️  Creating tag '{name}' for property '{property_id}' in space '{space_id}'...");
+
     let response = client
         .create_tag(space_id, property_id, request)
         .await
@@ -232,27 +300,15 @@ async fn update_tag(
     tag_id: &str,
     name: &str,
     color_str: &str,
+    dry_run: bool,
 ) -> Result<()> {
-    println!("🔄 Updating tag '{tag_id}' for property '{property_id}' in space '{space_id}'...");
-
     // Parse color
-    let color = match color_str.to_lowercase().as_str() {
-        "grey" => Color::Grey,
-        "yellow" => Color::Yellow,
-        "orange" => Color::Orange,
-        "red" => Color::Red,
-        "pink" => Color::Pink,
-        "purple" => Color::Purple,
-        "blue" => Color::Blue,
-        "ice" => Color::Ice,
-        "teal" => Color::Teal,
-        "lime" => Color::Lime,
-        _ => {
-            println!(
-                "❌ Invalid color: {color_str}. Valid options: grey, yellow, orange, red, pink, purple, blue, ice, teal, lime"
-            );
-            return Ok(());
-        }
+    let Some(color) = Color::from_name(color_str) else {
+        println!(
+            "❌ Invalid color: {color_str}. Valid options: {}",
+            Color::names()
+        );
+        return Ok(());
     };
 
     let request = UpdateTagRequest {
@@ -260,6 +316,13 @@ async fn update_tag(
         color: Some(color),
     };
 
+    if dry_run {
+        let path = format!("/v1/spaces/{space_id}/properties/{property_id}/tags/{tag_id}");
+        return super::print_dry_run_with_body("PATCH", &path, &request);
+    }
+
+    println!("🔄 Updating tag '{tag_id}' for property '{property_id}' in space '{space_id}'...");
+
     let response = client
         .update_tag(space_id, property_id, tag_id, request)
         .await
@@ -283,7 +346,16 @@ async fn delete_tag(
     space_id: &str,
     property_id: &str,
     tag_id: &str,
+    dry_run: bool,
 ) -> Result<()> {
+    if dry_run {
+        super::print_dry_run(
+            "DELETE",
+            &format!("/v1/spaces/{space_id}/properties/{property_id}/tags/{tag_id}"),
+        );
+        return Ok(());
+    }
+
     let response = client
         .delete_tag(space_id, property_id, tag_id)
         .await
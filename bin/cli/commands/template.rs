@@ -16,9 +16,9 @@ pub enum TemplateCommand {
         space_id: String,
         /// Type ID (the type for which to list templates)
         type_id: String,
-        /// Limit the number of results
-        #[arg(short, long, default_value = "10")]
-        limit: u32,
+        /// Limit the number of results (default 10, or the configured page size)
+        #[arg(short, long)]
+        limit: Option<u32>,
     },
     /// Get details of a specific template
     Get {
@@ -43,7 +43,10 @@ pub async fn handle_template_command(args: TemplateArgs) -> Result<()> {
             space_id,
             type_id,
             limit,
-        } => list_templates(&client, &space_id, &type_id, limit).await,
+        } => {
+            let limit = super::page_size(limit, 10)?;
+            list_templates(&client, &space_id, &type_id, limit).await
+        }
         TemplateCommand::Get {
             space_id,
             type_id,
@@ -92,17 +95,7 @@ async fn list_templates(
             println!("     📦 Archived: Yes");
         }
 
-        match &template.icon {
-            anytype_rs::api::Icon::Emoji { emoji } => {
-                println!("     🎨 Icon: {emoji}");
-            }
-            anytype_rs::api::Icon::File { file } => {
-                println!("     🎨 Icon: {file}");
-            }
-            anytype_rs::api::Icon::Icon { name, color } => {
-                println!("     🎨 Icon: {name} ({color:?})");
-            }
-        }
+        println!("     🎨 Icon: {}", template.icon.display());
 
         if let Some(snippet) = &template.snippet {
             let display_snippet = if snippet.len() > 80 {
@@ -159,17 +152,7 @@ async fn get_template(
         println!("  📦 Archived: {}", if archived { "Yes" } else { "No" });
     }
 
-    match &template.icon {
-        anytype_rs::api::Icon::Emoji { emoji } => {
-            println!("  🎨 Icon: {emoji}");
-        }
-        anytype_rs::api::Icon::File { file } => {
-            println!("  🎨 Icon: {file}");
-        }
-        anytype_rs::api::Icon::Icon { name, color } => {
-            println!("  🎨 Icon: {name} ({color:?})");
-        }
-    }
+    println!("  🎨 Icon: {}", template.icon.display());
 
     if let Some(snippet) = &template.snippet {
         println!("  📝 Snippet: {snippet}");
@@ -0,0 +1,145 @@
+//! Local object templates (`object new --template <file>`)
+//!
+//! A local template is a markdown file whose frontmatter holds default property values,
+//! like the files `import markdown` reads. Unlike server templates (`object create
+//! --template`) they need nothing set up in the space: `{{date}}` and `{{time}}` are
+//! filled in, empty frontmatter fields are asked for, and the result goes through the
+//! markdown import.
+
+use super::import::{
+    ImportOptions, OnExisting, ParsedDocument, import_document, parse_frontmatter,
+};
+use anyhow::{Context, Result};
+use anytype_rs::api::AnytypeClient;
+use chrono::{DateTime, Local};
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+
+/// Frontmatter field naming the type of the new object
+const TYPE_FIELD: &str = "type";
+/// Type used when neither `--type-key` nor the template names one
+const DEFAULT_TYPE_KEY: &str = "page";
+
+/// What `object new` was asked to create, on top of the template's defaults
+pub struct LocalTemplateArgs<'a> {
+    pub space_id: &'a str,
+    pub template: &'a str,
+    pub type_key: Option<&'a str>,
+    pub name: Option<&'a str>,
+    /// `key=value` pairs overriding the template's frontmatter
+    pub properties: &'a [(String, String)],
+    pub dry_run: bool,
+}
+
+/// Create an object from a local template file
+pub async fn create_from_local_template(
+    client: &AnytypeClient,
+    args: LocalTemplateArgs<'_>,
+) -> Result<()> {
+    println!("📄 Reading template: {}", args.template);
+    let content = std::fs::read_to_string(args.template)
+        .with_context(|| format!("Failed to read template: {}", args.template))?;
+
+    let now = Local::now();
+    let (mut frontmatter, body) = parse_frontmatter(&content)?;
+    for value in frontmatter.values_mut() {
+        substitute_value(value, now);
+    }
+    let body = substitute(&body, now);
+
+    for (key, value) in args.properties {
+        frontmatter.insert(key.clone(), JsonValue::String(value.clone()));
+    }
+    if let Some(name) = args.name {
+        frontmatter.insert("title".to_string(), JsonValue::String(name.to_string()));
+    }
+    for key in missing_fields(&frontmatter) {
+        let value = super::prompt(&format!("✏️  {key}: "))
+            .with_context(|| format!("No value for '{key}', which the template leaves empty"))?;
+        frontmatter.insert(key, JsonValue::String(value));
+    }
+
+    // The type is not a property, so it is taken out before the frontmatter is mapped
+    let template_type = frontmatter
+        .remove(TYPE_FIELD)
+        .and_then(|value| value.as_str().map(str::to_string));
+    let type_key = args
+        .type_key
+        .map(str::to_string)
+        .or(template_type)
+        .unwrap_or_else(|| DEFAULT_TYPE_KEY.to_string());
+
+    let document = ParsedDocument {
+        file_path: args.template,
+        frontmatter,
+        markdown_body: body,
+    };
+    let options = ImportOptions {
+        dry_run: args.dry_run,
+        verbose: args.dry_run,
+        on_existing: OnExisting::Create,
+    };
+    import_document(client, &document, args.space_id, &type_key, options).await
+}
+
+/// Fill in the `{{date}}` (`YYYY-MM-DD`) and `{{time}}` (`HH:MM`) placeholders
+fn substitute(text: &str, now: DateTime<Local>) -> String {
+    text.replace("{{date}}", &now.format("%Y-%m-%d").to_string())
+        .replace("{{time}}", &now.format("%H:%M").to_string())
+}
+
+/// [`substitute`] every string inside a frontmatter value
+fn substitute_value(value: &mut JsonValue, now: DateTime<Local>) {
+    match value {
+        JsonValue::String(text) => *text = substitute(text, now),
+        JsonValue::Array(items) => {
+            for item in items {
+                substitute_value(item, now);
+            }
+        }
+        JsonValue::Object(fields) => {
+            for field in fields.values_mut() {
+                substitute_value(field, now);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Frontmatter fields the template leaves empty, in name order
+fn missing_fields(frontmatter: &HashMap<String, JsonValue>) -> Vec<String> {
+    let mut missing: Vec<String> = frontmatter
+        .iter()
+        .filter(|(_, value)| match value {
+            JsonValue::Null => true,
+            JsonValue::String(text) => text.trim().is_empty(),
+            _ => false,
+        })
+        .map(|(key, _)| key.clone())
+        .collect();
+    missing.sort();
+    missing
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_substitute_placeholders_in_frontmatter() {
+        let now = Local.with_ymd_and_hms(2024, 3, 7, 9, 5, 0).unwrap();
+        let (mut frontmatter, body) = parse_frontmatter(
+            "---\ntitle: Daily {{date}}\nstatus:\nmood: \"\"\ntags: [\"log-{{date}}\"]\n---\nStarted at {{time}}\n",
+        )
+        .unwrap();
+        for value in frontmatter.values_mut() {
+            substitute_value(value, now);
+        }
+
+        assert_eq!(frontmatter["title"], "Daily 2024-03-07");
+        assert_eq!(frontmatter["tags"], serde_json::json!(["log-2024-03-07"]));
+        assert_eq!(substitute(&body, now).trim(), "Started at 09:05");
+        assert_eq!(missing_fields(&frontmatter), vec!["mood", "status"]);
+    }
+}
@@ -1,7 +1,7 @@
 use anyhow::{Context, Result};
 use anytype_rs::api::{
-    AnytypeClient, CreateTypeProperty, CreateTypeRequest, Icon, Layout, PropertyFormat,
-    UpdateTypeRequest,
+    AnytypeClient, CreateTypeProperty, CreateTypeRequest, Icon, Layout, Method, PropertyFormat,
+    Type, UpdateTypeRequest,
 };
 use clap::{Args, Subcommand};
 
@@ -20,17 +20,38 @@ struct CreateTypeParams {
     layout: String,
     icon_emoji: Option<String>,
     properties: Vec<String>,
+    dry_run: bool,
 }
 
+#[derive(Debug)]
+struct ListTypesParams {
+    limit: u32,
+    user_only: bool,
+    full: bool,
+    json: bool,
+}
+
+/// Number of properties shown per type in `type list` unless `--full` is given
+const SUMMARY_PROPERTIES: usize = 3;
+
 #[derive(Debug, Subcommand)]
 pub enum TypeCommand {
     /// List types in a space
     List {
         /// Space ID
         space_id: String,
-        /// Limit the number of results
-        #[arg(short, long, default_value = "20")]
-        limit: u32,
+        /// Limit the number of results (default 20, or the configured page size)
+        #[arg(short, long)]
+        limit: Option<u32>,
+        /// Hide Anytype's built-in types (page, note, task, ...) and show only user-created ones
+        #[arg(long)]
+        user_only: bool,
+        /// Show every property of each type rather than the first three
+        #[arg(long)]
+        full: bool,
+        /// Print the types as a JSON array; properties are only included with --full
+        #[arg(long)]
+        json: bool,
     },
     /// Get details of a specific type
     Get {
@@ -44,14 +65,14 @@ pub enum TypeCommand {
         /// Space ID where the type will be created
         space_id: String,
         /// Type key (unique identifier)
-        #[arg(short, long)]
-        key: String,
+        #[arg(short, long, required_unless_present = "raw_json")]
+        key: Option<String>,
         /// Type name
-        #[arg(short, long)]
-        name: String,
+        #[arg(short, long, required_unless_present = "raw_json")]
+        name: Option<String>,
         /// Plural name for the type
-        #[arg(short, long)]
-        plural_name: String,
+        #[arg(long, required_unless_present = "raw_json")]
+        plural_name: Option<String>,
         /// Layout for the type
         #[arg(short, long, default_value = "basic")]
         layout: String,
@@ -59,8 +80,19 @@ pub enum TypeCommand {
         #[arg(long)]
         icon_emoji: Option<String>,
         /// Property definitions in format "key:name:format" (can be repeated)
-        #[arg(long, value_delimiter = ',')]
+        #[arg(short, long, value_delimiter = ',')]
         properties: Vec<String>,
+        /// [Advanced, unstable] Send this JSON request body (file path, or - for stdin)
+        /// verbatim instead of building the request from the other flags
+        #[arg(
+            long,
+            value_name = "FILE|-",
+            conflicts_with_all = ["key", "name", "plural_name", "icon_emoji", "properties"]
+        )]
+        raw_json: Option<String>,
+        /// Print the request that would be sent without sending it
+        #[arg(long)]
+        dry_run: bool,
     },
     /// Update an existing type in a space
     Update {
@@ -75,7 +107,7 @@ pub enum TypeCommand {
         #[arg(short, long)]
         name: String,
         /// Plural name for the type
-        #[arg(short, long)]
+        #[arg(long)]
         plural_name: String,
         /// Layout for the type
         #[arg(short, long, default_value = "basic")]
@@ -84,8 +116,38 @@ pub enum TypeCommand {
         #[arg(long)]
         icon_emoji: Option<String>,
         /// Property definitions in format "key:name:format" (can be repeated)
-        #[arg(long, value_delimiter = ',')]
+        #[arg(short, long, value_delimiter = ',')]
         properties: Vec<String>,
+        /// Print the request that would be sent without sending it
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Rename a type, keeping its key, layout, icon and properties
+    Rename {
+        /// Current type name, key or ID
+        old: String,
+        /// New name for the type
+        new: String,
+        /// Space ID where the type exists
+        #[arg(short, long)]
+        space: String,
+        /// Print the request that would be sent without sending it
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Add properties to a type, keeping the ones it already has
+    AddProperties {
+        /// Type ID to add properties to
+        type_id: String,
+        /// Space ID where the type exists
+        #[arg(short, long)]
+        space: String,
+        /// Property definitions in format "key:name:format", comma-separated
+        #[arg(long, value_delimiter = ',', required = true)]
+        spec: Vec<String>,
+        /// List the properties that would be added without updating the type
+        #[arg(long)]
+        dry_run: bool,
     },
     /// Delete (archive) a type in a space
     Delete {
@@ -93,6 +155,31 @@ pub enum TypeCommand {
         space_id: String,
         /// Type ID to delete
         type_id: String,
+        /// Print the request that would be sent without sending it
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Export a type definition (key, name, layout, icon, properties) as JSON
+    Export {
+        /// Type ID or key (e.g. `ot_book`) to export
+        type_id: String,
+        /// Space ID where the type exists
+        #[arg(short, long)]
+        space: String,
+        /// Path of the JSON file to write
+        #[arg(short, long)]
+        out: String,
+    },
+    /// Recreate a type from a JSON file written by `type export`
+    Import {
+        /// Path to the exported type JSON file
+        file: String,
+        /// Space ID where the type will be created
+        #[arg(short, long)]
+        space: String,
+        /// Print the request that would be sent without sending it
+        #[arg(long)]
+        dry_run: bool,
     },
 }
 
@@ -104,8 +191,31 @@ pub async fn handle_type_command(args: TypeArgs) -> Result<()> {
     client.set_api_key(api_key);
 
     match args.command {
-        TypeCommand::List { space_id, limit } => list_types(&client, &space_id, limit).await,
+        TypeCommand::List {
+            space_id,
+            limit,
+            user_only,
+            full,
+            json,
+        } => {
+            let list_params = ListTypesParams {
+                limit: super::page_size(limit, 20)?,
+                user_only,
+                full,
+                json,
+            };
+            list_types(&client, &space_id, list_params).await
+        }
         TypeCommand::Get { space_id, type_id } => get_type(&client, &space_id, &type_id).await,
+        TypeCommand::Create {
+            space_id,
+            raw_json: Some(raw_json),
+            dry_run,
+            ..
+        } => {
+            let path = format!("/v1/spaces/{space_id}/types");
+            super::send_raw_json(&client, Method::POST, &path, &raw_json, dry_run).await
+        }
         TypeCommand::Create {
             space_id,
             key,
@@ -114,15 +224,18 @@ pub async fn handle_type_command(args: TypeArgs) -> Result<()> {
             layout,
             icon_emoji,
             properties,
+            dry_run,
+            ..
         } => {
             let create_params = CreateTypeParams {
                 space_id,
-                key,
-                name,
-                plural_name,
+                key: key.context("--key is required")?,
+                name: name.context("--name is required")?,
+                plural_name: plural_name.context("--plural-name is required")?,
                 layout,
                 icon_emoji,
                 properties,
+                dry_run,
             };
             create_type(&client, create_params).await
         }
@@ -135,6 +248,7 @@ pub async fn handle_type_command(args: TypeArgs) -> Result<()> {
             layout,
             icon_emoji,
             properties,
+            dry_run,
         } => {
             let update_params = CreateTypeParams {
                 space_id,
@@ -144,22 +258,71 @@ pub async fn handle_type_command(args: TypeArgs) -> Result<()> {
                 layout,
                 icon_emoji,
                 properties,
+                dry_run,
             };
             update_type(&client, &type_id, update_params).await
         }
-        TypeCommand::Delete { space_id, type_id } => {
-            delete_type(&client, &space_id, &type_id).await
-        }
+        TypeCommand::Rename {
+            old,
+            new,
+            space,
+            dry_run,
+        } => rename_type(&client, &space, &old, &new, dry_run).await,
+        TypeCommand::AddProperties {
+            type_id,
+            space,
+            spec,
+            dry_run,
+        } => add_properties(&client, &space, &type_id, &spec, dry_run).await,
+        TypeCommand::Delete {
+            space_id,
+            type_id,
+            dry_run,
+        } => delete_type(&client, &space_id, &type_id, dry_run).await,
+        TypeCommand::Export {
+            type_id,
+            space,
+            out,
+        } => export_type(&client, &space, &type_id, &out).await,
+        TypeCommand::Import {
+            file,
+            space,
+            dry_run,
+        } => import_type(&client, &space, &file, dry_run).await,
     }
 }
 
-async fn list_types(client: &AnytypeClient, space_id: &str, limit: u32) -> Result<()> {
-    println!("🏷️  Fetching types from space '{space_id}'...");
+async fn list_types(client: &AnytypeClient, space_id: &str, params: ListTypesParams) -> Result<()> {
+    let ListTypesParams {
+        limit,
+        user_only,
+        full,
+        json,
+    } = params;
+    if !json {
+        println!("🏷️  Fetching types from space '{space_id}'...");
+    }
 
-    let types = client
+    let mut types = client
         .list_types(space_id)
         .await
         .context("Failed to fetch types")?;
+    if user_only {
+        types.retain(|type_obj| !type_obj.is_system());
+    }
+
+    if json {
+        types.truncate(limit as usize);
+        let mut value = serde_json::to_value(&types).context("Failed to serialize types")?;
+        if !full && let Some(types) = value.as_array_mut() {
+            for type_obj in types.iter_mut().filter_map(|t| t.as_object_mut()) {
+                type_obj.remove("properties");
+            }
+        }
+        let json = serde_json::to_string_pretty(&value).context("Failed to serialize types")?;
+        println!("{json}");
+        return Ok(());
+    }
 
     if types.is_empty() {
         println!("📭 No types found in this space.");
@@ -188,30 +351,25 @@ async fn list_types(client: &AnytypeClient, space_id: &str, limit: u32) -> Resul
             println!("     📦 Archived: Yes");
         }
 
-        match &type_obj.icon {
-            Icon::Emoji { emoji } => {
-                println!("     🎨 Icon: {emoji}");
-            }
-            Icon::File { file } => {
-                println!("     🎨 Icon: {file}");
-            }
-            Icon::Icon { name, color } => {
-                println!("     🎨 Icon: {name} ({color:?})");
-            }
-        }
+        println!("     🎨 Icon: {}", type_obj.icon.display());
 
         if !type_obj.properties.is_empty() {
             println!(
                 "     🔑 Properties: {} properties",
                 type_obj.properties.len()
             );
-            for prop in type_obj.properties.iter().take(3) {
+            let shown = if full {
+                type_obj.properties.len()
+            } else {
+                SUMMARY_PROPERTIES
+            };
+            for prop in type_obj.properties.iter().take(shown) {
                 println!("       • {} ({}) - {}", prop.name, prop.format, prop.key);
             }
-            if type_obj.properties.len() > 3 {
+            if type_obj.properties.len() > shown {
                 println!(
-                    "       ... and {} more properties",
-                    type_obj.properties.len() - 3
+                    "       ... and {} more properties (use --full to show them)",
+                    type_obj.properties.len() - shown
                 );
             }
         }
@@ -227,11 +385,6 @@ async fn list_types(client: &AnytypeClient, space_id: &str, limit: u32) -> Resul
 }
 
 async fn create_type(client: &AnytypeClient, params: CreateTypeParams) -> Result<()> {
-    println!(
-        "🏗️  Creating type '{}' in space '{}'...",
-        params.name, params.space_id
-    );
-
     // Parse layout
     let layout_enum = match params.layout.to_lowercase().as_str() {
         "basic" => Layout::Basic,
@@ -251,61 +404,35 @@ async fn create_type(client: &AnytypeClient, params: CreateTypeParams) -> Result
         }
     };
 
-    // Parse icon - provide default if none specified
-    let icon = params
-        .icon_emoji
-        .map(|emoji| Icon::Emoji { emoji })
-        .unwrap_or(Icon::Emoji {
-            emoji: "📄".to_string(), // Default icon
-        });
-
     // Parse properties
-    let mut parsed_properties = Vec::new();
-    for prop_str in &params.properties {
-        let parts: Vec<&str> = prop_str.split(':').collect();
-        if parts.len() != 3 {
-            println!(
-                "❌ Invalid property format: '{prop_str}'. Expected format: 'key:name:format'"
-            );
+    let parsed_properties = match parse_property_specs(&params.properties) {
+        Ok(properties) => properties,
+        Err(message) => {
+            println!("❌ {message}");
             return Ok(());
         }
+    };
 
-        let property_format = match parts[2].to_lowercase().as_str() {
-            "text" => PropertyFormat::Text,
-            "number" => PropertyFormat::Number,
-            "select" => PropertyFormat::Select,
-            "multi_select" | "multiselect" => PropertyFormat::MultiSelect,
-            "date" => PropertyFormat::Date,
-            "files" => PropertyFormat::Files,
-            "checkbox" => PropertyFormat::Checkbox,
-            "url" => PropertyFormat::Url,
-            "email" => PropertyFormat::Email,
-            "phone" => PropertyFormat::Phone,
-            "objects" => PropertyFormat::Objects,
-            _ => {
-                println!(
-                    "❌ Invalid property format: '{}'. Valid options: text, number, select, multi_select, date, files, checkbox, url, email, phone, objects",
-                    parts[2]
-                );
-                return Ok(());
-            }
-        };
+    let mut request = CreateTypeRequest {
+        properties: parsed_properties,
+        ..CreateTypeRequest::new(params.key, params.name.clone())
+            .plural_name(params.plural_name)
+            .layout(layout_enum)
+    };
+    // The builder's default icon is used unless one is specified
+    if let Some(emoji) = params.icon_emoji {
+        request = request.icon(Icon::Emoji { emoji });
+    }
 
-        parsed_properties.push(CreateTypeProperty {
-            key: parts[0].to_string(),
-            name: parts[1].to_string(),
-            format: property_format,
-        });
+    if params.dry_run {
+        let path = format!("/v1/spaces/{}/types", params.space_id);
+        return super::print_dry_run_with_body("POST", &path, &request);
     }
 
-    let request = CreateTypeRequest {
-        key: params.key,
-        name: params.name.clone(),
-        plural_name: params.plural_name,
-        layout: layout_enum,
-        icon,
-        properties: parsed_properties,
-    };
+    println!(
+        "🏗️  Creating type '{}' in space '{}'...",
+        params.name, params.space_id
+    );
 
     let response = client
         .create_type(&params.space_id, request)
@@ -325,17 +452,7 @@ async fn create_type(client: &AnytypeClient, params: CreateTypeParams) -> Result
         println!("  📚 Plural: {plural_name}");
     }
 
-    match &response.type_data.icon {
-        Icon::Emoji { emoji } => {
-            println!("  🎨 Icon: {emoji}");
-        }
-        Icon::File { file } => {
-            println!("  🎨 Icon: {file}");
-        }
-        Icon::Icon { name, color } => {
-            println!("  🎨 Icon: {name} ({color:?})");
-        }
-    }
+    println!("  🎨 Icon: {}", response.type_data.icon.display());
 
     if !response.type_data.properties.is_empty() {
         println!(
@@ -377,17 +494,7 @@ async fn get_type(client: &AnytypeClient, space_id: &str, type_id: &str) -> Resu
         println!("  📦 Archived: Yes");
     }
 
-    match &type_obj.icon {
-        Icon::Emoji { emoji } => {
-            println!("  🎨 Icon: {emoji}");
-        }
-        Icon::File { file } => {
-            println!("  🎨 Icon: {file}");
-        }
-        Icon::Icon { name, color } => {
-            println!("  🎨 Icon: {name} ({color:?})");
-        }
-    }
+    println!("  🎨 Icon: {}", type_obj.icon.display());
 
     if !type_obj.properties.is_empty() {
         println!("  🔑 Properties: {} total", type_obj.properties.len());
@@ -406,11 +513,6 @@ async fn update_type(
     type_id: &str,
     params: CreateTypeParams,
 ) -> Result<()> {
-    println!(
-        "🔄 Updating type '{}' in space '{}'...",
-        type_id, params.space_id
-    );
-
     // Parse layout
     let layout_enum = match params.layout.to_lowercase().as_str() {
         "basic" => Layout::Basic,
@@ -439,43 +541,13 @@ async fn update_type(
         });
 
     // Parse properties
-    let mut parsed_properties = Vec::new();
-    for prop_str in &params.properties {
-        let parts: Vec<&str> = prop_str.split(':').collect();
-        if parts.len() != 3 {
-            println!(
-                "❌ Invalid property format: '{prop_str}'. Expected format: 'key:name:format'"
-            );
+    let parsed_properties = match parse_property_specs(&params.properties) {
+        Ok(properties) => properties,
+        Err(message) => {
+            println!("❌ {message}");
             return Ok(());
         }
-
-        let property_format = match parts[2].to_lowercase().as_str() {
-            "text" => PropertyFormat::Text,
-            "number" => PropertyFormat::Number,
-            "select" => PropertyFormat::Select,
-            "multi_select" | "multiselect" => PropertyFormat::MultiSelect,
-            "date" => PropertyFormat::Date,
-            "files" => PropertyFormat::Files,
-            "checkbox" => PropertyFormat::Checkbox,
-            "url" => PropertyFormat::Url,
-            "email" => PropertyFormat::Email,
-            "phone" => PropertyFormat::Phone,
-            "objects" => PropertyFormat::Objects,
-            _ => {
-                println!(
-                    "❌ Invalid property format: '{}'. Valid options: text, number, select, multi_select, date, files, checkbox, url, email, phone, objects",
-                    parts[2]
-                );
-                return Ok(());
-            }
-        };
-
-        parsed_properties.push(CreateTypeProperty {
-            key: parts[0].to_string(),
-            name: parts[1].to_string(),
-            format: property_format,
-        });
-    }
+    };
 
     let request = UpdateTypeRequest {
         key: Some(params.key),
@@ -486,6 +558,16 @@ async fn update_type(
         properties: Some(parsed_properties),
     };
 
+    if params.dry_run {
+        let path = format!("/v1/spaces/{}/types/{type_id}", params.space_id);
+        return super::print_dry_run_with_body("PATCH", &path, &request);
+    }
+
+    println!(
+        "🔄 Updating type '{}' in space '{}'...",
+        type_id, params.space_id
+    );
+
     let response = client
         .update_type(&params.space_id, type_id, request)
         .await
@@ -504,17 +586,7 @@ async fn update_type(
         println!("  📚 Plural: {plural_name}");
     }
 
-    match &response.type_data.icon {
-        Icon::Emoji { emoji } => {
-            println!("  🎨 Icon: {emoji}");
-        }
-        Icon::File { file } => {
-            println!("  🎨 Icon: {file}");
-        }
-        Icon::Icon { name, color } => {
-            println!("  🎨 Icon: {name} ({color:?})");
-        }
-    }
+    println!("  🎨 Icon: {}", response.type_data.icon.display());
 
     if !response.type_data.properties.is_empty() {
         println!(
@@ -529,7 +601,158 @@ async fn update_type(
     Ok(())
 }
 
-async fn delete_type(client: &AnytypeClient, space_id: &str, type_id: &str) -> Result<()> {
+async fn rename_type(
+    client: &AnytypeClient,
+    space_id: &str,
+    old: &str,
+    new: &str,
+    dry_run: bool,
+) -> Result<()> {
+    let types = client
+        .list_types(space_id)
+        .await
+        .context("Failed to fetch types")?;
+    let type_data = types
+        .iter()
+        .find(|t| t.id == old || t.key == old)
+        .or_else(|| types.iter().find(|t| t.name.eq_ignore_ascii_case(old)))
+        .with_context(|| format!("No type named '{old}' in space '{space_id}'"))?;
+
+    if dry_run {
+        super::print_dry_run(
+            "PATCH",
+            &format!("/v1/spaces/{space_id}/types/{}", type_data.id),
+        );
+        println!("  ✏️  name: '{}' → '{new}'", type_data.name);
+        return Ok(());
+    }
+
+    println!(
+        "✏️  Renaming type '{}' to '{new}' in space '{space_id}'...",
+        type_data.name
+    );
+
+    let response = client
+        .rename_type(space_id, &type_data.id, new)
+        .await
+        .context("Failed to rename type")?;
+
+    println!("✅ Type renamed successfully!");
+    println!("  🏷️  Name: {}", response.type_data.name);
+    println!("  🆔 ID: {}", response.type_data.id);
+    println!("  🔑 Key: {}", response.type_data.key);
+
+    Ok(())
+}
+
+async fn add_properties(
+    client: &AnytypeClient,
+    space_id: &str,
+    type_id: &str,
+    specs: &[String],
+    dry_run: bool,
+) -> Result<()> {
+    let properties = match parse_property_specs(specs) {
+        Ok(properties) => properties,
+        Err(message) => {
+            println!("❌ {message}");
+            return Ok(());
+        }
+    };
+
+    if dry_run {
+        let type_data = client
+            .get_type(space_id, type_id)
+            .await
+            .context("Failed to fetch type")?;
+        super::print_dry_run("PATCH", &format!("/v1/spaces/{space_id}/types/{type_id}"));
+        for property in &properties {
+            if type_data.properties.iter().any(|p| p.key == property.key) {
+                println!("  ⏭️  Already exists: {}", property.key);
+            } else {
+                println!("  ➕ Would add: {}", property.key);
+            }
+        }
+        return Ok(());
+    }
+
+    println!(
+        "🔑 Adding {} properties to type '{type_id}' in space '{space_id}'...",
+        properties.len()
+    );
+
+    let response = client
+        .add_type_properties(space_id, type_id, properties)
+        .await
+        .context("Failed to add properties to type")?;
+
+    for key in &response.added {
+        println!("  ✅ Added: {key}");
+    }
+    for key in &response.existing {
+        println!("  ⏭️  Already exists: {key}");
+    }
+    println!(
+        "✅ {} added, {} already existed on '{}'",
+        response.added.len(),
+        response.existing.len(),
+        response.type_data.name
+    );
+
+    Ok(())
+}
+
+/// Parse property definitions in `key:name:format` form
+fn parse_property_specs(specs: &[String]) -> std::result::Result<Vec<CreateTypeProperty>, String> {
+    specs.iter().map(|spec| parse_property_spec(spec)).collect()
+}
+
+fn parse_property_spec(spec: &str) -> std::result::Result<CreateTypeProperty, String> {
+    let parts: Vec<&str> = spec.split(':').collect();
+    if parts.len() != 3 {
+        return Err(format!(
+            "Invalid property format: '{spec}'. Expected format: 'key:name:format'"
+        ));
+    }
+
+    let format = match parts[2].to_lowercase().as_str() {
+        "text" => PropertyFormat::Text,
+        "number" => PropertyFormat::Number,
+        "select" => PropertyFormat::Select,
+        "multi_select" | "multiselect" => PropertyFormat::MultiSelect,
+        "date" => PropertyFormat::Date,
+        "files" => PropertyFormat::Files,
+        "checkbox" => PropertyFormat::Checkbox,
+        "url" => PropertyFormat::Url,
+        "email" => PropertyFormat::Email,
+        "phone" => PropertyFormat::Phone,
+        "objects" => PropertyFormat::Objects,
+        _ => {
+            return Err(format!(
+                "Invalid property format: '{}'. Valid options: text, number, select, multi_select, date, files, checkbox, url, email, phone, objects",
+                parts[2]
+            ));
+        }
+    };
+
+    Ok(CreateTypeProperty {
+        key: parts[0].to_string(),
+        name: parts[1].to_string(),
+        format,
+    })
+}
+
+async fn delete_type(
+    client: &AnytypeClient,
+    space_id: &str,
+    type_id: &str,
+    dry_run: bool,
+) -> Result<()> {
+    if dry_run {
+        super::print_dry_run("DELETE", &format!("/v1/spaces/{space_id}/types/{type_id}"));
+        return Ok(());
+    }
+
     println!("⚠️  Deleting (archiving) type '{type_id}' in space '{space_id}'...");
     println!("📝 Note: This will mark the type as archived, not permanently delete it.");
 
@@ -557,17 +780,7 @@ async fn delete_type(client: &AnytypeClient, space_id: &str, type_id: &str) -> R
         println!("  📚 Plural: {plural_name}");
     }
 
-    match &response.type_data.icon {
-        Icon::Emoji { emoji } => {
-            println!("  🎨 Icon: {emoji}");
-        }
-        Icon::File { file } => {
-            println!("  🎨 Icon: {file}");
-        }
-        Icon::Icon { name, color } => {
-            println!("  🎨 Icon: {name} ({color:?})");
-        }
-    }
+    println!("  🎨 Icon: {}", response.type_data.icon.display());
 
     if !response.type_data.properties.is_empty() {
         println!(
@@ -587,3 +800,79 @@ async fn delete_type(client: &AnytypeClient, space_id: &str, type_id: &str) -> R
 
     Ok(())
 }
+
+async fn export_type(
+    client: &AnytypeClient,
+    space_id: &str,
+    type_id: &str,
+    out: &str,
+) -> Result<()> {
+    println!("📤 Exporting type '{type_id}' from space '{space_id}'...");
+
+    // Accept a type key as well: the API only looks types up by ID
+    let type_obj = match client.get_type(space_id, type_id).await {
+        Err(e) if e.kind() == "not_found" => client.get_type_by_key(space_id, type_id).await,
+        result => result,
+    }
+    .context("Failed to fetch type")?;
+
+    let json = serde_json::to_string_pretty(&type_obj).context("Failed to serialize type")?;
+    std::fs::write(out, json).with_context(|| format!("Failed to write file: {out}"))?;
+
+    println!("✅ Type exported successfully!");
+    println!("  🏷️  Name: {} ({})", type_obj.name, type_obj.key);
+    println!("  🔑 Properties: {}", type_obj.properties.len());
+    println!("  💾 File: {out}");
+
+    Ok(())
+}
+
+async fn import_type(
+    client: &AnytypeClient,
+    space_id: &str,
+    file_path: &str,
+    dry_run: bool,
+) -> Result<()> {
+    let content = std::fs::read_to_string(file_path)
+        .with_context(|| format!("Failed to read file: {file_path}"))?;
+    let type_obj: Type = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse type definition: {file_path}"))?;
+
+    let request =
+        CreateTypeRequest::from_exported_type(&type_obj).context("Invalid type definition")?;
+    if request.key != type_obj.key {
+        println!(
+            "  ⚠️  Key '{}' is not snake_case, importing as '{}'",
+            type_obj.key, request.key
+        );
+    }
+
+    if dry_run {
+        let path = format!("/v1/spaces/{space_id}/types");
+        return super::print_dry_run_with_body("POST", &path, &request);
+    }
+
+    println!("📥 Importing type from '{file_path}' into space '{space_id}'...");
+
+    let response = client
+        .create_type(space_id, request)
+        .await
+        .context("Failed to create type")?;
+
+    println!("✅ Type imported successfully!");
+    println!("  🏷️  Name: {}", response.type_data.name);
+    println!("  🆔 ID: {}", response.type_data.id);
+    println!("  🔑 Key: {}", response.type_data.key);
+
+    if !response.type_data.properties.is_empty() {
+        println!(
+            "  🔑 Properties: {} created",
+            response.type_data.properties.len()
+        );
+        for prop in &response.type_data.properties {
+            println!("    • {} ({}) - {}", prop.name, prop.format, prop.key);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use anyhow::Result;
+use anytype_rs::api::{ANYTYPE_API_VERSION, AnytypeClient};
+
+pub async fn handle_version_command() -> Result<()> {
+    println!("📦 Client version: {}", env!("CARGO_PKG_VERSION"));
+    println!("🔌 API version: {ANYTYPE_API_VERSION}");
+
+    // The server is only asked when logged in; a failed probe is reported, not an error
+    let Some(api_key) = crate::config::load_api_key()? else {
+        println!("🖥️  Server version: unknown (run 'anytype auth login' to check)");
+        return Ok(());
+    };
+
+    let mut client = AnytypeClient::new()?;
+    client.set_api_key(api_key);
+
+    match client.server_version().await {
+        Ok(Some(version)) => {
+            println!("🖥️  Server version: {version}");
+            if version != ANYTYPE_API_VERSION {
+                println!("⚠️  The server's API version differs from the one this client targets");
+            }
+        }
+        Ok(None) => println!("🖥️  Server version: not reported by the server"),
+        Err(e) => println!("🖥️  Server version: unavailable ({e})"),
+    }
+
+    Ok(())
+}
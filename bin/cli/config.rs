@@ -39,11 +39,83 @@ pub fn load_api_key() -> Result<Option<String>> {
     }
 }
 
-/// Remove stored API key
-pub fn remove_api_key() -> Result<()> {
+/// Remove stored API key, returning the path of the removed file (if there was one)
+pub fn remove_api_key() -> Result<Option<PathBuf>> {
     let key_file = api_key_file()?;
     if key_file.exists() {
-        std::fs::remove_file(key_file)?;
+        std::fs::remove_file(&key_file)?;
+        Ok(Some(key_file))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Environment variable overriding the configured default page size
+pub const PAGE_SIZE_ENV: &str = "ANYTYPE_PAGE_SIZE";
+
+/// Get the path to the default page size file
+pub fn page_size_file() -> Result<PathBuf> {
+    Ok(config_dir()?.join("page_size"))
+}
+
+/// Save the default page size, or remove it with `None`
+pub fn save_page_size(page_size: Option<u32>) -> Result<()> {
+    let page_size_file = page_size_file()?;
+    match page_size {
+        Some(page_size) => std::fs::write(page_size_file, page_size.to_string())?,
+        None if page_size_file.exists() => std::fs::remove_file(page_size_file)?,
+        None => {}
     }
     Ok(())
 }
+
+/// Default number of results for list and search commands given no `--limit`
+///
+/// `ANYTYPE_PAGE_SIZE` takes precedence over the page size saved with `config page-size`.
+/// Returns `None` when neither is set, leaving each command its own default.
+pub fn default_page_size() -> Result<Option<u32>> {
+    let page_size_file = page_size_file()?;
+    let saved = if page_size_file.exists() {
+        Some(std::fs::read_to_string(page_size_file)?)
+    } else {
+        None
+    };
+    let env = std::env::var(PAGE_SIZE_ENV).ok();
+    choose_page_size(env.as_deref(), saved.as_deref())
+}
+
+/// Pick the page size from the environment variable, then the config file
+fn choose_page_size(env: Option<&str>, saved: Option<&str>) -> Result<Option<u32>> {
+    if let Some(value) = env.filter(|value| !value.trim().is_empty()) {
+        return parse_page_size(value)
+            .map(Some)
+            .map_err(|e| anyhow::anyhow!("Invalid {PAGE_SIZE_ENV}: {e}"));
+    }
+    saved
+        .map(|value| {
+            parse_page_size(value).map_err(|e| anyhow::anyhow!("Invalid saved page size: {e}"))
+        })
+        .transpose()
+}
+
+fn parse_page_size(value: &str) -> std::result::Result<u32, String> {
+    match value.trim().parse::<u32>() {
+        Ok(0) => Err("must be at least 1".to_string()),
+        Ok(page_size) => Ok(page_size),
+        Err(_) => Err(format!("'{}' is not a number", value.trim())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_size_env_overrides_saved_value() {
+        assert_eq!(choose_page_size(Some("50"), Some("25")).unwrap(), Some(50));
+        assert_eq!(choose_page_size(None, Some("25\n")).unwrap(), Some(25));
+        assert_eq!(choose_page_size(Some(" "), None).unwrap(), None);
+        assert!(choose_page_size(Some("0"), None).is_err());
+        assert!(choose_page_size(None, Some("many")).is_err());
+    }
+}
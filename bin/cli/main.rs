@@ -1,5 +1,6 @@
 mod commands;
 mod config;
+mod render;
 
 use anyhow::Result;
 use clap::{Parser, Subcommand};
@@ -34,6 +35,12 @@ pub enum Commands {
     /// Authentication commands
     Auth(commands::auth::AuthArgs),
 
+    /// Configuration commands
+    Config(commands::config::ConfigArgs),
+
+    /// Export commands
+    Export(commands::export::ExportArgs),
+
     /// Import commands
     Import(commands::import::ImportArgs),
 
@@ -63,6 +70,9 @@ pub enum Commands {
 
     /// Type management commands
     Type(commands::r#type::TypeArgs),
+
+    /// Show the client, API and server versions
+    Version,
 }
 
 #[tokio::main]
@@ -75,6 +85,8 @@ async fn main() -> Result<()> {
     // Handle commands
     let result = match cli.command {
         Commands::Auth(args) => commands::auth::handle_auth_command(args).await,
+        Commands::Config(args) => commands::config::handle_config_command(args).await,
+        Commands::Export(args) => commands::export::handle_export_command(args).await,
         Commands::Import(args) => commands::import::handle_import_command(args).await,
         Commands::List(args) => commands::list::handle_list_command(args).await,
         Commands::Member(args) => commands::member::handle_member_command(args).await,
@@ -85,13 +97,23 @@ async fn main() -> Result<()> {
         Commands::Tag(args) => commands::tag::handle_tag_command(args).await,
         Commands::Template(args) => commands::template::handle_template_command(args).await,
         Commands::Type(args) => commands::r#type::handle_type_command(args).await,
+        Commands::Version => commands::version::handle_version_command().await,
     };
 
     if let Err(ref error) = result {
-        eprintln!("❌ Error: {error}");
+        match error_hint(error) {
+            Some(hint) => eprintln!("❌ {hint}"),
+            None => eprintln!("❌ Error: {error}"),
+        }
 
-        // Print error chain if in debug mode
+        // Print error kind and chain if in debug mode
         if cli.debug {
+            if let Some(err) = error
+                .chain()
+                .find_map(|err| err.downcast_ref::<anytype_rs::api::AnytypeError>())
+            {
+                eprintln!("  Kind: {}", err.kind());
+            }
             let mut source = error.source();
             while let Some(err) = source {
                 eprintln!("  Caused by: {err}");
@@ -105,6 +127,38 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+/// A message saying what to do about an error from the library, for kinds whose own
+/// message doesn't
+///
+/// Commands wrap library errors with `anyhow` context, so the whole chain is searched for
+/// the [`AnytypeError`](anytype_rs::api::AnytypeError). Errors are matched by
+/// [`kind`](anytype_rs::api::AnytypeError::kind), so a plain HTTP error caused by a timeout
+/// or a refused connection gets the same hint as the matching variant.
+fn error_hint(error: &anyhow::Error) -> Option<String> {
+    use anytype_rs::api::AnytypeError;
+
+    let err = error
+        .chain()
+        .find_map(|err| err.downcast_ref::<AnytypeError>())?;
+    // Timeouts and refused connections usually mean the local app isn't running
+    match (err.kind(), err) {
+        ("timeout", AnytypeError::Timeout { duration }) => Some(format!(
+            "Anytype didn't respond within {}s — is the app running?",
+            duration.as_secs()
+        )),
+        ("timeout", _) => Some("Anytype didn't respond in time — is the app running?".to_string()),
+        ("connection", AnytypeError::Connection { message }) => Some(message.clone()),
+        ("connection", _) => Some(
+            "Could not connect to Anytype. Is the desktop app running and the API enabled?"
+                .to_string(),
+        ),
+        ("auth", _) => Some(format!(
+            "{err}\n💡 Run 'anytype auth login' to get a new API key."
+        )),
+        _ => None,
+    }
+}
+
 fn init_logging(trace_http: bool, debug: bool, verbose: bool) -> Result<()> {
     // Determine log level based on flags
     let level = if trace_http {
@@ -157,3 +211,76 @@ fn init_logging(trace_http: bool, debug: bool, verbose: bool) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    /// Short flags that mean the same thing in every subcommand, with a word their long
+    /// name must contain
+    const STANDARD_SHORTS: [(char, &str); 4] = [
+        ('s', "space"),
+        ('t', "type"),
+        ('p', "propert"),
+        ('n', "name"),
+    ];
+
+    fn check_shorts(command: &clap::Command, path: &str) {
+        for arg in command.get_arguments() {
+            let (Some(short), Some(long)) = (arg.get_short(), arg.get_long()) else {
+                continue;
+            };
+            if let Some((_, word)) = STANDARD_SHORTS.iter().find(|(c, _)| *c == short) {
+                assert!(
+                    long.contains(word),
+                    "`{path}`: -{short} is --{long}, expected a {word} flag"
+                );
+            }
+        }
+        for sub in command.get_subcommands() {
+            check_shorts(sub, &format!("{path} {}", sub.get_name()));
+        }
+    }
+
+    #[test]
+    fn test_cli_definition_is_valid() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_short_flags_are_consistent() {
+        check_shorts(&Cli::command(), "atc");
+    }
+
+    #[test]
+    fn test_error_hint_looks_through_context() {
+        use anyhow::Context;
+        use anytype_rs::api::AnytypeError;
+        use std::time::Duration;
+
+        let timeout: Result<()> = Err(AnytypeError::Timeout {
+            duration: Duration::from_secs(30),
+        })
+        .context("Failed to list spaces");
+        let hint = error_hint(&timeout.unwrap_err()).unwrap();
+        assert!(hint.contains("within 30s"), "{hint}");
+
+        let auth: Result<()> = Err(AnytypeError::Auth {
+            message: "HTTP 401".to_string(),
+        })
+        .context("Failed to list spaces")
+        .context("Failed to export");
+        let hint = error_hint(&auth.unwrap_err()).unwrap();
+        assert!(hint.contains("anytype auth login"), "{hint}");
+
+        // Errors without a tailored message keep the generic output
+        let api: Result<()> = Err(AnytypeError::Api {
+            message: "HTTP 500".to_string(),
+            status: None,
+        })
+        .context("Failed to list spaces");
+        assert_eq!(error_hint(&api.unwrap_err()), None);
+        assert_eq!(error_hint(&anyhow::anyhow!("No input")), None);
+    }
+}
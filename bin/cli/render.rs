@@ -0,0 +1,133 @@
+//! Terminal rendering of markdown object bodies (`object get --render`)
+//!
+//! A deliberately small subset of markdown: headings, lists, block quotes, rules, fenced
+//! code blocks, `**bold**` and `` `code` `` spans. Anything else is printed as-is.
+
+const BOLD: &str = "\x1b[1m";
+const UNDERLINE: &str = "\x1b[4m";
+const DIM: &str = "\x1b[2m";
+const CYAN: &str = "\x1b[36m";
+const RESET: &str = "\x1b[0m";
+
+/// Width of the line printed for horizontal rules
+const RULE_WIDTH: usize = 40;
+
+/// Render markdown with ANSI styles for display in a terminal
+pub fn render_markdown(markdown: &str) -> String {
+    let mut out = String::new();
+    let mut in_code_block = false;
+
+    for line in markdown.lines() {
+        let trimmed = line.trim_start();
+        let indent = &line[..line.len() - trimmed.len()];
+
+        if trimmed.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            out.push_str(&format!("    {CYAN}{line}{RESET}\n"));
+            continue;
+        }
+
+        let rendered = if let Some((level, text)) = heading(trimmed) {
+            let underline = if level == 1 { UNDERLINE } else { "" };
+            format!("{BOLD}{underline}{}{RESET}", render_inline(text))
+        } else if is_rule(trimmed) {
+            format!("{DIM}{}{RESET}", "─".repeat(RULE_WIDTH))
+        } else if let Some(text) = trimmed.strip_prefix("> ") {
+            format!("{DIM}│{RESET} {}", render_inline(text))
+        } else if let Some(text) = ["- ", "* ", "+ "]
+            .iter()
+            .find_map(|bullet| trimmed.strip_prefix(bullet))
+        {
+            format!("  {indent}• {}", render_inline(text))
+        } else if let Some((number, text)) = ordered_item(trimmed) {
+            format!("  {indent}{number}. {}", render_inline(text))
+        } else {
+            format!("{indent}{}", render_inline(trimmed))
+        };
+        out.push_str(&rendered);
+        out.push('\n');
+    }
+
+    out
+}
+
+/// Level and text of an ATX heading (`## Title`)
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    let text = line[level..].strip_prefix(' ')?;
+    (1..=6).contains(&level).then_some((level, text.trim()))
+}
+
+/// Number and text of an ordered list item (`3. Item`)
+fn ordered_item(line: &str) -> Option<(&str, &str)> {
+    let (number, text) = line.split_once(". ")?;
+    (!number.is_empty() && number.chars().all(|c| c.is_ascii_digit())).then_some((number, text))
+}
+
+/// Whether the line is a horizontal rule (`---`, `***` or `___`)
+fn is_rule(line: &str) -> bool {
+    let compact: String = line.chars().filter(|c| !c.is_whitespace()).collect();
+    compact.len() >= 3
+        && ['-', '*', '_']
+            .iter()
+            .any(|&marker| compact.chars().all(|c| c == marker))
+}
+
+/// Style `**bold**` and `` `code` `` spans; unmatched markers are kept as-is
+fn render_inline(text: &str) -> String {
+    let mut out = String::new();
+    let mut rest = text;
+
+    loop {
+        let bold = rest.find("**");
+        let code = rest.find('`');
+        let (start, marker, style) = match (bold, code) {
+            (Some(b), Some(c)) if c < b => (c, "`", CYAN),
+            (Some(b), _) => (b, "**", BOLD),
+            (None, Some(c)) => (c, "`", CYAN),
+            (None, None) => break,
+        };
+
+        let after = &rest[start + marker.len()..];
+        let Some(end) = after.find(marker) else {
+            break;
+        };
+        out.push_str(&rest[..start]);
+        out.push_str(&format!("{style}{}{RESET}", &after[..end]));
+        rest = &after[end + marker.len()..];
+    }
+
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_markdown_styles_blocks() {
+        let rendered = render_markdown("# Title\n- item\n  1. nested\n```\nlet x = 1;\n```\n---");
+
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines[0], format!("{BOLD}{UNDERLINE}Title{RESET}"));
+        assert_eq!(lines[1], "  • item");
+        assert_eq!(lines[2], "    1. nested");
+        assert_eq!(lines[3], format!("    {CYAN}let x = 1;{RESET}"));
+        assert_eq!(lines[4], format!("{DIM}{}{RESET}", "─".repeat(RULE_WIDTH)));
+    }
+
+    #[test]
+    fn test_render_inline_spans() {
+        assert_eq!(
+            render_inline("a **b** and `c`"),
+            format!("a {BOLD}b{RESET} and {CYAN}c{RESET}")
+        );
+        // An unmatched marker is left alone
+        assert_eq!(render_inline("2 ** 3"), "2 ** 3");
+        assert_eq!(render_inline("#hashtag"), "#hashtag");
+    }
+}
@@ -1,28 +1,31 @@
 //! Authentication module
 //!
-//! Handles authentication challenges and API key creation.
+//! Handles authentication challenges, API key creation and reachability checks.
 
-use super::AnytypeClient;
+use super::{ANYTYPE_API_HEADER, ANYTYPE_API_VERSION, AnytypeClient};
 use crate::error::Result;
+use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
-use tracing::info;
+use tracing::{debug, info};
+
+const LOG_TARGET: &str = "anytype_rs::client::auth";
 
 /// Request to create an authentication challenge
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateChallengeRequest {
     /// The name of the application requesting the challenge
     pub app_name: String,
 }
 
 /// Response containing challenge information
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct CreateChallengeResponse {
     /// The unique identifier for the challenge
     pub challenge_id: String,
 }
 
 /// Request to create an API key using challenge response
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateApiKeyRequest {
     /// The unique identifier for the challenge, returned from the challenge creation
     pub challenge_id: String,
@@ -31,15 +34,33 @@ pub struct CreateApiKeyRequest {
 }
 
 /// Response containing the API key
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct CreateApiKeyResponse {
     pub api_key: String,
 }
 
+/// Whether the Anytype app can be reached, as reported by [`AnytypeClient::ping`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PingStatus {
+    /// Nothing answered at the base URL: the app isn't running or the API is disabled
+    Unreachable,
+    /// The app answered but rejected the request because no valid API key was sent
+    Unauthorized,
+    /// The app answered and accepted the API key
+    Ready,
+}
+
+impl PingStatus {
+    /// Whether the app answered at all, whatever it made of the API key
+    pub fn is_reachable(&self) -> bool {
+        !matches!(self, Self::Unreachable)
+    }
+}
+
 impl AnytypeClient {
     /// Create an authentication challenge
     pub async fn create_challenge(&self) -> Result<CreateChallengeResponse> {
-        info!("Creating authentication challenge");
+        info!(target: LOG_TARGET, "Creating authentication challenge");
 
         let request = CreateChallengeRequest {
             app_name: self.config.app_name.clone(),
@@ -55,11 +76,41 @@ impl AnytypeClient {
         challenge_id: String,
         code: String,
     ) -> Result<CreateApiKeyResponse> {
-        info!("Creating API key with challenge ID: {}", challenge_id);
+        info!(target: LOG_TARGET, "Creating API key with challenge ID: {}", challenge_id);
 
         let request = CreateApiKeyRequest { challenge_id, code };
 
         self.post_unauthenticated("/v1/auth/api_keys", &request)
             .await
     }
+
+    /// Check whether the Anytype app is reachable, without requiring an API key
+    ///
+    /// Sends a one-item space listing, with the API key if one is set, and reports whether
+    /// anything answered and whether the key was accepted. Meant for checking the app is
+    /// running before starting [`create_challenge`](Self::create_challenge). The circuit
+    /// breaker is bypassed, so a ping always reaches the network.
+    pub async fn ping(&self) -> PingStatus {
+        let url = format!("{}/v1/spaces?limit=1", self.config.base_url);
+        let mut request = self
+            .http_client
+            .get(&url)
+            .header(ANYTYPE_API_HEADER, ANYTYPE_API_VERSION);
+        if let Some(api_key) = &self.api_key {
+            request = request.bearer_auth(api_key);
+        }
+
+        let status = match request.send().await {
+            Ok(response) => match response.status() {
+                StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => PingStatus::Unauthorized,
+                _ => PingStatus::Ready,
+            },
+            Err(e) => {
+                debug!(target: LOG_TARGET, "Ping to {} failed: {}", url, e);
+                PingStatus::Unreachable
+            }
+        };
+        info!(target: LOG_TARGET, "Pinged {}: {:?}", self.config.base_url, status);
+        status
+    }
 }
@@ -0,0 +1,207 @@
+//! Changes module
+//!
+//! Incremental sync on top of search. The API has no change feed or sequence number, so
+//! changes are found by walking a space's objects from the most recently modified down to
+//! the position a [`SyncToken`] points at.
+
+use super::{
+    AnytypeClient,
+    objects::{CREATED_KEY, LAST_MODIFIED_KEY, date_property},
+    search::{SearchObject, SearchSpaceRequest, Sort, SortDirection, SortProperty},
+};
+use crate::error::{AnytypeError, Result};
+use chrono::{DateTime, SecondsFormat, Utc};
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::{debug, info};
+
+const LOG_TARGET: &str = "anytype_rs::client::changes";
+
+/// Number of objects fetched per search request when looking for changes
+const CHANGES_PAGE_SIZE: usize = 100;
+/// Separates the timestamp of a sync token from the IDs seen at that time
+const TOKEN_IDS_SEPARATOR: char = '/';
+
+/// A change to an object, as reported by [`AnytypeClient::changes_since`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    /// The object was created after the token
+    Created(SearchObject),
+    /// The object existed at the token and was modified since
+    Updated(SearchObject),
+    /// The object was archived (the API's delete) since the token
+    Deleted(SearchObject),
+}
+
+impl Change {
+    /// The object that changed, as it is now
+    pub fn object(&self) -> &SearchObject {
+        match self {
+            Self::Created(object) | Self::Updated(object) | Self::Deleted(object) => object,
+        }
+    }
+
+    /// `"created"`, `"updated"` or `"deleted"`
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Created(_) => "created",
+            Self::Updated(_) => "updated",
+            Self::Deleted(_) => "deleted",
+        }
+    }
+}
+
+/// Position in a space's changes, returned by [`AnytypeClient::changes_since`]
+///
+/// Holds the latest modification time seen and the IDs of the objects modified at exactly
+/// that time, so a later call neither repeats them nor misses objects modified within the
+/// same second. Store it as a string with `to_string()` and read it back with
+/// [`SyncToken::parse`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncToken {
+    modified_at: SystemTime,
+    seen: Vec<String>,
+}
+
+impl SyncToken {
+    /// A token for every change made at or after `time`
+    pub fn at(time: SystemTime) -> Self {
+        Self {
+            modified_at: time,
+            seen: Vec::new(),
+        }
+    }
+
+    /// Read a token written with `to_string()`
+    pub fn parse(token: &str) -> Result<Self> {
+        let (time, ids) = token.split_once(TOKEN_IDS_SEPARATOR).unwrap_or((token, ""));
+        let modified_at = DateTime::parse_from_rfc3339(time)
+            .map_err(|e| AnytypeError::Config {
+                message: format!("Invalid sync token '{token}': {e}"),
+            })?
+            .into();
+        let seen = ids
+            .split(',')
+            .filter(|id| !id.is_empty())
+            .map(str::to_string)
+            .collect();
+        Ok(Self { modified_at, seen })
+    }
+
+    /// The latest modification time the token has seen
+    pub fn modified_at(&self) -> SystemTime {
+        self.modified_at
+    }
+
+    /// Whether a change made at `time` to `id` is new to this token
+    fn is_new(&self, time: SystemTime, id: &str) -> bool {
+        time > self.modified_at || (time == self.modified_at && !self.seen.iter().any(|s| s == id))
+    }
+
+    /// The token after `changes`, which are sorted oldest first
+    fn advance(mut self, changes: &[(SystemTime, Change)]) -> Self {
+        let Some((latest, _)) = changes.last() else {
+            return self;
+        };
+        if *latest > self.modified_at {
+            self.modified_at = *latest;
+            self.seen.clear();
+        }
+        self.seen.extend(
+            changes
+                .iter()
+                .filter(|(time, _)| time == latest)
+                .map(|(_, change)| change.object().id.clone()),
+        );
+        self
+    }
+}
+
+impl fmt::Display for SyncToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let time =
+            DateTime::<Utc>::from(self.modified_at).to_rfc3339_opts(SecondsFormat::AutoSi, true);
+        if self.seen.is_empty() {
+            f.write_str(&time)
+        } else {
+            write!(f, "{time}{TOKEN_IDS_SEPARATOR}{}", self.seen.join(","))
+        }
+    }
+}
+
+impl AnytypeClient {
+    /// Objects in a space created, updated or deleted after `token`, oldest change first
+    ///
+    /// Returns the changes and the token to pass next time. Without a token every object
+    /// in the space is reported as created.
+    ///
+    /// The API has no change feed, so this is best effort: the space is searched for all
+    /// objects, archived ones included, most recently modified first, and paging stops at
+    /// the first object older than the token. Changes are told apart by the objects'
+    /// `created_date`, `last_modified_date` and `archived` fields; an object created and
+    /// archived between two calls is reported as deleted. Objects without a modification
+    /// time are never reported.
+    pub async fn changes_since(
+        &self,
+        space_id: &str,
+        token: Option<&SyncToken>,
+    ) -> Result<(Vec<Change>, SyncToken)> {
+        info!(target: LOG_TARGET, "Listing changes in space: {}", space_id);
+
+        let token = token.cloned().unwrap_or_else(|| SyncToken::at(UNIX_EPOCH));
+        let mut changes = Vec::new();
+        let mut offset = 0;
+        loop {
+            let request = SearchSpaceRequest {
+                query: None,
+                limit: Some(CHANGES_PAGE_SIZE),
+                offset: Some(offset),
+                sort: Some(Sort {
+                    direction: SortDirection::Desc,
+                    property_key: SortProperty::LastModifiedDate,
+                }),
+                include_archived: true,
+            };
+            let page = self.search_space(space_id, request).await?;
+            let page_len = page.data.len();
+            debug!(target: LOG_TARGET, "Checking {} objects from offset {}", page_len, offset);
+
+            let mut reached_token = false;
+            for object in page.data {
+                let Some(modified_at) = date_property(&object.properties, LAST_MODIFIED_KEY) else {
+                    continue;
+                };
+                if modified_at < token.modified_at {
+                    reached_token = true;
+                    break;
+                }
+                if token.is_new(modified_at, &object.id) {
+                    changes.push((modified_at, classify(object, &token)));
+                }
+            }
+
+            offset += page_len;
+            if reached_token || !page.pagination.has_more || page_len == 0 {
+                break;
+            }
+        }
+
+        changes.sort_by_key(|(modified_at, _)| *modified_at);
+        let next = token.advance(&changes);
+        Ok((
+            changes.into_iter().map(|(_, change)| change).collect(),
+            next,
+        ))
+    }
+}
+
+/// Whether an object modified after `token` was created, updated or deleted
+fn classify(object: SearchObject, token: &SyncToken) -> Change {
+    if object.archived {
+        return Change::Deleted(object);
+    }
+    match date_property(&object.properties, CREATED_KEY) {
+        Some(created_at) if created_at >= token.modified_at => Change::Created(object),
+        _ => Change::Updated(object),
+    }
+}
@@ -2,33 +2,48 @@
 //!
 //! Handles list management operations.
 
-use super::AnytypeClient;
-use crate::{api::types::Icon, error::Result, types::Pagination};
+use super::{
+    AnytypeClient,
+    objects::{CreateObjectRequest, CreateObjectResponse},
+};
+use crate::{
+    api::types::Icon,
+    error::{AnytypeError, Result},
+    types::{ObjectKind, PageStart, Pagination},
+};
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info};
 
+const LOG_TARGET: &str = "anytype_rs::client::lists";
+
+/// Number of objects fetched per request when reading a whole list
+const LIST_PAGE_SIZE: usize = 100;
+
 // Import PropertyFormat from types module
 use super::types::PropertyFormat;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ListView {
     pub id: String,
     pub name: String,
     pub space_id: String,
+    #[serde(default)]
     pub created_at: String,
+    #[serde(default)]
     pub updated_at: String,
+    #[serde(default)]
     pub properties: Vec<PropertyFormat>,
 }
 
 /// Request to add objects to a list
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct AddListObjectsRequest {
     /// Array of object IDs to add to the list
     pub object_ids: Vec<String>,
 }
 
 /// Response when adding objects to a list
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct AddListObjectsResponse {
     /// Confirmation message
     pub message: String,
@@ -37,14 +52,14 @@ pub struct AddListObjectsResponse {
 }
 
 /// Response when removing objects from a list
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct RemoveListObjectsResponse {
     /// Confirmation message
     pub message: String,
 }
 
 /// Filter condition for list views
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct ListViewFilter {
     /// The filter condition
     pub condition: String,
@@ -59,7 +74,7 @@ pub struct ListViewFilter {
 }
 
 /// Sort configuration for list views
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct ListViewSort {
     /// The format of the property used for sorting
     pub format: PropertyFormat,
@@ -72,7 +87,7 @@ pub struct ListViewSort {
 }
 
 /// List view data
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct ListViewData {
     /// The list of filters
     pub filters: Vec<ListViewFilter>,
@@ -87,7 +102,7 @@ pub struct ListViewData {
 }
 
 /// Response when getting list views
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct GetListViewsResponse {
     /// The list of views in the current result set
     pub data: Vec<ListViewData>,
@@ -96,7 +111,7 @@ pub struct GetListViewsResponse {
 }
 
 /// Type information for objects in lists
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct ListObjectType {
     /// Whether the type is archived
     pub archived: bool,
@@ -111,7 +126,7 @@ pub struct ListObjectType {
     /// The name of the type
     pub name: String,
     /// The data model of the object
-    pub object: String,
+    pub object: ObjectKind,
     /// The plural name of the type
     pub plural_name: String,
     /// The properties linked to the type
@@ -119,7 +134,7 @@ pub struct ListObjectType {
 }
 
 /// Property information for object types
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct ObjectTypeProperty {
     /// The format of the property used for filtering
     pub format: PropertyFormat,
@@ -130,11 +145,11 @@ pub struct ObjectTypeProperty {
     /// The name of the property
     pub name: String,
     /// The data model of the object
-    pub object: String,
+    pub object: ObjectKind,
 }
 
 /// Object in a list
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct ListObject {
     /// Whether the object is archived
     pub archived: bool,
@@ -147,7 +162,7 @@ pub struct ListObject {
     /// The name of the object
     pub name: String,
     /// The data model of the object
-    pub object: String,
+    pub object: ObjectKind,
     /// The properties of the object
     pub properties: Vec<serde_json::Value>,
     /// The snippet of the object, especially important for notes as they don't have a name
@@ -159,8 +174,15 @@ pub struct ListObject {
     pub object_type: ListObjectType,
 }
 
+impl ListObject {
+    /// The name to show for this object, falling back to its snippet and then its ID
+    pub fn display_name(&self) -> String {
+        super::objects::display_name(Some(&self.name), self.snippet.as_deref(), &self.id)
+    }
+}
+
 /// Response when getting objects in a list
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct GetListObjectsResponse {
     /// The list of objects in the current result set
     pub data: Vec<ListObject>,
@@ -177,12 +199,13 @@ impl AnytypeClient {
         object_ids: Vec<String>,
     ) -> Result<AddListObjectsResponse> {
         info!(
+            target: LOG_TARGET,
             "Adding {} objects to list {} in space {}",
             object_ids.len(),
             list_id,
             space_id
         );
-        debug!("Object IDs: {:?}", object_ids);
+        debug!(target: LOG_TARGET, "Object IDs: {:?}", object_ids);
 
         let request = AddListObjectsRequest { object_ids };
 
@@ -193,6 +216,33 @@ impl AnytypeClient {
         .await
     }
 
+    /// Create an object and add it to a list (collection)
+    ///
+    /// The API has no parent field on objects: collections are its only way of nesting one
+    /// object under another, so this is the closest to creating a sub-object. It takes two
+    /// requests; if adding to the list fails, the object has still been created and the
+    /// error names it.
+    pub async fn create_object_in_list(
+        &self,
+        space_id: &str,
+        list_id: &str,
+        request: CreateObjectRequest,
+    ) -> Result<CreateObjectResponse> {
+        let response = self.create_object(space_id, request).await?;
+        let object_id = response.object.id.clone();
+
+        self.add_list_objects(space_id, list_id, vec![object_id.clone()])
+            .await
+            .map_err(|e| AnytypeError::Api {
+                message: format!(
+                    "Created object '{object_id}' but could not add it to list '{list_id}': {e}"
+                ),
+                status: None,
+            })?;
+
+        Ok(response)
+    }
+
     /// Get list views for a specific list
     pub async fn get_list_views(
         &self,
@@ -200,28 +250,95 @@ impl AnytypeClient {
         list_id: &str,
     ) -> Result<GetListViewsResponse> {
         info!(
+            target: LOG_TARGET,
             "Getting list views for list {} in space {}",
             list_id, space_id
         );
-        debug!("GET /v1/spaces/{}/lists/{}/views", space_id, list_id);
+        debug!(target: LOG_TARGET, "GET /v1/spaces/{}/lists/{}/views", space_id, list_id);
 
         self.get(&format!("/v1/spaces/{space_id}/lists/{list_id}/views"))
             .await
     }
 
     /// Get objects in a list
+    ///
+    /// Only returns the first page, at the server's default page size; use
+    /// [`Self::get_all_list_objects`] for every object.
     pub async fn get_list_objects(
         &self,
         space_id: &str,
         list_id: &str,
     ) -> Result<GetListObjectsResponse> {
-        info!("Getting objects in list {} in space {}", list_id, space_id);
-        debug!("GET /v1/spaces/{}/lists/{}/objects", space_id, list_id);
+        info!(target: LOG_TARGET, "Getting objects in list {} in space {}", list_id, space_id);
+        debug!(target: LOG_TARGET, "GET /v1/spaces/{}/lists/{}/objects", space_id, list_id);
 
         self.get(&format!("/v1/spaces/{space_id}/lists/{list_id}/objects"))
             .await
     }
 
+    /// Get one page of objects in a list, starting at `offset`
+    ///
+    /// `pagination.total` on the response is the number of objects in the whole list.
+    pub async fn get_list_objects_page(
+        &self,
+        space_id: &str,
+        list_id: &str,
+        offset: usize,
+        limit: usize,
+    ) -> Result<GetListObjectsResponse> {
+        self.get_list_objects_from(space_id, list_id, &PageStart::Offset(offset), limit)
+            .await
+    }
+
+    /// Get one page of objects in a list, starting at an offset or a cursor
+    pub async fn get_list_objects_from(
+        &self,
+        space_id: &str,
+        list_id: &str,
+        start: &PageStart,
+        limit: usize,
+    ) -> Result<GetListObjectsResponse> {
+        debug!(
+            target: LOG_TARGET,
+            "GET /v1/spaces/{}/lists/{}/objects ({:?}, limit {})",
+            space_id, list_id, start, limit
+        );
+
+        self.get(&format!(
+            "/v1/spaces/{space_id}/lists/{list_id}/objects?{}&limit={limit}",
+            start.query_param()
+        ))
+        .await
+    }
+
+    /// Get every object in a list, fetching page after page until the last one
+    pub async fn get_all_list_objects(
+        &self,
+        space_id: &str,
+        list_id: &str,
+    ) -> Result<Vec<ListObject>> {
+        info!(
+            target: LOG_TARGET,
+            "Getting all objects in list {} in space {}",
+            list_id, space_id
+        );
+
+        let mut objects = Vec::new();
+        let mut start = PageStart::default();
+        loop {
+            let page = self
+                .get_list_objects_from(space_id, list_id, &start, LIST_PAGE_SIZE)
+                .await?;
+            let page_len = page.data.len();
+            objects.extend(page.data);
+
+            match page.pagination.next_page(objects.len()) {
+                Some(next) if page_len > 0 => start = next,
+                _ => return Ok(objects),
+            }
+        }
+    }
+
     /// Remove objects from a list
     pub async fn remove_list_object(
         &self,
@@ -230,10 +347,11 @@ impl AnytypeClient {
         object_id: &str,
     ) -> Result<RemoveListObjectsResponse> {
         info!(
+            target: LOG_TARGET,
             "Removing {} from list {} in space {}",
             object_id, list_id, space_id
         );
-        debug!("Object ID: {:?}", object_id);
+        debug!(target: LOG_TARGET, "Object ID: {:?}", object_id);
 
         self.delete(&format!(
             "/v1/spaces/{space_id}/lists/{list_id}/objects/{object_id}"
@@ -2,12 +2,19 @@
 //!
 //! Handles member management operations.
 
-use super::AnytypeClient;
-use crate::{error::Result, types::Pagination};
+use super::{AnytypeClient, objects::Object, spaces::Space};
+use crate::{
+    error::Result,
+    types::{ObjectKind, Pagination},
+};
+use futures_util::future::join_all;
 use serde::{Deserialize, Serialize};
+use tracing::info;
+
+const LOG_TARGET: &str = "anytype_rs::client::members";
 
 /// Member information
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Member {
     /// The profile object id of the member
     pub id: String,
@@ -18,7 +25,7 @@ pub struct Member {
     /// The identity of the member in the network
     pub identity: Option<String>,
     /// The data model of the object (should be "member")
-    pub object: Option<String>,
+    pub object: Option<ObjectKind>,
     /// The role of the member
     pub role: MemberRole,
     /// The status of the member
@@ -29,8 +36,11 @@ pub struct Member {
 
 /// Member role enum
 /// Possible values: [viewer, editor, owner, no_permission]
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(
+    Debug, Deserialize, Serialize, Clone, PartialEq, Eq, strum::Display, strum::EnumString,
+)]
 #[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case", ascii_case_insensitive)]
 pub enum MemberRole {
     Viewer,
     Editor,
@@ -41,8 +51,11 @@ pub enum MemberRole {
 
 /// Member status enum  
 /// Possible values: [joining, active, removed, declined, removing, canceled]
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(
+    Debug, Deserialize, Serialize, Clone, PartialEq, Eq, strum::Display, strum::EnumString,
+)]
 #[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case", ascii_case_insensitive)]
 pub enum MemberStatus {
     Joining,
     Active,
@@ -52,14 +65,52 @@ pub enum MemberStatus {
     Canceled,
 }
 
+impl MemberStatus {
+    /// Whether the member has requested to join but has not been approved yet
+    pub fn is_pending(&self) -> bool {
+        matches!(self, Self::Joining)
+    }
+}
+
+/// Client-side member filter
+///
+/// The members endpoint has no query filters, so filtering happens after the list is fetched.
+/// All set criteria must match.
+#[derive(Debug, Clone, Default)]
+pub struct MemberFilter {
+    /// Only include members with this role
+    pub role: Option<MemberRole>,
+    /// Only include members with this status
+    pub status: Option<MemberStatus>,
+    /// Only include members with a pending join request
+    pub pending: bool,
+}
+
+impl MemberFilter {
+    /// Whether no criterion is set, so every member matches
+    pub fn is_empty(&self) -> bool {
+        self.role.is_none() && self.status.is_none() && !self.pending
+    }
+
+    /// Check whether a member matches every criterion of the filter
+    pub fn matches(&self, member: &Member) -> bool {
+        self.role.as_ref().is_none_or(|role| member.role == *role)
+            && self
+                .status
+                .as_ref()
+                .is_none_or(|status| member.status == *status)
+            && (!self.pending || member.status.is_pending())
+    }
+}
+
 /// Response for getting a single member
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct GetMemberResponse {
     pub member: Member,
 }
 
 /// Response for listing members
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct ListMembersResponse {
     pub data: Vec<Member>,
     pub pagination: Pagination,
@@ -74,6 +125,17 @@ impl AnytypeClient {
         Ok(response.member)
     }
 
+    /// Get the profile object of a member in a space
+    ///
+    /// A member's `id` is the ID of its participant (profile) object in the space, so the
+    /// member record is fetched and that object loaded with [`AnytypeClient::get_object`].
+    /// `identity` and `global_name` identify the account network-wide and have no object of
+    /// their own.
+    pub async fn get_member_profile(&self, space_id: &str, member_id: &str) -> Result<Object> {
+        let member = self.get_member(space_id, member_id).await?;
+        self.get_object(space_id, &member.id).await
+    }
+
     /// List members in a space
     pub async fn list_members(&self, space_id: &str) -> Result<Vec<Member>> {
         let response: ListMembersResponse =
@@ -81,6 +143,16 @@ impl AnytypeClient {
         Ok(response.data)
     }
 
+    /// List members in a space matching a filter
+    pub async fn list_members_filtered(
+        &self,
+        space_id: &str,
+        filter: &MemberFilter,
+    ) -> Result<Vec<Member>> {
+        let members = self.list_members(space_id).await?;
+        Ok(members.into_iter().filter(|m| filter.matches(m)).collect())
+    }
+
     /// List members in a space with pagination information
     pub async fn list_members_with_pagination(
         &self,
@@ -89,8 +161,61 @@ impl AnytypeClient {
         self.get(&format!("/v1/spaces/{space_id}/members")).await
     }
 
+    /// List the members of every space, for auditing who has access to what
+    ///
+    /// Returns each space with its members, in the order of [`AnytypeClient::list_spaces`].
+    /// Members are fetched concurrently, at most
+    /// [`ClientConfig::max_concurrency`](super::ClientConfig) spaces at a time.
+    pub async fn list_members_in_all_spaces(&self) -> Result<Vec<(Space, Vec<Member>)>> {
+        info!(target: LOG_TARGET, "Listing members in all spaces");
+
+        let spaces = self.list_spaces().await?;
+        let members = join_all(
+            spaces
+                .iter()
+                .map(|space| self.limited(self.list_members(&space.id))),
+        )
+        .await;
+
+        spaces
+            .into_iter()
+            .zip(members)
+            .map(|(space, members)| Ok((space, members?)))
+            .collect()
+    }
+
+    /// Find a member by global name (e.g. `jane.any`) in any of the spaces
+    ///
+    /// The API cannot look up arbitrary accounts, so this only finds people who already
+    /// share a space with the current user. Matching ignores case and a missing `.any`
+    /// suffix. Returns the first match in [`AnytypeClient::list_spaces`] order.
+    pub async fn find_member_by_global_name(&self, global_name: &str) -> Result<Option<Member>> {
+        info!(target: LOG_TARGET, "Looking up member with global name {global_name}");
+
+        let wanted = global_name_stem(global_name);
+        let spaces = self.list_members_in_all_spaces().await?;
+        Ok(spaces
+            .into_iter()
+            .flat_map(|(_, members)| members)
+            .find(|member| {
+                member
+                    .global_name
+                    .as_deref()
+                    .is_some_and(|name| global_name_stem(name) == wanted)
+            }))
+    }
+
     // TODO: Add additional member management methods like:
-    // - invite_member
+    // - invite_member (the 2025-05-20 API has no invite or add-member endpoint)
     // - remove_member
     // - update_member_role
 }
+
+/// Lowercased global name without the `.any` suffix
+fn global_name_stem(name: &str) -> String {
+    let name = name.trim().to_lowercase();
+    match name.strip_suffix(".any") {
+        Some(stem) => stem.to_string(),
+        None => name,
+    }
+}
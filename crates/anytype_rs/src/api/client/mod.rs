@@ -1,19 +1,39 @@
 //! Anytype API client modules
 //!
 //! This module is organized to match the official API reference structure.
+//!
+//! Each module logs under its own target, `anytype_rs::client::<module>` (HTTP handling here
+//! logs under `anytype_rs::client`), so `RUST_LOG` can select one group of calls.
 
 use crate::{error::Result, types::ApiErrorResponse};
-use reqwest::{Client, Method, RequestBuilder, Response};
+use reqwest::{
+    Client, Method, RequestBuilder, Response, StatusCode,
+    header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED},
+};
 use serde::{Serialize, de::DeserializeOwned};
-use std::time::Instant;
-use tracing::{debug, error, info, trace};
+use std::{
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+    path::PathBuf,
+    sync::{
+        Mutex,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+    },
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+use tokio::sync::Semaphore;
+use tracing::{debug, error, info, trace, warn};
+
+const LOG_TARGET: &str = "anytype_rs::client";
 
 // Include all module implementations
 pub mod auth;
+pub mod changes;
 pub mod lists;
 pub mod members;
 pub mod objects;
 pub mod properties;
+pub mod schema;
 pub mod search;
 pub mod spaces;
 pub mod tags;
@@ -23,7 +43,32 @@ pub mod types;
 const DEFAULT_BASE_URL: &str = "http://localhost:31009";
 const ANYTYPE_API_HEADER: &str = "Anytype-Version";
 // TODO: Better support multiple API versions
-const ANYTYPE_API_VERSION: &str = "2025-05-20";
+/// Version of the Anytype API the client targets, sent in the `Anytype-Version` header
+pub const ANYTYPE_API_VERSION: &str = "2025-05-20";
+/// Maximum number of characters of a non-JSON error body to include in error messages
+const MAX_ERROR_BODY_CHARS: usize = 500;
+/// Default cap on concurrent requests made by batch operations
+const DEFAULT_MAX_CONCURRENCY: usize = 8;
+/// Maximum number of responses kept for conditional requests; the least recently used
+/// entry is evicted beyond this
+const MAX_CONDITIONAL_ENTRIES: usize = 256;
+/// Default number of consecutive connection failures that opens the circuit breaker
+const DEFAULT_CIRCUIT_BREAKER_THRESHOLD: u32 = 5;
+/// JSON fields and headers whose values are redacted in TRACE logs and recorded bodies
+const DEFAULT_REDACTED_FIELDS: &[&str] = &["api_key", "code", "authorization"];
+
+/// Sequence number that keeps recordings written within the same millisecond ordered
+static RECORDING_SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+/// What a batch operation (such as `create_objects_with_policy`) does when a request fails
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BatchErrorPolicy {
+    /// Send every request and report each result
+    #[default]
+    ContinueOnError,
+    /// Stop at the first failure; later requests are not sent
+    FailFast,
+}
 
 /// Configuration for the Anytype client
 #[derive(Debug, Clone)]
@@ -31,6 +76,32 @@ pub struct ClientConfig {
     pub base_url: String,
     pub timeout_seconds: u64,
     pub app_name: String,
+    /// Send `If-None-Match`/`If-Modified-Since` on GET requests and reuse the cached
+    /// body when the server answers `304 Not Modified`. Responses are cached per API key,
+    /// and only the most recently used 256 are kept.
+    pub enable_conditional_requests: bool,
+    /// Directory to write every response body to as a timestamped JSON file, for turning
+    /// real responses into test fixtures when debugging deserialization failures
+    pub record_dir: Option<PathBuf>,
+    /// Maximum number of requests batch operations (such as `create_objects`) keep in
+    /// flight at once. The local app is easily saturated, so the default is conservative.
+    pub max_concurrency: usize,
+    /// Consecutive connection failures (within `circuit_breaker_window_seconds`) after which
+    /// requests fail fast without being sent. `0` disables the circuit breaker.
+    pub circuit_breaker_threshold: u32,
+    /// Window in which connection failures must occur to count as consecutive
+    pub circuit_breaker_window_seconds: u64,
+    /// How long requests fail fast once the circuit breaker has opened
+    pub circuit_breaker_cooldown_seconds: u64,
+    /// JSON fields (at any depth) and response headers whose values are replaced with
+    /// `[REDACTED]` before bodies are logged at TRACE level or recorded. Names are matched
+    /// case-insensitively. Defaults to `api_key`, `code` and `authorization`.
+    pub redacted_fields: Vec<String>,
+    /// Reuse search results for identical searches (same normalized query, space and other
+    /// parameters) made within this many seconds. Results may be stale inside the window, so
+    /// `0` (the default) disables the cache. Meant for incremental-search tools that send a
+    /// query per keystroke.
+    pub search_cache_ttl_seconds: u64,
 }
 
 impl Default for ClientConfig {
@@ -39,6 +110,86 @@ impl Default for ClientConfig {
             base_url: DEFAULT_BASE_URL.to_string(),
             timeout_seconds: 30,
             app_name: "anytype_rs".to_string(),
+            enable_conditional_requests: false,
+            record_dir: None,
+            max_concurrency: DEFAULT_MAX_CONCURRENCY,
+            circuit_breaker_threshold: DEFAULT_CIRCUIT_BREAKER_THRESHOLD,
+            circuit_breaker_window_seconds: 30,
+            circuit_breaker_cooldown_seconds: 10,
+            redacted_fields: DEFAULT_REDACTED_FIELDS
+                .iter()
+                .map(|field| field.to_string())
+                .collect(),
+            search_cache_ttl_seconds: 0,
+        }
+    }
+}
+
+/// Result of a conditional GET request
+#[derive(Debug, Clone, PartialEq)]
+pub enum CachedResponse<T> {
+    /// The server returned a new representation of the resource
+    Fresh(T),
+    /// The server answered `304 Not Modified`; the value was parsed from the cached body
+    NotModified(T),
+}
+
+impl<T> CachedResponse<T> {
+    /// Get the value regardless of whether it came from the cache
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::Fresh(value) | Self::NotModified(value) => value,
+        }
+    }
+
+    /// Whether the server reported the resource as unchanged
+    pub fn is_not_modified(&self) -> bool {
+        matches!(self, Self::NotModified(_))
+    }
+}
+
+/// Validators and body of a previously fetched GET response, keyed by API key and URL
+#[derive(Debug, Clone)]
+struct ConditionalEntry {
+    etag: Option<String>,
+    last_modified: Option<String>,
+    body: String,
+    /// When the entry was last stored or looked up, for evicting the least recently used
+    last_used: Instant,
+}
+
+/// Connection failure tracking for the circuit breaker
+#[derive(Debug, Default)]
+struct CircuitState {
+    /// Connection failures since the last success, within the current window
+    failures: u32,
+    /// When the current window of failures started
+    window_start: Option<Instant>,
+    /// Requests fail fast until this instant
+    open_until: Option<Instant>,
+    /// The single request let through after the cooldown is in flight; the others fail fast
+    /// until it finishes
+    half_open: bool,
+}
+
+/// What the circuit breaker allows for a request about to be sent
+enum CircuitPermit {
+    /// The circuit is closed: send as usual
+    Send,
+    /// The single trial request let through after the cooldown
+    Trial,
+    /// Fail fast; the circuit stays open this much longer (zero while a trial is in flight)
+    Refuse(Duration),
+}
+
+/// Ends the trial request when dropped, so the circuit is not left half-open if the request
+/// future is cancelled before it finishes
+struct TrialGuard<'a>(&'a Mutex<CircuitState>);
+
+impl Drop for TrialGuard<'_> {
+    fn drop(&mut self) {
+        if let Ok(mut circuit) = self.0.lock() {
+            circuit.half_open = false;
         }
     }
 }
@@ -49,6 +200,16 @@ pub struct AnytypeClient {
     pub(crate) http_client: Client,
     pub(crate) config: ClientConfig,
     pub(crate) api_key: Option<String>,
+    /// Cached GET responses by `(hash of the API key, URL)`, so a changed key never reuses
+    /// a response fetched with another
+    conditional_cache: Mutex<HashMap<(u64, String), ConditionalEntry>>,
+    /// Permits shared by all batch operations, sized by `max_concurrency`
+    batch_permits: Semaphore,
+    circuit: Mutex<CircuitState>,
+    /// Type IDs by `(space_id, type_key)`, filled by key-based type lookups
+    type_ids_by_key: Mutex<HashMap<(String, String), String>>,
+    /// Search responses by normalized request, with when they were fetched
+    pub(crate) search_cache: Mutex<HashMap<String, (Instant, search::SearchResponse)>>,
 }
 
 impl AnytypeClient {
@@ -63,10 +224,18 @@ impl AnytypeClient {
             .timeout(std::time::Duration::from_secs(config.timeout_seconds))
             .build()?;
 
+        // A limit of zero would block every batch operation forever
+        let batch_permits = Semaphore::new(config.max_concurrency.max(1));
+
         Ok(Self {
             http_client,
             config,
             api_key: None,
+            conditional_cache: Mutex::new(HashMap::new()),
+            batch_permits,
+            circuit: Mutex::new(CircuitState::default()),
+            type_ids_by_key: Mutex::new(HashMap::new()),
+            search_cache: Mutex::new(HashMap::new()),
         })
     }
 
@@ -80,21 +249,192 @@ impl AnytypeClient {
         self.api_key.as_deref()
     }
 
+    /// Ask the server which API version it runs
+    ///
+    /// Makes a single cheap request and reads the `Anytype-Version` response header, whatever
+    /// the status. Returns `None` if the server does not send the header.
+    pub async fn server_version(&self) -> Result<Option<String>> {
+        let url = format!("{}/v1/spaces?limit=1", self.config.base_url);
+        let request = self.authenticated_request(Method::GET, &url)?;
+
+        self.log_request(&Method::GET, &url, &request);
+        let response = self.send(request).await?;
+
+        Ok(response
+            .headers()
+            .get(ANYTYPE_API_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string))
+    }
+
+    /// Send an authenticated request with a caller-provided JSON body, returning the raw
+    /// JSON response
+    ///
+    /// An escape hatch for API fields the typed requests don't model yet; the body is sent
+    /// as-is, without any validation. `path` is relative to the base URL, e.g.
+    /// `/v1/spaces/{space_id}/objects`. Prefer the typed methods where they exist.
+    pub async fn raw_request(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&serde_json::Value>,
+    ) -> Result<serde_json::Value> {
+        let url = format!("{}{}", self.config.base_url, path);
+        let mut request = self.authenticated_request(method.clone(), &url)?;
+        if let Some(body) = body {
+            request = request.json(body);
+        }
+
+        self.log_request(&method, &url, &request);
+
+        // Log request body at TRACE level
+        if tracing::enabled!(target: LOG_TARGET, tracing::Level::TRACE)
+            && let Some(body) = body
+            && let Some(body_json) = self.redacted_body(body)
+        {
+            trace!(target: LOG_TARGET, body = %body_json, "Request body");
+        }
+
+        let start = Instant::now();
+        let response = self.send(request).await?;
+        let duration = start.elapsed();
+
+        self.log_response(&response, duration).await;
+        self.handle_response(response).await
+    }
+
     /// Make an authenticated GET request
     pub(crate) async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        if self.config.enable_conditional_requests {
+            return self
+                .get_conditional(path)
+                .await
+                .map(CachedResponse::into_inner);
+        }
+
         let url = format!("{}{}", self.config.base_url, path);
         let request = self.authenticated_request(Method::GET, &url)?;
 
         self.log_request(&Method::GET, &url, &request);
 
         let start = Instant::now();
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         let duration = start.elapsed();
 
         self.log_response(&response, duration).await;
         self.handle_response(response).await
     }
 
+    /// Make an authenticated GET request, revalidating against the cached response
+    ///
+    /// Validators (`ETag`/`Last-Modified`) from earlier successful responses are sent back as
+    /// `If-None-Match`/`If-Modified-Since`. A `304 Not Modified` answer is served from the
+    /// cached body. Only used when [`ClientConfig::enable_conditional_requests`] is set.
+    pub(crate) async fn get_conditional<T: DeserializeOwned>(
+        &self,
+        path: &str,
+    ) -> Result<CachedResponse<T>> {
+        let url = format!("{}{}", self.config.base_url, path);
+        let mut request = self.authenticated_request(Method::GET, &url)?;
+
+        let key = self.conditional_key(&url);
+        let cached = self.conditional_cache.lock().ok().and_then(|mut cache| {
+            cache.get_mut(&key).map(|entry| {
+                entry.last_used = Instant::now();
+                entry.clone()
+            })
+        });
+        if let Some(entry) = &cached {
+            if let Some(etag) = &entry.etag {
+                request = request.header(IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &entry.last_modified {
+                request = request.header(IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+
+        self.log_request(&Method::GET, &url, &request);
+
+        let start = Instant::now();
+        let response = self.send(request).await?;
+        let duration = start.elapsed();
+
+        self.log_response(&response, duration).await;
+
+        if response.status() == StatusCode::NOT_MODIFIED
+            && let Some(entry) = cached
+        {
+            debug!(
+                target: LOG_TARGET,
+                url = %url,
+                "Resource not modified, using cached response body"
+            );
+            return self
+                .parse_body(&entry.body)
+                .map(CachedResponse::NotModified);
+        }
+
+        if !response.status().is_success() {
+            return self
+                .handle_response(response)
+                .await
+                .map(CachedResponse::Fresh);
+        }
+
+        let header = |name| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string)
+        };
+        let etag = header(ETAG);
+        let last_modified = header(LAST_MODIFIED);
+
+        let status = response.status();
+        let body =
+            response
+                .text()
+                .await
+                .map_err(|e| crate::error::AnytypeError::InvalidResponse {
+                    message: format!("Failed to read response body: {e}"),
+                })?;
+        self.record_response(path, status, &body);
+        let data = self.parse_body(&body)?;
+
+        if (etag.is_some() || last_modified.is_some())
+            && let Ok(mut cache) = self.conditional_cache.lock()
+        {
+            if cache.len() >= MAX_CONDITIONAL_ENTRIES
+                && !cache.contains_key(&key)
+                && let Some(oldest) = cache
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.last_used)
+                    .map(|(key, _)| key.clone())
+            {
+                cache.remove(&oldest);
+            }
+            cache.insert(
+                key,
+                ConditionalEntry {
+                    etag,
+                    last_modified,
+                    body,
+                    last_used: Instant::now(),
+                },
+            );
+        }
+
+        Ok(CachedResponse::Fresh(data))
+    }
+
+    /// Key of the conditional cache entry for `url` fetched with the current API key
+    fn conditional_key(&self, url: &str) -> (u64, String) {
+        let mut hasher = DefaultHasher::new();
+        self.api_key.hash(&mut hasher);
+        (hasher.finish(), url.to_string())
+    }
+
     /// Make an authenticated POST request with JSON body
     pub(crate) async fn post<T: DeserializeOwned, B: Serialize>(
         &self,
@@ -107,14 +447,14 @@ impl AnytypeClient {
         self.log_request(&Method::POST, &url, &request);
 
         // Log request body at TRACE level
-        if tracing::enabled!(tracing::Level::TRACE) {
-            if let Ok(body_json) = serde_json::to_string_pretty(body) {
-                trace!(body = %body_json, "Request body");
-            }
+        if tracing::enabled!(target: LOG_TARGET, tracing::Level::TRACE)
+            && let Some(body_json) = self.redacted_body(body)
+        {
+            trace!(target: LOG_TARGET, body = %body_json, "Request body");
         }
 
         let start = Instant::now();
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         let duration = start.elapsed();
 
         self.log_response(&response, duration).await;
@@ -133,14 +473,14 @@ impl AnytypeClient {
         self.log_request(&Method::PATCH, &url, &request);
 
         // Log request body at TRACE level
-        if tracing::enabled!(tracing::Level::TRACE) {
-            if let Ok(body_json) = serde_json::to_string_pretty(body) {
-                trace!(body = %body_json, "Request body");
-            }
+        if tracing::enabled!(target: LOG_TARGET, tracing::Level::TRACE)
+            && let Some(body_json) = self.redacted_body(body)
+        {
+            trace!(target: LOG_TARGET, body = %body_json, "Request body");
         }
 
         let start = Instant::now();
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         let duration = start.elapsed();
 
         self.log_response(&response, duration).await;
@@ -155,7 +495,7 @@ impl AnytypeClient {
         self.log_request(&Method::DELETE, &url, &request);
 
         let start = Instant::now();
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         let duration = start.elapsed();
 
         self.log_response(&response, duration).await;
@@ -178,20 +518,150 @@ impl AnytypeClient {
         self.log_request(&Method::POST, &url, &request);
 
         // Log request body at TRACE level
-        if tracing::enabled!(tracing::Level::TRACE) {
-            if let Ok(body_json) = serde_json::to_string_pretty(body) {
-                trace!(body = %body_json, auth = "unauthenticated", "Request body");
-            }
+        if tracing::enabled!(target: LOG_TARGET, tracing::Level::TRACE)
+            && let Some(body_json) = self.redacted_body(body)
+        {
+            trace!(target: LOG_TARGET, body = %body_json, auth = "unauthenticated", "Request body");
         }
 
         let start = Instant::now();
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         let duration = start.elapsed();
 
         self.log_response(&response, duration).await;
         self.handle_response(response).await
     }
 
+    /// Run a request of a batch operation, waiting for a free slot under `max_concurrency`
+    pub(crate) async fn limited<F: Future>(&self, request: F) -> F::Output {
+        // The semaphore is never closed, so acquiring only waits for a permit
+        let _permit = self.batch_permits.acquire().await;
+        request.await
+    }
+
+    /// Like [`Self::limited`], but gives up without sending once `stop` is set
+    ///
+    /// `stop` is checked after the slot is free, so requests still queued when it is set are
+    /// never sent. Returns `None` for those.
+    pub(crate) async fn limited_unless<F: Future>(
+        &self,
+        stop: &AtomicBool,
+        request: F,
+    ) -> Option<F::Output> {
+        let _permit = self.batch_permits.acquire().await;
+        if stop.load(Ordering::SeqCst) {
+            return None;
+        }
+        Some(request.await)
+    }
+
+    /// Send a request, reporting timeouts and refused connections with actionable errors
+    ///
+    /// Once `circuit_breaker_threshold` consecutive connection failures have been seen, requests
+    /// fail fast with [`AnytypeError::Connection`](crate::error::AnytypeError::Connection) for
+    /// `circuit_breaker_cooldown_seconds` instead of being sent. After the cooldown a single
+    /// request is let through, and the others keep failing fast until it finishes or is dropped;
+    /// if it fails to connect, the circuit reopens straight away.
+    async fn send(&self, request: RequestBuilder) -> Result<Response> {
+        let _trial = match self.circuit_permit() {
+            CircuitPermit::Send => None,
+            CircuitPermit::Trial => Some(TrialGuard(&self.circuit)),
+            CircuitPermit::Refuse(remaining) => {
+                debug!(
+                    target: LOG_TARGET,
+                    remaining_ms = remaining.as_millis() as u64,
+                    "Circuit breaker open, skipping request"
+                );
+                return Err(self.connection_error());
+            }
+        };
+
+        match request.send().await {
+            Ok(response) => {
+                self.record_connection_success();
+                Ok(response)
+            }
+            Err(e) if e.is_timeout() => Err(crate::error::AnytypeError::Timeout {
+                duration: Duration::from_secs(self.config.timeout_seconds),
+            }),
+            Err(e) if e.is_connect() => {
+                self.record_connection_failure();
+                Err(self.connection_error())
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn connection_error(&self) -> crate::error::AnytypeError {
+        crate::error::AnytypeError::Connection {
+            message: format!(
+                "Could not connect to Anytype at {}. Is the desktop app running and the API enabled?",
+                self.config.base_url
+            ),
+        }
+    }
+
+    /// Whether the circuit breaker lets a request through
+    fn circuit_permit(&self) -> CircuitPermit {
+        let Ok(mut circuit) = self.circuit.lock() else {
+            return CircuitPermit::Send;
+        };
+        if circuit.half_open {
+            return CircuitPermit::Refuse(Duration::ZERO);
+        }
+        let Some(open_until) = circuit.open_until else {
+            return CircuitPermit::Send;
+        };
+        let now = Instant::now();
+        if now < open_until {
+            return CircuitPermit::Refuse(open_until - now);
+        }
+
+        // Cooldown over: allow a single trial request, which reopens the circuit if it fails
+        circuit.open_until = None;
+        circuit.half_open = true;
+        circuit.failures = self.config.circuit_breaker_threshold.saturating_sub(1);
+        circuit.window_start = Some(now);
+        CircuitPermit::Trial
+    }
+
+    fn record_connection_success(&self) {
+        if let Ok(mut circuit) = self.circuit.lock() {
+            *circuit = CircuitState::default();
+        }
+    }
+
+    fn record_connection_failure(&self) {
+        let threshold = self.config.circuit_breaker_threshold;
+        if threshold == 0 {
+            return;
+        }
+        let Ok(mut circuit) = self.circuit.lock() else {
+            return;
+        };
+
+        let now = Instant::now();
+        let window = Duration::from_secs(self.config.circuit_breaker_window_seconds);
+        match circuit.window_start {
+            Some(start) if now.duration_since(start) <= window => circuit.failures += 1,
+            _ => {
+                circuit.failures = 1;
+                circuit.window_start = Some(now);
+            }
+        }
+
+        if circuit.failures >= threshold {
+            let cooldown = Duration::from_secs(self.config.circuit_breaker_cooldown_seconds);
+            warn!(
+                target: LOG_TARGET,
+                failures = circuit.failures,
+                cooldown_secs = cooldown.as_secs(),
+                "Anytype unreachable, failing requests fast until the cooldown ends"
+            );
+            circuit.open_until = Some(now + cooldown);
+        }
+    }
+
     /// Create an authenticated request builder (internal helper)
     fn authenticated_request(&self, method: Method, url: &str) -> Result<RequestBuilder> {
         let api_key = self
@@ -209,6 +679,7 @@ impl AnytypeClient {
             _ => {
                 return Err(crate::error::AnytypeError::Api {
                     message: format!("Unsupported HTTP method: {method}"),
+                    status: None,
                 });
             }
         };
@@ -222,14 +693,16 @@ impl AnytypeClient {
     fn log_request(&self, method: &Method, url: &str, _request: &RequestBuilder) {
         // Log at INFO level: just method and URL
         info!(
+            target: LOG_TARGET,
             method = %method,
             url = %url,
             "HTTP request"
         );
 
         // Log at DEBUG level: add headers (but not body)
-        if tracing::enabled!(tracing::Level::DEBUG) {
+        if tracing::enabled!(target: LOG_TARGET, tracing::Level::DEBUG) {
             debug!(
+                target: LOG_TARGET,
                 method = %method,
                 url = %url,
                 api_version = ANYTYPE_API_VERSION,
@@ -240,8 +713,9 @@ impl AnytypeClient {
 
         // Log at TRACE level: full details
         // Note: We can't access RequestBuilder internals, so we log what we know
-        if tracing::enabled!(tracing::Level::TRACE) {
+        if tracing::enabled!(target: LOG_TARGET, tracing::Level::TRACE) {
             trace!(
+                target: LOG_TARGET,
                 method = %method,
                 url = %url,
                 headers.anytype_version = ANYTYPE_API_VERSION,
@@ -258,6 +732,7 @@ impl AnytypeClient {
 
         // Log at INFO level: just status and timing
         info!(
+            target: LOG_TARGET,
             status = status.as_u16(),
             duration_ms = duration.as_millis(),
             url = %url,
@@ -265,7 +740,7 @@ impl AnytypeClient {
         );
 
         // Log at DEBUG level: add headers
-        if tracing::enabled!(tracing::Level::DEBUG) {
+        if tracing::enabled!(target: LOG_TARGET, tracing::Level::DEBUG) {
             let headers: Vec<String> = response
                 .headers()
                 .iter()
@@ -273,6 +748,7 @@ impl AnytypeClient {
                 .collect();
 
             debug!(
+                target: LOG_TARGET,
                 status = status.as_u16(),
                 duration_ms = duration.as_millis(),
                 url = %url,
@@ -282,14 +758,22 @@ impl AnytypeClient {
         }
 
         // Log at TRACE level: headers detail (body will be logged separately)
-        if tracing::enabled!(tracing::Level::TRACE) {
+        if tracing::enabled!(target: LOG_TARGET, tracing::Level::TRACE) {
             let headers: Vec<(String, String)> = response
                 .headers()
                 .iter()
-                .map(|(k, v)| (k.to_string(), v.to_str().unwrap_or("[binary]").to_string()))
+                .map(|(k, v)| {
+                    let value = if self.is_redacted(k.as_str()) {
+                        "[REDACTED]"
+                    } else {
+                        v.to_str().unwrap_or("[binary]")
+                    };
+                    (k.to_string(), value.to_string())
+                })
                 .collect();
 
             trace!(
+                target: LOG_TARGET,
                 status = status.as_u16(),
                 duration_ms = duration.as_millis(),
                 url = %url,
@@ -305,6 +789,7 @@ impl AnytypeClient {
         response: reqwest::Response,
     ) -> Result<T> {
         let status = response.status();
+        let path = response.url().path().to_string();
 
         if status.is_success() {
             // Get the response text first for debugging
@@ -315,61 +800,241 @@ impl AnytypeClient {
                     .map_err(|e| crate::error::AnytypeError::InvalidResponse {
                         message: format!("Failed to read response body: {e}"),
                     })?;
+            self.record_response(&path, status, &response_text);
 
-            // Log response body at TRACE level (pretty formatted)
-            if tracing::enabled!(tracing::Level::TRACE) {
-                if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&response_text) {
-                    if let Ok(pretty) = serde_json::to_string_pretty(&parsed) {
-                        trace!(body = %pretty, "Response body");
+            self.parse_body(&response_text)
+        } else {
+            error!(target: LOG_TARGET, "API error {}", status);
+
+            // Read the body as text first so non-JSON error pages (HTML, plain text)
+            // are not lost when they fail to parse as ApiErrorResponse
+            let response_text = response.text().await.unwrap_or_default();
+            self.record_response(&path, status, &response_text);
+
+            let message = match serde_json::from_str::<ApiErrorResponse>(&response_text) {
+                Ok(error) => {
+                    // Log error response at TRACE level
+                    if tracing::enabled!(target: LOG_TARGET, tracing::Level::TRACE) {
+                        trace!(
+                            target: LOG_TARGET,
+                            error_message = %error.message,
+                            "API error response"
+                        );
                     }
-                } else {
-                    trace!(body = %response_text, "Response body (non-JSON)");
+
+                    error.message
                 }
-            } else if tracing::enabled!(tracing::Level::DEBUG) {
-                // At DEBUG level, just show body size
-                debug!(body_size = response_text.len(), "Response body size");
-            }
+                Err(_) => {
+                    trace!(
+                        target: LOG_TARGET,
+                        body = %response_text,
+                        "API error response (non-JSON)"
+                    );
 
-            let response = serde_json::from_str::<T>(&response_text);
-
-            match response {
-                Ok(data) => Ok(data),
-                Err(e) => {
-                    error!("Failed to deserialize response: {}", e);
-                    error!("Expected type: {}", std::any::type_name::<T>());
-                    error!("Response body was: {}", response_text);
-                    Err(crate::error::AnytypeError::InvalidResponse {
-                        message: format!(
-                            "Failed to parse JSON response: {}. Expected type: {}",
-                            e,
-                            std::any::type_name::<T>()
-                        ),
-                    })
+                    let body = truncate_error_body(response_text.trim());
+                    if body.is_empty() {
+                        format!("HTTP {status}")
+                    } else {
+                        format!("HTTP {status} - {body}")
+                    }
                 }
+            };
+
+            if status == 401 || status == 403 {
+                Err(crate::error::AnytypeError::Auth { message })
+            } else {
+                Err(crate::error::AnytypeError::Api {
+                    message,
+                    status: Some(status.as_u16()),
+                })
             }
-        } else {
-            let response = response.json::<ApiErrorResponse>().await;
-            error!("API error {}", status);
+        }
+    }
 
-            match response {
-                Ok(error) => {
-                    let message = error.message.clone();
+    /// Write a response body to the configured record directory
+    ///
+    /// Files are named `<unix millis>-<sequence>-<status>-<path>.json` so recordings sort in
+    /// request order. Known secret fields are redacted. Failures are logged and otherwise
+    /// ignored so recording never breaks a request.
+    fn record_response(&self, path: &str, status: StatusCode, body: &str) {
+        let Some(dir) = &self.config.record_dir else {
+            return;
+        };
 
-                    // Log error response at TRACE level
-                    if tracing::enabled!(tracing::Level::TRACE) {
-                        trace!(error_message = %error.message, "API error response");
-                    }
+        let millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis())
+            .unwrap_or_default();
+        let sequence = RECORDING_SEQUENCE.fetch_add(1, Ordering::Relaxed);
+        let slug: String = path
+            .trim_matches('/')
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        let file = dir.join(format!(
+            "{millis}-{sequence:04}-{}-{slug}.json",
+            status.as_u16()
+        ));
+
+        let contents = match serde_json::from_str::<serde_json::Value>(body) {
+            Ok(mut json) => {
+                self.redact_fields(&mut json);
+                serde_json::to_string_pretty(&json).unwrap_or_else(|_| body.to_string())
+            }
+            Err(_) => body.to_string(),
+        };
+
+        let result = std::fs::create_dir_all(dir).and_then(|_| std::fs::write(&file, contents));
+        match result {
+            Ok(()) => debug!(target: LOG_TARGET, file = %file.display(), "Recorded response body"),
+            Err(e) => {
+                warn!(
+                    target: LOG_TARGET,
+                    file = %file.display(),
+                    error = %e,
+                    "Failed to record response body"
+                )
+            }
+        }
+    }
 
-                    if status == 401 || status == 403 {
-                        Err(crate::error::AnytypeError::Auth { message })
+    /// Whether a JSON field or header is on the configured redaction list
+    fn is_redacted(&self, name: &str) -> bool {
+        self.config
+            .redacted_fields
+            .iter()
+            .any(|field| field.eq_ignore_ascii_case(name))
+    }
+
+    /// Replace the values of secret fields anywhere in a JSON document
+    fn redact_fields(&self, value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::Object(map) => {
+                for (key, field) in map.iter_mut() {
+                    if self.is_redacted(key) {
+                        *field = serde_json::Value::String("[REDACTED]".to_string());
                     } else {
-                        Err(crate::error::AnytypeError::Api { message })
+                        self.redact_fields(field);
+                    }
+                }
+            }
+            serde_json::Value::Array(items) => {
+                items.iter_mut().for_each(|item| self.redact_fields(item))
+            }
+            _ => {}
+        }
+    }
+
+    /// Pretty-print a request body for TRACE logging with secret fields redacted
+    fn redacted_body<B: Serialize>(&self, body: &B) -> Option<String> {
+        let mut json = serde_json::to_value(body).ok()?;
+        self.redact_fields(&mut json);
+        serde_json::to_string_pretty(&json).ok()
+    }
+
+    /// Deserialize a successful JSON response body
+    fn parse_body<T: DeserializeOwned>(&self, response_text: &str) -> Result<T> {
+        // Log response body at TRACE level (pretty formatted)
+        if tracing::enabled!(target: LOG_TARGET, tracing::Level::TRACE) {
+            if let Ok(mut parsed) = serde_json::from_str::<serde_json::Value>(response_text) {
+                self.redact_fields(&mut parsed);
+                if let Ok(pretty) = serde_json::to_string_pretty(&parsed) {
+                    trace!(target: LOG_TARGET, body = %pretty, "Response body");
+                }
+            } else {
+                trace!(target: LOG_TARGET, body = %response_text, "Response body (non-JSON)");
+            }
+        } else if tracing::enabled!(target: LOG_TARGET, tracing::Level::DEBUG) {
+            // At DEBUG level, just show body size
+            debug!(target: LOG_TARGET, body_size = response_text.len(), "Response body size");
+        }
+
+        let response = serde_json::from_str::<T>(response_text);
+
+        match response {
+            Ok(data) => Ok(data),
+            Err(e) => {
+                error!(target: LOG_TARGET, "Failed to deserialize response: {}", e);
+                error!(target: LOG_TARGET, "Expected type: {}", std::any::type_name::<T>());
+                match serde_json::from_str::<serde_json::Value>(response_text) {
+                    Ok(mut body) => {
+                        self.redact_fields(&mut body);
+                        error!(target: LOG_TARGET, "Response body was: {}", body);
                     }
+                    Err(_) => error!(target: LOG_TARGET, "Response body was: {}", response_text),
                 }
-                Err(e) => Err(crate::error::AnytypeError::Api {
-                    message: format!("HTTP {status} - {e}"),
-                }),
+                Err(crate::error::AnytypeError::InvalidResponse {
+                    message: format!(
+                        "Failed to parse JSON response: {}. Expected type: {}",
+                        e,
+                        std::any::type_name::<T>()
+                    ),
+                })
             }
         }
     }
 }
+
+/// Cap a raw error body so an HTML error page doesn't flood the error message
+fn truncate_error_body(body: &str) -> String {
+    if body.chars().count() > MAX_ERROR_BODY_CHARS {
+        let truncated: String = body.chars().take(MAX_ERROR_BODY_CHARS).collect();
+        format!("{truncated}...")
+    } else {
+        body.to_string()
+    }
+}
+
+/// Turn a key into one [`validate_type_key`](types::validate_type_key) and
+/// [`validate_property_key`](properties::validate_property_key) accept
+///
+/// Uppercase letters are lowered, any other character outside snake_case becomes an
+/// underscore (`ot-page` becomes `ot_page`) and a key that does not start with a letter
+/// gets a `key_` prefix. Valid keys are returned unchanged.
+pub fn snake_case_key(key: &str) -> String {
+    let mut snake: String = key
+        .chars()
+        .map(|c| match c.to_ascii_lowercase() {
+            c @ ('a'..='z' | '0'..='9') => c,
+            _ => '_',
+        })
+        .collect();
+    if !snake.starts_with(|c: char| c.is_ascii_lowercase()) {
+        snake.insert_str(0, "key_");
+    }
+    snake
+}
+
+/// Validate a user-defined type or property key
+///
+/// Keys must be snake_case: they start with a lowercase ASCII letter and contain only
+/// lowercase ASCII letters, digits and underscores, like the keys the server assigns
+/// (`page`, `last_modified_date`).
+pub(crate) fn validate_key(key: &str) -> std::result::Result<(), String> {
+    if key.is_empty() {
+        return Err("key must not be empty".to_string());
+    }
+
+    let first = key.chars().next().unwrap_or_default();
+    if !first.is_ascii_lowercase() {
+        return Err(format!(
+            "key must start with a lowercase letter, found '{first}'"
+        ));
+    }
+
+    for c in key.chars() {
+        if c.is_whitespace() {
+            return Err("key must not contain whitespace".to_string());
+        }
+        if c.is_ascii_uppercase() {
+            return Err(format!("key must be lowercase, found '{c}'"));
+        }
+        if !(c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_') {
+            return Err(format!(
+                "key may only contain lowercase letters, digits and underscores, found '{c}'"
+            ));
+        }
+    }
+
+    Ok(())
+}
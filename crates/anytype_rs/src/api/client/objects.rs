@@ -2,31 +2,185 @@
 //!
 //! Handles object management operations.
 
-use super::AnytypeClient;
-use crate::{error::Result, types::Pagination};
+use super::{
+    AnytypeClient, BatchErrorPolicy, CachedResponse, spaces::Space, types::PropertyFormat,
+};
+use crate::{
+    error::Result,
+    types::{ObjectKind, PageStart, Pagination},
+};
+use chrono::DateTime;
+use futures_util::{StreamExt, future::join_all, stream};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::atomic::AtomicBool;
+use std::time::SystemTime;
 use tracing::{debug, info};
 
+const LOG_TARGET: &str = "anytype_rs::client::objects";
+
+/// Separator placed between the existing body and appended markdown
+const APPEND_SEPARATOR: &str = "\n\n";
+/// Property formats whose values are lists; these are cleared with `[]` rather than `null`
+const LIST_FORMATS: [&str; 3] = ["multi_select", "files", "objects"];
+/// Key of the built-in property holding an object's last modification time
+pub(crate) const LAST_MODIFIED_KEY: &str = "last_modified_date";
+/// Key of the built-in property holding an object's creation time
+pub(crate) const CREATED_KEY: &str = "created_date";
+/// Start of the links the desktop app opens objects from
+const DEEP_LINK_PREFIX: &str = "anytype://object";
+/// Number of objects fetched per request when scanning a whole space
+const SCAN_PAGE_SIZE: usize = 100;
+/// Key of the built-in bookmark type
+const BOOKMARK_TYPE_KEY: &str = "bookmark";
+/// Key of the bookmark property holding the bookmarked URL
+const BOOKMARK_URL_KEY: &str = "source";
+
 /// Object information
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Object {
     pub id: String,
     pub name: Option<String>,
     pub space_id: Option<String>,
-    pub object: Option<String>, // object type
+    pub object: Option<ObjectKind>, // object type
+    #[serde(default)]
     pub properties: serde_json::Value,
+    /// The markdown body of the object, when included in the response
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub markdown: Option<String>,
+    /// The start of the body, which stands in for the name of objects without one (notes)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
     // Add more fields as needed
 }
 
+/// Longest snippet shown in place of a missing name, in characters
+const DISPLAY_SNIPPET_CHARS: usize = 40;
+
+/// The name to show for an object: its name, else the start of its snippet, else its ID
+///
+/// Blank names and snippets count as missing. The snippet is put on one line and cut at
+/// [`DISPLAY_SNIPPET_CHARS`] characters, ending with `…` if anything was cut.
+pub(crate) fn display_name(name: Option<&str>, snippet: Option<&str>, id: &str) -> String {
+    if let Some(name) = name.filter(|name| !name.trim().is_empty()) {
+        return name.to_string();
+    }
+    let Some(snippet) = snippet.filter(|snippet| !snippet.trim().is_empty()) else {
+        return id.to_string();
+    };
+
+    let line = snippet.split_whitespace().collect::<Vec<_>>().join(" ");
+    match line.char_indices().nth(DISPLAY_SNIPPET_CHARS) {
+        Some((cut, _)) => format!("{}…", line[..cut].trim_end()),
+        None => line,
+    }
+}
+
+impl Object {
+    /// The name to show for this object, falling back to its snippet and then its ID
+    ///
+    /// Use this instead of `name` when rendering, so unnamed objects such as notes are not
+    /// shown as blank rows.
+    pub fn display_name(&self) -> String {
+        display_name(self.name.as_deref(), self.snippet.as_deref(), &self.id)
+    }
+
+    /// The value of a property, looked up by key
+    ///
+    /// Properties come either as the API's list of `{"key": ..., "format": ..., "<format>": ...}`
+    /// entries or as a key/value map; both are handled. Returns `None` if the object has no
+    /// such property.
+    pub fn property(&self, key: &str) -> Option<&serde_json::Value> {
+        self.properties_iter()
+            .find(|(entry_key, _)| *entry_key == key)
+            .map(|(_, value)| value)
+    }
+
+    /// A text-like property (text, url, email, phone, date) as a string
+    ///
+    /// For a select property this is the name of the selected tag.
+    pub fn property_str(&self, key: &str) -> Option<&str> {
+        let value = self.property(key)?;
+        value
+            .as_str()
+            .or_else(|| value.get("name").and_then(|name| name.as_str()))
+    }
+
+    /// A checkbox property
+    pub fn property_bool(&self, key: &str) -> Option<bool> {
+        self.property(key)?.as_bool()
+    }
+
+    /// A number property
+    pub fn property_number(&self, key: &str) -> Option<f64> {
+        self.property(key)?.as_f64()
+    }
+
+    /// Every property of the object as `(key, value)` pairs
+    pub fn properties_iter(&self) -> Box<dyn Iterator<Item = (&str, &serde_json::Value)> + '_> {
+        property_entries(&self.properties)
+    }
+
+    /// When the object was last modified, from its `last_modified_date` property
+    ///
+    /// Returns `None` if the property is missing or not an RFC 3339 date.
+    pub fn updated_at(&self) -> Option<SystemTime> {
+        date_property(&self.properties, LAST_MODIFIED_KEY)
+    }
+
+    /// The `anytype://` link that opens this object in the desktop app
+    ///
+    /// `space_id` is used when the object does not say which space it is in.
+    pub fn deep_link(&self, space_id: &str) -> String {
+        let space_id = self.space_id.as_deref().unwrap_or(space_id);
+        format!(
+            "{DEEP_LINK_PREFIX}?objectId={}&spaceId={}",
+            self.id, space_id
+        )
+    }
+
+    /// When the object was created, from its `created_date` property
+    ///
+    /// Returns `None` if the property is missing or not an RFC 3339 date.
+    pub fn created_at(&self) -> Option<SystemTime> {
+        date_property(&self.properties, CREATED_KEY)
+    }
+}
+
+/// Properties as `(key, value)` pairs, from either a list of entries or a key/value map
+pub(crate) fn property_entries(
+    properties: &serde_json::Value,
+) -> Box<dyn Iterator<Item = (&str, &serde_json::Value)> + '_> {
+    match properties {
+        serde_json::Value::Array(entries) => Box::new(entries.iter().filter_map(|entry| {
+            let key = entry.get("key")?.as_str()?;
+            let format = entry.get("format")?.as_str()?;
+            Some((key, entry.get(format)?))
+        })),
+        serde_json::Value::Object(map) => {
+            Box::new(map.iter().map(|(key, value)| (key.as_str(), value)))
+        }
+        _ => Box::new(std::iter::empty()),
+    }
+}
+
+/// A date property parsed as an RFC 3339 timestamp
+pub(crate) fn date_property(properties: &serde_json::Value, key: &str) -> Option<SystemTime> {
+    let (_, value) = property_entries(properties).find(|(entry_key, _)| *entry_key == key)?;
+    DateTime::parse_from_rfc3339(value.as_str()?)
+        .ok()
+        .map(SystemTime::from)
+}
+
 /// Response for listing objects
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct ListObjectsResponse {
     pub data: Vec<Object>,
     pub pagination: Pagination,
 }
 
 /// Request to create a new object
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateObjectRequest {
     /// The key of the type of object to create (required)
     /// Example: "page"
@@ -49,8 +203,96 @@ pub struct CreateObjectRequest {
     pub properties: Option<Vec<serde_json::Value>>,
 }
 
+impl CreateObjectRequest {
+    /// Start a request for an object of the given type, with every optional field unset
+    pub fn new(type_key: impl Into<String>) -> Self {
+        Self {
+            type_key: type_key.into(),
+            name: None,
+            body: None,
+            icon: None,
+            template_id: None,
+            properties: None,
+        }
+    }
+
+    /// Set the name of the object
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the Markdown body of the object
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Set the icon of the object
+    pub fn icon(mut self, icon: serde_json::Value) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Create the object from a template
+    pub fn template_id(mut self, template_id: impl Into<String>) -> Self {
+        self.template_id = Some(template_id.into());
+        self
+    }
+
+    /// Set a property value, sent as `{"key": key, "<format>": value}`
+    pub fn property(
+        mut self,
+        key: impl Into<String>,
+        format: PropertyFormat,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        let mut entry = serde_json::Map::new();
+        entry.insert("key".to_string(), key.into().into());
+        entry.insert(format.as_str().to_string(), value.into());
+        self.properties
+            .get_or_insert_with(Vec::new)
+            .push(entry.into());
+        self
+    }
+
+    /// Set the property values as sent to the API, replacing any set so far
+    pub fn properties(mut self, properties: Vec<serde_json::Value>) -> Self {
+        self.properties = (!properties.is_empty()).then_some(properties);
+        self
+    }
+
+    /// Start a request for a bookmark object pointing at `url`
+    pub fn bookmark(url: impl Into<String>) -> Self {
+        Self::new(BOOKMARK_TYPE_KEY).property(BOOKMARK_URL_KEY, PropertyFormat::Url, url.into())
+    }
+}
+
+/// The text of the `<title>` element of an HTML page, on one line
+///
+/// Only the common character entities are decoded. Returns `None` if there is no title
+/// or it is blank.
+fn html_title(html: &str) -> Option<String> {
+    // ASCII lowercasing keeps byte offsets valid for slicing the original
+    let lower = html.to_ascii_lowercase();
+    let open = lower.find("<title")?;
+    let start = open + lower[open..].find('>')? + 1;
+    let end = start + lower[start..].find("</title")?;
+
+    let title = html[start..end]
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&");
+    (!title.is_empty()).then_some(title)
+}
+
 /// Response when creating an object
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct CreateObjectResponse {
     pub object: Object,
     pub properties: Option<serde_json::Value>,
@@ -59,13 +301,13 @@ pub struct CreateObjectResponse {
 }
 
 /// Response when deleting an object
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct DeleteObjectResponse {
     pub object: Object,
 }
 
 /// Request to update an existing object
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateObjectRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
@@ -79,7 +321,7 @@ pub struct UpdateObjectRequest {
 }
 
 /// Response when updating an object
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct UpdateObjectResponse {
     pub object: Object,
     pub properties: Option<serde_json::Value>,
@@ -87,6 +329,148 @@ pub struct UpdateObjectResponse {
     pub body: Option<String>,
 }
 
+/// How a property differs between two objects
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropertyChange {
+    /// Only the second object has the property
+    Added {
+        key: String,
+        value: serde_json::Value,
+    },
+    /// Only the first object has the property
+    Removed {
+        key: String,
+        value: serde_json::Value,
+    },
+    /// Both objects have the property, with different values
+    Changed {
+        key: String,
+        old: serde_json::Value,
+        new: serde_json::Value,
+    },
+}
+
+impl PropertyChange {
+    /// Key of the property that changed
+    pub fn key(&self) -> &str {
+        match self {
+            Self::Added { key, .. } | Self::Removed { key, .. } | Self::Changed { key, .. } => key,
+        }
+    }
+}
+
+/// Differences between two objects, from [`AnytypeClient::diff_objects`]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ObjectDiff {
+    /// Unified diff of the markdown bodies, empty when they are the same
+    pub body: String,
+    /// Properties that were added, removed or changed, sorted by key
+    pub properties: Vec<PropertyChange>,
+}
+
+impl ObjectDiff {
+    /// Compare two objects, going from `old` to `new`
+    ///
+    /// The diff header names each object by its [`Object::display_name`]. Objects without a
+    /// markdown body are compared as if the body were empty.
+    pub fn between(old: &Object, new: &Object) -> Self {
+        let old_body = old.markdown.as_deref().unwrap_or_default();
+        let new_body = new.markdown.as_deref().unwrap_or_default();
+        let body = if old_body == new_body {
+            String::new()
+        } else {
+            similar::TextDiff::from_lines(old_body, new_body)
+                .unified_diff()
+                .header(&old.display_name(), &new.display_name())
+                .to_string()
+        };
+
+        let old_properties: BTreeMap<&str, &serde_json::Value> = old.properties_iter().collect();
+        let new_properties: BTreeMap<&str, &serde_json::Value> = new.properties_iter().collect();
+        let mut properties = Vec::new();
+        for (&key, &old_value) in &old_properties {
+            match new_properties.get(key) {
+                None => properties.push(PropertyChange::Removed {
+                    key: key.to_string(),
+                    value: old_value.clone(),
+                }),
+                Some(&new_value) if new_value != old_value => {
+                    properties.push(PropertyChange::Changed {
+                        key: key.to_string(),
+                        old: old_value.clone(),
+                        new: new_value.clone(),
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+        for (&key, &new_value) in &new_properties {
+            if !old_properties.contains_key(key) {
+                properties.push(PropertyChange::Added {
+                    key: key.to_string(),
+                    value: new_value.clone(),
+                });
+            }
+        }
+        properties.sort_by(|a, b| a.key().cmp(b.key()));
+
+        Self { body, properties }
+    }
+
+    /// Whether the objects have the same body and properties
+    pub fn is_empty(&self) -> bool {
+        self.body.is_empty() && self.properties.is_empty()
+    }
+}
+
+/// Reject names that are empty or only whitespace
+///
+/// The API accepts them but produces an object with no visible title, so they are caught
+/// before any request is sent. A missing name (`None`) is left to the server's default.
+fn check_object_name(name: Option<&str>) -> Result<()> {
+    match name {
+        Some(name) if name.trim().is_empty() => Err(crate::error::AnytypeError::Config {
+            message: "Object name must not be empty or whitespace".to_string(),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Why a property entry of an update doesn't fit a type's properties, if it doesn't
+///
+/// Entries are `{"key": ..., <format>: value}`; the description names the key, or the
+/// whole entry when it has none.
+fn invalid_property_entry(
+    properties: &[super::types::TypeProperty],
+    entry: &serde_json::Value,
+) -> Option<String> {
+    let Some(key) = entry.get("key").and_then(|key| key.as_str()) else {
+        return Some(format!("{entry} (no key)"));
+    };
+    let Some(property) = properties.iter().find(|p| p.key == key) else {
+        return Some(format!("'{key}' (not a property of the type)"));
+    };
+    let formats: Vec<&str> = entry
+        .as_object()
+        .into_iter()
+        .flat_map(|fields| fields.keys())
+        .map(String::as_str)
+        .filter(|field| *field != "key")
+        .collect();
+    match formats.as_slice() {
+        [format] if *format == property.format => None,
+        [format] => Some(format!(
+            "'{key}' (expects {}, got {format})",
+            property.format
+        )),
+        _ => Some(format!(
+            "'{key}' (expects one {} value, got {})",
+            property.format,
+            formats.len()
+        )),
+    }
+}
+
 impl AnytypeClient {
     /// List objects in a space
     pub async fn list_objects(&self, space_id: &str) -> Result<Vec<Object>> {
@@ -95,33 +479,237 @@ impl AnytypeClient {
         Ok(response.data)
     }
 
+    /// List the objects of every space, for an overview across spaces
+    ///
+    /// Returns each space with its objects, in the order of [`AnytypeClient::list_spaces`].
+    /// Objects are fetched concurrently, at most
+    /// [`ClientConfig::max_concurrency`](super::ClientConfig) spaces at a time. A space whose
+    /// objects can't be listed gets its error, without failing the other spaces.
+    pub async fn list_objects_in_all_spaces(&self) -> Result<Vec<(Space, Result<Vec<Object>>)>> {
+        info!(target: LOG_TARGET, "Listing objects in all spaces");
+
+        let spaces = self.list_spaces().await?;
+        let objects = join_all(
+            spaces
+                .iter()
+                .map(|space| self.limited(self.list_objects(&space.id))),
+        )
+        .await;
+
+        Ok(spaces.into_iter().zip(objects).collect())
+    }
+
     /// Get a specific object by ID
     pub async fn get_object(&self, space_id: &str, object_id: &str) -> Result<Object> {
         self.get(&format!("/v1/spaces/{space_id}/objects/{object_id}"))
             .await
     }
 
+    /// Get a specific object by ID, reporting whether it changed since the last fetch
+    ///
+    /// Requires [`ClientConfig::enable_conditional_requests`](super::ClientConfig); without it
+    /// the object is always fetched and returned as [`CachedResponse::Fresh`].
+    pub async fn get_object_conditional(
+        &self,
+        space_id: &str,
+        object_id: &str,
+    ) -> Result<CachedResponse<Object>> {
+        let path = format!("/v1/spaces/{space_id}/objects/{object_id}");
+        if self.config.enable_conditional_requests {
+            self.get_conditional(&path).await
+        } else {
+            self.get(&path).await.map(CachedResponse::Fresh)
+        }
+    }
+
     /// Create a new object in a space
+    ///
+    /// The Markdown `body` and `properties` of the request are applied in the same call, so
+    /// no follow-up `update_object` is needed to set the content. A name that is empty or
+    /// only whitespace is rejected with [`AnytypeError::Config`](crate::error::AnytypeError).
     pub async fn create_object(
         &self,
         space_id: &str,
         request: CreateObjectRequest,
     ) -> Result<CreateObjectResponse> {
-        info!("Creating object in space: {}", space_id);
-        debug!("Request: {:?}", request);
-        debug!("Request JSON: {}", serde_json::to_string_pretty(&request)?);
+        check_object_name(request.name.as_deref())?;
+        info!(target: LOG_TARGET, "Creating object in space: {}", space_id);
+        debug!(target: LOG_TARGET, "Request: {:?}", request);
+        debug!(target: LOG_TARGET, "Request JSON: {}", serde_json::to_string_pretty(&request)?);
 
         self.post(&format!("/v1/spaces/{space_id}/objects"), &request)
             .await
     }
 
+    /// Create a bookmark object for a URL, named after the page's title
+    ///
+    /// The page is fetched to read its `<title>`. If it can't be fetched or has no title,
+    /// the bookmark is named after the URL instead, so an unreachable page doesn't stop
+    /// the bookmark from being created.
+    pub async fn create_bookmark(&self, space_id: &str, url: &str) -> Result<CreateObjectResponse> {
+        let name = match self.fetch_page_title(url).await {
+            Some(title) => title,
+            None => url.to_string(),
+        };
+        self.create_object(space_id, CreateObjectRequest::bookmark(url).name(name))
+            .await
+    }
+
+    /// Fetch a web page and return its title
+    ///
+    /// Returns `None` if the page can't be fetched, answers with an error status or has no
+    /// `<title>`. The request goes to `url` as-is, without the API key.
+    pub async fn fetch_page_title(&self, url: &str) -> Option<String> {
+        let response = match self.http_client.get(url).send().await {
+            Ok(response) if response.status().is_success() => response,
+            Ok(response) => {
+                debug!(
+                    target: LOG_TARGET,
+                    "Not reading title of {}: status {}",
+                    url, response.status()
+                );
+                return None;
+            }
+            Err(e) => {
+                debug!(target: LOG_TARGET, "Not reading title of {}: {}", url, e);
+                return None;
+            }
+        };
+        html_title(&response.text().await.ok()?)
+    }
+
+    /// Create several objects in a space
+    ///
+    /// Requests run concurrently, at most [`ClientConfig::max_concurrency`](super::ClientConfig)
+    /// at a time. Returns one result per request, in the same order, so a failure for one
+    /// object does not prevent the others from being created.
+    pub async fn create_objects(
+        &self,
+        space_id: &str,
+        requests: Vec<CreateObjectRequest>,
+    ) -> Vec<Result<CreateObjectResponse>> {
+        info!(target: LOG_TARGET, "Creating {} objects in space: {}", requests.len(), space_id);
+
+        join_all(
+            requests
+                .into_iter()
+                .map(|request| self.limited(self.create_object(space_id, request))),
+        )
+        .await
+    }
+
+    /// Update several objects in a space
+    ///
+    /// Takes `(object_id, request)` pairs. Like [`Self::create_objects`], requests run
+    /// concurrently and one result is returned per update, in the same order.
+    pub async fn update_objects(
+        &self,
+        space_id: &str,
+        updates: Vec<(String, UpdateObjectRequest)>,
+    ) -> Vec<Result<UpdateObjectResponse>> {
+        info!(target: LOG_TARGET, "Updating {} objects in space: {}", updates.len(), space_id);
+
+        join_all(updates.into_iter().map(|(object_id, request)| async move {
+            self.limited(self.update_object(space_id, &object_id, request))
+                .await
+        }))
+        .await
+    }
+
+    /// Create several objects in a space, with a choice of what to do when one fails
+    ///
+    /// With [`BatchErrorPolicy::ContinueOnError`] this behaves like [`Self::create_objects`].
+    /// With [`BatchErrorPolicy::FailFast`] the results stop at the first failure: requests
+    /// still in flight are cancelled and later ones are not sent.
+    pub async fn create_objects_with_policy(
+        &self,
+        space_id: &str,
+        requests: Vec<CreateObjectRequest>,
+        policy: BatchErrorPolicy,
+    ) -> Vec<Result<CreateObjectResponse>> {
+        if policy == BatchErrorPolicy::ContinueOnError {
+            return self.create_objects(space_id, requests).await;
+        }
+        info!(
+            target: LOG_TARGET,
+            "Creating {} objects in space (fail fast): {}",
+            requests.len(),
+            space_id
+        );
+
+        let count = requests.len();
+        let mut pending = stream::iter(requests)
+            .map(|request| self.limited(self.create_object(space_id, request)))
+            .buffered(count.max(1));
+
+        let mut results = Vec::with_capacity(count);
+        while let Some(result) = pending.next().await {
+            let failed = result.is_err();
+            results.push(result);
+            if failed {
+                break;
+            }
+        }
+        results
+    }
+
+    /// Create several objects in a space, stopping early once `stop` is set
+    ///
+    /// Behaves like [`Self::create_objects_with_policy`] until `stop` is set, e.g. by a
+    /// Ctrl-C handler. From then on no new request is sent, but those already sent are
+    /// awaited. Requests that were never sent are returned as `None`, so the results still
+    /// line up with `requests`.
+    pub async fn create_objects_until_stopped(
+        &self,
+        space_id: &str,
+        requests: Vec<CreateObjectRequest>,
+        policy: BatchErrorPolicy,
+        stop: &AtomicBool,
+    ) -> Vec<Option<Result<CreateObjectResponse>>> {
+        info!(target: LOG_TARGET, "Creating {} objects in space: {}", requests.len(), space_id);
+
+        let count = requests.len();
+        let mut pending = stream::iter(requests)
+            .map(|request| self.limited_unless(stop, self.create_object(space_id, request)))
+            .buffered(count.max(1));
+
+        let mut results = Vec::with_capacity(count);
+        while let Some(result) = pending.next().await {
+            let failed = matches!(result, Some(Err(_)));
+            results.push(result);
+            if failed && policy == BatchErrorPolicy::FailFast {
+                break;
+            }
+        }
+        results
+    }
+
+    /// Update several objects in a space, stopping early once `stop` is set
+    ///
+    /// Like [`Self::update_objects`] until `stop` is set; see
+    /// [`Self::create_objects_until_stopped`] for what happens after that.
+    pub async fn update_objects_until_stopped(
+        &self,
+        space_id: &str,
+        updates: Vec<(String, UpdateObjectRequest)>,
+        stop: &AtomicBool,
+    ) -> Vec<Option<Result<UpdateObjectResponse>>> {
+        info!(target: LOG_TARGET, "Updating {} objects in space: {}", updates.len(), space_id);
+
+        join_all(updates.into_iter().map(|(object_id, request)| async move {
+            self.limited_unless(stop, self.update_object(space_id, &object_id, request))
+                .await
+        }))
+        .await
+    }
+
     /// Delete an object in a space (marks it as archived)
     pub async fn delete_object(
         &self,
         space_id: &str,
         object_id: &str,
     ) -> Result<DeleteObjectResponse> {
-        info!("Deleting object {} in space: {}", object_id, space_id);
+        info!(target: LOG_TARGET, "Deleting object {} in space: {}", object_id, space_id);
 
         self.delete(&format!("/v1/spaces/{space_id}/objects/{object_id}"))
             .await
@@ -134,9 +722,10 @@ impl AnytypeClient {
         object_id: &str,
         request: UpdateObjectRequest,
     ) -> Result<UpdateObjectResponse> {
-        info!("Updating object {} in space: {}", object_id, space_id);
-        debug!("Request: {:?}", request);
-        debug!("Request JSON: {}", serde_json::to_string_pretty(&request)?);
+        check_object_name(request.name.as_deref())?;
+        info!(target: LOG_TARGET, "Updating object {} in space: {}", object_id, space_id);
+        debug!(target: LOG_TARGET, "Request: {:?}", request);
+        debug!(target: LOG_TARGET, "Request JSON: {}", serde_json::to_string_pretty(&request)?);
 
         self.patch(
             &format!("/v1/spaces/{space_id}/objects/{object_id}"),
@@ -145,6 +734,325 @@ impl AnytypeClient {
         .await
     }
 
+    /// Check the properties of an update against the object's type before sending it
+    ///
+    /// Fetches the object and its type, then checks that every property entry has a `key`
+    /// the type has, and is keyed by that property's format (`{"key": "due", "date": ...}`).
+    /// The API can silently ignore or vaguely reject such entries, so this names each bad
+    /// key in a [`Config`](crate::error::AnytypeError::Config) error instead. Updates
+    /// without properties pass without a request.
+    pub async fn validate_object_update(
+        &self,
+        space_id: &str,
+        object_id: &str,
+        request: &UpdateObjectRequest,
+    ) -> Result<()> {
+        let Some(entries) = request.properties.as_deref() else {
+            return Ok(());
+        };
+        debug!(
+            target: LOG_TARGET,
+            "Validating {} properties for object {}",
+            entries.len(), object_id
+        );
+
+        let object = self.get_object(space_id, object_id).await?;
+        let Some(type_key) = &object.object else {
+            return Err(crate::error::AnytypeError::Config {
+                message: format!("Object '{object_id}' has no type to validate properties against"),
+            });
+        };
+        let object_type = self.get_type_by_key(space_id, type_key.as_str()).await?;
+
+        let invalid: Vec<String> = entries
+            .iter()
+            .filter_map(|entry| invalid_property_entry(&object_type.properties, entry))
+            .collect();
+        if invalid.is_empty() {
+            return Ok(());
+        }
+        Err(crate::error::AnytypeError::Config {
+            message: format!(
+                "Invalid properties for type '{}': {}",
+                object_type.name,
+                invalid.join(", ")
+            ),
+        })
+    }
+
+    /// Rename an object, leaving its body and properties unchanged
+    ///
+    /// A name that is empty or only whitespace is rejected without sending a request.
+    pub async fn rename_object(
+        &self,
+        space_id: &str,
+        object_id: &str,
+        name: &str,
+    ) -> Result<UpdateObjectResponse> {
+        self.update_object(
+            space_id,
+            object_id,
+            UpdateObjectRequest {
+                name: Some(name.to_string()),
+                body: None,
+                properties: None,
+            },
+        )
+        .await
+    }
+
+    /// Replace the markdown body of an object, leaving its name and properties unchanged
+    pub async fn set_object_markdown(
+        &self,
+        space_id: &str,
+        object_id: &str,
+        markdown: &str,
+    ) -> Result<UpdateObjectResponse> {
+        self.update_object(
+            space_id,
+            object_id,
+            UpdateObjectRequest {
+                name: None,
+                body: Some(markdown.to_string()),
+                properties: None,
+            },
+        )
+        .await
+    }
+
+    /// Append markdown to the end of an object's body
+    ///
+    /// Fetches the current body and updates the object with the text appended after a
+    /// blank line, so the existing content is kept. Note that this is not atomic: edits
+    /// made between the fetch and the update are overwritten.
+    pub async fn append_markdown(
+        &self,
+        space_id: &str,
+        object_id: &str,
+        text: &str,
+    ) -> Result<UpdateObjectResponse> {
+        info!(
+            target: LOG_TARGET,
+            "Appending markdown to object {} in space: {}",
+            object_id, space_id
+        );
+
+        let current = self.get_object(space_id, object_id).await?;
+        let body = match current.markdown.as_deref().map(str::trim_end) {
+            Some(existing) if !existing.is_empty() => {
+                format!("{existing}{APPEND_SEPARATOR}{text}")
+            }
+            _ => text.to_string(),
+        };
+
+        self.set_object_markdown(space_id, object_id, &body).await
+    }
+
+    /// Clear the value of a property on an object
+    ///
+    /// `property` is matched against property keys first, then names. Property values are
+    /// sent keyed by their format (`{"key": "due", "date": ...}`), so the property's format is
+    /// looked up and the value sent as `null`, or `[]` for list formats such as `multi_select`.
+    pub async fn unset_object_property(
+        &self,
+        space_id: &str,
+        object_id: &str,
+        property: &str,
+    ) -> Result<UpdateObjectResponse> {
+        info!(
+            target: LOG_TARGET,
+            "Unsetting property {} on object {} in space: {}",
+            property, object_id, space_id
+        );
+
+        let properties = self.list_properties(space_id).await?;
+        let found = properties
+            .iter()
+            .find(|p| p.key == property)
+            .or_else(|| properties.iter().find(|p| p.name == property))
+            .ok_or_else(|| crate::error::AnytypeError::NotFound {
+                message: format!("No property '{property}' found in space '{space_id}'"),
+            })?;
+
+        let cleared = if LIST_FORMATS.contains(&found.format.as_str()) {
+            serde_json::json!([])
+        } else {
+            serde_json::Value::Null
+        };
+        let mut value = serde_json::Map::new();
+        value.insert("key".to_string(), found.key.clone().into());
+        value.insert(found.format.clone(), cleared);
+
+        self.update_object(
+            space_id,
+            object_id,
+            UpdateObjectRequest {
+                name: None,
+                body: None,
+                properties: Some(vec![value.into()]),
+            },
+        )
+        .await
+    }
+
+    /// Link an object to another through a relation (`objects`-format) property
+    ///
+    /// The target is appended to the objects the property already links to, so existing
+    /// links are kept; linking an object that is already linked does not duplicate it.
+    pub async fn add_object_relation(
+        &self,
+        space_id: &str,
+        object_id: &str,
+        property_key: &str,
+        target_id: &str,
+    ) -> Result<UpdateObjectResponse> {
+        info!(
+            target: LOG_TARGET,
+            "Linking object {} to {} through {} in space: {}",
+            object_id, target_id, property_key, space_id
+        );
+
+        let properties = self.list_properties(space_id).await?;
+        let found = properties
+            .iter()
+            .find(|p| p.key == property_key)
+            .ok_or_else(|| crate::error::AnytypeError::NotFound {
+                message: format!("No property '{property_key}' found in space '{space_id}'"),
+            })?;
+        if found.format != "objects" {
+            return Err(crate::error::AnytypeError::Api {
+                message: format!(
+                    "Property '{property_key}' has format '{}', not 'objects'",
+                    found.format
+                ),
+                status: None,
+            });
+        }
+
+        let object = self.get_object(space_id, object_id).await?;
+        // Linked objects come as IDs, or as objects with an `id` in richer responses
+        let mut linked: Vec<String> = object
+            .property(property_key)
+            .and_then(|value| value.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|item| item.as_str().or_else(|| item.get("id")?.as_str()))
+            .map(str::to_string)
+            .collect();
+        if !linked.iter().any(|id| id == target_id) {
+            linked.push(target_id.to_string());
+        }
+
+        let mut value = serde_json::Map::new();
+        value.insert("key".to_string(), found.key.clone().into());
+        value.insert("objects".to_string(), linked.into());
+
+        self.update_object(
+            space_id,
+            object_id,
+            UpdateObjectRequest {
+                name: None,
+                body: None,
+                properties: Some(vec![value.into()]),
+            },
+        )
+        .await
+    }
+
+    /// Fetch two objects concurrently and compare them, going from `old_id` to `new_id`
+    pub async fn diff_objects(
+        &self,
+        space_id: &str,
+        old_id: &str,
+        new_id: &str,
+    ) -> Result<ObjectDiff> {
+        info!(
+            target: LOG_TARGET,
+            "Comparing objects {} and {} in space: {}",
+            old_id, new_id, space_id
+        );
+
+        let (old, new) = futures_util::try_join!(
+            self.get_object(space_id, old_id),
+            self.get_object(space_id, new_id)
+        )?;
+        Ok(ObjectDiff::between(&old, &new))
+    }
+
+    /// List objects in a space modified at or after `since`, oldest change first
+    ///
+    /// The objects endpoint has no date filter, so every page of the space is fetched and
+    /// filtered on [`Object::updated_at`]. Objects without a modification time are left out.
+    pub async fn objects_modified_since(
+        &self,
+        space_id: &str,
+        since: SystemTime,
+    ) -> Result<Vec<Object>> {
+        info!(
+            target: LOG_TARGET,
+            "Listing objects modified since {:?} in space: {}",
+            since, space_id
+        );
+
+        let mut modified: Vec<_> = self
+            .list_all_objects(space_id)
+            .await?
+            .into_iter()
+            .filter_map(|object| {
+                let updated_at = object.updated_at()?;
+                (updated_at >= since).then_some((updated_at, object))
+            })
+            .collect();
+
+        modified.sort_by_key(|(updated_at, _)| *updated_at);
+        Ok(modified.into_iter().map(|(_, object)| object).collect())
+    }
+
+    /// List every object in a space, fetching page after page until the last one
+    ///
+    /// Listed objects carry no body; use [`AnytypeClient::get_objects`] for that.
+    pub async fn list_all_objects(&self, space_id: &str) -> Result<Vec<Object>> {
+        info!(target: LOG_TARGET, "Listing all objects in space: {}", space_id);
+
+        let mut objects = Vec::new();
+        let mut start = PageStart::default();
+        loop {
+            let page = self
+                .list_objects_from(space_id, &start, SCAN_PAGE_SIZE)
+                .await?;
+            let page_len = page.data.len();
+            objects.extend(page.data);
+
+            match page.pagination.next_page(objects.len()) {
+                Some(next) if page_len > 0 => start = next,
+                _ => return Ok(objects),
+            }
+        }
+    }
+
+    /// Fetch several objects with their bodies, in the order of `object_ids`
+    ///
+    /// Objects are fetched concurrently, at most
+    /// [`ClientConfig::max_concurrency`](super::ClientConfig) at a time. Fails on the first
+    /// object that cannot be fetched.
+    pub async fn get_objects(&self, space_id: &str, object_ids: &[String]) -> Result<Vec<Object>> {
+        info!(
+            target: LOG_TARGET,
+            "Fetching {} objects in space: {}",
+            object_ids.len(),
+            space_id
+        );
+
+        join_all(
+            object_ids
+                .iter()
+                .map(|object_id| self.limited(self.get_object(space_id, object_id))),
+        )
+        .await
+        .into_iter()
+        .collect()
+    }
+
     /// List objects in a space with pagination information
     pub async fn list_objects_with_pagination(
         &self,
@@ -152,4 +1060,67 @@ impl AnytypeClient {
     ) -> Result<ListObjectsResponse> {
         self.get(&format!("/v1/spaces/{space_id}/objects")).await
     }
+
+    /// List one page of objects in a space, starting at `offset`
+    ///
+    /// Use `pagination.has_more` on the response to decide whether to fetch the next page.
+    pub async fn list_objects_page(
+        &self,
+        space_id: &str,
+        offset: usize,
+        limit: usize,
+    ) -> Result<ListObjectsResponse> {
+        self.list_objects_from(space_id, &PageStart::Offset(offset), limit)
+            .await
+    }
+
+    /// List one page of objects in a space, starting at an offset or a cursor
+    ///
+    /// Pass [`Pagination::next_page`] of the previous response to walk every page, whichever
+    /// way the API pages.
+    pub async fn list_objects_from(
+        &self,
+        space_id: &str,
+        start: &PageStart,
+        limit: usize,
+    ) -> Result<ListObjectsResponse> {
+        debug!(
+            target: LOG_TARGET,
+            "GET /v1/spaces/{}/objects ({:?}, limit {})",
+            space_id, start, limit
+        );
+
+        self.get(&format!(
+            "/v1/spaces/{space_id}/objects?{}&limit={limit}",
+            start.query_param()
+        ))
+        .await
+    }
+
+    /// Count the objects in a space
+    ///
+    /// Fetches a single object and reads `pagination.total`, so the cost does not grow with
+    /// the size of the space.
+    pub async fn count_objects(&self, space_id: &str) -> Result<usize> {
+        debug!(target: LOG_TARGET, "Counting objects in space: {}", space_id);
+
+        let response = self.list_objects_page(space_id, 0, 1).await?;
+        Ok(response.pagination.total)
+    }
+
+    /// Count the objects in each of several spaces
+    ///
+    /// Requests run concurrently, at most [`ClientConfig::max_concurrency`](super::ClientConfig)
+    /// at a time. Returns one result per space, in the same order, so a space that cannot be
+    /// counted does not prevent the others from being counted.
+    pub async fn count_objects_in_spaces(&self, space_ids: &[String]) -> Vec<Result<usize>> {
+        info!(target: LOG_TARGET, "Counting objects in {} spaces", space_ids.len());
+
+        join_all(
+            space_ids
+                .iter()
+                .map(|space_id| self.limited(self.count_objects(space_id))),
+        )
+        .await
+    }
 }
@@ -2,39 +2,51 @@
 //!
 //! Handles property management operations.
 
-use super::AnytypeClient;
-use crate::{error::Result, types::Pagination};
+use super::{
+    AnytypeClient,
+    objects::UpdateObjectRequest,
+    tags::{CreateTagRequest, Tag},
+    types::{CreateTypeProperty, CreateTypeRequest, Type, UpdateTypeRequest},
+    validate_key,
+};
+use crate::{
+    error::{AnytypeError, Result},
+    types::{ObjectKind, Pagination},
+};
 use serde::{Deserialize, Serialize};
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
+
+const LOG_TARGET: &str = "anytype_rs::client::properties";
 
 // Import PropertyFormat from types module
 use super::types::PropertyFormat;
 
 /// Property information
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Property {
     pub format: String,
     pub id: String,
     pub key: String,
     pub name: String,
-    pub object: String,
+    #[serde(default)]
+    pub object: ObjectKind,
 }
 
 /// Response for listing properties
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct ListPropertiesResponse {
     pub data: Vec<Property>,
     pub pagination: Pagination,
 }
 
 /// Response when getting a property
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct GetPropertyResponse {
     pub property: Property,
 }
 
 /// Request to create a new property
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreatePropertyRequest {
     pub name: String,
     pub format: PropertyFormat,
@@ -43,13 +55,13 @@ pub struct CreatePropertyRequest {
 }
 
 /// Response when creating a property
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct CreatePropertyResponse {
     pub property: Property,
 }
 
 /// Request to update an existing property
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UpdatePropertyRequest {
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -57,17 +69,55 @@ pub struct UpdatePropertyRequest {
 }
 
 /// Response when updating a property
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct UpdatePropertyResponse {
     pub property: Property,
 }
 
 /// Response when deleting a property
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct DeletePropertyResponse {
     pub property: Property,
 }
 
+/// Formats whose values are plain strings
+const TEXT_FORMATS: [&str; 4] = ["text", "url", "email", "phone"];
+/// Separator used when a multi-select value is migrated to a text format
+const MIGRATED_TAGS_SEPARATOR: &str = ", ";
+
+/// What [`AnytypeClient::migrate_property_format`] will do, from
+/// [`AnytypeClient::plan_property_migration`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyMigration {
+    /// The property being migrated, which is deleted once its values are carried over
+    pub property: Property,
+    pub new_format: PropertyFormat,
+    /// Names of the tags to create on the new property, in the order they were first seen
+    pub tags: Vec<String>,
+    /// Objects with a value for the property, and the values they will get
+    pub objects: Vec<MigratedObject>,
+    /// Types that list the property, which get the new property in its place
+    pub types: Vec<Type>,
+}
+
+/// An object whose value is carried over by a property migration
+#[derive(Debug, Clone, PartialEq)]
+pub struct MigratedObject {
+    pub id: String,
+    pub name: String,
+    /// The value as text: one entry per tag for tag formats, otherwise a single entry
+    pub values: Vec<String>,
+}
+
+/// Validate a property key before sending it to the API
+///
+/// Property keys follow the same snake_case rules as type keys: a lowercase ASCII letter
+/// followed by lowercase letters, digits or underscores. Returns the reason the key was
+/// rejected.
+pub fn validate_property_key(key: &str) -> std::result::Result<(), String> {
+    validate_key(key)
+}
+
 impl AnytypeClient {
     /// List properties in a space
     pub async fn list_properties(&self, space_id: &str) -> Result<Vec<Property>> {
@@ -82,16 +132,16 @@ impl AnytypeClient {
         &self,
         space_id: &str,
     ) -> Result<ListPropertiesResponse> {
-        info!("Listing properties in space: {}", space_id);
-        debug!("GET /v1/spaces/{}/properties", space_id);
+        info!(target: LOG_TARGET, "Listing properties in space: {}", space_id);
+        debug!(target: LOG_TARGET, "GET /v1/spaces/{}/properties", space_id);
 
         self.get(&format!("/v1/spaces/{space_id}/properties")).await
     }
 
     /// Get a specific property by ID in a space
     pub async fn get_property(&self, space_id: &str, property_id: &str) -> Result<Property> {
-        info!("Getting property '{}' in space: {}", property_id, space_id);
-        debug!("GET /v1/spaces/{}/properties/{}", space_id, property_id);
+        info!(target: LOG_TARGET, "Getting property '{}' in space: {}", property_id, space_id);
+        debug!(target: LOG_TARGET, "GET /v1/spaces/{}/properties/{}", space_id, property_id);
 
         let response: GetPropertyResponse = self
             .get(&format!("/v1/spaces/{space_id}/properties/{property_id}"))
@@ -105,12 +155,19 @@ impl AnytypeClient {
         space_id: &str,
         request: CreatePropertyRequest,
     ) -> Result<CreatePropertyResponse> {
+        if let Some(key) = &request.key {
+            validate_property_key(key).map_err(|reason| AnytypeError::Config {
+                message: format!("Invalid property key '{key}': {reason}"),
+            })?;
+        }
+
         info!(
+            target: LOG_TARGET,
             "Creating property '{}' in space: {}",
             request.name, space_id
         );
-        debug!("Request: {:?}", request);
-        debug!("Request JSON: {}", serde_json::to_string_pretty(&request)?);
+        debug!(target: LOG_TARGET, "Request: {:?}", request);
+        debug!(target: LOG_TARGET, "Request JSON: {}", serde_json::to_string_pretty(&request)?);
 
         self.post(&format!("/v1/spaces/{space_id}/properties"), &request)
             .await
@@ -123,9 +180,9 @@ impl AnytypeClient {
         property_id: &str,
         request: UpdatePropertyRequest,
     ) -> Result<UpdatePropertyResponse> {
-        info!("Updating property '{}' in space: {}", property_id, space_id);
-        debug!("Request: {:?}", request);
-        debug!("Request JSON: {}", serde_json::to_string_pretty(&request)?);
+        info!(target: LOG_TARGET, "Updating property '{}' in space: {}", property_id, space_id);
+        debug!(target: LOG_TARGET, "Request: {:?}", request);
+        debug!(target: LOG_TARGET, "Request JSON: {}", serde_json::to_string_pretty(&request)?);
 
         self.patch(
             &format!("/v1/spaces/{space_id}/properties/{property_id}"),
@@ -140,10 +197,325 @@ impl AnytypeClient {
         space_id: &str,
         property_id: &str,
     ) -> Result<DeletePropertyResponse> {
-        info!("Deleting property '{}' in space: {}", property_id, space_id);
-        debug!("DELETE /v1/spaces/{}/properties/{}", space_id, property_id);
+        info!(target: LOG_TARGET, "Deleting property '{}' in space: {}", property_id, space_id);
+        debug!(target: LOG_TARGET, "DELETE /v1/spaces/{}/properties/{}", space_id, property_id);
 
         self.delete(&format!("/v1/spaces/{space_id}/properties/{property_id}"))
             .await
     }
+
+    /// Work out how a property's values would be carried over to a new format
+    ///
+    /// Scans every object in the space for a value of the property. Nothing is changed, so
+    /// this doubles as a dry run of [`Self::migrate_property_format`].
+    pub async fn plan_property_migration(
+        &self,
+        space_id: &str,
+        property_id: &str,
+        new_format: PropertyFormat,
+    ) -> Result<PropertyMigration> {
+        info!(
+            target: LOG_TARGET,
+            "Planning migration of property '{}' to {} in space: {}",
+            property_id,
+            new_format.as_str(),
+            space_id
+        );
+
+        let property = self.get_property(space_id, property_id).await?;
+        check_migration(&property, &new_format)?;
+
+        let mut tags: Vec<String> = Vec::new();
+        let mut objects = Vec::new();
+        for object in self.list_all_objects(space_id).await? {
+            let Some(value) = object.property(&property.key) else {
+                continue;
+            };
+            let values = value_texts(value);
+            if values.is_empty() {
+                continue;
+            }
+            for value in &values {
+                if !tags.contains(value) {
+                    tags.push(value.clone());
+                }
+            }
+            objects.push(MigratedObject {
+                name: object.display_name(),
+                id: object.id,
+                values,
+            });
+        }
+        if !is_tag_format(new_format.as_str()) {
+            tags.clear();
+        }
+        let types: Vec<Type> = self
+            .list_types(space_id)
+            .await?
+            .into_iter()
+            .filter(|type_data| {
+                type_data
+                    .properties
+                    .iter()
+                    .any(|listed| listed.id == property.id || listed.key == property.key)
+            })
+            .collect();
+        debug!(
+            target: LOG_TARGET,
+            "{} objects to migrate, {} tags to create, {} types to update",
+            objects.len(),
+            tags.len(),
+            types.len()
+        );
+
+        Ok(PropertyMigration {
+            property,
+            new_format,
+            tags,
+            objects,
+            types,
+        })
+    }
+
+    /// Change the format of a property, keeping the values objects have for it
+    ///
+    /// The API cannot change a property's format, so a property with the same name and the
+    /// new format is created, tags are created on it from the distinct values (for `select`
+    /// and `multi_select`), every object with a value gets it on the new property, and the
+    /// old property is deleted and its key given to the new one. Types that listed the old
+    /// property get the new one in its place. The new property has a new ID.
+    ///
+    /// Text formats (`text`, `url`, `email`, `phone`), `select` and `multi_select` can be
+    /// migrated between each other, except `multi_select` to `select`. If creating a tag,
+    /// updating an object or deleting the old property fails, the new property and its tags
+    /// are deleted again, the old property is kept and an error is returned. Failing to give
+    /// the new property the old key or to add it to a type is also an error, but the
+    /// migration itself is not undone.
+    pub async fn migrate_property_format(
+        &self,
+        space_id: &str,
+        property_id: &str,
+        new_format: PropertyFormat,
+    ) -> Result<Property> {
+        let plan = self
+            .plan_property_migration(space_id, property_id, new_format)
+            .await?;
+        let old = &plan.property;
+        info!(
+            target: LOG_TARGET,
+            "Migrating property '{}' from {} to {} in space: {}",
+            old.key,
+            old.format,
+            plan.new_format.as_str(),
+            space_id
+        );
+
+        let mut property = self
+            .create_property(
+                space_id,
+                CreatePropertyRequest {
+                    name: old.name.clone(),
+                    format: plan.new_format.clone(),
+                    key: None,
+                },
+            )
+            .await?
+            .property;
+
+        let mut tags = Vec::with_capacity(plan.tags.len());
+        let carried_over = async {
+            for name in &plan.tags {
+                let request = CreateTagRequest {
+                    name: name.clone(),
+                    color: None,
+                };
+                tags.push(self.create_tag(space_id, &property.id, request).await?.tag);
+            }
+
+            let updates = plan
+                .objects
+                .iter()
+                .map(|object| {
+                    let value = migrated_value(&object.values, &plan.new_format, &tags);
+                    let mut entry = serde_json::Map::new();
+                    entry.insert("key".to_string(), property.key.clone().into());
+                    entry.insert(plan.new_format.as_str().to_string(), value);
+                    let request = UpdateObjectRequest {
+                        name: None,
+                        body: None,
+                        properties: Some(vec![entry.into()]),
+                    };
+                    (object.id.clone(), request)
+                })
+                .collect();
+            let results = self.update_objects(space_id, updates).await;
+            let failed = results.iter().filter(|result| result.is_err()).count();
+            if let Some(Err(error)) = results.into_iter().find(Result::is_err) {
+                return Err(AnytypeError::Api {
+                    message: format!(
+                        "Migrated {} of {} objects to property '{}'; the old property was kept: {error}",
+                        plan.objects.len() - failed,
+                        plan.objects.len(),
+                        old.key
+                    ),
+                    status: None,
+                });
+            }
+
+            self.delete_property(space_id, &old.id).await?;
+            Ok(())
+        }
+        .await;
+        if let Err(error) = carried_over {
+            self.discard_migrated_property(space_id, &property, &tags)
+                .await;
+            return Err(error);
+        }
+
+        let request = UpdatePropertyRequest {
+            name: property.name.clone(),
+            key: Some(old.key.clone()),
+        };
+        property = self
+            .update_property(space_id, &property.id, request)
+            .await
+            .map_err(|e| AnytypeError::Api {
+                message: format!(
+                    "Property '{}' was migrated to '{}' ({}), but giving it back its key failed and no types were updated: {e}",
+                    old.key, property.key, property.id
+                ),
+                status: None,
+            })?
+            .property;
+
+        for type_data in &plan.types {
+            self.replace_type_property(space_id, &type_data.id, &property, &plan.new_format)
+                .await
+                .map_err(|e| AnytypeError::Api {
+                    message: format!(
+                        "Property '{}' was migrated, but adding it to type '{}' failed: {e}",
+                        property.key, type_data.key
+                    ),
+                    status: None,
+                })?;
+        }
+
+        Ok(property)
+    }
+
+    /// Undo a failed migration by deleting the property it created and the tags on it
+    ///
+    /// Failures are only logged, so the error that stopped the migration is the one
+    /// returned.
+    async fn discard_migrated_property(&self, space_id: &str, property: &Property, tags: &[Tag]) {
+        for tag in tags {
+            if let Err(e) = self.delete_tag(space_id, &property.id, &tag.id).await {
+                warn!(
+                    target: LOG_TARGET,
+                    "Failed to delete tag '{}' of discarded property '{}': {}",
+                    tag.name, property.id, e
+                );
+            }
+        }
+        if let Err(e) = self.delete_property(space_id, &property.id).await {
+            warn!(
+                target: LOG_TARGET,
+                "Failed to delete discarded property '{}': {}",
+                property.id, e
+            );
+        }
+    }
+
+    /// Put a migrated property into a type's property list, in place of the entry with its
+    /// key or at the end
+    async fn replace_type_property(
+        &self,
+        space_id: &str,
+        type_id: &str,
+        property: &Property,
+        format: &PropertyFormat,
+    ) -> Result<()> {
+        let type_data = self.get_type(space_id, type_id).await?;
+        let mut properties = CreateTypeRequest::from_type(&type_data)?.properties;
+        let entry = CreateTypeProperty {
+            format: format.clone(),
+            key: property.key.clone(),
+            name: property.name.clone(),
+        };
+        match properties.iter().position(|listed| listed.key == entry.key) {
+            Some(index) => properties[index] = entry,
+            None => properties.push(entry),
+        }
+
+        self.update_type(
+            space_id,
+            type_id,
+            UpdateTypeRequest {
+                icon: None,
+                key: None,
+                layout: None,
+                name: None,
+                plural_name: None,
+                properties: Some(properties),
+            },
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+/// Whether values of `format` are tags
+fn is_tag_format(format: &str) -> bool {
+    matches!(format, "select" | "multi_select")
+}
+
+/// Fail unless values of the property can be carried over to `new_format`
+fn check_migration(property: &Property, new_format: &PropertyFormat) -> Result<()> {
+    let (from, to) = (property.format.as_str(), new_format.as_str());
+    let supported = |format: &str| TEXT_FORMATS.contains(&format) || is_tag_format(format);
+    if from == to {
+        return Err(AnytypeError::Config {
+            message: format!("Property '{}' already has format '{to}'", property.key),
+        });
+    }
+    if !supported(from) || !supported(to) || (from == "multi_select" && to == "select") {
+        return Err(AnytypeError::Config {
+            message: format!(
+                "Migrating property '{}' from '{from}' to '{to}' is not supported",
+                property.key
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// A property value as text: a string as-is, a tag by its name, or a list of either
+fn value_texts(value: &serde_json::Value) -> Vec<String> {
+    let text = |item: &serde_json::Value| {
+        item.as_str()
+            .or_else(|| item.get("name")?.as_str())
+            .map(str::trim)
+            .filter(|text| !text.is_empty())
+            .map(str::to_string)
+    };
+    match value {
+        serde_json::Value::Array(items) => items.iter().filter_map(text).collect(),
+        value => text(value).into_iter().collect(),
+    }
+}
+
+/// The value to set on the new property: tag IDs for tag formats, otherwise joined text
+fn migrated_value(values: &[String], format: &PropertyFormat, tags: &[Tag]) -> serde_json::Value {
+    let tag_ids = || {
+        values.iter().filter_map(|value| {
+            tags.iter()
+                .find(|tag| &tag.name == value)
+                .map(|tag| serde_json::Value::String(tag.id.clone()))
+        })
+    };
+    match format {
+        PropertyFormat::Select => tag_ids().next().unwrap_or(serde_json::Value::Null),
+        PropertyFormat::MultiSelect => tag_ids().collect(),
+        _ => values.join(MIGRATED_TAGS_SEPARATOR).into(),
+    }
 }
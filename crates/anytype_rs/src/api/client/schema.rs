@@ -0,0 +1,111 @@
+//! Schema module
+//!
+//! Assembles a space's full type tree (types → properties → tags) from the list endpoints.
+
+use super::AnytypeClient;
+use crate::{api::client::tags::Tag, error::Result};
+use futures_util::future::try_join_all;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::{debug, info};
+
+const LOG_TARGET: &str = "anytype_rs::client::schema";
+
+/// Property formats whose values are picked from a list of tags
+const TAGGED_FORMATS: [&str; 2] = ["select", "multi_select"];
+
+/// Complete schema of a space
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SchemaDocument {
+    pub space_id: String,
+    pub types: Vec<SchemaType>,
+}
+
+/// Type entry in a schema document
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SchemaType {
+    pub id: String,
+    pub key: String,
+    pub name: String,
+    pub plural_name: Option<String>,
+    pub layout: Option<String>,
+    pub properties: Vec<SchemaProperty>,
+}
+
+/// Property entry in a schema document
+///
+/// `tags` is only populated for `select` and `multi_select` properties.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SchemaProperty {
+    pub id: String,
+    pub key: String,
+    pub name: String,
+    pub format: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<Tag>,
+}
+
+impl AnytypeClient {
+    /// Export the schema of a space: every type, its properties and the tags of its select properties
+    ///
+    /// Tags are fetched concurrently (up to `max_concurrency` at a time), once per distinct
+    /// select property.
+    pub async fn export_schema(&self, space_id: &str) -> Result<SchemaDocument> {
+        info!(target: LOG_TARGET, "Exporting schema of space: {}", space_id);
+
+        let types = self.list_types(space_id).await?;
+
+        let mut tagged_property_ids: Vec<&str> = types
+            .iter()
+            .flat_map(|type_data| &type_data.properties)
+            .filter(|prop| TAGGED_FORMATS.contains(&prop.format.as_str()))
+            .map(|prop| prop.id.as_str())
+            .collect();
+        tagged_property_ids.sort_unstable();
+        tagged_property_ids.dedup();
+        debug!(
+            target: LOG_TARGET,
+            "Fetching tags for {} select properties",
+            tagged_property_ids.len()
+        );
+
+        let tag_lists = try_join_all(
+            tagged_property_ids
+                .iter()
+                .map(|property_id| self.limited(self.list_tags(space_id, property_id))),
+        )
+        .await?;
+        let tags_by_property: HashMap<&str, Vec<Tag>> =
+            tagged_property_ids.into_iter().zip(tag_lists).collect();
+
+        let types = types
+            .iter()
+            .map(|type_data| SchemaType {
+                id: type_data.id.clone(),
+                key: type_data.key.clone(),
+                name: type_data.name.clone(),
+                plural_name: type_data.plural_name.clone(),
+                layout: type_data.layout.clone(),
+                properties: type_data
+                    .properties
+                    .iter()
+                    .map(|prop| SchemaProperty {
+                        id: prop.id.clone(),
+                        key: prop.key.clone(),
+                        name: prop.name.clone(),
+                        format: prop.format.clone(),
+                        tags: tags_by_property
+                            .get(prop.id.as_str())
+                            .cloned()
+                            .unwrap_or_default(),
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        Ok(SchemaDocument {
+            space_id: space_id.to_string(),
+            types,
+        })
+    }
+}
@@ -4,12 +4,23 @@
 
 use super::AnytypeClient;
 use crate::api::types::{Icon, Type};
-use crate::{error::Result, types::Pagination};
+use crate::{
+    error::Result,
+    types::{ObjectKind, Pagination},
+};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::watch;
 use tracing::{debug, info};
 
+const LOG_TARGET: &str = "anytype_rs::client::search";
+
+/// Number of search results checked for an exact match by [`AnytypeClient::find_object_by_name`]
+const FIND_BY_NAME_LIMIT: usize = 100;
+
 /// Sort direction for search results
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum SortDirection {
     Asc,
@@ -18,7 +29,7 @@ pub enum SortDirection {
 }
 
 /// Sort property for search results
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum SortProperty {
     CreatedDate,
@@ -29,62 +40,132 @@ pub enum SortProperty {
 }
 
 /// Sort options for search results
-#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Default)]
 pub struct Sort {
     pub direction: SortDirection,
     pub property_key: SortProperty,
 }
 
+/// Order of search results, as a shorthand for a [`Sort`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SearchOrder {
+    /// Best matches first: no sort is sent, so the server's ranking of matches is kept
+    #[default]
+    Relevance,
+    /// Most recently modified first
+    Recent,
+    /// Alphabetically by name
+    Name,
+}
+
+impl SearchOrder {
+    /// The sort to put in a search request for this order
+    pub fn sort(self) -> Option<Sort> {
+        match self {
+            Self::Relevance => None,
+            Self::Recent => Some(Sort {
+                direction: SortDirection::Desc,
+                property_key: SortProperty::LastModifiedDate,
+            }),
+            Self::Name => Some(Sort {
+                direction: SortDirection::Asc,
+                property_key: SortProperty::Name,
+            }),
+        }
+    }
+}
+
 /// Search request parameters
-#[derive(Debug, Serialize)]
+///
+/// The API has a single search mode: `query` is matched against object names and their
+/// body content, and matching text is returned in [`SearchObject::snippet`]. There is no
+/// separate metadata-only or full-text parameter.
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct SearchRequest {
     pub offset: Option<usize>,
     // TODO: Enforce max value of 1000
     pub limit: Option<usize>,
+    /// Text matched against object names and body content
     pub query: Option<String>,
     pub space_id: Option<String>,
     pub sort: Option<Sort>,
+    /// Keep archived objects in the results (they are dropped by default)
+    #[serde(skip)]
+    pub include_archived: bool,
 }
 
 /// Search request parameters for space-specific search
-#[derive(Debug, Serialize)]
+///
+/// Searches names and body content, like [`SearchRequest`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct SearchSpaceRequest {
+    /// Text matched against object names and body content
     pub query: Option<String>,
     pub limit: Option<usize>,
     pub offset: Option<usize>,
     pub sort: Option<Sort>,
+    /// Keep archived objects in the results (they are dropped by default)
+    #[serde(skip)]
+    pub include_archived: bool,
 }
 
 /// Basic object information for search results
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct SearchObject {
+    #[serde(default)]
     pub archived: bool,
     /// The icon of the object (optional in search results)
     pub icon: Option<Icon>,
     pub id: String,
     pub name: String,
-    pub object: String,
+    #[serde(default)]
+    pub object: ObjectKind,
     // TODO: The types for properties
+    #[serde(default)]
     pub properties: serde_json::Value,
+    #[serde(default)]
     pub snippet: String,
     pub space_id: String,
     pub r#type: Option<Type>,
 }
 
+impl SearchObject {
+    /// The name to show for this object, falling back to its snippet and then its ID
+    pub fn display_name(&self) -> String {
+        super::objects::display_name(Some(&self.name), Some(&self.snippet), &self.id)
+    }
+}
+
 /// Search response
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct SearchResponse {
     pub data: Vec<SearchObject>,
     pub pagination: Pagination,
 }
 
+impl SearchResponse {
+    /// Drop archived objects unless they were asked for
+    ///
+    /// The search endpoints have no archived filter, so this happens on the returned page.
+    /// `pagination` still describes the unfiltered results.
+    fn without_archived(mut self, include_archived: bool) -> Self {
+        if !include_archived {
+            self.data.retain(|object| !object.archived);
+        }
+        self
+    }
+}
+
 impl AnytypeClient {
     /// Search for objects and return full response with pagination
+    ///
+    /// Archived objects are left out unless [`SearchRequest::include_archived`] is set.
     pub async fn search_with_pagination(&self, request: SearchRequest) -> Result<SearchResponse> {
-        info!("Searching objects");
-        debug!("Search query: {:?}", request.query);
+        info!(target: LOG_TARGET, "Searching objects");
+        debug!(target: LOG_TARGET, "Search query: {:?}", request.query);
 
-        self.post("/v1/search", &request).await
+        let response = self.post_search("/v1/search", &request).await?;
+        Ok(response.without_archived(request.include_archived))
     }
 
     /// Search for objects and return just the objects array
@@ -98,17 +179,46 @@ impl AnytypeClient {
         self.search_with_pagination(request).await
     }
 
+    /// Find an object in a space by its exact name and type key
+    ///
+    /// Searches the space for the name and returns the first result whose name and type
+    /// key both match exactly. Archived objects are not considered.
+    pub async fn find_object_by_name(
+        &self,
+        space_id: &str,
+        name: &str,
+        type_key: &str,
+    ) -> Result<Option<SearchObject>> {
+        debug!(target: LOG_TARGET, "Looking up {} object named {:?}", type_key, name);
+
+        let request = SearchSpaceRequest {
+            query: Some(name.to_string()),
+            limit: Some(FIND_BY_NAME_LIMIT),
+            offset: None,
+            sort: None,
+            include_archived: false,
+        };
+        let response = self.search_space(space_id, request).await?;
+        Ok(response.data.into_iter().find(|object| {
+            object.name == name && object.r#type.as_ref().is_some_and(|t| t.key == type_key)
+        }))
+    }
+
     /// Search for objects within a specific space and return full response with pagination
+    ///
+    /// Archived objects are left out unless [`SearchSpaceRequest::include_archived`] is set.
     pub async fn search_space_with_pagination(
         &self,
         space_id: &str,
         request: SearchSpaceRequest,
     ) -> Result<SearchResponse> {
-        info!("Searching objects in space: {}", space_id);
-        debug!("Search query: {:?}", request.query);
+        info!(target: LOG_TARGET, "Searching objects in space: {}", space_id);
+        debug!(target: LOG_TARGET, "Search query: {:?}", request.query);
 
-        self.post(&format!("/v1/spaces/{space_id}/search"), &request)
-            .await
+        let response = self
+            .post_search(&format!("/v1/spaces/{space_id}/search"), &request)
+            .await?;
+        Ok(response.without_archived(request.include_archived))
     }
 
     /// Search for objects within a specific space and return just the objects array
@@ -129,4 +239,129 @@ impl AnytypeClient {
     ) -> Result<SearchResponse> {
         self.search_space_with_pagination(space_id, request).await
     }
+
+    /// Forget all cached search results
+    pub fn clear_search_cache(&self) {
+        if let Ok(mut cache) = self.search_cache.lock() {
+            cache.clear();
+        }
+    }
+
+    /// Send a search request, going through the search cache when it is enabled
+    ///
+    /// Entries are keyed by the path and the request body with its query normalized
+    /// (trimmed, lowercased, inner whitespace collapsed). Expired entries are dropped
+    /// whenever a new response is stored.
+    async fn post_search<B: Serialize>(&self, path: &str, request: &B) -> Result<SearchResponse> {
+        let ttl = Duration::from_secs(self.config.search_cache_ttl_seconds);
+        if ttl.is_zero() {
+            return self.post(path, request).await;
+        }
+
+        let mut key_body = serde_json::to_value(request)?;
+        if let Some(query) = key_body.get_mut("query")
+            && let Some(text) = query.as_str()
+        {
+            *query = normalize_query(text).into();
+        }
+        let key = format!("{path} {key_body}");
+
+        if let Ok(cache) = self.search_cache.lock()
+            && let Some((fetched, response)) = cache.get(&key)
+            && fetched.elapsed() < ttl
+        {
+            debug!(target: LOG_TARGET, "Search cache hit: {}", key);
+            return Ok(response.clone());
+        }
+
+        let response: SearchResponse = self.post(path, request).await?;
+        if let Ok(mut cache) = self.search_cache.lock() {
+            cache.retain(|_, (fetched, _)| fetched.elapsed() < ttl);
+            cache.insert(key, (Instant::now(), response.clone()));
+        }
+        Ok(response)
+    }
+}
+
+/// Normalize a search query for use in a cache key
+fn normalize_query(query: &str) -> String {
+    query
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Debounces searches from a live search box
+///
+/// Call [`SearchDebouncer::search`] on every keystroke, typically from a spawned task. Each
+/// call waits for the quiet period and only then sends its request; a newer call supersedes
+/// it, whether it is still waiting or already in flight, and the superseded call returns
+/// `None` without a result.
+///
+/// ```rust,no_run
+/// # async fn example(client: anytype_rs::AnytypeClient) {
+/// use anytype_rs::api::{SearchDebouncer, SearchRequest};
+/// use std::{sync::Arc, time::Duration};
+///
+/// let debouncer = Arc::new(SearchDebouncer::new(Arc::new(client), Duration::from_millis(250)));
+/// for query in ["a", "an", "any"] {
+///     let debouncer = debouncer.clone();
+///     tokio::spawn(async move {
+///         let request = SearchRequest {
+///             query: Some(query.to_string()),
+///             offset: None,
+///             limit: None,
+///             space_id: None,
+///             sort: None,
+///             include_archived: false,
+///         };
+///         if let Some(Ok(response)) = debouncer.search(request).await {
+///             println!("{query}: {} results", response.data.len());
+///         }
+///     });
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct SearchDebouncer {
+    client: Arc<AnytypeClient>,
+    quiet_period: Duration,
+    /// Number of the latest call to `search`
+    latest: watch::Sender<u64>,
+}
+
+impl SearchDebouncer {
+    /// Create a debouncer that waits `quiet_period` after the last query before searching
+    pub fn new(client: Arc<AnytypeClient>, quiet_period: Duration) -> Self {
+        Self {
+            client,
+            quiet_period,
+            latest: watch::Sender::new(0),
+        }
+    }
+
+    /// Search once no newer query has arrived for the quiet period
+    ///
+    /// Returns `None` if a newer call superseded this one; its request is then either never
+    /// sent or dropped while in flight.
+    pub async fn search(&self, request: SearchRequest) -> Option<Result<SearchResponse>> {
+        let mut generation = 0;
+        self.latest.send_modify(|latest| {
+            *latest += 1;
+            generation = *latest;
+        });
+        let mut changes = self.latest.subscribe();
+
+        tokio::select! {
+            _ = changes.wait_for(|&latest| latest != generation) => {
+                debug!(target: LOG_TARGET, "Search {} superseded", generation);
+                None
+            }
+            result = async {
+                tokio::time::sleep(self.quiet_period).await;
+                self.client.search(request).await
+            } => Some(result),
+        }
+    }
 }
@@ -3,43 +3,70 @@
 //! Handles space management operations.
 
 use super::AnytypeClient;
-use crate::{error::Result, types::Pagination};
+use crate::{
+    error::Result,
+    types::{ObjectKind, Pagination},
+};
 use serde::{Deserialize, Serialize};
 
+/// Path under which a space gateway serves objects
+const GATEWAY_FILE_PATH: &str = "file";
+
 /// Space information
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Space {
     pub id: String,
     pub name: String,
-    pub object: Option<String>, // "space"
+    pub object: Option<ObjectKind>, // "space"
     pub description: Option<String>,
     pub icon: Option<serde_json::Value>,
     pub gateway_url: Option<String>,
     pub network_id: Option<String>,
 }
 
+impl Space {
+    /// The URL of an object on the space's gateway, or `None` if the space has no gateway
+    ///
+    /// The gateway serves the content of file and image objects; other objects have no page
+    /// there.
+    pub fn gateway_object_url(&self, object_id: &str) -> Option<String> {
+        let gateway = self.gateway_url.as_deref()?.trim_end_matches('/');
+        (!gateway.is_empty()).then(|| format!("{gateway}/{GATEWAY_FILE_PATH}/{object_id}"))
+    }
+}
+
 /// Response for listing spaces
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct ListSpacesResponse {
     pub data: Vec<Space>,
     pub pagination: Pagination,
 }
 
+/// Order in which [`AnytypeClient::list_spaces_sorted`] returns spaces
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SpaceSort {
+    /// Keep the order the server returned
+    #[default]
+    Server,
+    /// Sort by name, case-insensitively
+    Name,
+}
+
 /// Request to create a new space
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateSpaceRequest {
     pub name: String,
     pub description: Option<String>,
 }
 
 /// Response when creating a space
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct CreateSpaceResponse {
     pub space: Space,
 }
 
 /// Request to update an existing space
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateSpaceRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
@@ -48,7 +75,7 @@ pub struct UpdateSpaceRequest {
 }
 
 /// Response when updating a space
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct UpdateSpaceResponse {
     pub space: Space,
 }
@@ -84,4 +111,18 @@ impl AnytypeClient {
     pub async fn list_spaces_with_pagination(&self) -> Result<ListSpacesResponse> {
         self.get("/v1/spaces").await
     }
+
+    /// List spaces in the given order, keeping the pagination information
+    ///
+    /// `pagination.total` is the number of spaces available to the user, which can be larger
+    /// than `data` when the server pages the listing.
+    pub async fn list_spaces_sorted(&self, sort: SpaceSort) -> Result<ListSpacesResponse> {
+        let mut response = self.list_spaces_with_pagination().await?;
+        if sort == SpaceSort::Name {
+            response
+                .data
+                .sort_by_cached_key(|space| space.name.to_lowercase());
+        }
+        Ok(response)
+    }
 }
@@ -2,71 +2,81 @@
 //!
 //! Handles tag management operations for properties.
 
-use super::AnytypeClient;
+use super::{AnytypeClient, types::TypeProperty};
 use crate::{
     error::Result,
-    types::{Color, Pagination},
+    types::{Color, ObjectKind, Pagination},
 };
+use futures_util::future::join_all;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info};
 
+const LOG_TARGET: &str = "anytype_rs::client::tags";
+
+/// Property formats whose values are tags
+const TAG_FORMATS: [&str; 2] = ["select", "multi_select"];
+
 /// Tag information
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize)]
 pub struct Tag {
     pub color: Option<Color>,
     pub id: String,
     pub key: String,
     pub name: String,
-    pub object: String,
+    #[serde(default)]
+    pub object: ObjectKind,
 }
 
 /// Response for listing tags
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct ListTagsResponse {
     pub data: Vec<Tag>,
     pub pagination: Pagination,
 }
 
 /// Request for creating a tag
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateTagRequest {
     pub name: String,
     pub color: Option<Color>,
 }
 
 /// Response for creating a tag
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct CreateTagResponse {
     pub tag: Tag,
 }
 
 /// Response for getting a tag
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct GetTagResponse {
     pub tag: Tag,
 }
 
 /// Request for updating a tag
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateTagRequest {
     pub name: Option<String>,
     pub color: Option<Color>,
 }
 
 /// Response for updating a tag
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct UpdateTagResponse {
     pub tag: Tag,
 }
 
 /// Response for deleting a tag
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct DeleteTagResponse {
     pub tag: Tag,
 }
 
 impl AnytypeClient {
     /// List all tags for a given property
+    ///
+    /// Tags are returned in the order the server lists them, which is the option order
+    /// shown in the Anytype UI. The API has no endpoint for changing that order.
     pub async fn list_tags(&self, space_id: &str, property_id: &str) -> Result<Vec<Tag>> {
         let response: ListTagsResponse = self
             .get(&format!(
@@ -83,10 +93,12 @@ impl AnytypeClient {
         property_id: &str,
     ) -> Result<ListTagsResponse> {
         info!(
+            target: LOG_TARGET,
             "Listing tags for property: {} in space: {}",
             property_id, space_id
         );
         debug!(
+            target: LOG_TARGET,
             "GET /v1/spaces/{}/properties/{}/tags",
             space_id, property_id
         );
@@ -105,11 +117,12 @@ impl AnytypeClient {
         request: CreateTagRequest,
     ) -> Result<CreateTagResponse> {
         info!(
+            target: LOG_TARGET,
             "Creating tag '{}' for property '{}' in space: {}",
             request.name, property_id, space_id
         );
-        debug!("Request: {:?}", request);
-        debug!("Request JSON: {}", serde_json::to_string_pretty(&request)?);
+        debug!(target: LOG_TARGET, "Request: {:?}", request);
+        debug!(target: LOG_TARGET, "Request JSON: {}", serde_json::to_string_pretty(&request)?);
 
         self.post(
             &format!("/v1/spaces/{space_id}/properties/{property_id}/tags"),
@@ -121,10 +134,12 @@ impl AnytypeClient {
     /// Get a specific tag by ID for a property in a space
     pub async fn get_tag(&self, space_id: &str, property_id: &str, tag_id: &str) -> Result<Tag> {
         info!(
+            target: LOG_TARGET,
             "Getting tag '{}' for property '{}' in space: {}",
             tag_id, property_id, space_id
         );
         debug!(
+            target: LOG_TARGET,
             "GET /v1/spaces/{}/properties/{}/tags/{}",
             space_id, property_id, tag_id
         );
@@ -146,11 +161,12 @@ impl AnytypeClient {
         request: UpdateTagRequest,
     ) -> Result<UpdateTagResponse> {
         info!(
+            target: LOG_TARGET,
             "Updating tag '{}' for property '{}' in space: {}",
             tag_id, property_id, space_id
         );
-        debug!("Request: {:?}", request);
-        debug!("Request JSON: {}", serde_json::to_string_pretty(&request)?);
+        debug!(target: LOG_TARGET, "Request: {:?}", request);
+        debug!(target: LOG_TARGET, "Request JSON: {}", serde_json::to_string_pretty(&request)?);
 
         self.patch(
             &format!("/v1/spaces/{space_id}/properties/{property_id}/tags/{tag_id}"),
@@ -162,10 +178,12 @@ impl AnytypeClient {
     /// Delete a tag by marking it as archived
     pub async fn delete_tag(&self, space_id: &str, property_id: &str, tag_id: &str) -> Result<Tag> {
         info!(
+            target: LOG_TARGET,
             "Deleting tag '{}' for property '{}' in space: {}",
             tag_id, property_id, space_id
         );
         debug!(
+            target: LOG_TARGET,
             "DELETE /v1/spaces/{}/properties/{}/tags/{}",
             space_id, property_id, tag_id
         );
@@ -177,4 +195,36 @@ impl AnytypeClient {
             .await?;
         Ok(response.tag)
     }
+
+    /// List the tags of every select and multi-select property of a type
+    ///
+    /// Returns one entry per tag property, in the type's property order. Tags are fetched
+    /// concurrently, at most [`ClientConfig::max_concurrency`](super::ClientConfig) at a time.
+    pub async fn list_type_tags(
+        &self,
+        space_id: &str,
+        type_id: &str,
+    ) -> Result<Vec<(TypeProperty, Vec<Tag>)>> {
+        info!(target: LOG_TARGET, "Listing tags of type '{}' in space: {}", type_id, space_id);
+
+        let type_data = self.get_type(space_id, type_id).await?;
+        let properties: Vec<TypeProperty> = type_data
+            .properties
+            .into_iter()
+            .filter(|property| TAG_FORMATS.contains(&property.format.as_str()))
+            .collect();
+
+        let tags = join_all(
+            properties
+                .iter()
+                .map(|property| self.limited(self.list_tags(space_id, &property.id))),
+        )
+        .await;
+
+        properties
+            .into_iter()
+            .zip(tags)
+            .map(|(property, tags)| Ok((property, tags?)))
+            .collect()
+    }
 }
@@ -2,13 +2,23 @@
 //!
 //! Handles template management operations.
 
-use super::AnytypeClient;
-use crate::{api::types::Icon, error::Result, types::Pagination};
+use super::{
+    AnytypeClient,
+    objects::{CreateObjectRequest, CreateObjectResponse},
+};
+use crate::{
+    api::types::Icon,
+    error::{AnytypeError, Result},
+    types::{ObjectKind, Pagination},
+};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use tracing::{debug, info};
 
+const LOG_TARGET: &str = "anytype_rs::client::templates";
+
 /// Object type information
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct ObjectType {
     pub archived: Option<bool>,
     pub icon: Icon,
@@ -16,13 +26,13 @@ pub struct ObjectType {
     pub key: String,
     pub layout: Option<String>,
     pub name: String,
-    pub object: String,
+    pub object: ObjectKind,
     pub plural_name: Option<String>,
     pub properties: Vec<serde_json::Value>, // Simplified for now
 }
 
 /// Template information
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Template {
     pub archived: Option<bool>,
     pub icon: Icon,
@@ -30,7 +40,9 @@ pub struct Template {
     pub layout: Option<String>,
     pub markdown: Option<String>,
     pub name: Option<String>,
-    pub object: String,
+    #[serde(default)]
+    pub object: ObjectKind,
+    #[serde(default)]
     pub properties: Vec<serde_json::Value>, // Simplified for now
     pub snippet: Option<String>,
     pub space_id: String,
@@ -39,18 +51,91 @@ pub struct Template {
 }
 
 /// Response for getting a single template
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct GetTemplateResponse {
     pub template: Template,
 }
 
 /// Response for listing templates
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct ListTemplatesResponse {
     pub data: Vec<Template>,
     pub pagination: Pagination,
 }
 
+/// Values that replace those of a template when creating an object from it
+#[derive(Debug, Default, Clone)]
+pub struct TemplateOverrides {
+    /// Name of the new object; the template's name is never copied
+    pub name: Option<String>,
+    /// Markdown body used instead of the template's
+    pub body: Option<String>,
+    /// Property values as `{"key": ..., "<format>": value}` entries; each replaces the
+    /// template's value for the same key
+    pub properties: Vec<Value>,
+}
+
+impl Template {
+    /// The template's property values in the form accepted when creating an object
+    ///
+    /// Select and multi-select values are reduced to tag IDs. Properties without a value
+    /// are left out.
+    pub fn property_values(&self) -> Vec<Value> {
+        self.properties
+            .iter()
+            .filter_map(|property| {
+                let key = property.get("key")?.as_str()?;
+                let format = property.get("format")?.as_str()?;
+                let value = match (format, property.get(format)?) {
+                    (_, Value::Null) => return None,
+                    ("select", tag) => tag.get("id").cloned().unwrap_or_else(|| tag.clone()),
+                    ("multi_select", Value::Array(tags)) => Value::Array(
+                        tags.iter()
+                            .map(|tag| tag.get("id").cloned().unwrap_or_else(|| tag.clone()))
+                            .collect(),
+                    ),
+                    (_, value) => value.clone(),
+                };
+                let mut entry = serde_json::Map::new();
+                entry.insert("key".to_string(), key.into());
+                entry.insert(format.to_string(), value);
+                Some(entry.into())
+            })
+            .collect()
+    }
+
+    /// Build the request that creates an object from this template
+    ///
+    /// Used by [`AnytypeClient::create_object_from_template`]; exposed so callers can show the
+    /// request before sending it.
+    pub fn object_request(self, overrides: TemplateOverrides) -> Result<CreateObjectRequest> {
+        let type_key = self
+            .object_type
+            .as_ref()
+            .map(|object_type| object_type.key.clone())
+            .ok_or_else(|| AnytypeError::InvalidResponse {
+                message: format!("Template '{}' has no type", self.id),
+            })?;
+
+        let mut properties = self.property_values();
+        for property in overrides.properties {
+            properties.retain(|existing| property_key(existing) != property_key(&property));
+            properties.push(property);
+        }
+
+        Ok(CreateObjectRequest {
+            name: overrides.name,
+            body: overrides.body.or(self.markdown),
+            ..CreateObjectRequest::new(type_key).properties(properties)
+        })
+    }
+}
+
+/// Key of a `{"key": ..., "<format>": value}` property entry
+fn property_key(property: &Value) -> Option<&str> {
+    property.get("key").and_then(|key| key.as_str())
+}
+
 impl AnytypeClient {
     /// List templates in a space for a specific type
     pub async fn list_templates(&self, space_id: &str, type_id: &str) -> Result<Vec<Template>> {
@@ -67,10 +152,11 @@ impl AnytypeClient {
         type_id: &str,
     ) -> Result<ListTemplatesResponse> {
         info!(
+            target: LOG_TARGET,
             "Listing templates for type: {} in space: {}",
             type_id, space_id
         );
-        debug!("GET /v1/spaces/{}/types/{}/templates", space_id, type_id);
+        debug!(target: LOG_TARGET, "GET /v1/spaces/{}/types/{}/templates", space_id, type_id);
 
         self.get(&format!("/v1/spaces/{space_id}/types/{type_id}/templates"))
             .await
@@ -84,10 +170,12 @@ impl AnytypeClient {
         template_id: &str,
     ) -> Result<Template> {
         info!(
+            target: LOG_TARGET,
             "Getting template: {} for type: {} in space: {}",
             template_id, type_id, space_id
         );
         debug!(
+            target: LOG_TARGET,
             "GET /v1/spaces/{}/types/{}/templates/{}",
             space_id, type_id, template_id
         );
@@ -100,4 +188,21 @@ impl AnytypeClient {
 
         Ok(response.template)
     }
+
+    /// Create an object from a template in a single request
+    ///
+    /// The template's type, markdown and property values are the starting point; `overrides`
+    /// then sets the name and replaces the body or individual properties. Templates are
+    /// looked up per type, so the ID of the template's type is needed as well.
+    pub async fn create_object_from_template(
+        &self,
+        space_id: &str,
+        type_id: &str,
+        template_id: &str,
+        overrides: TemplateOverrides,
+    ) -> Result<CreateObjectResponse> {
+        let template = self.get_template(space_id, type_id, template_id).await?;
+        let request = template.object_request(overrides)?;
+        self.create_object(space_id, request).await
+    }
 }
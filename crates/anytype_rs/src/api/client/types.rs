@@ -2,13 +2,46 @@
 //!
 //! Handles type management operations such as creating, updating, and deleting object types.
 
-use super::AnytypeClient;
-use crate::{api::types::Icon, error::Result, types::Pagination};
+use super::{AnytypeClient, snake_case_key, spaces::Space, validate_key};
+use crate::{
+    api::types::Icon,
+    error::{AnytypeError, Result},
+    types::{ObjectKind, Pagination},
+};
+use futures_util::future::join_all;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info};
 
+const LOG_TARGET: &str = "anytype_rs::client::types";
+
+/// Icon given to types created with [`CreateTypeRequest::new`]
+const DEFAULT_TYPE_EMOJI: &str = "📄";
+
+/// Keys of the types Anytype creates in every space
+const SYSTEM_TYPE_KEYS: [&str; 19] = [
+    "audio",
+    "bookmark",
+    "chat",
+    "collection",
+    "dashboard",
+    "date",
+    "file",
+    "image",
+    "note",
+    "page",
+    "participant",
+    "profile",
+    "project",
+    "set",
+    "space",
+    "tag",
+    "task",
+    "template",
+    "video",
+];
+
 /// Property format for type creation
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "snake_case")]
 pub enum PropertyFormat {
     Text,
@@ -24,8 +57,27 @@ pub enum PropertyFormat {
     Objects,
 }
 
+impl PropertyFormat {
+    /// The format's name in the API, such as `"multi_select"`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::Number => "number",
+            Self::Select => "select",
+            Self::MultiSelect => "multi_select",
+            Self::Date => "date",
+            Self::Files => "files",
+            Self::Checkbox => "checkbox",
+            Self::Url => "url",
+            Self::Email => "email",
+            Self::Phone => "phone",
+            Self::Objects => "objects",
+        }
+    }
+}
+
 /// Layout type for object types
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Layout {
     Basic,
@@ -39,7 +91,7 @@ pub enum Layout {
 }
 
 /// Property for type creation
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CreateTypeProperty {
     pub format: PropertyFormat,
     pub key: String,
@@ -47,7 +99,7 @@ pub struct CreateTypeProperty {
 }
 
 /// Request to create a new type
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CreateTypeRequest {
     pub icon: Icon,
     pub key: String,
@@ -57,32 +109,150 @@ pub struct CreateTypeRequest {
     pub properties: Vec<CreateTypeProperty>,
 }
 
+impl CreateTypeRequest {
+    /// Start a request for a type with the `basic` layout, a 📄 icon, no properties and the
+    /// name doubling as the plural name
+    pub fn new(key: impl Into<String>, name: impl Into<String>) -> Self {
+        let name = name.into();
+        Self {
+            icon: Icon::Emoji {
+                emoji: DEFAULT_TYPE_EMOJI.to_string(),
+            },
+            key: key.into(),
+            layout: Layout::Basic,
+            plural_name: name.clone(),
+            name,
+            properties: Vec::new(),
+        }
+    }
+
+    /// Set the plural name of the type
+    pub fn plural_name(mut self, plural_name: impl Into<String>) -> Self {
+        self.plural_name = plural_name.into();
+        self
+    }
+
+    /// Set the layout of objects of the type
+    pub fn layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Set the icon of the type
+    pub fn icon(mut self, icon: Icon) -> Self {
+        self.icon = icon;
+        self
+    }
+
+    /// Add a property to the type
+    pub fn property(
+        mut self,
+        key: impl Into<String>,
+        name: impl Into<String>,
+        format: PropertyFormat,
+    ) -> Self {
+        self.properties.push(CreateTypeProperty {
+            format,
+            key: key.into(),
+            name: name.into(),
+        });
+        self
+    }
+
+    /// Build a request that recreates an existing type, e.g. from an exported schema
+    ///
+    /// The layout and property formats are parsed from their string form. A missing
+    /// layout defaults to `basic` and a missing plural name falls back to the type name.
+    pub fn from_type(type_data: &Type) -> Result<Self> {
+        let layout = serde_json::from_value(serde_json::Value::String(
+            type_data.layout.as_deref().unwrap_or("basic").to_string(),
+        ))?;
+
+        let properties = type_data
+            .properties
+            .iter()
+            .map(|prop| {
+                let format =
+                    serde_json::from_value(serde_json::Value::String(prop.format.clone()))?;
+                Ok(CreateTypeProperty {
+                    format,
+                    key: prop.key.clone(),
+                    name: prop.name.clone(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            icon: type_data.icon.clone(),
+            key: type_data.key.clone(),
+            layout,
+            name: type_data.name.clone(),
+            plural_name: type_data
+                .plural_name
+                .clone()
+                .unwrap_or_else(|| type_data.name.clone()),
+            properties,
+        })
+    }
+
+    /// Build a request that recreates a type read from a `type export` file
+    ///
+    /// Like [`Self::from_type`], but keys the server assigned that [`validate_type_key`]
+    /// or [`validate_property_key`](super::properties::validate_property_key) would reject,
+    /// such as `ot-page`, are rewritten to snake_case (`ot_page`) so the request can be sent.
+    pub fn from_exported_type(type_data: &Type) -> Result<Self> {
+        let mut request = Self::from_type(type_data)?;
+        if validate_type_key(&request.key).is_err() {
+            let key = snake_case_key(&request.key);
+            debug!(
+                target: LOG_TARGET,
+                "Rewriting exported type key '{}' to '{}'",
+                request.key, key
+            );
+            request.key = key;
+        }
+        for property in &mut request.properties {
+            if super::properties::validate_property_key(&property.key).is_err() {
+                let key = snake_case_key(&property.key);
+                debug!(
+                    target: LOG_TARGET,
+                    "Rewriting exported property key '{}' to '{}'",
+                    property.key, key
+                );
+                property.key = key;
+            }
+        }
+        Ok(request)
+    }
+}
+
 /// Response when creating a type
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct CreateTypeResponse {
     #[serde(rename = "type")]
     pub type_data: Type,
 }
 
 /// Response when getting a type
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct GetTypeResponse {
     #[serde(rename = "type")]
     pub type_data: Type,
 }
 
 /// Property information for types
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct TypeProperty {
     pub format: String,
     pub id: String,
     pub key: String,
     pub name: String,
-    pub object: String,
+    #[serde(default)]
+    pub object: ObjectKind,
 }
 
 /// Type information
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Type {
     pub archived: Option<bool>,
     pub icon: Icon,
@@ -90,20 +260,36 @@ pub struct Type {
     pub key: String,
     pub layout: Option<String>,
     pub name: String,
-    pub object: String,
+    #[serde(default)]
+    pub object: ObjectKind,
     pub plural_name: Option<String>,
+    #[serde(default)]
     pub properties: Vec<TypeProperty>,
 }
 
+impl Type {
+    /// Whether this is one of Anytype's built-in types rather than one a user created
+    ///
+    /// The API doesn't say, so this goes by key: built-in types have the bundled keys
+    /// (`page`, `note`, `task`, ...), sometimes with an `ot-` or `ot_` prefix.
+    pub fn is_system(&self) -> bool {
+        let key = ["ot-", "ot_"]
+            .iter()
+            .find_map(|prefix| self.key.strip_prefix(prefix))
+            .unwrap_or(&self.key);
+        SYSTEM_TYPE_KEYS.contains(&key)
+    }
+}
+
 /// Response for listing types
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct ListTypesResponse {
     pub data: Vec<Type>,
     pub pagination: Pagination,
 }
 
 /// Request to update an existing type
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UpdateTypeRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub icon: Option<Icon>,
@@ -120,19 +306,45 @@ pub struct UpdateTypeRequest {
 }
 
 /// Response when updating a type
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct UpdateTypeResponse {
     #[serde(rename = "type")]
     pub type_data: Type,
 }
 
+/// Result of adding properties to a type with [`AnytypeClient::add_type_properties`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct AddTypePropertiesResponse {
+    /// The type after the update (unchanged if nothing was added)
+    pub type_data: Type,
+    /// Keys of the properties that were added
+    pub added: Vec<String>,
+    /// Keys of the requested properties the type already had
+    pub existing: Vec<String>,
+}
+
 /// Response when deleting a type
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct DeleteTypeResponse {
     #[serde(rename = "type")]
     pub type_data: Type,
 }
 
+/// Validate a type key before sending it to the API
+///
+/// Type keys must be snake_case (e.g. `ot_task`, `book_review`): a lowercase ASCII letter
+/// followed by lowercase letters, digits or underscores. Returns the reason the key was
+/// rejected.
+pub fn validate_type_key(key: &str) -> std::result::Result<(), String> {
+    validate_key(key)
+}
+
+fn type_key_not_found(space_id: &str, type_key: &str) -> AnytypeError {
+    AnytypeError::NotFound {
+        message: format!("No Type found with key '{type_key}' in space '{space_id}'"),
+    }
+}
+
 impl AnytypeClient {
     /// List types in a space
     pub async fn list_types(&self, space_id: &str) -> Result<Vec<Type>> {
@@ -140,10 +352,30 @@ impl AnytypeClient {
         Ok(response.data)
     }
 
+    /// List the types of every space, for an overview across spaces
+    ///
+    /// Returns each space with its types, in the order of [`AnytypeClient::list_spaces`].
+    /// Types are fetched concurrently, at most
+    /// [`ClientConfig::max_concurrency`](super::ClientConfig) spaces at a time. A space whose
+    /// types can't be listed gets its error, without failing the other spaces.
+    pub async fn list_types_in_all_spaces(&self) -> Result<Vec<(Space, Result<Vec<Type>>)>> {
+        info!(target: LOG_TARGET, "Listing types in all spaces");
+
+        let spaces = self.list_spaces().await?;
+        let types = join_all(
+            spaces
+                .iter()
+                .map(|space| self.limited(self.list_types(&space.id))),
+        )
+        .await;
+
+        Ok(spaces.into_iter().zip(types).collect())
+    }
+
     /// List types in a space with pagination information
     pub async fn list_types_with_pagination(&self, space_id: &str) -> Result<ListTypesResponse> {
-        info!("Listing types in space: {}", space_id);
-        debug!("GET /v1/spaces/{}/types", space_id);
+        info!(target: LOG_TARGET, "Listing types in space: {}", space_id);
+        debug!(target: LOG_TARGET, "GET /v1/spaces/{}/types", space_id);
 
         self.get(&format!("/v1/spaces/{space_id}/types")).await
     }
@@ -154,18 +386,31 @@ impl AnytypeClient {
         space_id: &str,
         request: CreateTypeRequest,
     ) -> Result<CreateTypeResponse> {
-        info!("Creating type '{}' in space: {}", request.name, space_id);
-        debug!("Request: {:?}", request);
-        debug!("Request JSON: {}", serde_json::to_string_pretty(&request)?);
+        validate_type_key(&request.key).map_err(|reason| AnytypeError::Config {
+            message: format!("Invalid type key '{}': {reason}", request.key),
+        })?;
+        for property in &request.properties {
+            super::properties::validate_property_key(&property.key).map_err(|reason| {
+                AnytypeError::Config {
+                    message: format!("Invalid property key '{}': {reason}", property.key),
+                }
+            })?;
+        }
 
+        info!(target: LOG_TARGET, "Creating type '{}' in space: {}", request.name, space_id);
+        debug!(target: LOG_TARGET, "Request: {:?}", request);
+        debug!(target: LOG_TARGET, "Request JSON: {}", serde_json::to_string_pretty(&request)?);
+
+        // A type that had this key before may have been deleted and replaced
+        self.evict_type_ids(space_id, |key, _| key == request.key);
         self.post(&format!("/v1/spaces/{space_id}/types"), &request)
             .await
     }
 
     /// Get a specific type by ID
     pub async fn get_type(&self, space_id: &str, type_id: &str) -> Result<Type> {
-        info!("Getting type '{}' from space: {}", type_id, space_id);
-        debug!("GET /v1/spaces/{}/types/{}", space_id, type_id);
+        info!(target: LOG_TARGET, "Getting type '{}' from space: {}", type_id, space_id);
+        debug!(target: LOG_TARGET, "GET /v1/spaces/{}/types/{}", space_id, type_id);
 
         let response: GetTypeResponse = self
             .get(&format!("/v1/spaces/{space_id}/types/{type_id}"))
@@ -173,6 +418,67 @@ impl AnytypeClient {
         Ok(response.type_data)
     }
 
+    /// Get a type by its key (e.g. `page` or `task`) rather than its space-specific ID
+    ///
+    /// The API only looks types up by ID, so the first lookup in a space lists its types
+    /// and caches the ID of every key. Later lookups in that space fetch the type by ID.
+    /// Creating, updating or deleting a type through this client drops its cached ID.
+    pub async fn get_type_by_key(&self, space_id: &str, type_key: &str) -> Result<Type> {
+        if let Some(type_id) = self.cached_type_id(space_id, type_key) {
+            return self.get_type(space_id, &type_id).await;
+        }
+
+        let types = self.list_types(space_id).await?;
+        self.cache_type_ids(space_id, &types);
+        types
+            .into_iter()
+            .find(|t| t.key == type_key)
+            .ok_or_else(|| type_key_not_found(space_id, type_key))
+    }
+
+    /// Get the space-specific ID of the type with the given key
+    ///
+    /// Uses the same cache as [`get_type_by_key`](Self::get_type_by_key), so repeated
+    /// lookups in a space need a single request.
+    pub async fn type_id_for_key(&self, space_id: &str, type_key: &str) -> Result<String> {
+        if let Some(type_id) = self.cached_type_id(space_id, type_key) {
+            return Ok(type_id);
+        }
+
+        let types = self.list_types(space_id).await?;
+        self.cache_type_ids(space_id, &types);
+        types
+            .into_iter()
+            .find(|t| t.key == type_key)
+            .map(|t| t.id)
+            .ok_or_else(|| type_key_not_found(space_id, type_key))
+    }
+
+    fn cached_type_id(&self, space_id: &str, type_key: &str) -> Option<String> {
+        let cache = self.type_ids_by_key.lock().ok()?;
+        cache
+            .get(&(space_id.to_string(), type_key.to_string()))
+            .cloned()
+    }
+
+    /// Drop the cached IDs in a space for which `evict(key, id)` is true
+    fn evict_type_ids(&self, space_id: &str, evict: impl Fn(&str, &str) -> bool) {
+        if let Ok(mut cache) = self.type_ids_by_key.lock() {
+            cache.retain(|(space, key), id| space != space_id || !evict(key, id));
+        }
+    }
+
+    fn cache_type_ids(&self, space_id: &str, types: &[Type]) {
+        if let Ok(mut cache) = self.type_ids_by_key.lock() {
+            for type_data in types {
+                cache.insert(
+                    (space_id.to_string(), type_data.key.clone()),
+                    type_data.id.clone(),
+                );
+            }
+        }
+    }
+
     /// Update an existing type in a space
     pub async fn update_type(
         &self,
@@ -180,22 +486,144 @@ impl AnytypeClient {
         type_id: &str,
         request: UpdateTypeRequest,
     ) -> Result<UpdateTypeResponse> {
-        info!("Updating type '{}' in space: {}", type_id, space_id);
-        debug!("Request: {:?}", request);
-        debug!("Request JSON: {}", serde_json::to_string_pretty(&request)?);
+        info!(target: LOG_TARGET, "Updating type '{}' in space: {}", type_id, space_id);
+        debug!(target: LOG_TARGET, "Request: {:?}", request);
+        debug!(target: LOG_TARGET, "Request JSON: {}", serde_json::to_string_pretty(&request)?);
 
+        // The update may change the key
+        self.evict_type_ids(space_id, |_, id| id == type_id);
         self.patch(&format!("/v1/spaces/{space_id}/types/{type_id}"), &request)
             .await
     }
 
+    /// Add properties to a type, keeping the properties it already has
+    ///
+    /// `update_type` replaces the whole property list, so the type is fetched first and the
+    /// new properties appended to its current ones. Properties whose key the type already has
+    /// are skipped and reported in [`AddTypePropertiesResponse::existing`]; if there is nothing
+    /// to add, no update is sent.
+    pub async fn add_type_properties(
+        &self,
+        space_id: &str,
+        type_id: &str,
+        properties: Vec<CreateTypeProperty>,
+    ) -> Result<AddTypePropertiesResponse> {
+        for property in &properties {
+            super::properties::validate_property_key(&property.key).map_err(|reason| {
+                AnytypeError::Config {
+                    message: format!("Invalid property key '{}': {reason}", property.key),
+                }
+            })?;
+        }
+
+        info!(
+            target: LOG_TARGET,
+            "Adding {} properties to type '{}' in space: {}",
+            properties.len(),
+            type_id,
+            space_id
+        );
+
+        let type_data = self.get_type(space_id, type_id).await?;
+        let (existing, new): (Vec<_>, Vec<_>) = properties.into_iter().partition(|property| {
+            type_data
+                .properties
+                .iter()
+                .any(|current| current.key == property.key)
+        });
+        let existing: Vec<String> = existing.into_iter().map(|p| p.key).collect();
+
+        if new.is_empty() {
+            debug!(target: LOG_TARGET, "Type '{}' already has every requested property", type_id);
+            return Ok(AddTypePropertiesResponse {
+                type_data,
+                added: Vec::new(),
+                existing,
+            });
+        }
+
+        let added: Vec<String> = new.iter().map(|p| p.key.clone()).collect();
+        let mut merged = CreateTypeRequest::from_type(&type_data)?.properties;
+        merged.extend(new);
+
+        let response = self
+            .update_type(
+                space_id,
+                type_id,
+                UpdateTypeRequest {
+                    icon: None,
+                    key: None,
+                    layout: None,
+                    name: None,
+                    plural_name: None,
+                    properties: Some(merged),
+                },
+            )
+            .await?;
+
+        Ok(AddTypePropertiesResponse {
+            type_data: response.type_data,
+            added,
+            existing,
+        })
+    }
+
+    /// Rename a type, keeping everything else about it
+    ///
+    /// Only the name is changed. The type is fetched first and its current properties are
+    /// sent back with the new name, so the rename cannot drop them. A blank name is rejected
+    /// with [`AnytypeError::Config`]; renaming to the current name sends no update.
+    pub async fn rename_type(
+        &self,
+        space_id: &str,
+        type_id: &str,
+        name: &str,
+    ) -> Result<UpdateTypeResponse> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(AnytypeError::Config {
+                message: "Type name must not be empty".to_string(),
+            });
+        }
+
+        info!(
+            target: LOG_TARGET,
+            "Renaming type '{}' in space {} to '{}'",
+            type_id, space_id, name
+        );
+
+        let type_data = self.get_type(space_id, type_id).await?;
+        if type_data.name == name {
+            debug!(target: LOG_TARGET, "Type '{}' is already named '{}'", type_id, name);
+            return Ok(UpdateTypeResponse { type_data });
+        }
+
+        let properties = CreateTypeRequest::from_type(&type_data)?.properties;
+        self.update_type(
+            space_id,
+            type_id,
+            UpdateTypeRequest {
+                icon: None,
+                key: None,
+                layout: None,
+                name: Some(name.to_string()),
+                plural_name: None,
+                properties: Some(properties),
+            },
+        )
+        .await
+    }
+
     /// Delete (archive) a type in a space
     pub async fn delete_type(&self, space_id: &str, type_id: &str) -> Result<DeleteTypeResponse> {
         info!(
+            target: LOG_TARGET,
             "Deleting (archiving) type '{}' in space: {}",
             type_id, space_id
         );
-        debug!("DELETE /v1/spaces/{}/types/{}", space_id, type_id);
+        debug!(target: LOG_TARGET, "DELETE /v1/spaces/{}/types/{}", space_id, type_id);
 
+        self.evict_type_ids(space_id, |_, id| id == type_id);
         self.delete(&format!("/v1/spaces/{space_id}/types/{type_id}"))
             .await
     }
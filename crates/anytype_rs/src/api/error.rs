@@ -1,3 +1,4 @@
+use std::time::Duration;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -12,7 +13,14 @@ pub enum AnytypeError {
     Auth { message: String },
 
     #[error("API error: {message}")]
-    Api { message: String },
+    Api {
+        message: String,
+        /// HTTP status of the error response, or `None` for errors found by the client
+        status: Option<u16>,
+    },
+
+    #[error("{message}")]
+    NotFound { message: String },
 
     #[error("Serialization error: {source}")]
     Serialization {
@@ -22,6 +30,51 @@ pub enum AnytypeError {
 
     #[error("Invalid response: {message}")]
     InvalidResponse { message: String },
+
+    #[error("Configuration error: {message}")]
+    Config { message: String },
+
+    #[error("Request timed out after {}s", duration.as_secs())]
+    Timeout { duration: Duration },
+
+    #[error("Connection error: {message}")]
+    Connection { message: String },
+}
+
+impl AnytypeError {
+    /// Whether the error was caused by a request timing out
+    pub fn is_timeout(&self) -> bool {
+        match self {
+            Self::Timeout { .. } => true,
+            Self::Http { source } => source.is_timeout(),
+            _ => false,
+        }
+    }
+
+    /// A stable tag for the kind of error, for logs and metrics
+    ///
+    /// One of `"http"`, `"auth"`, `"api"`, `"not_found"`, `"serialization"`,
+    /// `"invalid_response"`, `"config"`, `"timeout"` or `"connection"`. HTTP errors caused by
+    /// a timeout or a refused connection are tagged like the matching variants, and API
+    /// errors with status 404 are `"not_found"` like [`NotFound`](Self::NotFound).
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Http { source } if source.is_timeout() => "timeout",
+            Self::Http { source } if source.is_connect() => "connection",
+            Self::Http { .. } => "http",
+            Self::Auth { .. } => "auth",
+            Self::Api {
+                status: Some(404), ..
+            } => "not_found",
+            Self::Api { .. } => "api",
+            Self::NotFound { .. } => "not_found",
+            Self::Serialization { .. } => "serialization",
+            Self::InvalidResponse { .. } => "invalid_response",
+            Self::Config { .. } => "config",
+            Self::Timeout { .. } => "timeout",
+            Self::Connection { .. } => "connection",
+        }
+    }
 }
 
 pub type Result<T> = std::result::Result<T, AnytypeError>;
@@ -11,51 +11,67 @@
 //! - Template, type, property, and tag management
 //! - Async/await support with tokio
 //! - Comprehensive error handling
+//!
+//! ## Response Compatibility
+//!
+//! Response structs tolerate changes in newer Anytype versions: unknown fields are ignored,
+//! and fields the client can do without (`object`, `properties`, `snippet`, ...) fall back to
+//! their defaults when missing, so only IDs, names and other essential fields are required.
 
 pub mod client;
 pub mod error;
 pub mod types;
 
-pub use client::{AnytypeClient, ClientConfig};
+pub use client::{
+    ANYTYPE_API_VERSION, AnytypeClient, BatchErrorPolicy, CachedResponse, ClientConfig,
+    snake_case_key,
+};
 pub use error::{AnytypeError, Result};
+pub use reqwest::Method;
 pub use types::*;
 
 // Re-export types from client modules for convenience
 pub use client::auth::{
     CreateApiKeyRequest, CreateApiKeyResponse, CreateChallengeRequest, CreateChallengeResponse,
+    PingStatus,
 };
+pub use client::changes::{Change, SyncToken};
 pub use client::lists::{
     AddListObjectsRequest, AddListObjectsResponse, GetListObjectsResponse, GetListViewsResponse,
     ListObject, ListObjectType, ListViewData, ListViewFilter, ListViewSort, ObjectTypeProperty,
     RemoveListObjectsResponse,
 };
 pub use client::members::{
-    GetMemberResponse, ListMembersResponse, Member, MemberRole, MemberStatus,
+    GetMemberResponse, ListMembersResponse, Member, MemberFilter, MemberRole, MemberStatus,
 };
 pub use client::objects::{
     CreateObjectRequest, CreateObjectResponse, DeleteObjectResponse, ListObjectsResponse, Object,
-    UpdateObjectRequest, UpdateObjectResponse,
+    ObjectDiff, PropertyChange, UpdateObjectRequest, UpdateObjectResponse,
 };
 pub use client::properties::{
     CreatePropertyRequest, CreatePropertyResponse, DeletePropertyResponse, GetPropertyResponse,
-    ListPropertiesResponse, Property, UpdatePropertyRequest, UpdatePropertyResponse,
+    ListPropertiesResponse, MigratedObject, Property, PropertyMigration, UpdatePropertyRequest,
+    UpdatePropertyResponse, validate_property_key,
 };
+pub use client::schema::{SchemaDocument, SchemaProperty, SchemaType};
 pub use client::search::{
-    SearchObject, SearchRequest, SearchResponse, SearchSpaceRequest, Sort, SortDirection,
-    SortProperty,
+    SearchDebouncer, SearchObject, SearchOrder, SearchRequest, SearchResponse, SearchSpaceRequest,
+    Sort, SortDirection, SortProperty,
 };
 pub use client::spaces::{
-    CreateSpaceRequest, CreateSpaceResponse, ListSpacesResponse, Space, UpdateSpaceRequest,
-    UpdateSpaceResponse,
+    CreateSpaceRequest, CreateSpaceResponse, ListSpacesResponse, Space, SpaceSort,
+    UpdateSpaceRequest, UpdateSpaceResponse,
 };
 pub use client::tags::{
     CreateTagRequest, CreateTagResponse, DeleteTagResponse, GetTagResponse, ListTagsResponse, Tag,
     UpdateTagRequest, UpdateTagResponse,
 };
-pub use client::templates::{GetTemplateResponse, ListTemplatesResponse, ObjectType, Template};
+pub use client::templates::{
+    GetTemplateResponse, ListTemplatesResponse, ObjectType, Template, TemplateOverrides,
+};
 pub use client::types::{
-    CreateTypeProperty, CreateTypeRequest, CreateTypeResponse, DeleteTypeResponse, GetTypeResponse,
-    Layout, ListTypesResponse, PropertyFormat, Type, TypeProperty, UpdateTypeRequest,
-    UpdateTypeResponse,
+    AddTypePropertiesResponse, CreateTypeProperty, CreateTypeRequest, CreateTypeResponse,
+    DeleteTypeResponse, GetTypeResponse, Layout, ListTypesResponse, PropertyFormat, Type,
+    TypeProperty, UpdateTypeRequest, UpdateTypeResponse, validate_type_key,
 };
 pub use types::{Icon, IconFormat};
@@ -16,26 +16,210 @@ pub enum Color {
     Lime,
 }
 
+impl Color {
+    /// Every color, in the order Anytype's color pickers show them
+    pub const ALL: [Color; 10] = [
+        Color::Grey,
+        Color::Yellow,
+        Color::Orange,
+        Color::Red,
+        Color::Pink,
+        Color::Purple,
+        Color::Blue,
+        Color::Ice,
+        Color::Teal,
+        Color::Lime,
+    ];
+
+    /// Every color, in the order Anytype's color pickers show them
+    pub fn all() -> &'static [Color] {
+        &Self::ALL
+    }
+
+    /// The color after this one in [`Color::all`], wrapping around after the last
+    pub fn next(&self) -> Color {
+        let index = Self::ALL
+            .iter()
+            .position(|color| color == self)
+            .unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()].clone()
+    }
+
+    /// Parse a color by name, ignoring case (`"Teal"`, `"teal"`)
+    pub fn from_name(name: &str) -> Option<Color> {
+        Self::ALL
+            .iter()
+            .find(|color| color.to_string().eq_ignore_ascii_case(name))
+            .cloned()
+    }
+
+    /// The lowercase names of every color, comma-separated, for help and error messages
+    pub fn names() -> String {
+        Self::ALL
+            .iter()
+            .map(|color| color.to_string().to_lowercase())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// RGB hex code (`#rrggbb`) close to the swatch the Anytype app shows for this color
+    pub fn to_hex(&self) -> &'static str {
+        match self {
+            Color::Grey => "#b6b6b6",
+            Color::Yellow => "#ecd91b",
+            Color::Orange => "#ffb522",
+            Color::Red => "#f55522",
+            Color::Pink => "#e51ca0",
+            Color::Purple => "#ab50cc",
+            Color::Blue => "#3e58eb",
+            Color::Ice => "#2aa7ee",
+            Color::Teal => "#0fc8ba",
+            Color::Lime => "#5dd400",
+        }
+    }
+}
+
+/// Defines [`ObjectKind`] from a single list of variants and the values they stand for,
+/// so the serde renames, [`ObjectKind::as_str`] and `From<&str>` cannot drift apart
+macro_rules! object_kinds {
+    ($($variant:ident => $value:literal),* $(,)?) => {
+        /// Kind of entity named by the `object` field of API responses
+        ///
+        /// Known kinds have their own variant so they can be matched on; anything else
+        /// is kept as-is in [`ObjectKind::Other`]. Objects name their type key here
+        /// (`"ot-page"`, `"page"`, ...), which is open-ended and so always lands in
+        /// `Other`.
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+        pub enum ObjectKind {
+            $(
+                #[serde(rename = $value)]
+                $variant,
+            )*
+            /// Any other value, kept verbatim
+            #[serde(untagged)]
+            Other(String),
+        }
+
+        impl ObjectKind {
+            /// The value as it appears in the API
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $value,)*
+                    Self::Other(value) => value,
+                }
+            }
+        }
+
+        impl From<&str> for ObjectKind {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($value => Self::$variant,)*
+                    other => Self::Other(other.to_string()),
+                }
+            }
+        }
+    };
+}
+
+object_kinds! {
+    Object => "object",
+    Space => "space",
+    Member => "member",
+    Type => "type",
+    Property => "property",
+    Tag => "tag",
+    Template => "template",
+    Error => "error",
+}
+
+/// Fields missing from a response are left empty, as they were as plain strings
+impl Default for ObjectKind {
+    fn default() -> Self {
+        Self::Other(String::new())
+    }
+}
+
+impl std::fmt::Display for ObjectKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Generic API error response
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct ApiErrorResponse {
     pub code: String,
     pub message: String,
-    pub object: String,
+    pub object: ObjectKind,
     pub status: u32,
 }
 
 /// Pagination information
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Pagination {
     pub has_more: bool,
     pub limit: usize,
     pub offset: usize,
     pub total: usize,
+    /// Opaque token for the next page, for API versions that page with cursors
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+impl Pagination {
+    /// Where the page after this one starts, or `None` if this is the last page
+    ///
+    /// `fetched` is the number of items received so far, counting this page. A cursor is
+    /// preferred when the response has one; otherwise the next page starts at `fetched`.
+    pub fn next_page(&self, fetched: usize) -> Option<PageStart> {
+        if !self.has_more {
+            return None;
+        }
+        Some(match &self.next_cursor {
+            Some(cursor) => PageStart::Cursor(cursor.clone()),
+            None => PageStart::Offset(fetched),
+        })
+    }
+}
+
+/// Where a page of a listing starts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PageStart {
+    /// Number of items to skip
+    Offset(usize),
+    /// Cursor returned as `next_cursor` with the previous page
+    Cursor(String),
+}
+
+impl Default for PageStart {
+    fn default() -> Self {
+        Self::Offset(0)
+    }
+}
+
+impl PageStart {
+    /// Query parameter selecting this page (`offset=20` or `cursor=...`)
+    pub(crate) fn query_param(&self) -> String {
+        match self {
+            Self::Offset(offset) => format!("offset={offset}"),
+            Self::Cursor(cursor) => {
+                let encoded: String = cursor
+                    .bytes()
+                    .map(|b| match b {
+                        b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                            (b as char).to_string()
+                        }
+                        _ => format!("%{b:02X}"),
+                    })
+                    .collect();
+                format!("cursor={encoded}")
+            }
+        }
+    }
 }
 
 /// Icon format type
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, PartialEq, Deserialize, Serialize, Clone)]
 pub enum IconFormat {
     Emoji,
     File,
@@ -54,17 +238,37 @@ pub enum Icon {
     Icon { color: Color, name: String },
 }
 
+impl Icon {
+    /// The icon as shown in terminal output
+    ///
+    /// An emoji is returned as is, a built-in icon as an `[icon:name]` placeholder and a file
+    /// as its base name.
+    pub fn display(&self) -> String {
+        match self {
+            Self::Emoji { emoji } => emoji.clone(),
+            Self::Icon { name, .. } => format!("[icon:{name}]"),
+            Self::File { file } => file
+                .trim_end_matches('/')
+                .rsplit('/')
+                .next()
+                .unwrap_or(file)
+                .to_string(),
+        }
+    }
+}
+
 /// Property information for types
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct TypeProperty {
     pub format: Format,
     pub id: String,
     pub key: String,
     pub name: String,
-    pub object: String,
+    #[serde(default)]
+    pub object: ObjectKind,
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, PartialEq, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "snake_case")]
 pub enum Format {
     Text,
@@ -81,20 +285,23 @@ pub enum Format {
 }
 
 /// Type information
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Type {
+    #[serde(default)]
     pub archived: bool,
     pub icon: Option<Icon>,
     pub id: String,
     pub key: String,
     pub layout: Layout,
     pub name: String,
-    pub object: String,
+    #[serde(default)]
+    pub object: ObjectKind,
     pub plural_name: String,
+    #[serde(default)]
     pub properties: Vec<TypeProperty>,
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, PartialEq, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum Layout {
     Basic,
@@ -0,0 +1,341 @@
+//! Blocking client for synchronous code
+//!
+//! Requires the `blocking` feature. [`BlockingAnytypeClient`] has a synchronous version of
+//! every [`AnytypeClient`] request method, run on a private current-thread tokio runtime,
+//! so scripts and sync CLI tools don't need to set up a runtime of their own:
+//!
+//! ```rust,no_run
+//! # fn example() -> anytype_rs::Result<()> {
+//! use anytype_rs::blocking::BlockingAnytypeClient;
+//!
+//! let mut client = BlockingAnytypeClient::new()?;
+//! client.set_api_key("your-jwt-token".to_string());
+//! let spaces = client.list_spaces()?;
+//! println!("Found {} spaces", spaces.len());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The blocking methods must not be called from inside an async runtime: tokio panics when
+//! a runtime is blocked on from one of its own tasks. Async code should use
+//! [`AnytypeClient`] directly.
+
+use crate::api::*;
+use std::future::Future;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::AtomicBool;
+use std::time::SystemTime;
+use tokio::runtime::Runtime;
+
+/// An [`AnytypeClient`] with synchronous request methods
+///
+/// Dereferences to the wrapped client for its non-async methods, such as
+/// [`AnytypeClient::set_api_key`].
+pub struct BlockingAnytypeClient {
+    client: AnytypeClient,
+    runtime: Runtime,
+}
+
+impl AnytypeClient {
+    /// Wrap this client for use from synchronous code
+    pub fn blocking(self) -> Result<BlockingAnytypeClient> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| AnytypeError::Config {
+                message: format!("Failed to start runtime for blocking client: {e}"),
+            })?;
+        Ok(BlockingAnytypeClient {
+            client: self,
+            runtime,
+        })
+    }
+}
+
+impl BlockingAnytypeClient {
+    /// Create a blocking client with the default configuration
+    pub fn new() -> Result<Self> {
+        AnytypeClient::new()?.blocking()
+    }
+
+    /// Create a blocking client with a custom configuration
+    pub fn with_config(config: ClientConfig) -> Result<Self> {
+        AnytypeClient::with_config(config)?.blocking()
+    }
+
+    /// The wrapped async client
+    pub fn into_async(self) -> AnytypeClient {
+        self.client
+    }
+
+    /// Run any future to completion on this client's runtime
+    ///
+    /// For combining several calls concurrently, e.g. with `futures::join!`, from sync code.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+}
+
+impl Deref for BlockingAnytypeClient {
+    type Target = AnytypeClient;
+
+    fn deref(&self) -> &AnytypeClient {
+        &self.client
+    }
+}
+
+impl DerefMut for BlockingAnytypeClient {
+    fn deref_mut(&mut self) -> &mut AnytypeClient {
+        &mut self.client
+    }
+}
+
+/// Define a blocking wrapper for each listed async method of [`AnytypeClient`]
+macro_rules! blocking_methods {
+    ($(fn $name:ident($($arg:ident: $ty:ty),* $(,)?) -> $ret:ty;)*) => {
+        impl BlockingAnytypeClient {
+            $(
+                #[doc = concat!("Blocking version of [`AnytypeClient::", stringify!($name), "`]")]
+                pub fn $name(&self, $($arg: $ty),*) -> $ret {
+                    self.runtime.block_on(self.client.$name($($arg),*))
+                }
+            )*
+        }
+    };
+}
+
+blocking_methods! {
+    // Auth
+    fn create_challenge() -> Result<CreateChallengeResponse>;
+    fn create_api_key(challenge_id: String, code: String) -> Result<CreateApiKeyResponse>;
+    fn ping() -> PingStatus;
+    // Spaces
+    fn list_spaces() -> Result<Vec<Space>>;
+    fn get_space(space_id: &str) -> Result<Space>;
+    fn create_space(request: CreateSpaceRequest) -> Result<CreateSpaceResponse>;
+    fn update_space(space_id: &str, request: UpdateSpaceRequest) -> Result<UpdateSpaceResponse>;
+    fn list_spaces_with_pagination() -> Result<ListSpacesResponse>;
+    fn list_spaces_sorted(sort: SpaceSort) -> Result<ListSpacesResponse>;
+    // Objects
+    fn list_objects(space_id: &str) -> Result<Vec<Object>>;
+    fn list_objects_in_all_spaces() -> Result<Vec<(Space, Result<Vec<Object>>)>>;
+    fn count_objects(space_id: &str) -> Result<usize>;
+    fn count_objects_in_spaces(space_ids: &[String]) -> Vec<Result<usize>>;
+    fn get_object(space_id: &str, object_id: &str) -> Result<Object>;
+    fn get_object_conditional(space_id: &str, object_id: &str) -> Result<CachedResponse<Object>>;
+    fn create_object(space_id: &str, request: CreateObjectRequest) -> Result<CreateObjectResponse>;
+    fn create_bookmark(space_id: &str, url: &str) -> Result<CreateObjectResponse>;
+    fn fetch_page_title(url: &str) -> Option<String>;
+    fn create_objects(
+        space_id: &str,
+        requests: Vec<CreateObjectRequest>,
+    ) -> Vec<Result<CreateObjectResponse>>;
+    fn create_objects_with_policy(
+        space_id: &str,
+        requests: Vec<CreateObjectRequest>,
+        policy: BatchErrorPolicy,
+    ) -> Vec<Result<CreateObjectResponse>>;
+    fn create_objects_until_stopped(
+        space_id: &str,
+        requests: Vec<CreateObjectRequest>,
+        policy: BatchErrorPolicy,
+        stop: &AtomicBool,
+    ) -> Vec<Option<Result<CreateObjectResponse>>>;
+    fn update_objects(
+        space_id: &str,
+        updates: Vec<(String, UpdateObjectRequest)>,
+    ) -> Vec<Result<UpdateObjectResponse>>;
+    fn update_objects_until_stopped(
+        space_id: &str,
+        updates: Vec<(String, UpdateObjectRequest)>,
+        stop: &AtomicBool,
+    ) -> Vec<Option<Result<UpdateObjectResponse>>>;
+    fn delete_object(space_id: &str, object_id: &str) -> Result<DeleteObjectResponse>;
+    fn update_object(
+        space_id: &str,
+        object_id: &str,
+        request: UpdateObjectRequest,
+    ) -> Result<UpdateObjectResponse>;
+    fn validate_object_update(
+        space_id: &str,
+        object_id: &str,
+        request: &UpdateObjectRequest,
+    ) -> Result<()>;
+    fn rename_object(space_id: &str, object_id: &str, name: &str) -> Result<UpdateObjectResponse>;
+    fn set_object_markdown(
+        space_id: &str,
+        object_id: &str,
+        markdown: &str,
+    ) -> Result<UpdateObjectResponse>;
+    fn append_markdown(space_id: &str, object_id: &str, text: &str) -> Result<UpdateObjectResponse>;
+    fn unset_object_property(
+        space_id: &str,
+        object_id: &str,
+        property: &str,
+    ) -> Result<UpdateObjectResponse>;
+    fn add_object_relation(
+        space_id: &str,
+        object_id: &str,
+        property_key: &str,
+        target_id: &str,
+    ) -> Result<UpdateObjectResponse>;
+    fn diff_objects(space_id: &str, old_id: &str, new_id: &str) -> Result<ObjectDiff>;
+    fn objects_modified_since(space_id: &str, since: SystemTime) -> Result<Vec<Object>>;
+    fn list_all_objects(space_id: &str) -> Result<Vec<Object>>;
+    fn changes_since(
+        space_id: &str,
+        token: Option<&SyncToken>,
+    ) -> Result<(Vec<Change>, SyncToken)>;
+    fn get_objects(space_id: &str, object_ids: &[String]) -> Result<Vec<Object>>;
+    fn list_objects_with_pagination(space_id: &str) -> Result<ListObjectsResponse>;
+    fn list_objects_page(
+        space_id: &str,
+        offset: usize,
+        limit: usize,
+    ) -> Result<ListObjectsResponse>;
+    fn list_objects_from(
+        space_id: &str,
+        start: &PageStart,
+        limit: usize,
+    ) -> Result<ListObjectsResponse>;
+    // Lists
+    fn add_list_objects(
+        space_id: &str,
+        list_id: &str,
+        object_ids: Vec<String>,
+    ) -> Result<AddListObjectsResponse>;
+    fn create_object_in_list(
+        space_id: &str,
+        list_id: &str,
+        request: CreateObjectRequest,
+    ) -> Result<CreateObjectResponse>;
+    fn get_list_views(space_id: &str, list_id: &str) -> Result<GetListViewsResponse>;
+    fn get_list_objects(space_id: &str, list_id: &str) -> Result<GetListObjectsResponse>;
+    fn get_list_objects_page(
+        space_id: &str,
+        list_id: &str,
+        offset: usize,
+        limit: usize,
+    ) -> Result<GetListObjectsResponse>;
+    fn get_list_objects_from(
+        space_id: &str,
+        list_id: &str,
+        start: &PageStart,
+        limit: usize,
+    ) -> Result<GetListObjectsResponse>;
+    fn get_all_list_objects(space_id: &str, list_id: &str) -> Result<Vec<ListObject>>;
+    fn remove_list_object(
+        space_id: &str,
+        list_id: &str,
+        object_id: &str,
+    ) -> Result<RemoveListObjectsResponse>;
+    // Search
+    fn search_with_pagination(request: SearchRequest) -> Result<SearchResponse>;
+    fn search_objects(request: SearchRequest) -> Result<Vec<SearchObject>>;
+    fn search(request: SearchRequest) -> Result<SearchResponse>;
+    fn search_space_with_pagination(
+        space_id: &str,
+        request: SearchSpaceRequest,
+    ) -> Result<SearchResponse>;
+    fn search_space_objects(
+        space_id: &str,
+        request: SearchSpaceRequest,
+    ) -> Result<Vec<SearchObject>>;
+    fn search_space(space_id: &str, request: SearchSpaceRequest) -> Result<SearchResponse>;
+    fn find_object_by_name(
+        space_id: &str,
+        name: &str,
+        type_key: &str,
+    ) -> Result<Option<SearchObject>>;
+    // Types
+    fn list_types(space_id: &str) -> Result<Vec<Type>>;
+    fn list_types_in_all_spaces() -> Result<Vec<(Space, Result<Vec<Type>>)>>;
+    fn list_types_with_pagination(space_id: &str) -> Result<ListTypesResponse>;
+    fn create_type(space_id: &str, request: CreateTypeRequest) -> Result<CreateTypeResponse>;
+    fn get_type(space_id: &str, type_id: &str) -> Result<Type>;
+    fn get_type_by_key(space_id: &str, type_key: &str) -> Result<Type>;
+    fn type_id_for_key(space_id: &str, type_key: &str) -> Result<String>;
+    fn update_type(
+        space_id: &str,
+        type_id: &str,
+        request: UpdateTypeRequest,
+    ) -> Result<UpdateTypeResponse>;
+    fn add_type_properties(
+        space_id: &str,
+        type_id: &str,
+        properties: Vec<CreateTypeProperty>,
+    ) -> Result<AddTypePropertiesResponse>;
+    fn rename_type(space_id: &str, type_id: &str, name: &str) -> Result<UpdateTypeResponse>;
+    fn delete_type(space_id: &str, type_id: &str) -> Result<DeleteTypeResponse>;
+    // Properties
+    fn list_properties(space_id: &str) -> Result<Vec<Property>>;
+    fn list_properties_with_pagination(space_id: &str) -> Result<ListPropertiesResponse>;
+    fn get_property(space_id: &str, property_id: &str) -> Result<Property>;
+    fn create_property(
+        space_id: &str,
+        request: CreatePropertyRequest,
+    ) -> Result<CreatePropertyResponse>;
+    fn update_property(
+        space_id: &str,
+        property_id: &str,
+        request: UpdatePropertyRequest,
+    ) -> Result<UpdatePropertyResponse>;
+    fn delete_property(space_id: &str, property_id: &str) -> Result<DeletePropertyResponse>;
+    fn plan_property_migration(
+        space_id: &str,
+        property_id: &str,
+        new_format: PropertyFormat,
+    ) -> Result<PropertyMigration>;
+    fn migrate_property_format(
+        space_id: &str,
+        property_id: &str,
+        new_format: PropertyFormat,
+    ) -> Result<Property>;
+    // Tags
+    fn list_tags(space_id: &str, property_id: &str) -> Result<Vec<Tag>>;
+    fn list_tags_with_pagination(space_id: &str, property_id: &str) -> Result<ListTagsResponse>;
+    fn create_tag(
+        space_id: &str,
+        property_id: &str,
+        request: CreateTagRequest,
+    ) -> Result<CreateTagResponse>;
+    fn get_tag(space_id: &str, property_id: &str, tag_id: &str) -> Result<Tag>;
+    fn update_tag(
+        space_id: &str,
+        property_id: &str,
+        tag_id: &str,
+        request: UpdateTagRequest,
+    ) -> Result<UpdateTagResponse>;
+    fn delete_tag(space_id: &str, property_id: &str, tag_id: &str) -> Result<Tag>;
+    fn list_type_tags(space_id: &str, type_id: &str) -> Result<Vec<(TypeProperty, Vec<Tag>)>>;
+    // Templates
+    fn list_templates(space_id: &str, type_id: &str) -> Result<Vec<Template>>;
+    fn list_templates_with_pagination(
+        space_id: &str,
+        type_id: &str,
+    ) -> Result<ListTemplatesResponse>;
+    fn get_template(space_id: &str, type_id: &str, template_id: &str) -> Result<Template>;
+    fn create_object_from_template(
+        space_id: &str,
+        type_id: &str,
+        template_id: &str,
+        overrides: TemplateOverrides,
+    ) -> Result<CreateObjectResponse>;
+    // Members
+    fn get_member(space_id: &str, member_id: &str) -> Result<Member>;
+    fn get_member_profile(space_id: &str, member_id: &str) -> Result<Object>;
+    fn list_members(space_id: &str) -> Result<Vec<Member>>;
+    fn list_members_filtered(space_id: &str, filter: &MemberFilter) -> Result<Vec<Member>>;
+    fn list_members_with_pagination(space_id: &str) -> Result<ListMembersResponse>;
+    fn list_members_in_all_spaces() -> Result<Vec<(Space, Vec<Member>)>>;
+    fn find_member_by_global_name(global_name: &str) -> Result<Option<Member>>;
+    // Schema
+    fn export_schema(space_id: &str) -> Result<SchemaDocument>;
+    // Client
+    fn server_version() -> Result<Option<String>>;
+    fn raw_request(
+        method: Method,
+        path: &str,
+        body: Option<&serde_json::Value>,
+    ) -> Result<serde_json::Value>;
+}
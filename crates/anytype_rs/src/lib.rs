@@ -49,6 +49,10 @@
 //! ```
 
 pub mod api;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+#[cfg(feature = "testing")]
+pub mod testing;
 
 // Re-export the main API types for convenience
 pub use api::*;
@@ -2,6 +2,8 @@
 //!
 //! This module contains sample request and response data for all API endpoints.
 //! Data is based on the Anytype API documentation and existing type definitions.
+//! The crate's own mock tests include this file directly, so it must not depend on
+//! anything else in the `testing` module.
 
 use serde_json::json;
 
@@ -323,7 +325,7 @@ pub mod types {
     /// Sample create type request
     pub fn create_type_request() -> serde_json::Value {
         json!({
-            "key": "custom-type",
+            "key": "custom_type",
             "name": "Custom Type",
             "plural_name": "Custom Types",
             "layout": "basic",
@@ -346,7 +348,7 @@ pub mod types {
         json!({
             "type": {
                 "id": "custom-type-id",
-                "key": "custom-type",
+                "key": "custom_type",
                 "name": "Custom Type",
                 "plural_name": "Custom Types",
                 "layout": "basic",
@@ -691,6 +693,13 @@ pub mod search {
             }
         })
     }
+
+    /// Sample search response with one of the two objects archived
+    pub fn search_response_with_archived() -> serde_json::Value {
+        let mut response = search_response();
+        response["data"][1]["archived"] = json!(true);
+        response
+    }
 }
 
 /// Template fixtures
@@ -780,6 +789,38 @@ pub mod templates {
             "template": template()
         })
     }
+
+    /// Sample get template response for a template with property values
+    pub fn get_template_with_properties_response() -> serde_json::Value {
+        let mut template = template();
+        template["properties"] = json!([
+            {
+                "object": "property",
+                "id": "prop-status",
+                "key": "status",
+                "name": "Status",
+                "format": "select",
+                "select": { "id": "tag-draft", "name": "Draft", "color": "grey" }
+            },
+            {
+                "object": "property",
+                "id": "prop-summary",
+                "key": "summary",
+                "name": "Summary",
+                "format": "text",
+                "text": "Template summary"
+            },
+            {
+                "object": "property",
+                "id": "prop-due",
+                "key": "due_date",
+                "name": "Due date",
+                "format": "date",
+                "date": null
+            }
+        ]);
+        json!({ "template": template })
+    }
 }
 
 /// List fixtures
@@ -0,0 +1,132 @@
+//! Helpers for testing code built on this crate against a fake Anytype
+//!
+//! Requires the `testing` feature. [`mock_client`] starts a local mock server that answers
+//! the common read endpoints with the sample data in [`fixtures`], and returns a client that
+//! talks to it:
+//!
+//! ```rust,no_run
+//! # async fn example() -> anytype_rs::Result<()> {
+//! use anytype_rs::testing::{self, TEST_SPACE_ID};
+//!
+//! let mock = testing::mock_client().await;
+//! let objects = mock.list_objects(TEST_SPACE_ID).await?;
+//! assert_eq!(objects.len(), 2);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! More endpoints can be mocked through [`MockAnytype::server`].
+
+pub mod fixtures;
+
+use crate::api::{AnytypeClient, ClientConfig};
+use std::ops::Deref;
+
+pub use httpmock::MockServer;
+
+/// API key set on the client returned by [`mock_client`]
+pub const TEST_API_KEY: &str = "test-api-key-12345";
+
+/// ID of the space in the fixtures
+pub const TEST_SPACE_ID: &str = "bafyreiabc123example";
+
+/// ID of the object in the fixtures
+pub const TEST_OBJECT_ID: &str = "bafyreiabc456object";
+
+/// ID of the type in the fixtures
+pub const TEST_TYPE_ID: &str = "ot-page";
+
+/// ID of the property whose tags are in the fixtures
+pub const TEST_PROPERTY_ID: &str = "prop-123";
+
+/// A mock Anytype server and a client pointed at it
+///
+/// Dereferences to the client. The server stops when this is dropped.
+pub struct MockAnytype {
+    pub server: MockServer,
+    pub client: AnytypeClient,
+}
+
+impl Deref for MockAnytype {
+    type Target = AnytypeClient;
+
+    fn deref(&self) -> &AnytypeClient {
+        &self.client
+    }
+}
+
+/// Start a mock Anytype server seeded with the fixtures and return an authenticated client
+///
+/// The server answers listing and fetching spaces, objects, types, properties, tags,
+/// templates and members for [`TEST_SPACE_ID`], and global and space searches.
+pub async fn mock_client() -> MockAnytype {
+    let server = MockServer::start_async().await;
+    seed(&server);
+
+    let mut client = AnytypeClient::with_config(ClientConfig {
+        base_url: server.base_url(),
+        ..ClientConfig::default()
+    })
+    .expect("Failed to create mock client");
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    MockAnytype { server, client }
+}
+
+/// Register the fixture responses on a mock server
+fn seed(server: &MockServer) {
+    let space = format!("/v1/spaces/{TEST_SPACE_ID}");
+    let get_routes = [
+        (
+            "/v1/spaces".to_string(),
+            fixtures::spaces::list_spaces_response(),
+        ),
+        (space.clone(), fixtures::spaces::space()),
+        (
+            format!("{space}/objects"),
+            fixtures::objects::list_objects_response(),
+        ),
+        (
+            format!("{space}/objects/{TEST_OBJECT_ID}"),
+            fixtures::objects::object(),
+        ),
+        (
+            format!("{space}/types"),
+            fixtures::types::list_types_response(),
+        ),
+        (
+            format!("{space}/types/{TEST_TYPE_ID}"),
+            fixtures::types::get_type_response(),
+        ),
+        (
+            format!("{space}/types/{TEST_TYPE_ID}/templates"),
+            fixtures::templates::list_templates_response(),
+        ),
+        (
+            format!("{space}/properties"),
+            fixtures::properties::list_properties_response(),
+        ),
+        (
+            format!("{space}/properties/{TEST_PROPERTY_ID}/tags"),
+            fixtures::tags::list_tags_response(),
+        ),
+        (
+            format!("{space}/members"),
+            fixtures::members::list_members_response(),
+        ),
+    ];
+    for (path, body) in get_routes {
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path(path);
+            then.status(200).json_body(body);
+        });
+    }
+
+    for path in ["/v1/search".to_string(), format!("{space}/search")] {
+        server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path(path);
+            then.status(200)
+                .json_body(fixtures::search::search_response());
+        });
+    }
+}
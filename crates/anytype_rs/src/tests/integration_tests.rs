@@ -16,6 +16,7 @@ fn test_custom_config() {
         base_url: "http://localhost:31009".to_string(),
         timeout_seconds: 60,
         app_name: "test-app".to_string(),
+        ..ClientConfig::default()
     };
 
     let client = AnytypeClient::with_config(config).expect("Failed to create client with config");
@@ -28,6 +29,7 @@ fn test_default_config_values() {
     assert_eq!(config.base_url, "http://localhost:31009");
     assert_eq!(config.timeout_seconds, 30);
     assert_eq!(config.app_name, "anytype_rs");
+    assert_eq!(config.redacted_fields, ["api_key", "code", "authorization"]);
 }
 
 #[tokio::test]
@@ -0,0 +1,77 @@
+//! Tests for the `blocking` feature's synchronous client
+//!
+//! Run with `cargo test -p anytype_rs --features blocking`.
+
+#![cfg(feature = "blocking")]
+
+use anytype_rs::api::{ClientConfig, CreateObjectRequest};
+use anytype_rs::blocking::BlockingAnytypeClient;
+use httpmock::prelude::*;
+
+const TEST_API_KEY: &str = "test-api-key-12345";
+const TEST_SPACE_ID: &str = "bafyreiabc123example";
+
+fn blocking_client(base_url: String) -> BlockingAnytypeClient {
+    let mut client = BlockingAnytypeClient::with_config(ClientConfig {
+        base_url,
+        ..ClientConfig::default()
+    })
+    .unwrap();
+    client.set_api_key(TEST_API_KEY.to_string());
+    client
+}
+
+#[test]
+fn test_blocking_client_sends_requests() {
+    let server = MockServer::start();
+
+    let spaces_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v1/spaces")
+            .header("Authorization", format!("Bearer {TEST_API_KEY}"));
+        then.status(200).json_body(serde_json::json!({
+            "data": [{ "id": TEST_SPACE_ID, "name": "My Space", "object": "space" }],
+            "pagination": { "has_more": false, "limit": 100, "offset": 0, "total": 1 }
+        }));
+    });
+    let create_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path(format!("/v1/spaces/{TEST_SPACE_ID}/objects"))
+            .body_includes("\"From sync code\"");
+        then.status(200).json_body(serde_json::json!({
+            "object": { "id": "obj-1", "name": "From sync code", "properties": {} },
+            "properties": {}
+        }));
+    });
+
+    let client = blocking_client(server.base_url());
+
+    let spaces = client.list_spaces().unwrap();
+    assert_eq!(spaces[0].id, TEST_SPACE_ID);
+
+    let created = client
+        .create_object(
+            TEST_SPACE_ID,
+            CreateObjectRequest::new("page").name("From sync code"),
+        )
+        .unwrap();
+    assert_eq!(created.object.id, "obj-1");
+
+    spaces_mock.assert();
+    create_mock.assert();
+}
+
+#[test]
+fn test_blocking_client_block_on_runs_async_code() {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/v1/spaces");
+        then.status(404).body("not found");
+    });
+
+    let client = blocking_client(server.base_url());
+    let async_client = &*client;
+
+    let result = client.block_on(async { async_client.list_spaces().await });
+    assert!(result.is_err());
+}
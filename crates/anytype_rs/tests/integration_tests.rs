@@ -16,6 +16,7 @@ fn test_custom_config() {
         base_url: "http://localhost:31009".to_string(),
         timeout_seconds: 60,
         app_name: "test-app".to_string(),
+        ..ClientConfig::default()
     };
 
     let client = AnytypeClient::with_config(config).expect("Failed to create client with config");
@@ -4,6 +4,8 @@
 //! They verify that the client correctly formats requests and handles responses.
 
 mod mock_tests {
+    // Shared with downstream crates through the `testing` feature
+    #[path = "../../src/testing/fixtures.rs"]
     pub mod fixtures;
     pub mod auth_tests;
     pub mod spaces_tests;
@@ -24,10 +26,31 @@ mod mock_tests {
             base_url: base_url.to_string(),
             timeout_seconds: 30,
             app_name: "test-app".to_string(),
+            enable_conditional_requests: false,
+            record_dir: None,
+            max_concurrency: 8,
+            circuit_breaker_threshold: 5,
+            circuit_breaker_window_seconds: 30,
+            circuit_breaker_cooldown_seconds: 10,
+            redacted_fields: Vec::new(),
+            search_cache_ttl_seconds: 0,
         };
         AnytypeClient::with_config(config).expect("Failed to create test client")
     }
 
+    /// Base URL of a local port nothing is listening on
+    ///
+    /// The port is bound and released straight away, so connecting to it is refused until
+    /// a test binds it again (at the address without the `http://` prefix).
+    pub fn unused_base_url() -> String {
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        format!("http://127.0.0.1:{port}")
+    }
+
     /// Standard API version header value
     pub const API_VERSION: &str = "2025-05-20";
 
@@ -1,6 +1,7 @@
 //! Mock tests for authentication endpoints
 
 use super::*;
+use anytype_rs::api::PingStatus;
 use fixtures::auth::*;
 use fixtures::errors::*;
 use httpmock::prelude::*;
@@ -82,6 +83,94 @@ async fn test_create_api_key_success() {
     mock.assert();
 }
 
+#[tokio::test]
+async fn test_record_dir_writes_redacted_response() {
+    let server = MockServer::start_async().await;
+
+    let mock = server.mock(|when, then| {
+        when.method(POST).path("/v1/auth/api_keys");
+        then.status(201)
+            .header("content-type", "application/json")
+            .json_body(create_api_key_response());
+    });
+
+    let record_dir = std::env::temp_dir().join(format!(
+        "anytype_rs-recordings-{}",
+        std::process::id()
+    ));
+    let client = anytype_rs::api::AnytypeClient::with_config(anytype_rs::api::ClientConfig {
+        base_url: server.base_url(),
+        app_name: "test-app".to_string(),
+        record_dir: Some(record_dir.clone()),
+        redacted_fields: vec!["api_key".to_string()],
+        ..anytype_rs::api::ClientConfig::default()
+    })
+    .unwrap();
+
+    let result = client
+        .create_api_key("challenge-abc-123".to_string(), "1234".to_string())
+        .await;
+    assert!(result.is_ok());
+
+    let recordings: Vec<_> = std::fs::read_dir(&record_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    assert_eq!(recordings.len(), 1);
+
+    let file_name = recordings[0].file_name().unwrap().to_string_lossy().to_string();
+    assert!(file_name.ends_with("-201-v1_auth_api_keys.json"), "{file_name}");
+
+    let recorded: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&recordings[0]).unwrap()).unwrap();
+    assert_eq!(recorded["api_key"], "[REDACTED]");
+
+    std::fs::remove_dir_all(&record_dir).unwrap();
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_record_dir_uses_configured_redacted_fields() {
+    let server = MockServer::start_async().await;
+
+    let mock = server.mock(|when, then| {
+        when.method(POST).path("/v1/auth/challenges");
+        then.status(201)
+            .header("content-type", "application/json")
+            .json_body(create_challenge_response());
+    });
+
+    let record_dir = std::env::temp_dir().join(format!(
+        "anytype_rs-recordings-custom-{}",
+        std::process::id()
+    ));
+    let client = anytype_rs::api::AnytypeClient::with_config(anytype_rs::api::ClientConfig {
+        base_url: server.base_url(),
+        record_dir: Some(record_dir.clone()),
+        // Matched case-insensitively
+        redacted_fields: vec!["Challenge_ID".to_string()],
+        ..anytype_rs::api::ClientConfig::default()
+    })
+    .unwrap();
+
+    let response = client.create_challenge().await.unwrap();
+    // Redaction only applies to what is logged and recorded, not to the parsed response
+    assert_eq!(response.challenge_id, "challenge-abc-123");
+
+    let recordings: Vec<_> = std::fs::read_dir(&record_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    assert_eq!(recordings.len(), 1);
+
+    let recorded: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&recordings[0]).unwrap()).unwrap();
+    assert_eq!(recorded["challenge_id"], "[REDACTED]");
+
+    std::fs::remove_dir_all(&record_dir).unwrap();
+    mock.assert();
+}
+
 #[tokio::test]
 async fn test_create_api_key_bad_request() {
     let server = MockServer::start_async().await;
@@ -125,3 +214,47 @@ async fn test_create_api_key_server_error() {
     assert!(result.is_err());
     mock.assert();
 }
+
+#[tokio::test]
+async fn test_ping_distinguishes_unreachable_from_unauthorized() {
+    let server = MockServer::start_async().await;
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v1/spaces")
+            .query_param("limit", "1")
+            .header("Anytype-Version", API_VERSION);
+        then.status(401)
+            .header("content-type", "application/json")
+            .json_body(unauthorized_error());
+    });
+
+    // No API key set: the app answers but rejects the request
+    let client = create_test_client(&server.base_url());
+    assert_eq!(client.ping().await, PingStatus::Unauthorized);
+    assert!(client.ping().await.is_reachable());
+    mock.assert_calls(2);
+
+    // Nothing listens on port 1
+    let client = create_test_client("http://127.0.0.1:1");
+    assert_eq!(client.ping().await, PingStatus::Unreachable);
+}
+
+#[tokio::test]
+async fn test_ping_ready_with_accepted_key() {
+    let server = MockServer::start_async().await;
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v1/spaces")
+            .header("Authorization", format!("Bearer {TEST_API_KEY}"));
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(fixtures::spaces::list_spaces_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+    assert_eq!(client.ping().await, PingStatus::Ready);
+    mock.assert();
+}
@@ -1,6 +1,7 @@
 //! Mock tests for lists endpoints
 
 use super::*;
+use anytype_rs::api::CreateObjectRequest;
 use fixtures::errors::*;
 use fixtures::lists::*;
 use httpmock::prelude::*;
@@ -203,3 +204,118 @@ async fn test_get_list_views_unauthorized() {
     assert!(result.is_err());
     mock.assert();
 }
+
+#[tokio::test]
+async fn test_create_object_in_list() {
+    let server = MockServer::start_async().await;
+
+    let create_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path(format!("/v1/spaces/{}/objects", TEST_SPACE_ID));
+        then.status(200)
+            .json_body(fixtures::objects::create_object_response());
+    });
+    let add_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path(format!(
+                "/v1/spaces/{}/lists/{}/objects",
+                TEST_SPACE_ID, TEST_LIST_ID
+            ))
+            .json_body(serde_json::json!({ "object_ids": ["bafyreiabc456newobj"] }));
+        then.status(200).json_body(add_list_objects_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let response = client
+        .create_object_in_list(
+            TEST_SPACE_ID,
+            TEST_LIST_ID,
+            CreateObjectRequest::new("page").name("Child"),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.object.id, "bafyreiabc456newobj");
+    create_mock.assert();
+    add_mock.assert();
+}
+
+#[tokio::test]
+async fn test_create_object_in_list_names_object_when_add_fails() {
+    let server = MockServer::start_async().await;
+
+    server.mock(|when, then| {
+        when.method(POST)
+            .path(format!("/v1/spaces/{}/objects", TEST_SPACE_ID));
+        then.status(200)
+            .json_body(fixtures::objects::create_object_response());
+    });
+    server.mock(|when, then| {
+        when.method(POST).path(format!(
+            "/v1/spaces/{}/lists/{}/objects",
+            TEST_SPACE_ID, TEST_LIST_ID
+        ));
+        then.status(400).json_body(bad_request_error());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let error = client
+        .create_object_in_list(
+            TEST_SPACE_ID,
+            TEST_LIST_ID,
+            CreateObjectRequest::new("page").name("Child"),
+        )
+        .await
+        .unwrap_err();
+
+    assert!(error.to_string().contains("bafyreiabc456newobj"));
+}
+
+#[tokio::test]
+async fn test_get_all_list_objects_follows_pages() {
+    let server = MockServer::start_async().await;
+
+    let list_object_with_id = |id: &str| {
+        let mut obj = list_object();
+        obj["id"] = id.into();
+        obj
+    };
+    let first_page = serde_json::json!({
+        "data": [list_object_with_id("obj-1"), list_object_with_id("obj-2")],
+        "pagination": { "has_more": true, "limit": 100, "offset": 0, "total": 3 }
+    });
+    let second_page = serde_json::json!({
+        "data": [list_object_with_id("obj-3")],
+        "pagination": { "has_more": false, "limit": 100, "offset": 2, "total": 3 }
+    });
+    let path = format!("/v1/spaces/{}/lists/{}/objects", TEST_SPACE_ID, TEST_LIST_ID);
+
+    let first_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(&path)
+            .query_param("offset", "0")
+            .query_param("limit", "100");
+        then.status(200).json_body(first_page);
+    });
+    let second_mock = server.mock(|when, then| {
+        when.method(GET).path(&path).query_param("offset", "2");
+        then.status(200).json_body(second_page);
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let objects = client
+        .get_all_list_objects(TEST_SPACE_ID, TEST_LIST_ID)
+        .await
+        .unwrap();
+
+    let ids: Vec<&str> = objects.iter().map(|o| o.id.as_str()).collect();
+    assert_eq!(ids, vec!["obj-1", "obj-2", "obj-3"]);
+    first_mock.assert_calls(1);
+    second_mock.assert_calls(1);
+}
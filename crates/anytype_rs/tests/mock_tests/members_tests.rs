@@ -1,6 +1,7 @@
 //! Mock tests for members endpoints
 
 use super::*;
+use anytype_rs::api::{MemberFilter, MemberRole, MemberStatus};
 use fixtures::errors::*;
 use fixtures::members::*;
 use httpmock::prelude::*;
@@ -32,6 +33,81 @@ async fn test_list_members_success() {
     mock.assert();
 }
 
+#[tokio::test]
+async fn test_list_members_filtered() {
+    let server = MockServer::start_async().await;
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path(format!("/v1/spaces/{}/members", TEST_SPACE_ID));
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(list_members_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let editors = client
+        .list_members_filtered(
+            TEST_SPACE_ID,
+            &MemberFilter {
+                role: Some(MemberRole::Editor),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(editors.len(), 1);
+    assert_eq!(editors[0].role, MemberRole::Editor);
+
+    let active = client
+        .list_members_filtered(
+            TEST_SPACE_ID,
+            &MemberFilter {
+                status: Some(MemberStatus::Active),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(active.len(), 2);
+
+    let pending = client
+        .list_members_filtered(
+            TEST_SPACE_ID,
+            &MemberFilter {
+                pending: true,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert!(pending.is_empty());
+
+    mock.assert_calls(3);
+}
+
+#[test]
+fn test_member_role_and_status_parse() {
+    assert_eq!("editor".parse::<MemberRole>().unwrap(), MemberRole::Editor);
+    assert_eq!(
+        "no_permission".parse::<MemberRole>().unwrap(),
+        MemberRole::NoPermission
+    );
+    assert_eq!("Active".parse::<MemberStatus>().unwrap(), MemberStatus::Active);
+    assert!("admin".parse::<MemberRole>().is_err());
+    assert!(MemberStatus::Joining.is_pending());
+    assert!(!MemberStatus::Active.is_pending());
+    assert!(MemberFilter::default().is_empty());
+    assert!(
+        !MemberFilter {
+            pending: true,
+            ..Default::default()
+        }
+        .is_empty()
+    );
+}
+
 #[tokio::test]
 async fn test_list_members_unauthorized() {
     let server = MockServer::start_async().await;
@@ -81,6 +157,36 @@ async fn test_get_member_success() {
     mock.assert();
 }
 
+#[tokio::test]
+async fn test_get_member_profile_loads_participant_object() {
+    let server = MockServer::start_async().await;
+
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/members/{}", TEST_SPACE_ID, TEST_MEMBER_ID));
+        then.status(200).json_body(get_member_response());
+    });
+    let mut profile = fixtures::objects::object();
+    profile["id"] = TEST_MEMBER_ID.into();
+    profile["name"] = "John Doe".into();
+    let object_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/objects/{}", TEST_SPACE_ID, TEST_MEMBER_ID));
+        then.status(200).json_body(profile);
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let result = client
+        .get_member_profile(TEST_SPACE_ID, TEST_MEMBER_ID)
+        .await;
+
+    assert!(result.is_ok(), "Expected success, got error: {:?}", result.err());
+    assert_eq!(result.unwrap().name.as_deref(), Some("John Doe"));
+    object_mock.assert();
+}
+
 #[tokio::test]
 async fn test_get_member_not_found() {
     let server = MockServer::start_async().await;
@@ -103,3 +209,73 @@ async fn test_get_member_not_found() {
     assert!(result.is_err());
     mock.assert();
 }
+
+#[tokio::test]
+async fn test_list_members_in_all_spaces() {
+    let server = MockServer::start_async().await;
+
+    server.mock(|when, then| {
+        when.method(GET).path("/v1/spaces");
+        then.status(200)
+            .json_body(fixtures::spaces::list_spaces_response());
+    });
+    let first_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/members", TEST_SPACE_ID));
+        then.status(200).json_body(list_members_response());
+    });
+    let second_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v1/spaces/bafyreiabc123space2/members");
+        then.status(200).json_body(serde_json::json!({
+            "data": [member()],
+            "pagination": { "has_more": false, "limit": 100, "offset": 0, "total": 1 }
+        }));
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let spaces = client.list_members_in_all_spaces().await.unwrap();
+
+    assert_eq!(spaces.len(), 2);
+    assert_eq!(spaces[0].0.id, TEST_SPACE_ID);
+    assert_eq!(spaces[0].1.len(), 2);
+    assert_eq!(spaces[1].0.id, "bafyreiabc123space2");
+    assert_eq!(spaces[1].1.len(), 1);
+    first_mock.assert();
+    second_mock.assert();
+}
+
+#[tokio::test]
+async fn test_find_member_by_global_name() {
+    let server = MockServer::start_async().await;
+
+    server.mock(|when, then| {
+        when.method(GET).path("/v1/spaces");
+        then.status(200)
+            .json_body(fixtures::spaces::list_spaces_response());
+    });
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/members", TEST_SPACE_ID));
+        then.status(200).json_body(list_members_response());
+    });
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/v1/spaces/bafyreiabc123space2/members");
+        then.status(200).json_body(serde_json::json!({
+            "data": [],
+            "pagination": { "has_more": false, "limit": 100, "offset": 0, "total": 0 }
+        }));
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let jane = client.find_member_by_global_name("Jane").await.unwrap().unwrap();
+    assert_eq!(jane.identity.as_deref(), Some("identity-def"));
+
+    let nobody = client.find_member_by_global_name("nobody.any").await.unwrap();
+    assert!(nobody.is_none());
+}
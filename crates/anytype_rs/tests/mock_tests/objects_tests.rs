@@ -1,10 +1,14 @@
 //! Mock tests for objects endpoints
 
 use super::*;
-use anytype_rs::api::{CreateObjectRequest, UpdateObjectRequest};
+use anytype_rs::api::{
+    AnytypeClient, AnytypeError, BatchErrorPolicy, CachedResponse, ClientConfig,
+    CreateObjectRequest, Method, Object, PropertyChange, UpdateObjectRequest,
+};
 use fixtures::errors::*;
 use fixtures::objects::*;
 use httpmock::prelude::*;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 #[tokio::test]
 async fn test_list_objects_success() {
@@ -82,6 +86,149 @@ async fn test_get_object_success() {
     mock.assert();
 }
 
+#[tokio::test]
+async fn test_get_object_tolerates_unknown_and_missing_fields() {
+    let server = MockServer::start_async().await;
+
+    // A newer app version: an added field, and `properties` dropped
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/objects/{}", TEST_SPACE_ID, TEST_OBJECT_ID));
+        then.status(200).json_body(serde_json::json!({
+            "id": TEST_OBJECT_ID,
+            "name": "My Page",
+            "layout": "basic",
+            "new_field": { "nested": [1, 2, 3] }
+        }));
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let obj = client
+        .get_object(TEST_SPACE_ID, TEST_OBJECT_ID)
+        .await
+        .unwrap();
+
+    assert_eq!(obj.id, TEST_OBJECT_ID);
+    assert!(obj.properties.is_null());
+}
+
+#[tokio::test]
+async fn test_get_object_conditional_not_modified() {
+    let server = MockServer::start_async().await;
+
+    let first = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/objects/{}", TEST_SPACE_ID, TEST_OBJECT_ID))
+            .header_missing("If-None-Match");
+        then.status(200)
+            .header("content-type", "application/json")
+            .header("ETag", "\"v1\"")
+            .json_body(object());
+    });
+    let revalidate = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/objects/{}", TEST_SPACE_ID, TEST_OBJECT_ID))
+            .header("If-None-Match", "\"v1\"");
+        then.status(304);
+    });
+
+    let mut client = AnytypeClient::with_config(ClientConfig {
+        base_url: server.base_url(),
+        app_name: "test-app".to_string(),
+        enable_conditional_requests: true,
+        ..ClientConfig::default()
+    })
+    .unwrap();
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let result = client
+        .get_object_conditional(TEST_SPACE_ID, TEST_OBJECT_ID)
+        .await
+        .unwrap();
+    assert!(matches!(result, CachedResponse::Fresh(_)));
+
+    let result = client
+        .get_object_conditional(TEST_SPACE_ID, TEST_OBJECT_ID)
+        .await
+        .unwrap();
+    assert!(result.is_not_modified());
+    assert_eq!(result.into_inner().id, TEST_OBJECT_ID);
+
+    // Plain getters also use the cached body when conditional requests are enabled
+    let obj = client.get_object(TEST_SPACE_ID, TEST_OBJECT_ID).await.unwrap();
+    assert_eq!(obj.name, Some("My Page".to_string()));
+
+    first.assert_calls(1);
+    revalidate.assert_calls(2);
+}
+
+#[tokio::test]
+async fn test_get_object_conditional_not_shared_across_api_keys() {
+    let server = MockServer::start_async().await;
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/objects/{}", TEST_SPACE_ID, TEST_OBJECT_ID))
+            .header_missing("If-None-Match");
+        then.status(200)
+            .header("content-type", "application/json")
+            .header("ETag", "\"v1\"")
+            .json_body(object());
+    });
+
+    let mut client = AnytypeClient::with_config(ClientConfig {
+        base_url: server.base_url(),
+        enable_conditional_requests: true,
+        ..ClientConfig::default()
+    })
+    .unwrap();
+    client.set_api_key(TEST_API_KEY.to_string());
+    client
+        .get_object_conditional(TEST_SPACE_ID, TEST_OBJECT_ID)
+        .await
+        .unwrap();
+
+    // A response fetched with one key must not be revalidated with another
+    client.set_api_key("another-api-key".to_string());
+    let result = client
+        .get_object_conditional(TEST_SPACE_ID, TEST_OBJECT_ID)
+        .await
+        .unwrap();
+
+    assert!(matches!(result, CachedResponse::Fresh(_)));
+    mock.assert_calls(2);
+}
+
+#[tokio::test]
+async fn test_get_object_conditional_disabled() {
+    let server = MockServer::start_async().await;
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/objects/{}", TEST_SPACE_ID, TEST_OBJECT_ID))
+            .header_missing("If-None-Match");
+        then.status(200)
+            .header("content-type", "application/json")
+            .header("ETag", "\"v1\"")
+            .json_body(object());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    for _ in 0..2 {
+        let result = client
+            .get_object_conditional(TEST_SPACE_ID, TEST_OBJECT_ID)
+            .await
+            .unwrap();
+        assert!(!result.is_not_modified());
+    }
+
+    mock.assert_calls(2);
+}
+
 #[tokio::test]
 async fn test_get_object_not_found() {
     let server = MockServer::start_async().await;
@@ -206,6 +353,89 @@ async fn test_update_object_success() {
     mock.assert();
 }
 
+#[tokio::test]
+async fn test_rename_and_set_markdown_send_single_field() {
+    let server = MockServer::start_async().await;
+
+    let path = format!("/v1/spaces/{}/objects/{}", TEST_SPACE_ID, TEST_OBJECT_ID);
+    let rename_mock = server.mock(|when, then| {
+        when.method(PATCH)
+            .path(&path)
+            .json_body(serde_json::json!({ "name": "Renamed" }));
+        then.status(200).json_body(update_object_response());
+    });
+    let markdown_mock = server.mock(|when, then| {
+        when.method(PATCH)
+            .path(&path)
+            .json_body(serde_json::json!({ "body": "# New body" }));
+        then.status(200).json_body(update_object_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    client
+        .rename_object(TEST_SPACE_ID, TEST_OBJECT_ID, "Renamed")
+        .await
+        .unwrap();
+    client
+        .set_object_markdown(TEST_SPACE_ID, TEST_OBJECT_ID, "# New body")
+        .await
+        .unwrap();
+
+    rename_mock.assert();
+    markdown_mock.assert();
+}
+
+#[tokio::test]
+async fn test_blank_object_name_is_rejected() {
+    let server = MockServer::start_async().await;
+
+    let create_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path(format!("/v1/spaces/{}/objects", TEST_SPACE_ID));
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(create_object_response());
+    });
+    let update_mock = server.mock(|when, then| {
+        when.method(PATCH).path(format!(
+            "/v1/spaces/{}/objects/{}",
+            TEST_SPACE_ID, TEST_OBJECT_ID
+        ));
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(update_object_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    for name in ["", "   ", "\t\n"] {
+        let result = client
+            .create_object(TEST_SPACE_ID, CreateObjectRequest::new("page").name(name))
+            .await;
+        assert!(
+            matches!(result, Err(AnytypeError::Config { .. })),
+            "Accepted blank name {name:?}"
+        );
+
+        let result = client
+            .rename_object(TEST_SPACE_ID, TEST_OBJECT_ID, name)
+            .await;
+        assert!(matches!(result, Err(AnytypeError::Config { .. })));
+    }
+
+    // Leaving the name out is still allowed
+    client
+        .create_object(TEST_SPACE_ID, CreateObjectRequest::new("page"))
+        .await
+        .unwrap();
+
+    create_mock.assert_calls(1);
+    update_mock.assert_calls(0);
+}
+
 #[tokio::test]
 async fn test_update_object_not_found() {
     let server = MockServer::start_async().await;
@@ -283,3 +513,902 @@ async fn test_delete_object_not_found() {
     assert!(result.is_err());
     mock.assert();
 }
+
+#[tokio::test]
+async fn test_append_markdown_keeps_existing_body() {
+    let server = MockServer::start_async().await;
+
+    let mut existing = object();
+    existing["markdown"] = "# Journal\n\n- Monday\n".into();
+    let get_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/objects/{}", TEST_SPACE_ID, TEST_OBJECT_ID));
+        then.status(200).json_body(existing);
+    });
+    let patch_mock = server.mock(|when, then| {
+        when.method(PATCH)
+            .path(format!("/v1/spaces/{}/objects/{}", TEST_SPACE_ID, TEST_OBJECT_ID))
+            .json_body(serde_json::json!({ "body": "# Journal\n\n- Monday\n\n- Tuesday" }));
+        then.status(200).json_body(update_object_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let result = client
+        .append_markdown(TEST_SPACE_ID, TEST_OBJECT_ID, "- Tuesday")
+        .await;
+
+    assert!(result.is_ok(), "Expected success, got error: {:?}", result.err());
+    get_mock.assert();
+    patch_mock.assert();
+}
+
+#[tokio::test]
+async fn test_append_markdown_to_empty_body() {
+    let server = MockServer::start_async().await;
+
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/objects/{}", TEST_SPACE_ID, TEST_OBJECT_ID));
+        then.status(200).json_body(object());
+    });
+    let patch_mock = server.mock(|when, then| {
+        when.method(PATCH)
+            .path(format!("/v1/spaces/{}/objects/{}", TEST_SPACE_ID, TEST_OBJECT_ID))
+            .json_body(serde_json::json!({ "body": "- Tuesday" }));
+        then.status(200).json_body(update_object_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    client
+        .append_markdown(TEST_SPACE_ID, TEST_OBJECT_ID, "- Tuesday")
+        .await
+        .unwrap();
+
+    patch_mock.assert();
+}
+
+#[tokio::test]
+async fn test_unset_object_property_sends_null_for_format() {
+    let server = MockServer::start_async().await;
+
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/properties", TEST_SPACE_ID));
+        then.status(200)
+            .json_body(fixtures::properties::list_properties_response());
+    });
+    let patch_mock = server.mock(|when, then| {
+        when.method(PATCH)
+            .path(format!("/v1/spaces/{}/objects/{}", TEST_SPACE_ID, TEST_OBJECT_ID))
+            .json_body(serde_json::json!({
+                "properties": [{ "key": "status", "select": null }]
+            }));
+        then.status(200).json_body(update_object_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    // Matched by name as well as key
+    let result = client
+        .unset_object_property(TEST_SPACE_ID, TEST_OBJECT_ID, "Status")
+        .await;
+
+    assert!(result.is_ok(), "Expected success, got error: {:?}", result.err());
+    patch_mock.assert();
+}
+
+#[tokio::test]
+async fn test_unset_object_property_unknown_property() {
+    let server = MockServer::start_async().await;
+
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/properties", TEST_SPACE_ID));
+        then.status(200)
+            .json_body(fixtures::properties::list_properties_response());
+    });
+    let patch_mock = server.mock(|when, then| {
+        when.method(PATCH);
+        then.status(200).json_body(update_object_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let result = client
+        .unset_object_property(TEST_SPACE_ID, TEST_OBJECT_ID, "priority")
+        .await;
+
+    assert_eq!(result.unwrap_err().kind(), "not_found");
+    patch_mock.assert_calls(0);
+}
+
+#[tokio::test]
+async fn test_objects_modified_since_filters_and_sorts() {
+    let server = MockServer::start_async().await;
+
+    let modified_object = |id: &str, date: &str| {
+        let mut obj = object();
+        obj["id"] = id.into();
+        obj["properties"] = serde_json::json!([
+            { "key": "last_modified_date", "format": "date", "date": date }
+        ]);
+        obj
+    };
+    let first_page = serde_json::json!({
+        "data": [
+            modified_object("newest", "2025-03-01T09:00:00Z"),
+            modified_object("too-old", "2024-12-31T23:59:59Z"),
+        ],
+        "pagination": { "has_more": true, "limit": 100, "offset": 0, "total": 3 }
+    });
+    let second_page = serde_json::json!({
+        "data": [modified_object("older", "2025-01-02T00:00:00+01:00"), object()],
+        "pagination": { "has_more": false, "limit": 100, "offset": 2, "total": 3 }
+    });
+
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/objects", TEST_SPACE_ID))
+            .query_param("offset", "0");
+        then.status(200).json_body(first_page);
+    });
+    let second_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/objects", TEST_SPACE_ID))
+            .query_param("offset", "2");
+        then.status(200).json_body(second_page);
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    // 2025-01-01T00:00:00Z
+    let since = std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_735_689_600);
+    let objects = client
+        .objects_modified_since(TEST_SPACE_ID, since)
+        .await
+        .unwrap();
+
+    let ids: Vec<&str> = objects.iter().map(|o| o.id.as_str()).collect();
+    assert_eq!(ids, vec!["older", "newest"]);
+    second_mock.assert();
+}
+
+#[tokio::test]
+async fn test_objects_modified_since_follows_cursor() {
+    let server = MockServer::start_async().await;
+
+    let mut modified = object();
+    modified["properties"] = serde_json::json!([
+        { "key": "last_modified_date", "format": "date", "date": "2025-03-01T09:00:00Z" }
+    ]);
+    let first_page = serde_json::json!({
+        "data": [modified.clone()],
+        "pagination": {
+            "has_more": true, "limit": 100, "offset": 0, "total": 2, "next_cursor": "c2/+=="
+        }
+    });
+    let second_page = serde_json::json!({
+        "data": [modified],
+        "pagination": { "has_more": false, "limit": 100, "offset": 0, "total": 2 }
+    });
+
+    let first_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/objects", TEST_SPACE_ID))
+            .query_param("offset", "0");
+        then.status(200).json_body(first_page);
+    });
+    // The cursor is sent instead of an offset, URL-encoded
+    let cursor_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/objects", TEST_SPACE_ID))
+            .query_param("cursor", "c2/+==")
+            .query_param_missing("offset");
+        then.status(200).json_body(second_page);
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let objects = client
+        .objects_modified_since(TEST_SPACE_ID, std::time::UNIX_EPOCH)
+        .await
+        .unwrap();
+
+    assert_eq!(objects.len(), 2);
+    first_mock.assert();
+    cursor_mock.assert();
+}
+
+#[tokio::test]
+async fn test_create_objects_reports_each_result() {
+    let server = MockServer::start_async().await;
+
+    let ok_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path(format!("/v1/spaces/{}/objects", TEST_SPACE_ID))
+            .body_includes("\"First\"");
+        then.status(200).json_body(create_object_response());
+    });
+    let error_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path(format!("/v1/spaces/{}/objects", TEST_SPACE_ID))
+            .body_includes("\"Second\"");
+        then.status(400).json_body(bad_request_error());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let requests = ["First", "Second"]
+        .into_iter()
+        .map(|name| CreateObjectRequest {
+            type_key: "page".to_string(),
+            name: Some(name.to_string()),
+            body: None,
+            icon: None,
+            template_id: None,
+            properties: None,
+        })
+        .collect();
+
+    let results = client.create_objects(TEST_SPACE_ID, requests).await;
+
+    assert_eq!(results.len(), 2);
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+    ok_mock.assert();
+    error_mock.assert();
+}
+
+#[tokio::test]
+async fn test_list_objects_page_sends_offset_and_limit() {
+    let server = MockServer::start_async().await;
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/objects", TEST_SPACE_ID))
+            .query_param("offset", "100")
+            .query_param("limit", "50");
+        then.status(200).json_body(list_objects_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let page = client
+        .list_objects_page(TEST_SPACE_ID, 100, 50)
+        .await
+        .unwrap();
+
+    assert!(!page.data.is_empty());
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_count_objects_in_spaces_reads_total() {
+    let server = MockServer::start_async().await;
+
+    let mut response = list_objects_response();
+    response["pagination"]["total"] = serde_json::json!(42);
+    let count_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/objects", TEST_SPACE_ID))
+            .query_param("limit", "1");
+        then.status(200).json_body(response);
+    });
+    let missing_mock = server.mock(|when, then| {
+        when.method(GET).path("/v1/spaces/missing/objects");
+        then.status(404).json_body(not_found_error());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let space_ids = [TEST_SPACE_ID.to_string(), "missing".to_string()];
+    let counts = client.count_objects_in_spaces(&space_ids).await;
+
+    assert_eq!(counts.len(), 2);
+    assert_eq!(counts[0].as_ref().unwrap(), &42);
+    assert!(counts[1].is_err());
+    count_mock.assert();
+    missing_mock.assert();
+}
+
+#[tokio::test]
+async fn test_create_objects_until_stopped_sends_nothing_once_stopped() {
+    let server = MockServer::start_async().await;
+
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path(format!("/v1/spaces/{}/objects", TEST_SPACE_ID));
+        then.status(200).json_body(create_object_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let requests = || {
+        ["First", "Second"]
+            .into_iter()
+            .map(|name| CreateObjectRequest::new("page").name(name))
+            .collect()
+    };
+    let stop = AtomicBool::new(false);
+
+    let results = client
+        .create_objects_until_stopped(TEST_SPACE_ID, requests(), BatchErrorPolicy::FailFast, &stop)
+        .await;
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|result| matches!(result, Some(Ok(_)))));
+
+    stop.store(true, Ordering::SeqCst);
+    let results = client
+        .create_objects_until_stopped(TEST_SPACE_ID, requests(), BatchErrorPolicy::FailFast, &stop)
+        .await;
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(Option::is_none));
+    mock.assert_calls(2);
+}
+
+#[tokio::test]
+async fn test_create_objects_respects_max_concurrency() {
+    let server = MockServer::start_async().await;
+
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path(format!("/v1/spaces/{}/objects", TEST_SPACE_ID));
+        then.status(200)
+            .delay(std::time::Duration::from_millis(200))
+            .json_body(create_object_response());
+    });
+
+    let mut client = AnytypeClient::with_config(ClientConfig {
+        base_url: server.base_url(),
+        max_concurrency: 1,
+        ..ClientConfig::default()
+    })
+    .unwrap();
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let requests = (0..3)
+        .map(|i| CreateObjectRequest {
+            type_key: "page".to_string(),
+            name: Some(format!("Object {i}")),
+            body: None,
+            icon: None,
+            template_id: None,
+            properties: None,
+        })
+        .collect();
+
+    let start = std::time::Instant::now();
+    let results = client.create_objects(TEST_SPACE_ID, requests).await;
+
+    // With a single permit the delayed requests run one after another
+    assert!(start.elapsed() >= std::time::Duration::from_millis(600));
+    assert!(results.iter().all(|result| result.is_ok()));
+    mock.assert_calls(3);
+}
+
+#[tokio::test]
+async fn test_create_objects_fail_fast_stops_at_first_error() {
+    let server = MockServer::start_async().await;
+
+    let ok_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path(format!("/v1/spaces/{}/objects", TEST_SPACE_ID))
+            .body_includes("\"First\"");
+        then.status(200).json_body(create_object_response());
+    });
+    let error_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path(format!("/v1/spaces/{}/objects", TEST_SPACE_ID))
+            .body_includes("\"Second\"");
+        then.status(400).json_body(bad_request_error());
+    });
+    let skipped_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path(format!("/v1/spaces/{}/objects", TEST_SPACE_ID))
+            .body_includes("\"Third\"");
+        then.status(200).json_body(create_object_response());
+    });
+
+    // One request at a time, so the third is only sent after the second has failed
+    let mut client = AnytypeClient::with_config(ClientConfig {
+        base_url: server.base_url(),
+        max_concurrency: 1,
+        ..ClientConfig::default()
+    })
+    .unwrap();
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let requests = ["First", "Second", "Third"]
+        .into_iter()
+        .map(|name| CreateObjectRequest::new("page").name(name))
+        .collect();
+
+    let results = client
+        .create_objects_with_policy(TEST_SPACE_ID, requests, BatchErrorPolicy::FailFast)
+        .await;
+
+    assert_eq!(results.len(), 2);
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+    ok_mock.assert();
+    error_mock.assert();
+    skipped_mock.assert_calls(0);
+}
+
+#[test]
+fn test_object_property_accessors_on_key_value_map() {
+    let obj: Object = serde_json::from_value(object()).unwrap();
+
+    assert_eq!(obj.property_str("title"), Some("My Page Title"));
+    assert_eq!(
+        obj.property("description"),
+        Some(&serde_json::json!("Page description"))
+    );
+    assert_eq!(obj.property("missing"), None);
+    assert_eq!(obj.property_bool("title"), None);
+    assert_eq!(obj.properties_iter().count(), 2);
+}
+
+#[test]
+fn test_object_property_accessors_on_api_entries() {
+    let mut value = object();
+    value["properties"] = serde_json::json!([
+        { "key": "done", "format": "checkbox", "checkbox": true },
+        { "key": "estimate", "format": "number", "number": 2.5 },
+        { "key": "status", "format": "select", "select": { "id": "tag-1", "name": "Done" } },
+        { "key": "notes", "format": "text", "text": "Ship it" }
+    ]);
+    let obj: Object = serde_json::from_value(value).unwrap();
+
+    assert_eq!(obj.property_bool("done"), Some(true));
+    assert_eq!(obj.property_number("estimate"), Some(2.5));
+    assert_eq!(obj.property_str("status"), Some("Done"));
+    assert_eq!(obj.property_str("notes"), Some("Ship it"));
+    let keys: Vec<&str> = obj.properties_iter().map(|(key, _)| key).collect();
+    assert_eq!(keys, vec!["done", "estimate", "status", "notes"]);
+}
+
+#[test]
+fn test_object_display_name_falls_back_to_snippet_then_id() {
+    let mut value = object();
+    assert_eq!(
+        serde_json::from_value::<Object>(value.clone())
+            .unwrap()
+            .display_name(),
+        "My Page"
+    );
+
+    // A blank name counts as missing; the snippet is put on one line and shortened
+    value["name"] = "  ".into();
+    value["snippet"] = "Groceries:\n  milk, eggs, bread, butter, cheese and some apples".into();
+    let obj: Object = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(obj.display_name(), "Groceries: milk, eggs, bread, butter, ch…");
+
+    value["snippet"] = serde_json::Value::Null;
+    let obj: Object = serde_json::from_value(value).unwrap();
+    assert_eq!(obj.display_name(), TEST_OBJECT_ID);
+}
+
+#[tokio::test]
+async fn test_raw_request_sends_body_verbatim() {
+    let server = MockServer::start_async().await;
+
+    let body = serde_json::json!({
+        "type_key": "page",
+        "name": "Raw Page",
+        "future_field": { "enabled": true }
+    });
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path(format!("/v1/spaces/{}/objects", TEST_SPACE_ID))
+            .header("Authorization", format!("Bearer {}", TEST_API_KEY))
+            .json_body(body.clone());
+        then.status(200).json_body(create_object_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let response = client
+        .raw_request(
+            Method::POST,
+            &format!("/v1/spaces/{}/objects", TEST_SPACE_ID),
+            Some(&body),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response["object"]["id"], "bafyreiabc456newobj");
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_add_object_relation_appends_to_existing_links() {
+    let server = MockServer::start_async().await;
+
+    let mut properties = fixtures::properties::list_properties_response();
+    properties["data"]
+        .as_array_mut()
+        .unwrap()
+        .push(serde_json::json!({
+            "id": "prop-related-789",
+            "key": "related",
+            "name": "Related",
+            "format": "objects",
+            "object": "property"
+        }));
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/properties", TEST_SPACE_ID));
+        then.status(200).json_body(properties);
+    });
+
+    let mut linked_object = object();
+    linked_object["properties"] = serde_json::json!([
+        { "key": "related", "format": "objects", "objects": ["obj-existing"] }
+    ]);
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/objects/{}", TEST_SPACE_ID, TEST_OBJECT_ID));
+        then.status(200).json_body(linked_object);
+    });
+    let patch_mock = server.mock(|when, then| {
+        when.method(PATCH)
+            .path(format!("/v1/spaces/{}/objects/{}", TEST_SPACE_ID, TEST_OBJECT_ID))
+            .json_body(serde_json::json!({
+                "properties": [{ "key": "related", "objects": ["obj-existing", "obj-target"] }]
+            }));
+        then.status(200).json_body(update_object_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let result = client
+        .add_object_relation(TEST_SPACE_ID, TEST_OBJECT_ID, "related", "obj-target")
+        .await;
+
+    assert!(result.is_ok(), "Expected success, got error: {:?}", result.err());
+    patch_mock.assert();
+}
+
+#[tokio::test]
+async fn test_add_object_relation_rejects_non_relation_property() {
+    let server = MockServer::start_async().await;
+
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/properties", TEST_SPACE_ID));
+        then.status(200)
+            .json_body(fixtures::properties::list_properties_response());
+    });
+    let patch_mock = server.mock(|when, then| {
+        when.method(PATCH);
+        then.status(200).json_body(update_object_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let result = client
+        .add_object_relation(TEST_SPACE_ID, TEST_OBJECT_ID, "status", "obj-target")
+        .await;
+
+    assert!(result.is_err());
+    patch_mock.assert_calls(0);
+}
+
+#[tokio::test]
+async fn test_list_objects_in_all_spaces() {
+    let server = MockServer::start_async().await;
+
+    server.mock(|when, then| {
+        when.method(GET).path("/v1/spaces");
+        then.status(200)
+            .json_body(fixtures::spaces::list_spaces_response());
+    });
+    let first_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/objects", TEST_SPACE_ID));
+        then.status(200).json_body(list_objects_response());
+    });
+    let second_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v1/spaces/bafyreiabc123space2/objects");
+        then.status(200).json_body(serde_json::json!({
+            "data": [object()],
+            "pagination": { "has_more": false, "limit": 100, "offset": 0, "total": 1 }
+        }));
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let spaces = client.list_objects_in_all_spaces().await.unwrap();
+
+    assert_eq!(spaces.len(), 2);
+    assert_eq!(spaces[0].0.id, TEST_SPACE_ID);
+    assert_eq!(spaces[0].1.as_ref().unwrap().len(), 2);
+    assert_eq!(spaces[1].0.id, "bafyreiabc123space2");
+    assert_eq!(spaces[1].1.as_ref().unwrap().len(), 1);
+    first_mock.assert();
+    second_mock.assert();
+}
+
+#[tokio::test]
+async fn test_diff_objects() {
+    let server = MockServer::start_async().await;
+
+    let old_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/objects/obj-old", TEST_SPACE_ID));
+        then.status(200).json_body(serde_json::json!({
+            "id": "obj-old",
+            "name": "Draft v1",
+            "markdown": "# Plan\nShip it\n",
+            "properties": { "status": "Draft", "owner": "Ana" }
+        }));
+    });
+    let new_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/objects/obj-new", TEST_SPACE_ID));
+        then.status(200).json_body(serde_json::json!({
+            "id": "obj-new",
+            "name": "Draft v2",
+            "markdown": "# Plan\nShip it today\n",
+            "properties": { "status": "Done", "due": "2026-01-01" }
+        }));
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let diff = client
+        .diff_objects(TEST_SPACE_ID, "obj-old", "obj-new")
+        .await
+        .unwrap();
+
+    old_mock.assert();
+    new_mock.assert();
+    assert!(diff.body.starts_with("--- Draft v1\n+++ Draft v2\n"));
+    assert!(diff.body.contains("-Ship it\n+Ship it today\n"));
+    assert_eq!(
+        diff.properties,
+        vec![
+            PropertyChange::Added {
+                key: "due".to_string(),
+                value: serde_json::json!("2026-01-01"),
+            },
+            PropertyChange::Removed {
+                key: "owner".to_string(),
+                value: serde_json::json!("Ana"),
+            },
+            PropertyChange::Changed {
+                key: "status".to_string(),
+                old: serde_json::json!("Draft"),
+                new: serde_json::json!("Done"),
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_create_bookmark_uses_page_title() {
+    let server = MockServer::start_async().await;
+
+    let page_mock = server.mock(|when, then| {
+        when.method(GET).path("/article");
+        then.status(200)
+            .header("content-type", "text/html")
+            .body("<html><head><TITLE>\n  Rust &amp; Anytype\n</TITLE></head></html>");
+    });
+    let page_url = server.url("/article");
+    let create_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path(format!("/v1/spaces/{}/objects", TEST_SPACE_ID))
+            .body_includes("\"type_key\":\"bookmark\"")
+            .body_includes("\"name\":\"Rust & Anytype\"")
+            .body_includes(format!("{{\"key\":\"source\",\"url\":\"{page_url}\"}}"));
+        then.status(200).json_body(create_object_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    client
+        .create_bookmark(TEST_SPACE_ID, &page_url)
+        .await
+        .unwrap();
+
+    page_mock.assert();
+    create_mock.assert();
+}
+
+#[tokio::test]
+async fn test_create_bookmark_falls_back_to_url() {
+    let server = MockServer::start_async().await;
+
+    server.mock(|when, then| {
+        when.method(GET).path("/missing");
+        then.status(404);
+    });
+    let page_url = server.url("/missing");
+    let create_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path(format!("/v1/spaces/{}/objects", TEST_SPACE_ID))
+            .body_includes(format!("\"name\":\"{page_url}\""));
+        then.status(200).json_body(create_object_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    client
+        .create_bookmark(TEST_SPACE_ID, &page_url)
+        .await
+        .unwrap();
+
+    create_mock.assert();
+}
+
+#[tokio::test]
+async fn test_get_objects_keeps_requested_order() {
+    let server = MockServer::start_async().await;
+
+    for (id, created) in [("first", "2024-01-01T00:00:00Z"), ("second", "2025-01-01T00:00:00Z")] {
+        let mut obj = object();
+        obj["id"] = id.into();
+        obj["markdown"] = format!("Body of {id}").into();
+        obj["properties"] = serde_json::json!([
+            { "key": "created_date", "format": "date", "date": created }
+        ]);
+        server.mock(|when, then| {
+            when.method(GET)
+                .path(format!("/v1/spaces/{}/objects/{}", TEST_SPACE_ID, id));
+            then.status(200).json_body(obj);
+        });
+    }
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let ids = vec!["second".to_string(), "first".to_string()];
+    let objects = client.get_objects(TEST_SPACE_ID, &ids).await.unwrap();
+
+    assert_eq!(objects[0].id, "second");
+    assert_eq!(objects[0].markdown.as_deref(), Some("Body of second"));
+    assert_eq!(objects[1].id, "first");
+    assert!(objects[1].created_at() < objects[0].created_at());
+}
+
+#[test]
+fn test_object_deep_link() {
+    let mut obj: anytype_rs::api::Object = serde_json::from_value(object()).unwrap();
+    obj.space_id = Some(TEST_SPACE_ID.to_string());
+    assert_eq!(
+        obj.deep_link("other-space"),
+        format!("anytype://object?objectId={}&spaceId={}", obj.id, TEST_SPACE_ID)
+    );
+
+    // Without its own space the given one is used
+    obj.space_id = None;
+    assert_eq!(
+        obj.deep_link("other-space"),
+        format!("anytype://object?objectId={}&spaceId=other-space", obj.id)
+    );
+}
+
+#[tokio::test]
+async fn test_update_objects_reports_each_result() {
+    let server = MockServer::start_async().await;
+
+    let ok_mock = server.mock(|when, then| {
+        when.method(PATCH)
+            .path(format!("/v1/spaces/{}/objects/{}", TEST_SPACE_ID, TEST_OBJECT_ID))
+            .body_includes("\"archived_reason\"");
+        then.status(200).json_body(update_object_response());
+    });
+    server.mock(|when, then| {
+        when.method(PATCH)
+            .path(format!("/v1/spaces/{}/objects/missing", TEST_SPACE_ID));
+        then.status(404).json_body(not_found_error());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let request = UpdateObjectRequest {
+        name: None,
+        body: None,
+        properties: Some(vec![
+            serde_json::json!({ "key": "archived_reason", "text": "cleanup" }),
+        ]),
+    };
+    let results = client
+        .update_objects(
+            TEST_SPACE_ID,
+            vec![
+                (TEST_OBJECT_ID.to_string(), request.clone()),
+                ("missing".to_string(), request),
+            ],
+        )
+        .await;
+
+    assert_eq!(results.len(), 2);
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+    ok_mock.assert();
+}
+
+#[tokio::test]
+async fn test_validate_object_update_lists_invalid_keys() {
+    let server = MockServer::start_async().await;
+
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/objects/{}", TEST_SPACE_ID, TEST_OBJECT_ID));
+        then.status(200).json_body(object());
+    });
+    let types_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/types", TEST_SPACE_ID));
+        then.status(200)
+            .json_body(fixtures::types::list_types_response());
+    });
+    let type_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/types/ot-page", TEST_SPACE_ID));
+        then.status(200)
+            .json_body(fixtures::types::get_type_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let request = |properties: Vec<serde_json::Value>| UpdateObjectRequest {
+        name: None,
+        body: None,
+        properties: Some(properties),
+    };
+
+    // The object is a page, whose type has text `title` and `description` properties
+    let valid = request(vec![serde_json::json!({"key": "description", "text": "Updated"})]);
+    client
+        .validate_object_update(TEST_SPACE_ID, TEST_OBJECT_ID, &valid)
+        .await
+        .expect("Properties of the type should validate");
+
+    let invalid = request(vec![
+        serde_json::json!({"key": "title", "text": "Fine"}),
+        serde_json::json!({"key": "descripton", "text": "Typo"}),
+        serde_json::json!({"key": "description", "date": "2024-01-01T00:00:00Z"}),
+    ]);
+    let err = client
+        .validate_object_update(TEST_SPACE_ID, TEST_OBJECT_ID, &invalid)
+        .await
+        .unwrap_err();
+    match err {
+        AnytypeError::Config { message } => {
+            assert!(message.contains("'descripton' (not a property of the type)"), "{message}");
+            assert!(message.contains("'description' (expects text, got date)"), "{message}");
+            assert!(!message.contains("'title'"), "{message}");
+        }
+        other => panic!("Expected a Config error, got {other:?}"),
+    }
+
+    // Types are listed once per space, then fetched by their cached ID
+    types_mock.assert_calls(1);
+    type_mock.assert_calls(1);
+}
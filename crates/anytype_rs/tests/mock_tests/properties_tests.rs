@@ -3,6 +3,7 @@
 use super::*;
 use anytype_rs::api::client::properties::{CreatePropertyRequest, UpdatePropertyRequest};
 use anytype_rs::api::client::types::PropertyFormat;
+use anytype_rs::api::AnytypeError;
 use fixtures::errors::*;
 use fixtures::properties::*;
 use httpmock::prelude::*;
@@ -276,3 +277,315 @@ async fn test_delete_property_not_found() {
     assert!(result.is_err());
     mock.assert();
 }
+
+#[tokio::test]
+async fn test_migrate_property_format_text_to_select() {
+    let server = MockServer::start_async().await;
+
+    let colored = |id: &str, color: Option<&str>| {
+        let mut obj = fixtures::objects::object();
+        obj["id"] = id.into();
+        obj["properties"] = match color {
+            Some(color) => serde_json::json!([
+                { "key": "custom-field", "format": "text", "text": color }
+            ]),
+            None => serde_json::json!([]),
+        };
+        obj
+    };
+    let objects = serde_json::json!({
+        "data": [
+            colored("obj-a", Some("Red")),
+            colored("obj-b", Some("Blue")),
+            colored("obj-c", Some("Red")),
+            colored("obj-d", None),
+        ],
+        "pagination": { "has_more": false, "limit": 100, "offset": 0, "total": 4 }
+    });
+    let new_property = serde_json::json!({
+        "id": "prop-new-789",
+        "key": "custom_field_2",
+        "name": "Custom Field",
+        "format": "select",
+        "object": "property"
+    });
+    let properties = format!("/v1/spaces/{}/properties", TEST_SPACE_ID);
+    let mut page_type = fixtures::types::type_obj();
+    page_type["properties"]
+        .as_array_mut()
+        .unwrap()
+        .push(fixtures::properties::property());
+    let mut types = fixtures::types::list_types_response();
+    types["data"][0] = page_type.clone();
+
+    server.mock(|when, then| {
+        when.method(GET).path(format!("{properties}/prop-custom-123"));
+        then.status(200).json_body(get_property_response());
+    });
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/objects", TEST_SPACE_ID));
+        then.status(200).json_body(objects);
+    });
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/types", TEST_SPACE_ID));
+        then.status(200).json_body(types);
+    });
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/types/ot-page", TEST_SPACE_ID));
+        then.status(200)
+            .json_body(serde_json::json!({ "type": page_type }));
+    });
+    let type_mock = server.mock(|when, then| {
+        when.method(PATCH)
+            .path(format!("/v1/spaces/{}/types/ot-page", TEST_SPACE_ID))
+            .body_includes(
+                r#"{"format":"text","key":"description","name":"Description"},{"format":"select","key":"custom-field","name":"Custom Field"}]"#,
+            );
+        then.status(200)
+            .json_body(fixtures::types::update_type_response());
+    });
+    let create_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path(&properties)
+            .body_includes(r#""format":"select""#);
+        then.status(200)
+            .json_body(serde_json::json!({ "property": new_property }));
+    });
+    let tag_mocks: Vec<_> = ["Red", "Blue"]
+        .into_iter()
+        .map(|name| {
+            server.mock(|when, then| {
+                when.method(POST)
+                    .path(format!("{properties}/prop-new-789/tags"))
+                    .body_includes(format!(r#""name":"{name}""#));
+                then.status(200).json_body(serde_json::json!({
+                    "tag": { "id": format!("tag-{name}"), "key": name, "name": name, "color": null }
+                }));
+            })
+        })
+        .collect();
+    let update_mocks: Vec<_> = [("obj-a", "Red"), ("obj-b", "Blue"), ("obj-c", "Red")]
+        .into_iter()
+        .map(|(id, tag)| {
+            server.mock(|when, then| {
+                when.method(PATCH)
+                    .path(format!("/v1/spaces/{}/objects/{id}", TEST_SPACE_ID))
+                    .body_includes(format!(
+                        r#""properties":[{{"key":"custom_field_2","select":"tag-{tag}"}}]"#
+                    ));
+                then.status(200).json_body(serde_json::json!({
+                    "object": fixtures::objects::object()
+                }));
+            })
+        })
+        .collect();
+    let delete_mock = server.mock(|when, then| {
+        when.method(DELETE)
+            .path(format!("{properties}/prop-custom-123"));
+        then.status(200).json_body(get_property_response());
+    });
+    let rekey_mock = server.mock(|when, then| {
+        when.method(PATCH)
+            .path(format!("{properties}/prop-new-789"))
+            .body_includes(r#""key":"custom-field""#);
+        then.status(200).json_body(serde_json::json!({
+            "property": {
+                "id": "prop-new-789",
+                "key": "custom-field",
+                "name": "Custom Field",
+                "format": "select",
+                "object": "property"
+            }
+        }));
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let plan = client
+        .plan_property_migration(TEST_SPACE_ID, "prop-custom-123", PropertyFormat::Select)
+        .await
+        .unwrap();
+    assert_eq!(plan.tags, vec!["Red", "Blue"]);
+    let ids: Vec<&str> = plan.objects.iter().map(|o| o.id.as_str()).collect();
+    assert_eq!(ids, vec!["obj-a", "obj-b", "obj-c"]);
+    let type_ids: Vec<&str> = plan.types.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(type_ids, vec!["ot-page"]);
+    // Planning changes nothing
+    create_mock.assert_calls(0);
+
+    let property = client
+        .migrate_property_format(TEST_SPACE_ID, "prop-custom-123", PropertyFormat::Select)
+        .await
+        .unwrap();
+
+    assert_eq!(property.id, "prop-new-789");
+    assert_eq!(property.key, "custom-field");
+    create_mock.assert_calls(1);
+    for mock in tag_mocks.iter().chain(&update_mocks) {
+        mock.assert_calls(1);
+    }
+    delete_mock.assert_calls(1);
+    rekey_mock.assert_calls(1);
+    type_mock.assert_calls(1);
+}
+
+#[tokio::test]
+async fn test_migrate_property_format_cleans_up_on_failure() {
+    let server = MockServer::start_async().await;
+
+    let mut obj = fixtures::objects::object();
+    obj["properties"] = serde_json::json!([
+        { "key": "custom-field", "format": "text", "text": "Red" }
+    ]);
+    let properties = format!("/v1/spaces/{}/properties", TEST_SPACE_ID);
+
+    server.mock(|when, then| {
+        when.method(GET).path(format!("{properties}/prop-custom-123"));
+        then.status(200).json_body(get_property_response());
+    });
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/objects", TEST_SPACE_ID));
+        then.status(200).json_body(serde_json::json!({
+            "data": [obj],
+            "pagination": { "has_more": false, "limit": 100, "offset": 0, "total": 1 }
+        }));
+    });
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/types", TEST_SPACE_ID));
+        then.status(200)
+            .json_body(fixtures::types::list_types_response());
+    });
+    server.mock(|when, then| {
+        when.method(POST).path(&properties);
+        then.status(200).json_body(serde_json::json!({
+            "property": {
+                "id": "prop-new-789",
+                "key": "custom_field_2",
+                "name": "Custom Field",
+                "format": "select",
+                "object": "property"
+            }
+        }));
+    });
+    server.mock(|when, then| {
+        when.method(POST)
+            .path(format!("{properties}/prop-new-789/tags"));
+        then.status(200).json_body(serde_json::json!({
+            "tag": { "id": "tag-red", "key": "red", "name": "Red", "color": null }
+        }));
+    });
+    server.mock(|when, then| {
+        when.method(PATCH).path_includes("/objects/");
+        then.status(400).json_body(bad_request_error());
+    });
+    let delete_tag_mock = server.mock(|when, then| {
+        when.method(DELETE)
+            .path(format!("{properties}/prop-new-789/tags/tag-red"));
+        then.status(200).json_body(fixtures::tags::delete_tag_response());
+    });
+    let delete_new_mock = server.mock(|when, then| {
+        when.method(DELETE)
+            .path(format!("{properties}/prop-new-789"));
+        then.status(200).json_body(delete_property_response());
+    });
+    let delete_old_mock = server.mock(|when, then| {
+        when.method(DELETE)
+            .path(format!("{properties}/prop-custom-123"));
+        then.status(200).json_body(delete_property_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let result = client
+        .migrate_property_format(TEST_SPACE_ID, "prop-custom-123", PropertyFormat::Select)
+        .await;
+
+    assert!(matches!(result, Err(AnytypeError::Api { .. })));
+    delete_tag_mock.assert_calls(1);
+    delete_new_mock.assert_calls(1);
+    delete_old_mock.assert_calls(0);
+}
+
+#[tokio::test]
+async fn test_migrate_property_format_fails_when_key_is_not_restored() {
+    let server = MockServer::start_async().await;
+
+    let properties = format!("/v1/spaces/{}/properties", TEST_SPACE_ID);
+    server.mock(|when, then| {
+        when.method(GET).path(format!("{properties}/prop-custom-123"));
+        then.status(200).json_body(get_property_response());
+    });
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/objects", TEST_SPACE_ID));
+        then.status(200).json_body(serde_json::json!({
+            "data": [],
+            "pagination": { "has_more": false, "limit": 100, "offset": 0, "total": 0 }
+        }));
+    });
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/types", TEST_SPACE_ID));
+        then.status(200)
+            .json_body(fixtures::types::list_types_response());
+    });
+    server.mock(|when, then| {
+        when.method(POST).path(&properties);
+        then.status(200).json_body(serde_json::json!({
+            "property": {
+                "id": "prop-new-789",
+                "key": "custom_field_2",
+                "name": "Custom Field",
+                "format": "url",
+                "object": "property"
+            }
+        }));
+    });
+    server.mock(|when, then| {
+        when.method(DELETE)
+            .path(format!("{properties}/prop-custom-123"));
+        then.status(200).json_body(delete_property_response());
+    });
+    server.mock(|when, then| {
+        when.method(PATCH)
+            .path(format!("{properties}/prop-new-789"));
+        then.status(400).json_body(bad_request_error());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let result = client
+        .migrate_property_format(TEST_SPACE_ID, "prop-custom-123", PropertyFormat::Url)
+        .await;
+
+    assert!(matches!(result, Err(AnytypeError::Api { .. })));
+}
+
+#[tokio::test]
+async fn test_plan_property_migration_rejects_unsupported_formats() {
+    let server = MockServer::start_async().await;
+
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/properties/prop-custom-123", TEST_SPACE_ID));
+        then.status(200).json_body(get_property_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    for format in [PropertyFormat::Text, PropertyFormat::Number] {
+        let result = client
+            .plan_property_migration(TEST_SPACE_ID, "prop-custom-123", format)
+            .await;
+        assert!(matches!(result, Err(AnytypeError::Config { .. })));
+    }
+}
@@ -1,7 +1,9 @@
 //! Mock tests for search endpoints
 
 use super::*;
+use anytype_rs::api::SyncToken;
 use anytype_rs::api::client::search::{SearchRequest, SearchSpaceRequest};
+use anytype_rs::api::{AnytypeClient, ClientConfig};
 use fixtures::errors::*;
 use fixtures::search::*;
 use httpmock::prelude::*;
@@ -30,6 +32,7 @@ async fn test_search_success() {
         offset: Some(0),
         space_id: None,
         sort: None,
+        include_archived: false,
     };
 
     let result = client.search(request).await;
@@ -42,6 +45,43 @@ async fn test_search_success() {
     mock.assert();
 }
 
+#[tokio::test]
+async fn test_search_tolerates_missing_optional_fields() {
+    let server = MockServer::start_async().await;
+
+    server.mock(|when, then| {
+        when.method(POST).path("/v1/search");
+        then.status(200).json_body(serde_json::json!({
+            "data": [{
+                "id": "obj-1",
+                "name": "Sparse Result",
+                "space_id": TEST_SPACE_ID,
+                "unexpected": true
+            }],
+            "pagination": { "has_more": false, "limit": 50, "offset": 0, "total": 1 }
+        }));
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let request = SearchRequest {
+        query: Some("sparse".to_string()),
+        limit: None,
+        offset: None,
+        space_id: None,
+        sort: None,
+        include_archived: false,
+    };
+
+    let response = client.search(request).await.unwrap();
+
+    let result = &response.data[0];
+    assert_eq!(result.name, "Sparse Result");
+    assert!(!result.archived);
+    assert!(result.snippet.is_empty());
+}
+
 #[tokio::test]
 async fn test_search_unauthorized() {
     let server = MockServer::start_async().await;
@@ -64,6 +104,7 @@ async fn test_search_unauthorized() {
         offset: None,
         space_id: None,
         sort: None,
+        include_archived: false,
     };
 
     let result = client.search(request).await;
@@ -95,6 +136,7 @@ async fn test_search_space_success() {
         limit: Some(50),
         offset: Some(0),
         sort: None,
+        include_archived: false,
     };
 
     let result = client.search_space(TEST_SPACE_ID, request).await;
@@ -128,6 +170,7 @@ async fn test_search_space_unauthorized() {
         limit: None,
         offset: None,
         sort: None,
+        include_archived: false,
     };
 
     let result = client.search_space(TEST_SPACE_ID, request).await;
@@ -135,3 +178,291 @@ async fn test_search_space_unauthorized() {
     assert!(result.is_err());
     mock.assert();
 }
+
+#[tokio::test]
+async fn test_search_space_excludes_archived_by_default() {
+    let server = MockServer::start_async().await;
+
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path(format!("/v1/spaces/{}/search", TEST_SPACE_ID));
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(search_response_with_archived());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let request = |include_archived| SearchSpaceRequest {
+        query: Some("test".to_string()),
+        limit: None,
+        offset: None,
+        sort: None,
+        include_archived,
+    };
+
+    let active = client
+        .search_space(TEST_SPACE_ID, request(false))
+        .await
+        .unwrap();
+    assert_eq!(active.data.len(), 1);
+    assert_eq!(active.data[0].name, "Test Page");
+
+    let all = client
+        .search_space(TEST_SPACE_ID, request(true))
+        .await
+        .unwrap();
+    assert_eq!(all.data.len(), 2);
+    assert!(all.data[1].archived);
+
+    mock.assert_calls(2);
+}
+
+#[tokio::test]
+async fn test_search_cache_reuses_results_for_same_query() {
+    let server = MockServer::start_async().await;
+
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path(format!("/v1/spaces/{}/search", TEST_SPACE_ID));
+        then.status(200).json_body(search_response());
+    });
+
+    let mut client = AnytypeClient::with_config(ClientConfig {
+        base_url: server.base_url(),
+        search_cache_ttl_seconds: 60,
+        ..ClientConfig::default()
+    })
+    .unwrap();
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let request = |query: &str| SearchSpaceRequest {
+        query: Some(query.to_string()),
+        limit: Some(10),
+        offset: None,
+        sort: None,
+        include_archived: false,
+    };
+
+    // Queries differing only in case and whitespace share a cache entry
+    let first = client
+        .search_space(TEST_SPACE_ID, request("Test  page"))
+        .await
+        .unwrap();
+    let second = client
+        .search_space(TEST_SPACE_ID, request(" test page "))
+        .await
+        .unwrap();
+    assert_eq!(first, second);
+    mock.assert_calls(1);
+
+    client
+        .search_space(TEST_SPACE_ID, request("other"))
+        .await
+        .unwrap();
+    mock.assert_calls(2);
+
+    client.clear_search_cache();
+    client
+        .search_space(TEST_SPACE_ID, request("test page"))
+        .await
+        .unwrap();
+    mock.assert_calls(3);
+}
+
+#[tokio::test]
+async fn test_search_cache_disabled_by_default() {
+    let server = MockServer::start_async().await;
+
+    let mock = server.mock(|when, then| {
+        when.method(POST).path("/v1/search");
+        then.status(200).json_body(search_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    for _ in 0..2 {
+        let request = SearchRequest {
+            query: Some("test".to_string()),
+            limit: None,
+            offset: None,
+            space_id: None,
+            sort: None,
+            include_archived: false,
+        };
+        client.search(request).await.unwrap();
+    }
+
+    mock.assert_calls(2);
+}
+
+#[tokio::test]
+async fn test_search_sends_sort_for_each_order() {
+    use anytype_rs::api::SearchOrder;
+
+    let cases = [
+        (SearchOrder::Relevance, r#""sort":null"#),
+        (
+            SearchOrder::Recent,
+            r#""sort":{"direction":"desc","property_key":"last_modified_date"}"#,
+        ),
+        (
+            SearchOrder::Name,
+            r#""sort":{"direction":"asc","property_key":"name"}"#,
+        ),
+    ];
+
+    for (order, expected_sort) in cases {
+        let server = MockServer::start_async().await;
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/v1/search")
+                .body_includes(expected_sort);
+            then.status(200).json_body(search_response());
+        });
+
+        let mut client = create_test_client(&server.base_url());
+        client.set_api_key(TEST_API_KEY.to_string());
+
+        let request = SearchRequest {
+            query: Some("test".to_string()),
+            limit: Some(10),
+            offset: Some(0),
+            space_id: None,
+            sort: order.sort(),
+            include_archived: false,
+        };
+        client.search(request).await.unwrap();
+
+        mock.assert();
+    }
+}
+
+#[tokio::test]
+async fn test_search_debouncer_sends_only_latest_query() {
+    use anytype_rs::api::SearchDebouncer;
+    use std::{sync::Arc, time::Duration};
+
+    let server = MockServer::start_async().await;
+
+    let latest_mock = server.mock(|when, then| {
+        when.method(POST).path("/v1/search").body_includes("\"any\"");
+        then.status(200).json_body(search_response());
+    });
+    let any_mock = server.mock(|when, then| {
+        when.method(POST).path("/v1/search");
+        then.status(200).json_body(search_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+    let debouncer = SearchDebouncer::new(Arc::new(client), Duration::from_millis(50));
+
+    let request = |query: &str| SearchRequest {
+        query: Some(query.to_string()),
+        limit: None,
+        offset: None,
+        space_id: None,
+        sort: None,
+        include_archived: false,
+    };
+    let (first, second, last) = tokio::join!(
+        debouncer.search(request("a")),
+        debouncer.search(request("an")),
+        debouncer.search(request("any")),
+    );
+
+    assert!(first.is_none());
+    assert!(second.is_none());
+    assert!(last.unwrap().is_ok());
+    latest_mock.assert();
+    any_mock.assert_calls(0);
+}
+
+#[tokio::test]
+async fn test_find_object_by_name_matches_name_and_type() {
+    let server = MockServer::start_async().await;
+
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path(format!("/v1/spaces/{}/search", TEST_SPACE_ID))
+            .body_includes("\"query\":\"Test Note\"");
+        then.status(200).json_body(search_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let found = client
+        .find_object_by_name(TEST_SPACE_ID, "Test Note", "ot-note")
+        .await
+        .unwrap();
+    assert_eq!(found.unwrap().id, "bafyreiabc789note");
+
+    // Same name but another type
+    let other_type = client
+        .find_object_by_name(TEST_SPACE_ID, "Test Note", "ot-page")
+        .await
+        .unwrap();
+    assert!(other_type.is_none());
+    mock.assert_calls(2);
+}
+
+#[tokio::test]
+async fn test_changes_since_reports_changes_after_token() {
+    let server = MockServer::start_async().await;
+
+    let changed_object = |id: &str, created: &str, modified: &str, archived: bool| {
+        let mut obj = search_object();
+        obj["id"] = id.into();
+        obj["archived"] = archived.into();
+        obj["properties"] = serde_json::json!([
+            { "key": "created_date", "format": "date", "date": created },
+            { "key": "last_modified_date", "format": "date", "date": modified }
+        ]);
+        obj
+    };
+    // Most recently modified first, as requested
+    let page = serde_json::json!({
+        "data": [
+            changed_object("gone", "2025-01-01T00:00:00Z", "2025-03-01T00:00:00Z", true),
+            changed_object("new", "2025-02-10T00:00:00Z", "2025-02-20T00:00:00Z", false),
+            changed_object("edited", "2025-01-01T00:00:00Z", "2025-02-01T00:00:00Z", false),
+            changed_object("seen", "2025-01-01T00:00:00Z", "2025-02-01T00:00:00Z", false),
+            changed_object("old", "2025-01-01T00:00:00Z", "2025-01-15T00:00:00Z", false),
+        ],
+        "pagination": { "has_more": true, "limit": 100, "offset": 0, "total": 200 }
+    });
+
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path(format!("/v1/spaces/{}/search", TEST_SPACE_ID))
+            .body_includes(r#""property_key":"last_modified_date""#)
+            .body_includes(r#""offset":0"#);
+        then.status(200).json_body(page);
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let token = SyncToken::parse("2025-02-01T00:00:00Z/seen").unwrap();
+    let (changes, next) = client
+        .changes_since(TEST_SPACE_ID, Some(&token))
+        .await
+        .unwrap();
+
+    let summary: Vec<(&str, &str)> = changes
+        .iter()
+        .map(|change| (change.kind(), change.object().id.as_str()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![("updated", "edited"), ("created", "new"), ("deleted", "gone")]
+    );
+    assert_eq!(next.to_string(), "2025-03-01T00:00:00Z/gone");
+    assert_eq!(SyncToken::parse(&next.to_string()).unwrap(), next);
+    // Paging stops at the first object older than the token
+    mock.assert_calls(1);
+}
@@ -1,7 +1,7 @@
 //! Mock tests for spaces endpoints
 
 use super::*;
-use anytype_rs::api::{CreateSpaceRequest, UpdateSpaceRequest};
+use anytype_rs::api::{CreateSpaceRequest, ObjectKind, SpaceSort, UpdateSpaceRequest};
 use fixtures::errors::*;
 use fixtures::spaces::*;
 use httpmock::prelude::*;
@@ -33,6 +33,61 @@ async fn test_list_spaces_success() {
     mock.assert();
 }
 
+#[tokio::test]
+async fn test_list_spaces_deserializes_all_fields() {
+    let server = MockServer::start_async().await;
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/v1/spaces");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(list_spaces_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let spaces = client.list_spaces().await.unwrap();
+    let other = &spaces[1];
+    assert_eq!(other.id, "bafyreiabc123space2");
+    assert_eq!(other.object, Some(ObjectKind::Space));
+    assert_eq!(other.description, None);
+    assert_eq!(other.gateway_url, None);
+    assert_eq!(other.network_id.as_deref(), Some("network-123"));
+    assert_eq!(other.icon, Some(serde_json::json!({ "emoji": "📚" })));
+
+    // Serializing for `space list --json` keeps every field, including nulls
+    let json = serde_json::to_value(other).unwrap();
+    assert_eq!(json, list_spaces_response()["data"][1]);
+
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_list_spaces_sorted_by_name() {
+    let server = MockServer::start_async().await;
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/v1/spaces");
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(list_spaces_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let response = client.list_spaces_sorted(SpaceSort::Name).await.unwrap();
+    let names: Vec<&str> = response.data.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, ["Another Space", "My Space"]);
+    assert_eq!(response.pagination.total, 2);
+
+    let response = client.list_spaces_sorted(SpaceSort::Server).await.unwrap();
+    assert_eq!(response.data[0].name, "My Space");
+
+    mock.assert_calls(2);
+}
+
 #[tokio::test]
 async fn test_list_spaces_unauthorized() {
     let server = MockServer::start_async().await;
@@ -52,6 +107,7 @@ async fn test_list_spaces_unauthorized() {
     let result = client.list_spaces().await;
 
     assert!(result.is_err());
+    assert_eq!(result.as_ref().unwrap_err().kind(), "auth");
     mock.assert();
 }
 
@@ -102,6 +158,7 @@ async fn test_get_space_not_found() {
     let result = client.get_space("nonexistent").await;
 
     assert!(result.is_err());
+    assert_eq!(result.as_ref().unwrap_err().kind(), "not_found");
     mock.assert();
 }
 
@@ -224,3 +281,253 @@ async fn test_update_space_not_found() {
     assert!(result.is_err());
     mock.assert();
 }
+
+#[tokio::test]
+async fn test_list_spaces_non_json_error_body() {
+    let server = MockServer::start_async().await;
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v1/spaces")
+            .header("Authorization", format!("Bearer {}", TEST_API_KEY))
+            .header("Anytype-Version", API_VERSION);
+        then.status(502)
+            .header("content-type", "text/html")
+            .body("<html><body>Bad Gateway</body></html>");
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let result = client.list_spaces().await;
+
+    match result {
+        Err(err @ anytype_rs::api::AnytypeError::Api { .. }) => {
+            assert_eq!(err.kind(), "api");
+            let message = err.to_string();
+            assert!(message.contains("502"));
+            assert!(message.contains("Bad Gateway"));
+        }
+        other => panic!("Expected API error, got: {:?}", other),
+    }
+
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_list_spaces_timeout() {
+    let server = MockServer::start_async().await;
+
+    server.mock(|when, then| {
+        when.method(GET).path("/v1/spaces");
+        then.status(200)
+            .header("content-type", "application/json")
+            .delay(std::time::Duration::from_secs(3))
+            .json_body(list_spaces_response());
+    });
+
+    let mut client = AnytypeClient::with_config(ClientConfig {
+        base_url: server.base_url(),
+        timeout_seconds: 1,
+        app_name: "test-app".to_string(),
+        ..ClientConfig::default()
+    })
+    .expect("Failed to create test client");
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let result = client.list_spaces().await;
+
+    match result {
+        Err(err @ anytype_rs::api::AnytypeError::Timeout { duration }) => {
+            assert_eq!(duration.as_secs(), 1);
+            assert!(err.is_timeout());
+            assert_eq!(err.kind(), "timeout");
+        }
+        other => panic!("Expected timeout error, got: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_list_spaces_connection_refused() {
+    let base_url = unused_base_url();
+
+    let mut client = create_test_client(&base_url);
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let result = client.list_spaces().await;
+
+    match result {
+        Err(anytype_rs::api::AnytypeError::Connection { message }) => {
+            assert!(message.contains(&base_url));
+            assert!(message.contains("Is the desktop app running"));
+        }
+        other => panic!("Expected connection error, got: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_circuit_breaker_fails_fast_after_connection_failures() {
+    let base_url = unused_base_url();
+
+    let config = ClientConfig {
+        base_url: base_url.clone(),
+        timeout_seconds: 1,
+        circuit_breaker_threshold: 2,
+        circuit_breaker_cooldown_seconds: 60,
+        ..ClientConfig::default()
+    };
+    let mut client = AnytypeClient::with_config(config).unwrap();
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    for _ in 0..2 {
+        assert!(matches!(
+            client.list_spaces().await,
+            Err(anytype_rs::api::AnytypeError::Connection { .. })
+        ));
+    }
+
+    // Something now listens on the port, but the open circuit must not connect to it
+    let listener = std::net::TcpListener::bind(base_url.trim_start_matches("http://")).unwrap();
+    listener.set_nonblocking(true).unwrap();
+
+    let result = client.list_spaces().await;
+
+    assert!(matches!(
+        result,
+        Err(anytype_rs::api::AnytypeError::Connection { .. })
+    ));
+    assert!(listener.accept().is_err(), "Request should not have been sent");
+}
+
+#[tokio::test]
+async fn test_circuit_breaker_lets_one_trial_request_through_after_cooldown() {
+    let base_url = unused_base_url();
+
+    let config = ClientConfig {
+        base_url: base_url.clone(),
+        timeout_seconds: 1,
+        circuit_breaker_threshold: 1,
+        // The cooldown is over as soon as the circuit opens
+        circuit_breaker_cooldown_seconds: 0,
+        ..ClientConfig::default()
+    };
+    let mut client = AnytypeClient::with_config(config).unwrap();
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    assert!(matches!(
+        client.list_spaces().await,
+        Err(anytype_rs::api::AnytypeError::Connection { .. })
+    ));
+
+    // A listener that never answers keeps the trial request in flight until it times out
+    let listener = std::net::TcpListener::bind(base_url.trim_start_matches("http://")).unwrap();
+    listener.set_nonblocking(true).unwrap();
+
+    let results = futures_util::future::join_all((0..5).map(|_| client.list_spaces())).await;
+
+    let timeouts = results
+        .iter()
+        .filter(|result| matches!(result, Err(anytype_rs::api::AnytypeError::Timeout { .. })))
+        .count();
+    let refused = results
+        .iter()
+        .filter(|result| matches!(result, Err(anytype_rs::api::AnytypeError::Connection { .. })))
+        .count();
+    assert_eq!(timeouts, 1, "Only the trial request should have been sent");
+    assert_eq!(refused, 4);
+    assert!(listener.accept().is_ok());
+    assert!(listener.accept().is_err(), "Only one connection should have been made");
+}
+
+#[tokio::test]
+async fn test_circuit_breaker_admits_a_request_after_the_trial_is_cancelled() {
+    let base_url = unused_base_url();
+
+    let config = ClientConfig {
+        base_url: base_url.clone(),
+        timeout_seconds: 1,
+        circuit_breaker_threshold: 1,
+        // The cooldown is over as soon as the circuit opens
+        circuit_breaker_cooldown_seconds: 0,
+        ..ClientConfig::default()
+    };
+    let mut client = AnytypeClient::with_config(config).unwrap();
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    assert!(matches!(
+        client.list_spaces().await,
+        Err(anytype_rs::api::AnytypeError::Connection { .. })
+    ));
+
+    // A listener that never answers keeps the trial request in flight until it is dropped
+    let listener = std::net::TcpListener::bind(base_url.trim_start_matches("http://")).unwrap();
+    listener.set_nonblocking(true).unwrap();
+
+    let trial =
+        tokio::time::timeout(std::time::Duration::from_millis(200), client.list_spaces()).await;
+    assert!(trial.is_err(), "The trial request should have been cancelled");
+
+    let result = client.list_spaces().await;
+
+    assert!(
+        matches!(result, Err(anytype_rs::api::AnytypeError::Timeout { .. })),
+        "The next request should have been sent, got: {result:?}"
+    );
+    assert!(listener.accept().is_ok());
+    assert!(listener.accept().is_ok(), "The next request should have connected");
+}
+
+#[tokio::test]
+async fn test_server_version_reads_response_header() {
+    let server = MockServer::start_async().await;
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v1/spaces")
+            .query_param("limit", "1")
+            .header("Authorization", format!("Bearer {}", TEST_API_KEY));
+        then.status(200)
+            .header("content-type", "application/json")
+            .header("Anytype-Version", "2025-11-08")
+            .json_body(list_spaces_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let version = client.server_version().await.unwrap();
+
+    assert_eq!(version.as_deref(), Some("2025-11-08"));
+    assert_eq!(anytype_rs::api::ANYTYPE_API_VERSION, API_VERSION);
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_server_version_missing_header() {
+    let server = MockServer::start_async().await;
+
+    server.mock(|when, then| {
+        when.method(GET).path("/v1/spaces");
+        then.status(401)
+            .header("content-type", "application/json")
+            .json_body(unauthorized_error());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    // The status doesn't matter, only whether the header was sent
+    assert_eq!(client.server_version().await.unwrap(), None);
+}
+
+#[test]
+fn test_space_gateway_object_url() {
+    let mut space: anytype_rs::api::Space = serde_json::from_value(space()).unwrap();
+    assert_eq!(space.gateway_object_url(TEST_OBJECT_ID), None);
+
+    space.gateway_url = Some("http://127.0.0.1:47800/".to_string());
+    assert_eq!(
+        space.gateway_object_url(TEST_OBJECT_ID).as_deref(),
+        Some("http://127.0.0.1:47800/file/bafyreiabc456object")
+    );
+}
@@ -274,3 +274,97 @@ async fn test_delete_tag_not_found() {
     assert!(result.is_err());
     mock.assert();
 }
+
+#[tokio::test]
+async fn test_list_tags_preserves_server_order() {
+    let server = MockServer::start_async().await;
+
+    let names = ["Todo", "Doing", "Review", "Done"];
+    let data: Vec<_> = names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            serde_json::json!({
+                "id": format!("tag-{i}"),
+                "key": name.to_lowercase(),
+                "name": name,
+                "color": "grey",
+                "object": "tag"
+            })
+        })
+        .collect();
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/properties/{}/tags", TEST_SPACE_ID, TEST_PROPERTY_ID));
+        then.status(200).json_body(serde_json::json!({
+            "data": data,
+            "pagination": { "has_more": false, "limit": 50, "offset": 0, "total": 4 }
+        }));
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let tags = client.list_tags(TEST_SPACE_ID, TEST_PROPERTY_ID).await.unwrap();
+
+    let listed: Vec<&str> = tags.iter().map(|tag| tag.name.as_str()).collect();
+    assert_eq!(listed, names);
+
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_list_type_tags_groups_by_select_property() {
+    let server = MockServer::start_async().await;
+
+    let mut type_response = fixtures::types::get_type_response();
+    type_response["type"]["properties"] = serde_json::json!([
+        { "id": "prop-title", "key": "title", "name": "Title", "format": "text", "object": "property" },
+        { "id": TEST_PROPERTY_ID, "key": "status", "name": "Status", "format": "select", "object": "property" },
+        { "id": "prop-labels", "key": "labels", "name": "Labels", "format": "multi_select", "object": "property" }
+    ]);
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/types/{}", TEST_SPACE_ID, TEST_TYPE_ID));
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(type_response);
+    });
+    let status_tags = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/properties/{}/tags", TEST_SPACE_ID, TEST_PROPERTY_ID));
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(list_tags_response());
+    });
+    let label_tags = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/properties/prop-labels/tags", TEST_SPACE_ID));
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "data": [],
+                "pagination": { "has_more": false, "limit": 50, "offset": 0, "total": 0 }
+            }));
+    });
+    let text_tags = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/properties/prop-title/tags", TEST_SPACE_ID));
+        then.status(200);
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let grouped = client.list_type_tags(TEST_SPACE_ID, TEST_TYPE_ID).await.unwrap();
+
+    assert_eq!(grouped.len(), 2);
+    assert_eq!(grouped[0].0.key, "status");
+    assert_eq!(grouped[0].1.len(), 2);
+    assert_eq!(grouped[1].0.key, "labels");
+    assert!(grouped[1].1.is_empty());
+    status_tags.assert();
+    label_tags.assert();
+    text_tags.assert_calls(0);
+}
@@ -1,6 +1,7 @@
 //! Mock tests for templates endpoints
 
 use super::*;
+use anytype_rs::api::TemplateOverrides;
 use fixtures::errors::*;
 use fixtures::templates::*;
 use httpmock::prelude::*;
@@ -103,3 +104,78 @@ async fn test_get_template_not_found() {
     assert!(result.is_err());
     mock.assert();
 }
+
+#[tokio::test]
+async fn test_create_object_from_template_applies_overrides() {
+    let server = MockServer::start_async().await;
+
+    let template_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/types/{}/templates/{}", TEST_SPACE_ID, TEST_TYPE_ID, TEST_TEMPLATE_ID));
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(get_template_with_properties_response());
+    });
+
+    // Template type, body and properties are kept; the summary is overridden and the
+    // empty due date is left out
+    let create_mock = server.mock(|when, then| {
+        when.method(POST)
+            .path(format!("/v1/spaces/{}/objects", TEST_SPACE_ID))
+            .json_body(serde_json::json!({
+                "type_key": "ot-page",
+                "name": "From Template",
+                "body": "# Template\n\nThis is a template.",
+                "properties": [
+                    { "key": "status", "select": "tag-draft" },
+                    { "key": "summary", "text": "Overridden" }
+                ]
+            }));
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(fixtures::objects::create_object_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let overrides = TemplateOverrides {
+        name: Some("From Template".to_string()),
+        body: None,
+        properties: vec![serde_json::json!({ "key": "summary", "text": "Overridden" })],
+    };
+    let result = client
+        .create_object_from_template(TEST_SPACE_ID, TEST_TYPE_ID, TEST_TEMPLATE_ID, overrides)
+        .await;
+
+    assert!(result.is_ok(), "Expected success, got error: {:?}", result.err());
+    template_mock.assert();
+    create_mock.assert();
+}
+
+#[tokio::test]
+async fn test_create_object_from_template_not_found() {
+    let server = MockServer::start_async().await;
+
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/types/{}/templates/nonexistent", TEST_SPACE_ID, TEST_TYPE_ID));
+        then.status(404)
+            .header("content-type", "application/json")
+            .json_body(not_found_error());
+    });
+    let create_mock = server.mock(|when, then| {
+        when.method(POST).path(format!("/v1/spaces/{}/objects", TEST_SPACE_ID));
+        then.status(200);
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let result = client
+        .create_object_from_template(TEST_SPACE_ID, TEST_TYPE_ID, "nonexistent", TemplateOverrides::default())
+        .await;
+
+    assert!(result.is_err());
+    create_mock.assert_calls(0);
+}
@@ -1,7 +1,7 @@
 //! Mock tests for types endpoints
 
 use super::*;
-use anytype_rs::api::{CreateTypeProperty, CreateTypeRequest, Icon, Layout, PropertyFormat, UpdateTypeRequest};
+use anytype_rs::api::{Color, CreateTypeProperty, CreateTypeRequest, Icon, Layout, PropertyFormat, Type, UpdateTypeRequest, validate_property_key, validate_type_key};
 use fixtures::errors::*;
 use fixtures::types::*;
 use httpmock::prelude::*;
@@ -82,6 +82,136 @@ async fn test_get_type_success() {
     mock.assert();
 }
 
+#[tokio::test]
+async fn test_get_type_by_key_caches_key_lookup() {
+    let server = MockServer::start_async().await;
+
+    let list = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/types", TEST_SPACE_ID));
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(list_types_response());
+    });
+    let get = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/types/ot-note", TEST_SPACE_ID));
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(serde_json::json!({
+                "type": list_types_response()["data"][1]
+            }));
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    // The first lookup lists the types; later ones use the cached key → ID mapping
+    let type_data = client
+        .get_type_by_key(TEST_SPACE_ID, "ot-note")
+        .await
+        .unwrap();
+    assert_eq!(type_data.name, "Note");
+
+    let type_data = client
+        .get_type_by_key(TEST_SPACE_ID, "ot-note")
+        .await
+        .unwrap();
+    assert_eq!(type_data.id, "ot-note");
+
+    let type_id = client
+        .type_id_for_key(TEST_SPACE_ID, "ot-page")
+        .await
+        .unwrap();
+    assert_eq!(type_id, "ot-page");
+
+    list.assert_calls(1);
+    get.assert_calls(1);
+}
+
+#[tokio::test]
+async fn test_type_id_for_key_after_delete_lists_types_again() {
+    let server = MockServer::start_async().await;
+
+    let list = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/types", TEST_SPACE_ID));
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(list_types_response());
+    });
+    let delete = server.mock(|when, then| {
+        when.method(DELETE)
+            .path(format!("/v1/spaces/{}/types/ot-page", TEST_SPACE_ID));
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(delete_type_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    client.type_id_for_key(TEST_SPACE_ID, "ot-page").await.unwrap();
+    client.type_id_for_key(TEST_SPACE_ID, "ot-page").await.unwrap();
+    list.assert_calls(1);
+
+    client.delete_type(TEST_SPACE_ID, "ot-page").await.unwrap();
+    // The deleted type's ID is no longer trusted, so the types are listed again
+    client.type_id_for_key(TEST_SPACE_ID, "ot-page").await.unwrap();
+
+    delete.assert_calls(1);
+    list.assert_calls(2);
+}
+
+#[tokio::test]
+async fn test_type_id_for_key_not_found() {
+    let server = MockServer::start_async().await;
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/types", TEST_SPACE_ID));
+        then.status(200)
+            .header("content-type", "application/json")
+            .json_body(list_types_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let result = client.type_id_for_key(TEST_SPACE_ID, "ot-missing").await;
+
+    match result {
+        Err(anytype_rs::api::AnytypeError::NotFound { message }) => {
+            assert!(message.contains("ot-missing"))
+        }
+        other => panic!("Expected NotFound error, got {other:?}"),
+    }
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_get_type_tolerates_unknown_and_missing_fields() {
+    let server = MockServer::start_async().await;
+
+    let mut type_data = type_obj();
+    type_data.as_object_mut().unwrap().remove("object");
+    type_data.as_object_mut().unwrap().remove("properties");
+    type_data["color"] = "red".into();
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/types/{}", TEST_SPACE_ID, TEST_TYPE_ID));
+        then.status(200).json_body(serde_json::json!({ "type": type_data }));
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let result = client.get_type(TEST_SPACE_ID, TEST_TYPE_ID).await.unwrap();
+
+    assert_eq!(result.name, "Page");
+    assert!(result.properties.is_empty());
+}
+
 #[tokio::test]
 async fn test_get_type_not_found() {
     let server = MockServer::start_async().await;
@@ -124,7 +254,7 @@ async fn test_create_type_success() {
     client.set_api_key(TEST_API_KEY.to_string());
 
     let request = CreateTypeRequest {
-        key: "custom-type".to_string(),
+        key: "custom_type".to_string(),
         name: "Custom Type".to_string(),
         plural_name: "Custom Types".to_string(),
         layout: Layout::Basic,
@@ -165,8 +295,8 @@ async fn test_create_type_bad_request() {
     client.set_api_key(TEST_API_KEY.to_string());
 
     let request = CreateTypeRequest {
-        key: "".to_string(), // Invalid empty key
-        name: "Custom Type".to_string(),
+        key: "custom_type".to_string(),
+        name: "".to_string(), // Invalid empty name
         plural_name: "Custom Types".to_string(),
         layout: Layout::Basic,
         icon: Icon::Emoji {
@@ -181,6 +311,43 @@ async fn test_create_type_bad_request() {
     mock.assert();
 }
 
+#[tokio::test]
+async fn test_create_type_invalid_key() {
+    let server = MockServer::start_async().await;
+
+    let mock = server.mock(|when, then| {
+        when.method(POST).path(format!("/v1/spaces/{}/types", TEST_SPACE_ID));
+        then.status(201)
+            .header("content-type", "application/json")
+            .json_body(create_type_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let request = CreateTypeRequest {
+        key: "Custom Type".to_string(),
+        name: "Custom Type".to_string(),
+        plural_name: "Custom Types".to_string(),
+        layout: Layout::Basic,
+        icon: Icon::Emoji {
+            emoji: "🎨".to_string(),
+        },
+        properties: vec![],
+    };
+
+    let result = client.create_type(TEST_SPACE_ID, request).await;
+
+    match result {
+        Err(anytype_rs::api::AnytypeError::Config { message }) => {
+            assert!(message.contains("Custom Type"));
+        }
+        other => panic!("Expected config error, got: {other:?}"),
+    }
+    // Validation happens before the request is sent
+    mock.assert_calls(0);
+}
+
 #[tokio::test]
 async fn test_update_type_success() {
     let server = MockServer::start_async().await;
@@ -305,3 +472,380 @@ async fn test_delete_type_not_found() {
     assert!(result.is_err());
     mock.assert();
 }
+
+#[test]
+fn test_create_type_request_from_exported_type() {
+    // Round-trip through JSON the way `type export`/`type import` does
+    let exported = serde_json::to_string(&type_obj()).unwrap();
+    let type_data: Type = serde_json::from_str(&exported).unwrap();
+
+    let request = CreateTypeRequest::from_type(&type_data).unwrap();
+
+    assert_eq!(request.key, "ot-page");
+    assert_eq!(request.name, "Page");
+    assert_eq!(request.plural_name, "Pages");
+    assert!(matches!(request.layout, Layout::Basic));
+    assert_eq!(request.properties.len(), type_data.properties.len());
+    assert_eq!(request.properties[1].key, "description");
+    assert!(matches!(request.properties[1].format, PropertyFormat::Text));
+}
+
+#[tokio::test]
+async fn test_import_exported_type_rewrites_invalid_keys() {
+    let server = MockServer::start_async().await;
+
+    let mut exported_type = type_obj();
+    exported_type["properties"][1]["key"] = "custom-field".into();
+    let exported = serde_json::to_string(&exported_type).unwrap();
+    let type_data: Type = serde_json::from_str(&exported).unwrap();
+
+    // `ot-page` and `custom-field` would fail validation as-is
+    let request = CreateTypeRequest::from_exported_type(&type_data).unwrap();
+    assert_eq!(request.key, "ot_page");
+    assert_eq!(request.properties[0].key, "title");
+    assert_eq!(request.properties[1].key, "custom_field");
+
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path(format!("/v1/spaces/{}/types", TEST_SPACE_ID))
+            .body_includes(r#""key":"ot_page""#);
+        then.status(200).json_body(create_type_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let result = client.create_type(TEST_SPACE_ID, request).await;
+
+    assert!(result.is_ok(), "Expected success, got error: {:?}", result.err());
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_exported_server_keys_pass_validation() {
+    let server = MockServer::start_async().await;
+
+    // The fixtures' keys as the server assigns them (`ot-page`, `ot-note`), exported and
+    // read back the way `type import` does
+    let exported = serde_json::to_string(&list_types_response()["data"]).unwrap();
+    let types: Vec<Type> = serde_json::from_str(&exported).unwrap();
+    let requests: Vec<CreateTypeRequest> = types
+        .iter()
+        .map(|type_data| CreateTypeRequest::from_exported_type(type_data).unwrap())
+        .collect();
+
+    let keys: Vec<&str> = requests.iter().map(|r| r.key.as_str()).collect();
+    assert_eq!(keys, ["ot_page", "ot_note"]);
+    for request in &requests {
+        assert_eq!(validate_type_key(&request.key), Ok(()));
+        for property in &request.properties {
+            assert_eq!(validate_property_key(&property.key), Ok(()));
+        }
+    }
+
+    let mock = server.mock(|when, then| {
+        when.method(POST)
+            .path(format!("/v1/spaces/{}/types", TEST_SPACE_ID));
+        then.status(200).json_body(create_type_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    for request in requests {
+        client
+            .create_type(TEST_SPACE_ID, request)
+            .await
+            .expect("Exported type should be accepted");
+    }
+    mock.assert_calls(2);
+}
+
+#[test]
+fn test_icon_display_for_each_format() {
+    let emoji = Icon::Emoji {
+        emoji: "📄".to_string(),
+    };
+    let icon = Icon::Icon {
+        color: Color::Blue,
+        name: "document".to_string(),
+    };
+    let file = Icon::File {
+        file: "http://127.0.0.1:31006/image/bafy/cover.png".to_string(),
+    };
+
+    assert_eq!(emoji.display(), "📄");
+    assert_eq!(icon.display(), "[icon:document]");
+    assert_eq!(file.display(), "cover.png");
+}
+
+#[tokio::test]
+async fn test_export_schema_fetches_tags_once_per_select_property() {
+    let server = MockServer::start_async().await;
+
+    let status = serde_json::json!({
+        "id": "prop-status",
+        "key": "status",
+        "name": "Status",
+        "format": "select",
+        "object": "property"
+    });
+    let mut task = type_obj();
+    task["id"] = "ot-task".into();
+    task["key"] = "ot-task".into();
+    task["properties"] = serde_json::json!([type_property(), status.clone()]);
+    let mut bug = type_obj();
+    bug["id"] = "ot-bug".into();
+    bug["key"] = "ot-bug".into();
+    bug["properties"] = serde_json::json!([status]);
+
+    let types_mock = server.mock(|when, then| {
+        when.method(GET).path("/v1/spaces/space-123/types");
+        then.status(200).json_body(serde_json::json!({
+            "data": [task, bug],
+            "pagination": { "has_more": false, "limit": 50, "offset": 0, "total": 2 }
+        }));
+    });
+    let tags_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v1/spaces/space-123/properties/prop-status/tags");
+        then.status(200)
+            .json_body(fixtures::tags::list_tags_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let schema = client.export_schema("space-123").await.unwrap();
+
+    assert_eq!(schema.space_id, "space-123");
+    assert_eq!(schema.types.len(), 2);
+    let task_properties = &schema.types[0].properties;
+    assert_eq!(task_properties.len(), 2);
+    assert!(task_properties[0].tags.is_empty());
+    assert_eq!(task_properties[1].tags.len(), 2);
+    assert_eq!(task_properties[1].tags[0].name, "Urgent");
+    assert_eq!(schema.types[1].properties[0].tags.len(), 2);
+
+    types_mock.assert();
+    tags_mock.assert_calls(1);
+}
+
+#[tokio::test]
+async fn test_rename_type_sends_name_and_current_properties() {
+    let server = MockServer::start_async().await;
+
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/types/{}", TEST_SPACE_ID, TEST_TYPE_ID));
+        then.status(200).json_body(get_type_response());
+    });
+    let patch_mock = server.mock(|when, then| {
+        when.method(PATCH)
+            .path(format!("/v1/spaces/{}/types/{}", TEST_SPACE_ID, TEST_TYPE_ID))
+            .json_body(serde_json::json!({
+                "name": "Article",
+                "properties": [
+                    { "format": "text", "key": "title", "name": "Title" },
+                    { "format": "text", "key": "description", "name": "Description" }
+                ]
+            }));
+        then.status(200).json_body(update_type_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    client
+        .rename_type(TEST_SPACE_ID, TEST_TYPE_ID, " Article ")
+        .await
+        .unwrap();
+    let unchanged = client
+        .rename_type(TEST_SPACE_ID, TEST_TYPE_ID, "Page")
+        .await
+        .unwrap();
+
+    assert_eq!(unchanged.type_data.name, "Page");
+    patch_mock.assert_calls(1);
+}
+
+#[tokio::test]
+async fn test_add_type_properties_keeps_existing_properties() {
+    let server = MockServer::start_async().await;
+
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/types/{}", TEST_SPACE_ID, TEST_TYPE_ID));
+        then.status(200).json_body(get_type_response());
+    });
+    let patch_mock = server.mock(|when, then| {
+        when.method(PATCH)
+            .path(format!("/v1/spaces/{}/types/{}", TEST_SPACE_ID, TEST_TYPE_ID))
+            .json_body(serde_json::json!({
+                "properties": [
+                    { "format": "text", "key": "title", "name": "Title" },
+                    { "format": "text", "key": "description", "name": "Description" },
+                    { "format": "select", "key": "status", "name": "Status" }
+                ]
+            }));
+        then.status(200).json_body(update_type_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let result = client
+        .add_type_properties(
+            TEST_SPACE_ID,
+            TEST_TYPE_ID,
+            vec![
+                CreateTypeProperty {
+                    format: PropertyFormat::Select,
+                    key: "status".to_string(),
+                    name: "Status".to_string(),
+                },
+                CreateTypeProperty {
+                    format: PropertyFormat::Text,
+                    key: "description".to_string(),
+                    name: "Description".to_string(),
+                },
+            ],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result.added, vec!["status"]);
+    assert_eq!(result.existing, vec!["description"]);
+    patch_mock.assert();
+}
+
+#[tokio::test]
+async fn test_add_type_properties_skips_update_when_nothing_new() {
+    let server = MockServer::start_async().await;
+
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/types/{}", TEST_SPACE_ID, TEST_TYPE_ID));
+        then.status(200).json_body(get_type_response());
+    });
+    let patch_mock = server.mock(|when, then| {
+        when.method(PATCH);
+        then.status(200).json_body(update_type_response());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let result = client
+        .add_type_properties(
+            TEST_SPACE_ID,
+            TEST_TYPE_ID,
+            vec![CreateTypeProperty {
+                format: PropertyFormat::Text,
+                key: "title".to_string(),
+                name: "Title".to_string(),
+            }],
+        )
+        .await
+        .unwrap();
+
+    assert!(result.added.is_empty());
+    assert_eq!(result.existing, vec!["title"]);
+    patch_mock.assert_calls(0);
+}
+
+#[tokio::test]
+async fn test_list_types_in_all_spaces_reports_failed_spaces() {
+    let server = MockServer::start_async().await;
+
+    server.mock(|when, then| {
+        when.method(GET).path("/v1/spaces");
+        then.status(200)
+            .json_body(fixtures::spaces::list_spaces_response());
+    });
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/types", TEST_SPACE_ID));
+        then.status(200).json_body(list_types_response());
+    });
+    let failing_mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/v1/spaces/bafyreiabc123space2/types");
+        then.status(404).json_body(not_found_error());
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let spaces = client.list_types_in_all_spaces().await.unwrap();
+
+    assert_eq!(spaces.len(), 2);
+    assert!(!spaces[0].1.as_ref().unwrap().is_empty());
+    assert_eq!(spaces[1].0.id, "bafyreiabc123space2");
+    assert!(spaces[1].1.is_err());
+    failing_mock.assert();
+}
+
+#[tokio::test]
+async fn test_type_is_system_by_key() {
+    let server = MockServer::start_async().await;
+
+    let mut body = list_types_response();
+    let mut custom = type_obj();
+    custom["id"] = "bafyreicustomtype".into();
+    custom["key"] = "recipe".into();
+    custom["name"] = "Recipe".into();
+    body["data"].as_array_mut().unwrap().push(custom);
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/types", TEST_SPACE_ID));
+        then.status(200).json_body(body);
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let types = client.list_types(TEST_SPACE_ID).await.unwrap();
+    let user_types: Vec<&str> = types
+        .iter()
+        .filter(|t| !t.is_system())
+        .map(|t| t.key.as_str())
+        .collect();
+
+    // "ot-page" and "ot-note" are built in, whatever the prefix
+    assert_eq!(user_types, vec!["recipe"]);
+}
+
+#[tokio::test]
+async fn test_list_types_keeps_every_property() {
+    let server = MockServer::start_async().await;
+
+    let mut body = list_types_response();
+    let properties: Vec<serde_json::Value> = (0..5)
+        .map(|i| {
+            serde_json::json!({
+                "id": format!("prop-{i}"),
+                "key": format!("key_{i}"),
+                "name": format!("Property {i}"),
+                "format": "text",
+                "object": "property"
+            })
+        })
+        .collect();
+    body["data"][0]["properties"] = properties.into();
+    server.mock(|when, then| {
+        when.method(GET)
+            .path(format!("/v1/spaces/{}/types", TEST_SPACE_ID));
+        then.status(200).json_body(body);
+    });
+
+    let mut client = create_test_client(&server.base_url());
+    client.set_api_key(TEST_API_KEY.to_string());
+
+    let types = client.list_types(TEST_SPACE_ID).await.unwrap();
+
+    assert_eq!(types[0].properties.len(), 5);
+    let json = serde_json::to_value(&types[0]).unwrap();
+    assert_eq!(json["properties"].as_array().unwrap().len(), 5);
+    assert_eq!(json["properties"][4]["key"], "key_4");
+}
@@ -3,7 +3,7 @@
 //! These tests verify that certain invariants hold for API types,
 //! such as pagination logic and field constraints.
 
-use anytype_rs::api::types::Pagination;
+use anytype_rs::api::types::{Color, Pagination};
 use proptest::prelude::*;
 
 use super::strategies::*;
@@ -23,6 +23,7 @@ proptest! {
             limit,
             offset,
             total,
+            next_cursor: None,
         };
 
         // Invariant: if has_more is false, we've seen all items
@@ -50,6 +51,7 @@ proptest! {
             limit,
             offset,
             total,
+            next_cursor: None,
         };
 
         // Invariant: if has_more is true, there should be items beyond current page
@@ -83,6 +85,7 @@ proptest! {
             limit,
             offset: base_offset,
             total,
+            next_cursor: None,
         };
 
         // Offset should never exceed total
@@ -107,8 +110,40 @@ proptest! {
             limit,
             offset,
             total,
+            next_cursor: None,
         };
 
         assert!(pagination.limit > 0, "Limit must be positive");
     }
 }
+
+proptest! {
+    /// Test that every color is listed once in Color::all and parses back from its name
+    #[test]
+    fn test_color_all_and_from_name(color in color_strategy()) {
+        assert_eq!(Color::all().iter().filter(|c| **c == color).count(), 1);
+        assert_eq!(Color::from_name(&color.to_string()), Some(color.clone()));
+        assert_eq!(
+            Color::from_name(&color.to_string().to_uppercase()),
+            Some(color.clone())
+        );
+        assert!(Color::names().contains(&color.to_string().to_lowercase()));
+
+        let hex = color.to_hex();
+        assert!(hex.len() == 7 && hex.starts_with('#'), "Bad hex code: {hex}");
+        assert!(hex[1..].chars().all(|c| c.is_ascii_hexdigit()));
+    }
+
+    /// Test that Color::next visits every color and wraps around
+    #[test]
+    fn test_color_next_cycles(color in color_strategy()) {
+        let mut seen = vec![color.clone()];
+        let mut current = color.next();
+        while current != color {
+            assert!(!seen.contains(&current), "{current} visited twice");
+            seen.push(current.clone());
+            current = current.next();
+        }
+        assert_eq!(seen.len(), Color::all().len());
+    }
+}
@@ -4,7 +4,7 @@
 //! for all types: deserialize(serialize(x)) == x
 
 use anytype_rs::api::Sort;
-use anytype_rs::api::types::{Color, Format, Icon, Pagination};
+use anytype_rs::api::types::{Color, Format, Icon, ObjectKind, Pagination};
 use proptest::prelude::*;
 
 use super::strategies::*;
@@ -124,6 +124,7 @@ proptest! {
             limit,
             offset,
             total,
+            next_cursor: None,
         };
         let json = serde_json::to_string(&pagination).unwrap();
         let deserialized: Pagination = serde_json::from_str(&json).unwrap();
@@ -131,4 +132,13 @@ proptest! {
         let json2 = serde_json::to_string(&deserialized).unwrap();
         assert_eq!(json, json2);
     }
+
+    #[test]
+    fn test_object_kind_keeps_any_value(value in "[a-z][a-z-]{0,15}") {
+        // Known kinds get their own variant, everything else is kept verbatim
+        let kind: ObjectKind = serde_json::from_value(serde_json::json!(value)).unwrap();
+        assert_eq!(kind.as_str(), value);
+        assert_eq!(kind.clone(), ObjectKind::from(value.as_str()));
+        assert_eq!(serde_json::to_value(&kind).unwrap(), serde_json::json!(value));
+    }
 }
@@ -2,7 +2,10 @@
 //!
 //! These tests verify that API types handle edge cases and arbitrary inputs correctly.
 
-use anytype_rs::api::{CreateObjectRequest, SearchRequest, Sort, UpdateObjectRequest};
+use anytype_rs::api::{
+    CreateObjectRequest, SearchRequest, Sort, UpdateObjectRequest, validate_property_key,
+    validate_type_key,
+};
 use proptest::prelude::*;
 
 use super::strategies::*;
@@ -22,6 +25,7 @@ proptest! {
             offset,
             space_id,
             sort: None,
+            include_archived: false,
         };
 
         // Should serialize without panicking
@@ -45,6 +49,7 @@ proptest! {
                 direction,
                 property_key: property,
             }),
+            include_archived: false,
         };
 
         let result = serde_json::to_string(&request);
@@ -127,6 +132,7 @@ proptest! {
             offset: Some(0),
             space_id: None,
             sort: None,
+            include_archived: false,
         };
 
         let result = serde_json::to_string(&request);
@@ -142,6 +148,7 @@ proptest! {
             offset: Some(0),
             space_id: None,
             sort: None,
+            include_archived: false,
         };
 
         let result = serde_json::to_string(&request);
@@ -165,4 +172,23 @@ proptest! {
         let result = serde_json::to_string(&request);
         assert!(result.is_ok(), "Failed with special characters");
     }
+
+    /// Test that plugin-style generated type keys (`ot_{name}`) pass validation
+    #[test]
+    fn test_generated_type_keys_are_valid(name in "[a-z][a-z0-9_]{0,30}") {
+        let key = format!("ot_{name}");
+        assert!(validate_type_key(&key).is_ok(), "Rejected valid key: {key}");
+    }
+
+    /// Test that keys containing characters outside snake_case are rejected
+    #[test]
+    fn test_keys_with_invalid_characters_are_rejected(
+        prefix in "[a-z]{1,10}",
+        invalid in "[A-Z \\-.!@#]",
+        suffix in "[a-z0-9_]{0,10}",
+    ) {
+        let key = format!("{prefix}{invalid}{suffix}");
+        assert!(validate_type_key(&key).is_err(), "Accepted invalid key: {key}");
+        assert!(validate_property_key(&key).is_err(), "Accepted invalid key: {key}");
+    }
 }
@@ -11,6 +11,7 @@ fn test_error_display_formatting() {
 
     let api_error = AnytypeError::Api {
         message: "Object not found".to_string(),
+        status: None,
     };
     insta::assert_snapshot!("error_api", format!("{}", api_error));
 
@@ -29,6 +30,7 @@ fn test_error_debug_formatting() {
 
     let api_error = AnytypeError::Api {
         message: "Rate limit exceeded".to_string(),
+        status: Some(429),
     };
     insta::assert_snapshot!("error_api_debug", format!("{:?}", api_error));
 }
@@ -27,7 +27,7 @@ fn test_member_serialization() {
         name: Some("John Doe".to_string()),
         global_name: Some("john.any".to_string()),
         identity: Some("identity456".to_string()),
-        object: Some("member".to_string()),
+        object: Some("member".into()),
         role: MemberRole::Editor,
         status: MemberStatus::Active,
         icon: Some(serde_json::json!({"emoji": "👤"})),
@@ -1,6 +1,6 @@
 //! Snapshot tests for objects module types
 
-use anytype_rs::api::{CreateObjectRequest, Object, UpdateObjectRequest};
+use anytype_rs::api::{CreateObjectRequest, Object, PropertyFormat, UpdateObjectRequest};
 
 #[test]
 fn test_object_serialization() {
@@ -8,12 +8,14 @@ fn test_object_serialization() {
         id: "obj123".to_string(),
         name: Some("My Object".to_string()),
         space_id: Some("space456".to_string()),
-        object: Some("page".to_string()),
+        object: Some("page".into()),
         properties: serde_json::json!({
             "title": "Test Page",
             "description": "A test page",
             "tags": ["test", "example"]
         }),
+        markdown: None,
+        snippet: None,
     };
     insta::assert_json_snapshot!("object_full", object);
 
@@ -23,6 +25,8 @@ fn test_object_serialization() {
         space_id: None,
         object: None,
         properties: serde_json::json!({}),
+        markdown: None,
+        snippet: None,
     };
     insta::assert_json_snapshot!("object_minimal", object_minimal);
 }
@@ -35,10 +39,10 @@ fn test_create_object_request_serialization() {
         body: None,
         icon: None,
         template_id: None,
-        properties: Some(vec![serde_json::json!({
-            "title": "My Note",
-            "content": "Note content"
-        })]),
+        properties: Some(vec![
+            serde_json::json!({ "key": "title", "text": "My Note" }),
+            serde_json::json!({ "key": "content", "text": "Note content" }),
+        ]),
     };
     insta::assert_json_snapshot!("create_object_request_full", request);
 
@@ -53,6 +57,27 @@ fn test_create_object_request_serialization() {
     insta::assert_json_snapshot!("create_object_request_minimal", request_minimal);
 }
 
+#[test]
+fn test_create_object_request_builder_matches_literal() {
+    let built = CreateObjectRequest::new("note")
+        .name("New Note")
+        .property("title", PropertyFormat::Text, "My Note")
+        .property("content", PropertyFormat::Text, "Note content");
+    insta::assert_json_snapshot!("create_object_request_full", built);
+
+    assert_eq!(
+        CreateObjectRequest::new("page"),
+        CreateObjectRequest {
+            type_key: "page".to_string(),
+            name: None,
+            body: None,
+            icon: None,
+            template_id: None,
+            properties: None,
+        }
+    );
+}
+
 #[test]
 fn test_update_object_request_serialization() {
     let request = UpdateObjectRequest {
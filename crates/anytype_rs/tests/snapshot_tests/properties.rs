@@ -24,7 +24,7 @@ fn test_property_serialization() {
         id: "prop123".to_string(),
         key: "title".to_string(),
         name: "Title".to_string(),
-        object: "property".to_string(),
+        object: "property".into(),
     };
     insta::assert_json_snapshot!("property_text", property);
 
@@ -33,7 +33,7 @@ fn test_property_serialization() {
         id: "prop456".to_string(),
         key: "created_at".to_string(),
         name: "Created At".to_string(),
-        object: "property".to_string(),
+        object: "property".into(),
     };
     insta::assert_json_snapshot!("property_date", property_date);
 }
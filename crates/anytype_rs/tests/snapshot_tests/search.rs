@@ -49,6 +49,7 @@ fn test_search_request_serialization() {
             direction: SortDirection::Desc,
             property_key: SortProperty::LastModifiedDate,
         }),
+        include_archived: false,
     };
     insta::assert_json_snapshot!("search_request_full", request);
 
@@ -58,6 +59,7 @@ fn test_search_request_serialization() {
         query: None,
         space_id: None,
         sort: None,
+        include_archived: false,
     };
     insta::assert_json_snapshot!("search_request_minimal", request_minimal);
 }
@@ -71,7 +73,7 @@ fn test_search_object_serialization() {
         }),
         id: "obj123".to_string(),
         name: "Test Object".to_string(),
-        object: "page".to_string(),
+        object: "page".into(),
         properties: serde_json::json!({
             "title": "Test",
             "description": "A test object"
@@ -85,7 +87,7 @@ fn test_search_object_serialization() {
             key: "page".to_string(),
             layout: Layout::Basic,
             name: "Page".to_string(),
-            object: "type".to_string(),
+            object: "type".into(),
             plural_name: "Pages".to_string(),
             properties: vec![],
         }),
@@ -7,7 +7,7 @@ fn test_space_serialization() {
     let space = Space {
         id: "space123".to_string(),
         name: "My Space".to_string(),
-        object: Some("space".to_string()),
+        object: Some("space".into()),
         description: Some("A workspace for collaboration".to_string()),
         icon: Some(serde_json::json!({"emoji": "🏢"})),
         gateway_url: Some("https://gateway.example.com".to_string()),
@@ -10,7 +10,7 @@ fn test_tag_serialization() {
         id: "tag123".to_string(),
         key: "important".to_string(),
         name: "Important".to_string(),
-        object: "tag".to_string(),
+        object: "tag".into(),
     };
     insta::assert_json_snapshot!("tag_with_color", tag);
 
@@ -19,7 +19,7 @@ fn test_tag_serialization() {
         id: "tag456".to_string(),
         key: "note".to_string(),
         name: "Note".to_string(),
-        object: "tag".to_string(),
+        object: "tag".into(),
     };
     insta::assert_json_snapshot!("tag_no_color", tag_no_color);
 }
@@ -14,7 +14,7 @@ fn test_object_type_serialization() {
         key: "note".to_string(),
         layout: Some("note".to_string()),
         name: "Note".to_string(),
-        object: "type".to_string(),
+        object: "type".into(),
         plural_name: Some("Notes".to_string()),
         properties: vec![serde_json::json!({
             "id": "prop1",
@@ -38,7 +38,7 @@ fn test_template_serialization() {
         layout: Some("basic".to_string()),
         markdown: Some("# Template\n\nDefault content".to_string()),
         name: Some("My Template".to_string()),
-        object: "template".to_string(),
+        object: "template".into(),
         properties: vec![serde_json::json!({
             "key": "description",
             "value": "Template description"
@@ -54,7 +54,7 @@ fn test_template_serialization() {
             key: "page".to_string(),
             layout: Some("basic".to_string()),
             name: "Page".to_string(),
-            object: "type".to_string(),
+            object: "type".into(),
             plural_name: Some("Pages".to_string()),
             properties: vec![],
         }),
@@ -70,7 +70,7 @@ fn test_template_serialization() {
         layout: None,
         markdown: None,
         name: None,
-        object: "template".to_string(),
+        object: "template".into(),
         properties: vec![],
         snippet: None,
         space_id: "space123".to_string(),
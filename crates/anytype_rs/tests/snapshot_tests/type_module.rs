@@ -59,6 +59,25 @@ fn test_create_type_request_serialization() {
     insta::assert_json_snapshot!("create_type_request_minimal", request_minimal);
 }
 
+#[test]
+fn test_create_type_request_builder() {
+    let built = CreateTypeRequest::new("note", "Note")
+        .plural_name("Notes")
+        .layout(Layout::Note)
+        .icon(Icon::Emoji {
+            emoji: "📝".to_string(),
+        })
+        .property("title", "Title", PropertyFormat::Text)
+        .property("created", "Created", PropertyFormat::Date);
+    insta::assert_json_snapshot!("create_type_request_with_properties", built);
+
+    // Defaults: basic layout and the name doubling as the plural name
+    let minimal = CreateTypeRequest::new("page", "Page");
+    assert_eq!(minimal.layout, Layout::Basic);
+    assert_eq!(minimal.plural_name, "Page");
+    assert!(minimal.properties.is_empty());
+}
+
 #[test]
 fn test_layout_variants() {
     insta::assert_json_snapshot!("layout_basic", Layout::Basic);
@@ -78,6 +78,7 @@ fn test_pagination_serialization() {
         limit: 50,
         offset: 100,
         total: 250,
+        next_cursor: None,
     };
     insta::assert_json_snapshot!("pagination_with_more", pagination);
 
@@ -86,6 +87,7 @@ fn test_pagination_serialization() {
         limit: 50,
         offset: 200,
         total: 250,
+        next_cursor: None,
     };
     insta::assert_json_snapshot!("pagination_no_more", pagination_no_more);
 }
@@ -97,7 +99,7 @@ fn test_type_property_serialization() {
         id: "prop123".to_string(),
         key: "title".to_string(),
         name: "Title".to_string(),
-        object: "property".to_string(),
+        object: "property".into(),
     };
     insta::assert_json_snapshot!("type_property", type_property);
 }
@@ -113,7 +115,7 @@ fn test_type_serialization() {
         key: "note".to_string(),
         layout: Layout::Note,
         name: "Note".to_string(),
-        object: "type".to_string(),
+        object: "type".into(),
         plural_name: "Notes".to_string(),
         properties: vec![
             TypeProperty {
@@ -121,14 +123,14 @@ fn test_type_serialization() {
                 id: "prop1".to_string(),
                 key: "title".to_string(),
                 name: "Title".to_string(),
-                object: "property".to_string(),
+                object: "property".into(),
             },
             TypeProperty {
                 format: Format::Date,
                 id: "prop2".to_string(),
                 key: "created".to_string(),
                 name: "Created".to_string(),
-                object: "property".to_string(),
+                object: "property".into(),
             },
         ],
     };
@@ -0,0 +1,24 @@
+//! Tests for the `testing` feature's mock server
+//!
+//! Run with `cargo test -p anytype_rs --features testing`.
+
+#![cfg(feature = "testing")]
+
+use anytype_rs::testing::{self, TEST_OBJECT_ID, TEST_SPACE_ID, TEST_TYPE_ID};
+
+#[tokio::test]
+async fn test_mock_client_serves_fixtures() {
+    let mock = testing::mock_client().await;
+
+    let spaces = mock.list_spaces().await.unwrap();
+    assert!(spaces.iter().any(|space| space.id == TEST_SPACE_ID));
+
+    let object = mock
+        .get_object(TEST_SPACE_ID, TEST_OBJECT_ID)
+        .await
+        .unwrap();
+    assert_eq!(object.name.as_deref(), Some("My Page"));
+
+    let type_obj = mock.get_type(TEST_SPACE_ID, TEST_TYPE_ID).await.unwrap();
+    assert_eq!(type_obj.id, TEST_TYPE_ID);
+}
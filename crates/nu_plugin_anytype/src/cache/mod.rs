@@ -3,7 +3,12 @@ pub mod resolver;
 pub use resolver::Resolver;
 
 use dashmap::DashMap;
+use std::collections::HashSet;
+use std::fmt::Debug;
 use std::time::Instant;
+use tracing::{debug, trace};
+
+const LOG_TARGET: &str = "anytype_rs::cache";
 
 /// Cache entry with TTL
 #[derive(Debug, Clone)]
@@ -25,6 +30,17 @@ impl<T> CacheEntry<T> {
     }
 }
 
+/// A cached name and the ID it resolves to, as listed by [`ResolveCache::space_entries`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedName {
+    /// `"type"`, `"object"`, `"property"` or `"tag"`
+    pub kind: &'static str,
+    pub name: String,
+    pub id: String,
+    /// ID of the property a tag belongs to
+    pub property_id: Option<String>,
+}
+
 /// Thread-safe, in-memory cache for name-to-ID mappings
 pub struct ResolveCache {
     /// Cache for space names -> IDs
@@ -66,6 +82,7 @@ impl ResolveCache {
     }
 
     pub fn invalidate_space(&self, space_id: &str) {
+        debug!(target: LOG_TARGET, "Invalidating space {} and its entries", space_id);
         // Remove space
         self.spaces.retain(|_, entry| entry.value != space_id);
         // Cascade: remove all types, objects, lists in this space
@@ -85,6 +102,7 @@ impl ResolveCache {
     }
 
     pub fn invalidate_type(&self, space_id: &str, type_id: &str) {
+        debug!(target: LOG_TARGET, "Invalidating type {} and its properties", type_id);
         // Collect property IDs to invalidate tags
         let property_ids: Vec<String> = self
             .properties
@@ -144,6 +162,7 @@ impl ResolveCache {
     }
 
     pub fn invalidate_property(&self, type_id: &str, property_id: &str) {
+        debug!(target: LOG_TARGET, "Invalidating property {} and its tags", property_id);
         // Remove property
         self.properties
             .retain(|k, entry| !(k.0 == type_id && entry.value == property_id));
@@ -166,8 +185,35 @@ impl ResolveCache {
             .remove(&(property_id.to_string(), name.to_string()));
     }
 
+    /// Live names cached for a space: its types, objects and properties, then the tags of
+    /// those properties
+    ///
+    /// Each kind is sorted by name. The resolver caches properties under their space, so
+    /// tags are found through the space's cached properties.
+    pub fn space_entries(&self, space_id: &str) -> Vec<CachedName> {
+        let mut entries = Vec::new();
+        for (kind, map) in [
+            ("type", &self.types),
+            ("object", &self.objects),
+            ("property", &self.properties),
+        ] {
+            entries.extend(Self::live_names(map, kind, |parent| parent == space_id));
+        }
+
+        let property_ids: HashSet<String> = entries
+            .iter()
+            .filter(|entry| entry.kind == "property")
+            .map(|entry| entry.id.clone())
+            .collect();
+        entries.extend(Self::live_names(&self.tags, "tag", |parent| {
+            property_ids.contains(parent)
+        }));
+        entries
+    }
+
     // Clear all caches
     pub fn clear_all(&self) {
+        debug!(target: LOG_TARGET, "Clearing all caches");
         self.spaces.clear();
         self.types.clear();
         self.objects.clear();
@@ -176,22 +222,71 @@ impl ResolveCache {
         self.tags.clear();
     }
 
+    /// Flush the cache, returning the number of expired entries dropped
+    ///
+    /// The cache only lives in memory, so there are no pending writes yet; flushing
+    /// prunes expired entries so only live mappings remain for persistence to write out.
+    pub fn flush(&self) -> usize {
+        let pruned = Self::prune(&self.spaces)
+            + Self::prune(&self.types)
+            + Self::prune(&self.objects)
+            + Self::prune(&self.lists)
+            + Self::prune(&self.properties)
+            + Self::prune(&self.tags);
+        debug!(target: LOG_TARGET, "Flushed {} expired entries", pruned);
+        pruned
+    }
+
+    // Helper to list a map's live entries under matching parents, sorted by name
+    fn live_names(
+        map: &DashMap<(String, String), CacheEntry<String>>,
+        kind: &'static str,
+        parent_matches: impl Fn(&str) -> bool,
+    ) -> Vec<CachedName> {
+        let mut names: Vec<CachedName> = map
+            .iter()
+            .filter(|entry| parent_matches(&entry.key().0) && entry.value().is_valid())
+            .map(|entry| CachedName {
+                kind,
+                name: entry.key().1.clone(),
+                id: entry.value().value.clone(),
+                property_id: (kind == "tag").then(|| entry.key().0.clone()),
+            })
+            .collect();
+        names.sort_by(|a, b| a.name.cmp(&b.name));
+        names
+    }
+
+    // Helper to drop expired entries from a map
+    fn prune<K, V>(map: &DashMap<K, CacheEntry<V>>) -> usize
+    where
+        K: Eq + std::hash::Hash,
+    {
+        let before = map.len();
+        map.retain(|_, entry| entry.is_valid());
+        before - map.len()
+    }
+
     // Helper to get value if valid (TTL check)
     fn get_if_valid<K, Q, V>(&self, map: &DashMap<K, CacheEntry<V>>, key: &Q) -> Option<V>
     where
         K: Eq + std::hash::Hash + std::borrow::Borrow<Q>,
-        Q: Eq + std::hash::Hash + ?Sized,
+        Q: Eq + std::hash::Hash + Debug + ?Sized,
         V: Clone,
     {
-        map.get(key).and_then(|entry| {
-            if entry.is_valid() {
-                Some(entry.value.clone())
-            } else {
-                drop(entry);
-                map.remove(key);
-                None
-            }
-        })
+        let Some(entry) = map.get(key) else {
+            trace!(target: LOG_TARGET, "Cache miss for {:?}", key);
+            return None;
+        };
+        if entry.is_valid() {
+            trace!(target: LOG_TARGET, "Cache hit for {:?}", key);
+            Some(entry.value.clone())
+        } else {
+            drop(entry);
+            map.remove(key);
+            trace!(target: LOG_TARGET, "Cache entry for {:?} expired", key);
+            None
+        }
     }
 }
 
@@ -263,4 +358,79 @@ mod tests {
         assert!(cache.get_property("ot_456", "Status").is_none());
         assert!(cache.get_tag("prop_789", "Done").is_none());
     }
+
+    #[test]
+    fn test_space_entries_lists_live_names_in_space() {
+        let cache = ResolveCache::new(300);
+        cache.insert_type("sp_123".to_string(), "Task".to_string(), "ot_1".to_string());
+        cache.insert_object(
+            "sp_123".to_string(),
+            "Roadmap".to_string(),
+            "obj_2".to_string(),
+        );
+        cache.insert_object(
+            "sp_123".to_string(),
+            "Ideas".to_string(),
+            "obj_1".to_string(),
+        );
+        cache.insert_property(
+            "sp_123".to_string(),
+            "Status".to_string(),
+            "prop_1".to_string(),
+        );
+        cache.insert_tag(
+            "prop_1".to_string(),
+            "Done".to_string(),
+            "tag_1".to_string(),
+        );
+        // Another space, and a tag of a property that isn't in the space
+        cache.insert_type("sp_456".to_string(), "Note".to_string(), "ot_2".to_string());
+        cache.insert_tag(
+            "prop_9".to_string(),
+            "Open".to_string(),
+            "tag_9".to_string(),
+        );
+
+        let entries = cache.space_entries("sp_123");
+        let summary: Vec<(&str, &str, &str)> = entries
+            .iter()
+            .map(|e| (e.kind, e.name.as_str(), e.id.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("type", "Task", "ot_1"),
+                ("object", "Ideas", "obj_1"),
+                ("object", "Roadmap", "obj_2"),
+                ("property", "Status", "prop_1"),
+                ("tag", "Done", "tag_1"),
+            ]
+        );
+        assert_eq!(entries[4].property_id.as_deref(), Some("prop_1"));
+        assert_eq!(entries[0].property_id, None);
+
+        // Expired entries are left out
+        let expired = ResolveCache::new(0);
+        expired.insert_type("sp_123".to_string(), "Task".to_string(), "ot_1".to_string());
+        assert!(expired.space_entries("sp_123").is_empty());
+    }
+
+    #[test]
+    fn test_flush_drops_expired_entries() {
+        let cache = ResolveCache::new(300);
+        cache.insert_space("Work".to_string(), "sp_123".to_string());
+
+        let expired = ResolveCache::new(0);
+        expired.insert_space("Old".to_string(), "sp_456".to_string());
+        expired.insert_tag(
+            "prop_789".to_string(),
+            "Done".to_string(),
+            "tag_999".to_string(),
+        );
+
+        assert_eq!(cache.flush(), 0);
+        assert_eq!(cache.get_space("Work"), Some("sp_123".to_string()));
+        assert_eq!(expired.flush(), 2);
+        assert_eq!(expired.flush(), 0);
+    }
 }
@@ -1,11 +1,25 @@
-use super::ResolveCache;
-use anytype_rs::{AnytypeClient, AnytypeError, Result};
+use super::{CachedName, ResolveCache};
+use anytype_rs::{AnytypeClient, AnytypeError, Result, Type};
+use dashmap::DashMap;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
 use std::sync::Arc;
+use tokio::sync::Mutex;
+use tracing::{debug, trace};
+
+const LOG_TARGET: &str = "anytype_rs::resolver";
+
+/// Prefix of Anytype IDs, which are base32 CIDs (space IDs add a `.suffix`)
+const ID_PREFIX: &str = "bafy";
+/// Shortest string treated as an ID; CIDs are 59 characters, names this long are unlikely
+const MIN_ID_LEN: usize = 50;
 
 /// Resolver that wraps API client and caching layer
 pub struct Resolver {
     client: Arc<AnytypeClient>,
     cache: ResolveCache,
+    /// Lookups in progress, keyed by what they resolve, so identical ones share one request
+    in_flight: DashMap<String, Arc<Mutex<()>>>,
 }
 
 impl Resolver {
@@ -13,85 +27,176 @@ impl Resolver {
         Self {
             client,
             cache: ResolveCache::new(cache_ttl),
+            in_flight: DashMap::new(),
         }
     }
 
-    /// Resolve space name to ID
-    pub async fn resolve_space(&self, name: &str) -> Result<String> {
-        // Check cache first
-        if let Some(id) = self.cache.get_space(name) {
+    /// Run a cache-filling lookup at most once for concurrent callers with the same key
+    ///
+    /// Later callers wait for the lookup in progress, then find its result in the cache.
+    /// If it failed, the next waiter runs its own lookup.
+    async fn single_flight<C, F, Fut>(&self, key: String, cached: C, fetch: F) -> Result<String>
+    where
+        C: Fn() -> Option<String>,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<String>>,
+    {
+        if let Some(id) = cached() {
+            trace!(target: LOG_TARGET, "Resolved {:?} from the cache", key);
             return Ok(id);
         }
 
-        // Cache miss - fetch from API
+        let gate = self.in_flight.entry(key.clone()).or_default().clone();
+        let result = {
+            let _guard = gate.lock().await;
+            match cached() {
+                Some(id) => {
+                    trace!(target: LOG_TARGET, "Resolved {:?} by a concurrent lookup", key);
+                    Ok(id)
+                }
+                None => fetch().await,
+            }
+        };
+
+        // Drop the gate once no one else is waiting on it
+        self.in_flight.remove_if(&key, |_, g| {
+            Arc::ptr_eq(g, &gate) && Arc::strong_count(g) == 2
+        });
+
+        result
+    }
+
+    /// Resolve space name to ID
+    pub async fn resolve_space(&self, name: &str) -> Result<String> {
+        self.single_flight(
+            format!("space\0{name}"),
+            || self.cache.get_space(name),
+            || self.fetch_space(name),
+        )
+        .await
+    }
+
+    async fn fetch_space(&self, name: &str) -> Result<String> {
+        debug!(target: LOG_TARGET, "Looking up space {:?}", name);
         let spaces = self.client.list_spaces().await?;
 
         // TODO: Implement case-insensitive matching based on config
-        let space = spaces
-            .iter()
-            .find(|s| s.name == name)
-            .ok_or_else(|| AnytypeError::Api {
-                message: format!("No Space found with name '{}'", name),
-            })?;
+        let space =
+            spaces
+                .iter()
+                .find(|s| s.name == name)
+                .ok_or_else(|| AnytypeError::NotFound {
+                    message: format!("No Space found with name '{}'", name),
+                })?;
 
         // Cache the result
         self.cache.insert_space(name.to_string(), space.id.clone());
 
+        debug!(target: LOG_TARGET, "Resolved space {:?} to {}", name, space.id);
         Ok(space.id.clone())
     }
 
     /// Resolve type name to ID within a space
+    ///
+    /// Matches either the singular or the plural name ("Task" or "Tasks"). The other name is
+    /// cached as well when looking it up would find the same type.
     pub async fn resolve_type(&self, space_id: &str, name: &str) -> Result<String> {
-        // Check cache first
-        if let Some(id) = self.cache.get_type(space_id, name) {
-            return Ok(id);
-        }
+        self.single_flight(
+            format!("type\0{space_id}\0{name}"),
+            || self.cache.get_type(space_id, name),
+            || self.fetch_type(space_id, name),
+        )
+        .await
+    }
 
-        // Cache miss - fetch from API
+    async fn fetch_type(&self, space_id: &str, name: &str) -> Result<String> {
+        debug!(target: LOG_TARGET, "Looking up type {:?}", name);
         let types = self.client.list_types(space_id).await?;
 
-        let type_data = types
-            .iter()
-            .find(|t| t.name == name)
-            .ok_or_else(|| AnytypeError::Api {
-                message: format!("No Type found with name '{}' in space '{}'", name, space_id),
-            })?;
+        let type_data = find_type_by_name(&types, name)?.ok_or_else(|| AnytypeError::NotFound {
+            message: format!("No Type found with name '{}' in space '{}'", name, space_id),
+        })?;
 
-        // Cache the result
+        // Cache the result under both names, unless the other name is ambiguous or names
+        // another type
         self.cache
             .insert_type(space_id.to_string(), name.to_string(), type_data.id.clone());
+        for alias in [Some(&type_data.name), type_data.plural_name.as_ref()]
+            .into_iter()
+            .flatten()
+            .filter(|alias| alias.as_str() != name)
+            .filter(|alias| {
+                find_type_by_name(&types, alias)
+                    .is_ok_and(|found| found.is_some_and(|t| t.id == type_data.id))
+            })
+        {
+            self.cache
+                .insert_type(space_id.to_string(), alias.clone(), type_data.id.clone());
+        }
 
+        debug!(target: LOG_TARGET, "Resolved type {:?} to {}", name, type_data.id);
         Ok(type_data.id.clone())
     }
 
+    /// Resolve a space name to its ID, passing ID-shaped input through without a lookup
+    pub async fn resolve_space_or_id(&self, name_or_id: &str) -> Result<String> {
+        if looks_like_id(name_or_id) {
+            trace!(target: LOG_TARGET, "Using {:?} as an ID", name_or_id);
+            return Ok(name_or_id.to_string());
+        }
+        self.resolve_space(name_or_id).await
+    }
+
+    /// Resolve a type name to its ID, passing ID-shaped input through without a lookup
+    pub async fn resolve_type_or_id(&self, space_id: &str, name_or_id: &str) -> Result<String> {
+        if looks_like_id(name_or_id) {
+            trace!(target: LOG_TARGET, "Using {:?} as an ID", name_or_id);
+            return Ok(name_or_id.to_string());
+        }
+        self.resolve_type(space_id, name_or_id).await
+    }
+
     /// Resolve type_key (global) to type_id (space-specific)
+    ///
+    /// The client caches key lookups per space, so this lists types at most once per space.
     pub async fn resolve_type_by_key(&self, space_id: &str, type_key: &str) -> Result<String> {
-        // For now, do a simple lookup by key
-        // In the future, this could use a separate cache
-        let types = self.client.list_types(space_id).await?;
-
-        let type_data =
-            types
-                .iter()
-                .find(|t| t.key == type_key)
-                .ok_or_else(|| AnytypeError::Api {
-                    message: format!(
-                        "No Type found with key '{}' in space '{}'",
-                        type_key, space_id
-                    ),
-                })?;
+        self.client.type_id_for_key(space_id, type_key).await
+    }
 
-        Ok(type_data.id.clone())
+    /// Resolve several type keys to type IDs in a space with a single request
+    ///
+    /// For mapping many objects to their types at once: however many keys are given, the
+    /// space's types are listed once. Keys with no type in the space (such as some system
+    /// types) are left out of the map.
+    pub async fn resolve_type_keys(
+        &self,
+        space_id: &str,
+        type_keys: &HashSet<String>,
+    ) -> Result<HashMap<String, String>> {
+        if type_keys.is_empty() {
+            return Ok(HashMap::new());
+        }
+        debug!(target: LOG_TARGET, "Looking up {} type keys", type_keys.len());
+        let types = self.client.list_types(space_id).await?;
+        Ok(types
+            .into_iter()
+            .filter(|t| type_keys.contains(&t.key))
+            .map(|t| (t.key, t.id))
+            .collect())
     }
 
     /// Resolve object name to ID within a space
     pub async fn resolve_object(&self, space_id: &str, name: &str) -> Result<String> {
-        // Check cache first
-        if let Some(id) = self.cache.get_object(space_id, name) {
-            return Ok(id);
-        }
+        self.single_flight(
+            format!("object\0{space_id}\0{name}"),
+            || self.cache.get_object(space_id, name),
+            || self.fetch_object(space_id, name),
+        )
+        .await
+    }
 
-        // Cache miss - fetch from API
+    async fn fetch_object(&self, space_id: &str, name: &str) -> Result<String> {
+        debug!(target: LOG_TARGET, "Looking up object {:?}", name);
         let objects = self.client.list_objects(space_id).await?;
 
         // Find first object matching the name
@@ -99,7 +204,7 @@ impl Resolver {
         let object = objects
             .iter()
             .find(|o| o.name.as_deref() == Some(name))
-            .ok_or_else(|| AnytypeError::Api {
+            .ok_or_else(|| AnytypeError::NotFound {
                 message: format!(
                     "No Object found with name '{}' in space '{}'",
                     name, space_id
@@ -110,9 +215,19 @@ impl Resolver {
         self.cache
             .insert_object(space_id.to_string(), name.to_string(), object.id.clone());
 
+        debug!(target: LOG_TARGET, "Resolved object {:?} to {}", name, object.id);
         Ok(object.id.clone())
     }
 
+    /// Resolve an object name to its ID, passing ID-shaped input through without a lookup
+    pub async fn resolve_object_or_id(&self, space_id: &str, name_or_id: &str) -> Result<String> {
+        if looks_like_id(name_or_id) {
+            trace!(target: LOG_TARGET, "Using {:?} as an ID", name_or_id);
+            return Ok(name_or_id.to_string());
+        }
+        self.resolve_object(space_id, name_or_id).await
+    }
+
     /// Resolve list name to ID within a space
     pub async fn resolve_list(&self, space_id: &str, name: &str) -> Result<String> {
         // Check cache first
@@ -128,24 +243,30 @@ impl Resolver {
                 "List resolution not yet implemented. Cannot find '{}'",
                 name
             ),
+            status: None,
         })
     }
 
     /// Resolve property name to ID within a space
     pub async fn resolve_property(&self, space_id: &str, name: &str) -> Result<String> {
-        // Check cache first - use space_id as the parent context
-        if let Some(id) = self.cache.get_property(space_id, name) {
-            return Ok(id);
-        }
+        // The cache uses space_id as the parent context
+        self.single_flight(
+            format!("property\0{space_id}\0{name}"),
+            || self.cache.get_property(space_id, name),
+            || self.fetch_property(space_id, name),
+        )
+        .await
+    }
 
-        // Cache miss - fetch from API
+    async fn fetch_property(&self, space_id: &str, name: &str) -> Result<String> {
+        debug!(target: LOG_TARGET, "Looking up property {:?}", name);
         let properties = self.client.list_properties(space_id).await?;
 
         // Find property by name (case-insensitive)
         let property = properties
             .iter()
             .find(|p| p.name.eq_ignore_ascii_case(name))
-            .ok_or_else(|| AnytypeError::Api {
+            .ok_or_else(|| AnytypeError::NotFound {
                 message: format!(
                     "No Property found with name '{}' in space '{}'",
                     name, space_id
@@ -156,6 +277,7 @@ impl Resolver {
         self.cache
             .insert_property(space_id.to_string(), name.to_string(), property.id.clone());
 
+        debug!(target: LOG_TARGET, "Resolved property {:?} to {}", name, property.id);
         Ok(property.id.clone())
     }
 
@@ -166,18 +288,22 @@ impl Resolver {
         property_id: &str,
         name: &str,
     ) -> Result<String> {
-        // Check cache first
-        if let Some(id) = self.cache.get_tag(property_id, name) {
-            return Ok(id);
-        }
+        self.single_flight(
+            format!("tag\0{property_id}\0{name}"),
+            || self.cache.get_tag(property_id, name),
+            || self.fetch_tag(space_id, property_id, name),
+        )
+        .await
+    }
 
-        // Cache miss - fetch from API
+    async fn fetch_tag(&self, space_id: &str, property_id: &str, name: &str) -> Result<String> {
+        debug!(target: LOG_TARGET, "Looking up tag {:?}", name);
         let tags = self.client.list_tags(space_id, property_id).await?;
 
         let tag = tags
             .iter()
             .find(|t| t.name == name)
-            .ok_or_else(|| AnytypeError::Api {
+            .ok_or_else(|| AnytypeError::NotFound {
                 message: format!(
                     "No Tag found with name '{}' in property '{}'",
                     name, property_id
@@ -188,9 +314,58 @@ impl Resolver {
         self.cache
             .insert_tag(property_id.to_string(), name.to_string(), tag.id.clone());
 
+        debug!(target: LOG_TARGET, "Resolved tag {:?} to {}", name, tag.id);
         Ok(tag.id.clone())
     }
 
+    /// Fill the cache with the name of every type, object, property and tag in a space
+    ///
+    /// Lists each kind once, and the tags of every select and multi-select property, so
+    /// later lookups of any of those names are cache hits.
+    pub async fn warm_space(&self, space_id: &str) -> Result<()> {
+        debug!(target: LOG_TARGET, "Warming the cache for space {}", space_id);
+
+        for type_data in self.client.list_types(space_id).await? {
+            for name in [Some(&type_data.name), type_data.plural_name.as_ref()]
+                .into_iter()
+                .flatten()
+            {
+                self.cache
+                    .insert_type(space_id.to_string(), name.clone(), type_data.id.clone());
+            }
+        }
+
+        // Insert in reverse so the first object with a name wins, as in `fetch_object`
+        let objects = self.client.list_all_objects(space_id).await?;
+        for object in objects.iter().rev() {
+            if let Some(name) = object.name.as_ref().filter(|name| !name.is_empty()) {
+                self.cache
+                    .insert_object(space_id.to_string(), name.clone(), object.id.clone());
+            }
+        }
+
+        for property in self.client.list_properties(space_id).await? {
+            self.cache.insert_property(
+                space_id.to_string(),
+                property.name.clone(),
+                property.id.clone(),
+            );
+            if !matches!(property.format.as_str(), "select" | "multi_select") {
+                continue;
+            }
+            for tag in self.client.list_tags(space_id, &property.id).await? {
+                self.cache.insert_tag(property.id.clone(), tag.name, tag.id);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Live names cached for a space, as filled by [`warm_space`](Self::warm_space)
+    pub fn space_entries(&self, space_id: &str) -> Vec<CachedName> {
+        self.cache.space_entries(space_id)
+    }
+
     /// Invalidate tag cache
     pub fn invalidate_tag(&self, property_id: &str, name: &str) {
         self.cache.invalidate_tag(property_id, name);
@@ -206,6 +381,11 @@ impl Resolver {
         self.cache.clear_all();
     }
 
+    /// Flush the cache, returning the number of expired entries dropped
+    pub fn flush_cache(&self) -> usize {
+        self.cache.flush()
+    }
+
     /// Invalidate space cache (with cascade)
     pub fn invalidate_space(&self, space_id: &str) {
         self.cache.invalidate_space(space_id);
@@ -227,7 +407,152 @@ impl Resolver {
     }
 }
 
+/// Whether the input is shaped like an Anytype ID rather than a name
+fn looks_like_id(input: &str) -> bool {
+    input.starts_with(ID_PREFIX)
+        && input.len() >= MIN_ID_LEN
+        && input
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '.')
+}
+
+/// Find a type by its singular or plural name
+///
+/// Errors if the name is the singular name of one type and the plural name of another.
+fn find_type_by_name<'a>(types: &'a [Type], name: &str) -> Result<Option<&'a Type>> {
+    let singular = types.iter().find(|t| t.name == name);
+    let plural = types
+        .iter()
+        .find(|t| t.plural_name.as_deref() == Some(name) && singular.is_none_or(|s| s.id != t.id));
+
+    match (singular, plural) {
+        (Some(singular), Some(plural)) => Err(AnytypeError::Api {
+            message: format!(
+                "Type name '{}' is ambiguous: it names '{}' ({}) and is the plural of '{}' ({})",
+                name, singular.name, singular.id, plural.name, plural.id
+            ),
+            status: None,
+        }),
+        (singular, plural) => Ok(singular.or(plural)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    // TODO: Add tests with mock client
+    use super::*;
+    use anytype_rs::Icon;
+
+    fn type_named(id: &str, name: &str, plural_name: Option<&str>) -> Type {
+        Type {
+            archived: None,
+            icon: Icon::Emoji {
+                emoji: "📄".to_string(),
+            },
+            id: id.to_string(),
+            key: name.to_lowercase(),
+            layout: None,
+            name: name.to_string(),
+            object: "type".into(),
+            plural_name: plural_name.map(str::to_string),
+            properties: vec![],
+        }
+    }
+
+    #[test]
+    fn test_find_type_by_singular_or_plural_name() {
+        let types = vec![
+            type_named("ot_task", "Task", Some("Tasks")),
+            type_named("ot_note", "Note", None),
+        ];
+
+        let by_singular = find_type_by_name(&types, "Task").unwrap().unwrap();
+        assert_eq!(by_singular.id, "ot_task");
+        let by_plural = find_type_by_name(&types, "Tasks").unwrap().unwrap();
+        assert_eq!(by_plural.id, "ot_task");
+        assert!(find_type_by_name(&types, "Notes").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_find_type_by_name_is_ambiguous_across_types() {
+        // "News" is both a type of its own and the plural of another
+        let types = vec![
+            type_named("ot_news", "News", Some("News")),
+            type_named("ot_item", "News Item", Some("News")),
+        ];
+
+        assert!(find_type_by_name(&types, "News").is_err());
+        // Same type on both sides isn't ambiguous
+        let types = vec![type_named("ot_news", "News", Some("News"))];
+        assert_eq!(
+            find_type_by_name(&types, "News").unwrap().unwrap().id,
+            "ot_news"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_type_does_not_cache_an_ambiguous_alias() {
+        let mock = anytype_rs::testing::mock_client().await;
+        mock.server.mock(|when, then| {
+            when.method("GET").path("/v1/spaces/sp_news/types");
+            then.status(200).json_body(serde_json::json!({
+                "data": [
+                    type_named("ot_item", "News Item", Some("News")),
+                    type_named("ot_news", "News", Some("News")),
+                ],
+                "pagination": { "has_more": false, "limit": 50, "offset": 0, "total": 2 }
+            }));
+        });
+        let resolver = Resolver::new(Arc::new(mock.client), 300);
+
+        let item = resolver.resolve_type("sp_news", "News Item").await;
+        assert_eq!(item.unwrap(), "ot_item");
+        // "News" is the plural of "News Item" but also names another type
+        assert!(resolver.resolve_type("sp_news", "News").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_single_flight_shares_concurrent_lookups() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let resolver = Resolver::new(Arc::new(AnytypeClient::new().unwrap()), 300);
+        let fetches = AtomicUsize::new(0);
+        let lookup = || {
+            resolver.single_flight(
+                "space\0Work".to_string(),
+                || resolver.cache.get_space("Work"),
+                || async {
+                    fetches.fetch_add(1, Ordering::SeqCst);
+                    tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+                    resolver
+                        .cache
+                        .insert_space("Work".to_string(), "sp_123".to_string());
+                    Ok("sp_123".to_string())
+                },
+            )
+        };
+
+        let (a, b, c) = tokio::join!(lookup(), lookup(), lookup());
+        assert_eq!(a.unwrap(), "sp_123");
+        assert_eq!(b.unwrap(), "sp_123");
+        assert_eq!(c.unwrap(), "sp_123");
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+        assert!(resolver.in_flight.is_empty());
+    }
+
+    #[test]
+    fn test_looks_like_id() {
+        assert!(looks_like_id(
+            "bafyreiaxnbfpd5ozxu7s5xwpzpcpsjpn2gbd3wc2cysrb3dmcfgnkcx5de"
+        ));
+        // Space IDs carry a suffix after the CID
+        assert!(looks_like_id(
+            "bafyreiaxnbfpd5ozxu7s5xwpzpcpsjpn2gbd3wc2cysrb3dmcfgnkcx5de.2v3k4ubzj1ryv"
+        ));
+        assert!(!looks_like_id("Work"));
+        assert!(!looks_like_id("bafy notes"));
+        assert!(!looks_like_id("bafyreiabc123"));
+        assert!(!looks_like_id(
+            "Bafyreiaxnbfpd5ozxu7s5xwpzpcpsjpn2gbd3wc2cysrb3dmcfgnkcx5de"
+        ));
+    }
 }
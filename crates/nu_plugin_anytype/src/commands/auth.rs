@@ -19,6 +19,12 @@ impl PluginCommand for AuthLogin {
     fn signature(&self) -> Signature {
         Signature::build(self.name())
             .optional("code", nu_protocol::SyntaxShape::String, "4-digit authentication code from Anytype app")
+            .named(
+                "app-name",
+                nu_protocol::SyntaxShape::String,
+                "Name the API key is registered under in the Anytype app (default: anytype_rs)",
+                Some('a'),
+            )
             .category(Category::Custom("anytype".into()))
     }
 
@@ -97,7 +103,12 @@ impl PluginCommand for AuthLogin {
             eprintln!("🔐 Starting authentication with local Anytype app...");
             eprintln!("📱 Creating authentication challenge...");
 
-            let client = anytype_rs::AnytypeClient::new()
+            // The app name is only sent with the challenge, so it doesn't matter with a code
+            let mut config = anytype_rs::ClientConfig::default();
+            if let Some(app_name) = call.get_flag::<String>("app-name")? {
+                config.app_name = app_name;
+            }
+            let client = anytype_rs::AnytypeClient::with_config(config)
                 .map_err(|e| LabeledError::new(format!("Failed to create client: {}", e)))?;
 
             let challenge = plugin.run_async(client.create_challenge()).map_err(|e| {
@@ -1,61 +1,165 @@
 /// Common helper functions for commands
-use crate::{AnytypePlugin, value::AnytypeValue};
+use crate::{AnytypePlugin, cache::Resolver, value::AnytypeValue};
+use anytype_rs::Space;
 use nu_plugin::EvaluatedCall;
-use nu_protocol::{LabeledError, Span, Value};
+use nu_protocol::{LabeledError, Signature, Span, SyntaxShape, Value};
 
-/// Extract space_id from multiple sources (flag, pipeline, config)
-pub fn get_space_id(
+/// `--space` value that selects every accessible space
+pub const ALL_SPACES: &str = "*";
+
+/// A space as given to a command, before any name lookup
+pub enum SpaceRef {
+    /// A space ID from `--space-id` or the pipeline
+    Id(String),
+    /// A space name from `--space`
+    Name(String),
+    /// The `default_space` name from the plugin configuration
+    Default(String),
+}
+
+/// Find where the space comes from (flag, pipeline, config) without resolving it
+///
+/// `--space-id` wins over `--space`, which needs a lookup to resolve.
+pub fn get_space_ref(
     plugin: &AnytypePlugin,
     call: &EvaluatedCall,
     input: &Value,
     span: Span,
-) -> Result<String, LabeledError> {
-    // Priority 1: Check for --space flag
-    if let Ok(Some(space_name)) = call.get_flag::<String>("space") {
-        let resolver = plugin.resolver().map_err(|e| {
-            LabeledError::new(format!("Failed to get resolver: {}", e))
-                .with_label("Authentication required", span)
-        })?;
+) -> Result<SpaceRef, LabeledError> {
+    // Priority 1: Check for --space-id flag (no resolution needed)
+    if let Ok(Some(space_id)) = call.get_flag::<String>("space-id") {
+        return Ok(SpaceRef::Id(space_id));
+    }
 
-        return plugin
-            .run_async(resolver.resolve_space(&space_name))
-            .map_err(|e| {
-                LabeledError::new(format!("Failed to resolve space '{}': {}", space_name, e))
-            });
+    // Priority 2: Check for --space flag
+    if let Ok(Some(space_name)) = call.get_flag::<String>("space") {
+        return Ok(SpaceRef::Name(space_name));
     }
 
-    // Priority 2: Check for AnytypeValue from pipeline
+    // Priority 3: Check for AnytypeValue from pipeline
     if let Ok(custom_value) = input.as_custom_value()
         && let Some(anytype_value) = custom_value.as_any().downcast_ref::<AnytypeValue>()
         && let Some(space_id) = anytype_value.space_id()
     {
-        return Ok(space_id.to_string());
+        return Ok(SpaceRef::Id(space_id.to_string()));
     }
 
-    // Priority 3: Check for default_space in config
+    // Priority 4: Check for default_space in config
     if let Some(ref default_space) = plugin.config.default_space {
-        let resolver = plugin.resolver().map_err(|e| {
-            LabeledError::new(format!("Failed to get resolver: {}", e))
-                .with_label("Authentication required", span)
-        })?;
-
-        return plugin
-            .run_async(resolver.resolve_space(default_space))
-            .map_err(|e| {
-                LabeledError::new(format!(
-                    "Failed to resolve default space '{}': {}",
-                    default_space, e
-                ))
-            });
+        return Ok(SpaceRef::Default(default_space.clone()));
     }
 
     // No space context found
     Err(LabeledError::new(
-        "Space context required. Use --space <name>, pipe a Space, or configure default_space",
+        "Space context required. Use --space <name>, --space-id <id>, pipe a Space, or configure default_space",
     )
     .with_label("Missing space context", span))
 }
 
+impl SpaceRef {
+    /// Resolve to a space ID, looking names up through the resolver
+    pub async fn resolve(&self, resolver: &Resolver) -> anytype_rs::Result<String> {
+        match self {
+            Self::Id(space_id) => Ok(space_id.clone()),
+            Self::Name(name) | Self::Default(name) => resolver.resolve_space_or_id(name).await,
+        }
+    }
+
+    /// Error for a failed [`resolve`](Self::resolve)
+    pub fn resolve_error(&self, error: impl std::fmt::Display) -> LabeledError {
+        match self {
+            Self::Default(name) => LabeledError::new(format!(
+                "Failed to resolve default space '{}': {}",
+                name, error
+            )),
+            Self::Id(name) | Self::Name(name) => {
+                LabeledError::new(format!("Failed to resolve space '{}': {}", name, error))
+            }
+        }
+    }
+}
+
+/// Whether `--space '*'` asks for every accessible space
+///
+/// `--space-id` still wins, as it does over any other `--space`.
+pub fn wants_all_spaces(call: &EvaluatedCall) -> bool {
+    !matches!(call.get_flag::<String>("space-id"), Ok(Some(_)))
+        && matches!(call.get_flag::<String>("space"), Ok(Some(space)) if space == ALL_SPACES)
+}
+
+/// Keep the spaces whose listing succeeded in a `--space '*'` result
+///
+/// Failed spaces are reported on stderr and left out, so one unreachable space doesn't hide
+/// the others. Fails only if every space failed.
+pub fn successful_spaces<T>(
+    results: Vec<(Space, anytype_rs::Result<Vec<T>>)>,
+    what: &str,
+) -> Result<Vec<(Space, Vec<T>)>, LabeledError> {
+    let total = results.len();
+    let mut spaces = Vec::new();
+    let mut last_error = None;
+    for (space, result) in results {
+        match result {
+            Ok(items) => spaces.push((space, items)),
+            Err(e) => {
+                eprintln!(
+                    "⚠️  Skipping space '{}': failed to list {what}: {e}",
+                    space.name
+                );
+                last_error = Some(e);
+            }
+        }
+    }
+
+    match last_error {
+        Some(e) if spaces.is_empty() => Err(LabeledError::new(format!(
+            "Failed to list {what} in all {total} spaces: {e}"
+        ))),
+        _ => Ok(spaces),
+    }
+}
+
+/// Signature helpers shared by the commands
+pub trait SignatureExt {
+    /// Add the `--space-id` flag read by [`get_space_id`] and [`get_space_ref`]
+    fn space_id_flag(self) -> Self;
+}
+
+impl SignatureExt for Signature {
+    fn space_id_flag(self) -> Self {
+        self.named(
+            "space-id",
+            SyntaxShape::String,
+            "ID of the space; skips name resolution and takes precedence over --space",
+            None,
+        )
+    }
+}
+
+/// Extract space_id from multiple sources (flag, pipeline, config)
+///
+/// `--space-id` is used as-is and wins over `--space`, which costs a lookup to resolve.
+pub fn get_space_id(
+    plugin: &AnytypePlugin,
+    call: &EvaluatedCall,
+    input: &Value,
+    span: Span,
+) -> Result<String, LabeledError> {
+    let space = get_space_ref(plugin, call, input, span)?;
+    if let SpaceRef::Id(space_id) = space {
+        return Ok(space_id);
+    }
+
+    let resolver = plugin.resolver().map_err(|e| {
+        LabeledError::new(format!("Failed to get resolver: {}", e))
+            .with_label("Authentication required", span)
+    })?;
+
+    plugin
+        .run_async(space.resolve(&resolver))
+        .map_err(|e| space.resolve_error(e))
+}
+
 /// Extract type_id from multiple sources (flag, pipeline)
 pub fn get_type_id(
     plugin: &AnytypePlugin,
@@ -72,7 +176,7 @@ pub fn get_type_id(
         })?;
 
         return plugin
-            .run_async(resolver.resolve_type(space_id, &type_name))
+            .run_async(resolver.resolve_type_or_id(space_id, &type_name))
             .map_err(|e| {
                 LabeledError::new(format!(
                     "Failed to resolve type '{}' in space '{}': {}",
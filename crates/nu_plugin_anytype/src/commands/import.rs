@@ -1,4 +1,9 @@
-use crate::{AnytypePlugin, commands::common::get_space_id, value::AnytypeValue};
+use crate::{
+    AnytypePlugin,
+    cache::Resolver,
+    commands::common::{SignatureExt, get_space_id},
+    value::AnytypeValue,
+};
 use anytype_rs::api::CreateObjectRequest;
 use gray_matter::Matter;
 use gray_matter::engine::YAML;
@@ -26,6 +31,7 @@ impl PluginCommand for ImportMarkdown {
         Signature::build(self.name())
             .required("file", SyntaxShape::Filepath, "Path to markdown file")
             .named("space", SyntaxShape::String, "Target space name", Some('s'))
+            .space_id_flag()
             .named("type", SyntaxShape::String, "Object type name", Some('t'))
             .switch("dry-run", "Preview without importing", Some('d'))
             .switch("verbose", "Detailed output", Some('v'))
@@ -121,7 +127,7 @@ impl PluginCommand for ImportMarkdown {
 
         // Resolve type name to ID
         let type_id = plugin
-            .run_async(resolver.resolve_type(&space_id, &type_name))
+            .run_async(resolver.resolve_type_or_id(&space_id, &type_name))
             .map_err(|e| {
                 LabeledError::new(format!(
                     "Failed to resolve type '{}' in space '{}': {}",
@@ -147,10 +153,23 @@ impl PluginCommand for ImportMarkdown {
         let object_name = extract_object_name(&frontmatter, resolved_path.to_str().unwrap_or("Untitled"));
 
         // Map frontmatter to properties
-        let (properties, unmapped_fields) =
+        let (mut properties, unmapped_fields) =
             map_frontmatter_to_properties(&frontmatter, &type_data.properties)
                 .map_err(|e| LabeledError::new(format!("Failed to map properties: {}", e)))?;
 
+        // Replace object names in relation properties with object IDs
+        let unresolved_objects = plugin
+            .run_async(resolve_object_relations(
+                &resolver,
+                &space_id,
+                &mut properties,
+                &type_data.properties,
+            ))
+            .map_err(|e| LabeledError::new(format!("Failed to resolve related objects: {}", e)))?;
+        for name in &unresolved_objects {
+            eprintln!("⚠️  Warning: No object named '{}' in space '{}'", name, space_id);
+        }
+
         // Display mapping information
         if verbose || dry_run {
             eprintln!("\n📋 Property Mapping:");
@@ -215,6 +234,16 @@ impl PluginCommand for ImportMarkdown {
                     span,
                 ),
             );
+            record.push(
+                "unresolved_objects",
+                Value::list(
+                    unresolved_objects
+                        .iter()
+                        .map(|name| Value::string(name, span))
+                        .collect(),
+                    span,
+                ),
+            );
             record.push("dry_run", Value::bool(true, span));
 
             return Ok(PipelineData::Value(Value::record(record, span), None));
@@ -228,11 +257,7 @@ impl PluginCommand for ImportMarkdown {
         };
 
         let request = CreateObjectRequest {
-            type_key: type_data.key.clone(),
-            name: Some(object_name.clone()),
             body: body_content,
-            icon: None,
-            template_id: None,
             properties: if let Some(props_obj) = properties.as_object() {
                 if props_obj.is_empty() {
                     None
@@ -242,6 +267,7 @@ impl PluginCommand for ImportMarkdown {
             } else {
                 None
             },
+            ..CreateObjectRequest::new(&type_data.key).name(&object_name)
         };
 
         if verbose {
@@ -295,7 +321,12 @@ impl PluginCommand for ImportMarkdown {
         }
 
         // Get the type_key from the created object or use the one from type_data
-        let type_key = response.object.object.clone().unwrap_or_else(|| type_data.key.clone());
+        let type_key = response
+            .object
+            .object
+            .as_ref()
+            .map(ToString::to_string)
+            .unwrap_or_else(|| type_data.key.clone());
 
         // Convert to AnytypeValue::Object with full context
         let anytype_value: AnytypeValue = (response.object, space_id, type_id, type_key).into();
@@ -425,6 +456,43 @@ fn map_frontmatter_to_properties(
     Ok((JsonValue::Object(properties), unmapped_fields))
 }
 
+/// Replace the object names in `objects`-format properties with object IDs
+///
+/// Values that already look like object IDs are kept. Returns the names that could not be
+/// resolved; they are removed from the property value.
+async fn resolve_object_relations(
+    resolver: &Resolver,
+    space_id: &str,
+    properties: &mut JsonValue,
+    type_properties: &[anytype_rs::api::TypeProperty],
+) -> anytype_rs::Result<Vec<String>> {
+    let mut unresolved = Vec::new();
+    let Some(props_obj) = properties.as_object_mut() else {
+        return Ok(unresolved);
+    };
+
+    for (key, value) in props_obj.iter_mut() {
+        let is_relation = type_properties
+            .iter()
+            .any(|p| &p.key == key && p.format.eq_ignore_ascii_case("objects"));
+        let Some(names) = value.as_array().filter(|_| is_relation) else {
+            continue;
+        };
+
+        let mut ids = Vec::new();
+        for name in names.iter().filter_map(|v| v.as_str()) {
+            match resolver.resolve_object_or_id(space_id, name).await {
+                Ok(id) => ids.push(JsonValue::String(id)),
+                Err(anytype_rs::AnytypeError::NotFound { .. }) => unresolved.push(name.to_string()),
+                Err(e) => return Err(e),
+            }
+        }
+        *value = JsonValue::Array(ids);
+    }
+
+    Ok(unresolved)
+}
+
 /// Convert a JSON value to match the expected property format (string-based)
 fn convert_value_to_format_str(
     value: &JsonValue,
@@ -516,7 +584,25 @@ fn convert_value_to_format_str(
                 _ => bail!("MultiSelect must be an array of strings"),
             }
         }
-        "files" | "objects" => {
+        "objects" => {
+            // Object relations - array of object names, resolved to IDs before the object
+            // is created (see resolve_object_relations)
+            match value {
+                JsonValue::Array(arr) => {
+                    let names: Result<Vec<JsonValue>, anyhow::Error> = arr
+                        .iter()
+                        .map(|v| match v {
+                            JsonValue::String(s) => Ok(JsonValue::String(s.clone())),
+                            _ => bail!("Objects array must contain only object names"),
+                        })
+                        .collect();
+                    Ok(JsonValue::Array(names?))
+                }
+                JsonValue::String(s) => Ok(JsonValue::Array(vec![JsonValue::String(s.clone())])),
+                _ => bail!("Objects must be an object name or an array of names"),
+            }
+        }
+        "files" => {
             // Complex formats - pass through as-is
             Ok(value.clone())
         }
@@ -664,6 +750,20 @@ This is the body."#;
         assert_eq!(result.as_array().unwrap().len(), 1);
     }
 
+    #[test]
+    fn test_convert_value_to_format_objects() {
+        let value = JsonValue::String("Project Alpha".to_string());
+        let result = convert_value_to_format_str(&value, "objects").unwrap();
+        assert_eq!(result, serde_json::json!(["Project Alpha"]));
+
+        let value = serde_json::json!(["Project Alpha", "Project Beta"]);
+        let result = convert_value_to_format_str(&value, "objects").unwrap();
+        assert_eq!(result, value);
+
+        let value = serde_json::json!([1, 2]);
+        assert!(convert_value_to_format_str(&value, "objects").is_err());
+    }
+
     #[test]
     fn test_map_frontmatter_to_properties() {
         let mut frontmatter = HashMap::new();
@@ -688,21 +788,21 @@ This is the body."#;
                 id: "prop1".to_string(),
                 key: "status".to_string(),
                 name: "Status".to_string(),
-                object: "property".to_string(),
+                object: "property".into(),
             },
             anytype_rs::api::TypeProperty {
                 format: "number".to_string(),
                 id: "prop2".to_string(),
                 key: "priority".to_string(),
                 name: "Priority".to_string(),
-                object: "property".to_string(),
+                object: "property".into(),
             },
             anytype_rs::api::TypeProperty {
                 format: "checkbox".to_string(),
                 id: "prop3".to_string(),
                 key: "published".to_string(),
                 name: "Published".to_string(),
-                object: "property".to_string(),
+                object: "property".into(),
             },
         ];
 
@@ -738,14 +838,14 @@ This is the body."#;
                 id: "prop1".to_string(),
                 key: "status".to_string(),
                 name: "Status".to_string(),
-                object: "property".to_string(),
+                object: "property".into(),
             },
             anytype_rs::api::TypeProperty {
                 format: "number".to_string(),
                 id: "prop2".to_string(),
                 key: "priority".to_string(),
                 name: "Priority".to_string(),
-                object: "property".to_string(),
+                object: "property".into(),
             },
         ];
 
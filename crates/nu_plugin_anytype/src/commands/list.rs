@@ -1,7 +1,14 @@
-use crate::{AnytypePlugin, commands::common::get_space_id, value::AnytypeValue};
+use crate::{
+    AnytypePlugin,
+    commands::common::{SignatureExt, get_space_id},
+    value::AnytypeValue,
+};
 use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
 use nu_protocol::{Category, LabeledError, PipelineData, Signature, SyntaxShape, Value};
 
+/// Number of objects fetched per request when reading a list
+const LIST_PAGE_SIZE: usize = 100;
+
 /// Command: anytype list add
 pub struct ListAdd;
 
@@ -31,6 +38,7 @@ impl PluginCommand for ListAdd {
                 "Name of the space (can also accept Space/List from pipeline)",
                 Some('s'),
             )
+            .space_id_flag()
             .input_output_types(vec![
                 (nu_protocol::Type::Nothing, nu_protocol::Type::String),
                 (
@@ -76,7 +84,7 @@ impl PluginCommand for ListAdd {
 
         // Resolve list name to ID (or use as ID if resolution fails)
         let list_id = plugin
-            .run_async(resolver.resolve_object(&space_id, &list_identifier))
+            .run_async(resolver.resolve_object_or_id(&space_id, &list_identifier))
             .unwrap_or_else(|_| list_identifier.clone());
 
         // Get client
@@ -125,6 +133,7 @@ impl PluginCommand for ListViews {
                 "Name of the space (can also accept Space/List from pipeline)",
                 Some('s'),
             )
+            .space_id_flag()
             .input_output_types(vec![
                 (
                     nu_protocol::Type::Nothing,
@@ -162,7 +171,7 @@ impl PluginCommand for ListViews {
 
         // Resolve list name to ID (or use as ID if resolution fails)
         let list_id = plugin
-            .run_async(resolver.resolve_object(&space_id, &list_identifier))
+            .run_async(resolver.resolve_object_or_id(&space_id, &list_identifier))
             .unwrap_or_else(|_| list_identifier.clone());
 
         // Get client
@@ -218,12 +227,24 @@ impl PluginCommand for ListObjects {
                 "Name of the space (can also accept Space/List from pipeline)",
                 Some('s'),
             )
+            .space_id_flag()
             .named(
                 "limit",
                 SyntaxShape::Int,
-                "Maximum number of objects to return",
+                "Maximum number of objects to return (default: all)",
                 Some('l'),
             )
+            .named(
+                "offset",
+                SyntaxShape::Int,
+                "Number of objects to skip (for pagination)",
+                Some('o'),
+            )
+            .switch(
+                "total",
+                "Return a record with the list's total object count and the objects",
+                Some('t'),
+            )
             .input_output_types(vec![
                 (
                     nu_protocol::Type::Nothing,
@@ -237,6 +258,11 @@ impl PluginCommand for ListObjects {
                         "AnytypeValue".into(),
                     ))),
                 ),
+                (nu_protocol::Type::Nothing, nu_protocol::Type::record()),
+                (
+                    nu_protocol::Type::Custom("AnytypeValue".into()),
+                    nu_protocol::Type::record(),
+                ),
             ])
             .category(Category::Custom("anytype".into()))
     }
@@ -254,8 +280,10 @@ impl PluginCommand for ListObjects {
         // Get list identifier (name or ID)
         let list_identifier: String = call.req(0)?;
 
-        // Get optional limit
+        // Get optional window and whether to report the total
         let limit: Option<i64> = call.get_flag("limit")?;
+        let offset: Option<i64> = call.get_flag("offset")?;
+        let with_total = call.has_flag("total")?;
 
         // Get space_id from multiple sources
         let space_id = get_space_id(plugin, call, &input, span)?;
@@ -268,7 +296,7 @@ impl PluginCommand for ListObjects {
 
         // Resolve list name to ID (or use as ID if resolution fails)
         let list_id = plugin
-            .run_async(resolver.resolve_object(&space_id, &list_identifier))
+            .run_async(resolver.resolve_object_or_id(&space_id, &list_identifier))
             .unwrap_or_else(|_| list_identifier.clone());
 
         // Get client
@@ -277,22 +305,17 @@ impl PluginCommand for ListObjects {
                 .with_label("Authentication required", span)
         })?;
 
-        // Get list objects
-        let response = plugin
-            .run_async(client.get_list_objects(&space_id, &list_id))
+        // Get list objects, page by page so large lists are not truncated
+        let (objects, total) = plugin
+            .run_async(list_objects_window(
+                &client,
+                &space_id,
+                &list_id,
+                offset.map_or(0, |o| o.max(0) as usize),
+                limit.map(|l| l.max(0) as usize),
+            ))
             .map_err(|e| LabeledError::new(format!("Failed to get list objects: {}", e)))?;
 
-        // Apply limit if specified
-        let objects = if let Some(lim) = limit {
-            response
-                .data
-                .into_iter()
-                .take(lim.max(0) as usize)
-                .collect()
-        } else {
-            response.data
-        };
-
         // Convert to AnytypeValue::Object with full context
         let mut values = Vec::new();
         for obj in objects {
@@ -307,21 +330,25 @@ impl PluginCommand for ListObjects {
                 space_id: Some(space_id.clone()),
                 properties: serde_json::to_value(&obj.properties).unwrap_or(serde_json::json!([])),
                 object: Some(obj.object),
+                markdown: None,
+                snippet: obj.snippet,
             };
 
             // Convert to AnytypeValue with full context
-            let mut anytype_value: AnytypeValue =
+            let anytype_value: AnytypeValue =
                 (anytype_obj, space_id.clone(), type_id, type_key).into();
 
-            // Add snippet if available
-            if let AnytypeValue::Object { snippet, .. } = &mut anytype_value {
-                *snippet = obj.snippet;
-            }
-
             values.push(Value::custom(Box::new(anytype_value), span));
         }
 
-        Ok(PipelineData::Value(Value::list(values, span), None))
+        let objects = Value::list(values, span);
+        if with_total {
+            let mut record = nu_protocol::Record::new();
+            record.push("total", Value::int(total as i64, span));
+            record.push("objects", objects);
+            return Ok(PipelineData::Value(Value::record(record, span), None));
+        }
+        Ok(PipelineData::Value(objects, None))
     }
 }
 
@@ -354,6 +381,7 @@ impl PluginCommand for ListRemove {
                 "Name of the space (can also accept Space/List from pipeline)",
                 Some('s'),
             )
+            .space_id_flag()
             .input_output_types(vec![
                 (nu_protocol::Type::Nothing, nu_protocol::Type::String),
                 (
@@ -393,7 +421,7 @@ impl PluginCommand for ListRemove {
 
         // Resolve list name to ID (or use as ID if resolution fails)
         let list_id = plugin
-            .run_async(resolver.resolve_object(&space_id, &list_identifier))
+            .run_async(resolver.resolve_object_or_id(&space_id, &list_identifier))
             .unwrap_or_else(|_| list_identifier.clone());
 
         // Get client
@@ -413,3 +441,37 @@ impl PluginCommand for ListRemove {
         ))
     }
 }
+
+/// Fetch up to `limit` objects of a list from `offset` (all of them without a limit), with
+/// the number of objects in the whole list
+async fn list_objects_window(
+    client: &anytype_rs::AnytypeClient,
+    space_id: &str,
+    list_id: &str,
+    mut offset: usize,
+    limit: Option<usize>,
+) -> anytype_rs::Result<(Vec<anytype_rs::ListObject>, usize)> {
+    let mut objects = Vec::new();
+    loop {
+        let wanted = limit.map_or(LIST_PAGE_SIZE, |limit| {
+            (limit - objects.len()).min(LIST_PAGE_SIZE)
+        });
+        if wanted == 0 {
+            // Still ask for the total when no objects are wanted
+            let page = client
+                .get_list_objects_page(space_id, list_id, offset, 1)
+                .await?;
+            return Ok((objects, page.pagination.total));
+        }
+
+        let page = client
+            .get_list_objects_page(space_id, list_id, offset, wanted)
+            .await?;
+        let page_len = page.data.len();
+        offset += page_len;
+        objects.extend(page.data);
+        if !page.pagination.has_more || page_len == 0 || limit == Some(objects.len()) {
+            return Ok((objects, page.pagination.total));
+        }
+    }
+}
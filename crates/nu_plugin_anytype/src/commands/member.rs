@@ -1,4 +1,9 @@
-use crate::{AnytypePlugin, commands::common::get_space_id, value::AnytypeValue};
+use crate::{
+    AnytypePlugin,
+    commands::common::{SignatureExt, get_space_id},
+    value::AnytypeValue,
+};
+use anytype_rs::{MemberFilter, MemberRole, MemberStatus};
 use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
 use nu_protocol::{Category, LabeledError, PipelineData, Signature, SyntaxShape, Value};
 
@@ -19,6 +24,20 @@ impl PluginCommand for MemberList {
     fn signature(&self) -> Signature {
         Signature::build(self.name())
             .named("space", SyntaxShape::String, "Name of the space", Some('s'))
+            .space_id_flag()
+            .named(
+                "role",
+                SyntaxShape::String,
+                "Only members with this role (viewer, editor, owner, no_permission)",
+                Some('r'),
+            )
+            .named(
+                "status",
+                SyntaxShape::String,
+                "Only members with this status (joining, active, removed, declined, removing, canceled)",
+                None,
+            )
+            .switch("pending", "Only members with a pending join request", None)
             .category(Category::Custom("anytype".into()))
     }
 
@@ -33,12 +52,36 @@ impl PluginCommand for MemberList {
         let input = input.into_value(span)?;
         let space_id = get_space_id(plugin, call, &input, span)?;
 
+        let role = match call.get_flag::<String>("role")? {
+            Some(role) => Some(role.parse::<MemberRole>().map_err(|_| {
+                LabeledError::new(format!(
+                    "Invalid role '{}'. Valid options: viewer, editor, owner, no_permission",
+                    role
+                ))
+            })?),
+            None => None,
+        };
+        let status = match call.get_flag::<String>("status")? {
+            Some(status) => Some(status.parse::<MemberStatus>().map_err(|_| {
+                LabeledError::new(format!(
+                    "Invalid status '{}'. Valid options: joining, active, removed, declined, removing, canceled",
+                    status
+                ))
+            })?),
+            None => None,
+        };
+        let filter = MemberFilter {
+            role,
+            status,
+            pending: call.has_flag("pending")?,
+        };
+
         let client = plugin
             .client()
             .map_err(|e| LabeledError::new(format!("Failed to get client: {}", e)))?;
 
         let members = plugin
-            .run_async(client.list_members(&space_id))
+            .run_async(client.list_members_filtered(&space_id, &filter))
             .map_err(|e| LabeledError::new(format!("Failed to list members: {}", e)))?;
 
         let values: Vec<Value> = members
@@ -21,9 +21,11 @@ pub use auth::{AuthLogin, AuthDelete, AuthStatus};
 pub use import::ImportMarkdown;
 pub use list::{ListAdd, ListObjects, ListRemove, ListViews};
 pub use member::MemberList;
-pub use object::{ObjectGet, ObjectList};
+pub use object::{
+    ObjectAppend, ObjectBookmark, ObjectCreate, ObjectDiff, ObjectGet, ObjectLink, ObjectList, ObjectUnsetProperty,
+};
 pub use property::{PropertyCreate, PropertyDelete, PropertyGet, PropertyList, PropertyUpdate};
-pub use resolve::{CacheClear, CacheStats, ResolveObject, ResolveSpace, ResolveType};
+pub use resolve::{CacheClear, CacheFlush, CacheStats, ResolveAll, ResolveObject, ResolveSpace, ResolveType};
 pub use search::Search;
 pub use space::{SpaceCreate, SpaceGet, SpaceList};
 pub use tag::{TagCreate, TagDelete, TagGet, TagList, TagUpdate};
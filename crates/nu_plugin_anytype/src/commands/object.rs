@@ -1,6 +1,15 @@
-use crate::{AnytypePlugin, commands::common::get_space_id, value::AnytypeValue};
+use crate::{
+    AnytypePlugin,
+    cache::Resolver,
+    commands::common::{
+        SignatureExt, get_space_id, get_type_id, successful_spaces, wants_all_spaces,
+    },
+    value::AnytypeValue,
+};
+use anytype_rs::api::{CreateObjectRequest, Object, PropertyChange, TemplateOverrides};
 use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
-use nu_protocol::{Category, LabeledError, PipelineData, Signature, SyntaxShape, Value};
+use nu_protocol::{Category, LabeledError, PipelineData, Record, Signature, SyntaxShape, Value};
+use std::collections::HashSet;
 
 /// Command: anytype object list
 pub struct ObjectList;
@@ -13,7 +22,7 @@ impl PluginCommand for ObjectList {
     }
 
     fn description(&self) -> &str {
-        "List all objects in a space"
+        "List all objects in a space, or in every space with --space '*'"
     }
 
     fn signature(&self) -> Signature {
@@ -21,9 +30,16 @@ impl PluginCommand for ObjectList {
             .named(
                 "space",
                 SyntaxShape::String,
-                "Name of the space (can also accept Space from pipeline)",
+                "Name of the space, or '*' for every space (can also accept Space from pipeline)",
                 Some('s'),
             )
+            .space_id_flag()
+            .named(
+                "type-key",
+                SyntaxShape::String,
+                "Only list objects of the type with this key (like 'ot_page'); matched as given, without looking the type up",
+                None,
+            )
             .input_output_types(vec![
                 (
                     nu_protocol::Type::Nothing,
@@ -50,9 +66,7 @@ impl PluginCommand for ObjectList {
     ) -> Result<PipelineData, LabeledError> {
         let span = call.head;
         let input = input.into_value(span)?;
-
-        // Get space_id from multiple sources
-        let space_id = get_space_id(plugin, call, &input, span)?;
+        let type_key: Option<String> = call.get_flag("type-key")?;
 
         // Get client and resolver
         let client = plugin.client().map_err(|e| {
@@ -65,38 +79,89 @@ impl PluginCommand for ObjectList {
                 .with_label("Authentication required", span)
         })?;
 
+        // Fan out across every space, tagging each object with its space name
+        if wants_all_spaces(call) {
+            let spaces = plugin
+                .run_async(client.list_objects_in_all_spaces())
+                .map_err(|e| LabeledError::new(format!("Failed to list objects: {}", e)))?;
+
+            let mut values = Vec::new();
+            for (space, objects) in successful_spaces(spaces, "objects")? {
+                let objects = with_type_key(objects, type_key.as_deref());
+                for anytype_value in object_values(plugin, &resolver, &space.id, objects) {
+                    let anytype_value = anytype_value.with_space_name(&space.name);
+                    values.push(Value::custom(Box::new(anytype_value), span));
+                }
+            }
+            return Ok(PipelineData::Value(Value::list(values, span), None));
+        }
+
+        // Get space_id from multiple sources
+        let space_id = get_space_id(plugin, call, &input, span)?;
+
         // List objects from API
         let objects = plugin
             .run_async(client.list_objects(&space_id))
             .map_err(|e| LabeledError::new(format!("Failed to list objects: {}", e)))?;
 
-        // Convert to AnytypeValue::Object with full context
-        let mut values = Vec::new();
-        for obj in objects {
-            // Extract type_key from object.object field (this is the global type key like "ot_page")
-            let type_key = match obj.object.as_ref() {
-                Some(key) => key.clone(),
-                None => {
-                    // Skip objects without type_key
-                    continue;
-                }
-            };
-
-            // Resolve type_key to space-specific type_id
-            // If resolution fails (e.g., for system types), use the type_key as fallback
-            let type_id = plugin
-                .run_async(resolver.resolve_type_by_key(&space_id, &type_key))
-                .unwrap_or_else(|_| type_key.clone());
-
-            // Use From<(Object, String, String, String)> for conversion
-            let anytype_value: AnytypeValue = (obj, space_id.clone(), type_id, type_key).into();
-            values.push(Value::custom(Box::new(anytype_value), span));
-        }
+        let objects = with_type_key(objects, type_key.as_deref());
+        let values = object_values(plugin, &resolver, &space_id, objects)
+            .into_iter()
+            .map(|anytype_value| Value::custom(Box::new(anytype_value), span))
+            .collect();
 
         Ok(PipelineData::Value(Value::list(values, span), None))
     }
 }
 
+/// Keep only the objects whose `object` field is `type_key`, or all of them without one
+///
+/// The field already holds the type key, so this needs no request.
+fn with_type_key(objects: Vec<Object>, type_key: Option<&str>) -> Vec<Object> {
+    let Some(type_key) = type_key else {
+        return objects;
+    };
+    objects
+        .into_iter()
+        .filter(|obj| {
+            obj.object
+                .as_ref()
+                .is_some_and(|key| key.as_str() == type_key)
+        })
+        .collect()
+}
+
+/// Convert listed objects to AnytypeValue::Object with full context
+fn object_values(
+    plugin: &AnytypePlugin,
+    resolver: &Resolver,
+    space_id: &str,
+    objects: Vec<Object>,
+) -> Vec<AnytypeValue> {
+    // Resolve each distinct type key once, up front, rather than once per object.
+    // Type keys that can't be resolved (e.g. system types) fall back to the key itself.
+    let type_keys: HashSet<String> = objects
+        .iter()
+        .filter_map(|obj| obj.object.as_ref().map(|key| key.to_string()))
+        .collect();
+    let type_ids = plugin
+        .run_async(resolver.resolve_type_keys(space_id, &type_keys))
+        .unwrap_or_default();
+
+    objects
+        .into_iter()
+        .filter_map(|obj| {
+            // The object field holds the global type key (like "ot_page"); skip objects without one
+            let type_key = obj.object.as_ref()?.to_string();
+            let type_id = type_ids
+                .get(&type_key)
+                .cloned()
+                .unwrap_or_else(|| type_key.clone());
+            Some((obj, space_id.to_string(), type_id, type_key).into())
+        })
+        .collect()
+}
+
 /// Command: anytype object get
 pub struct ObjectGet;
 
@@ -120,6 +185,7 @@ impl PluginCommand for ObjectGet {
                 "Name of the space (can also accept Space from pipeline)",
                 Some('s'),
             )
+            .space_id_flag()
             .input_output_types(vec![
                 (
                     nu_protocol::Type::Nothing,
@@ -157,7 +223,7 @@ impl PluginCommand for ObjectGet {
 
         // Resolve object name to ID within the space
         let object_id = plugin
-            .run_async(resolver.resolve_object(&space_id, &name))
+            .run_async(resolver.resolve_object_or_id(&space_id, &name))
             .map_err(|e| {
                 LabeledError::new(format!(
                     "Failed to resolve object '{}' in space '{}': {}",
@@ -181,7 +247,7 @@ impl PluginCommand for ObjectGet {
             .object
             .as_ref()
             .ok_or_else(|| LabeledError::new(format!("Object {} missing type key", obj.id)))?
-            .clone();
+            .to_string();
 
         // Resolve type_key to space-specific type_id
         // If resolution fails (e.g., for system types), use the type_key as fallback
@@ -197,3 +263,644 @@ impl PluginCommand for ObjectGet {
         ))
     }
 }
+
+/// Command: anytype object create
+pub struct ObjectCreate;
+
+impl PluginCommand for ObjectCreate {
+    type Plugin = AnytypePlugin;
+
+    fn name(&self) -> &str {
+        "anytype object create"
+    }
+
+    fn description(&self) -> &str {
+        "Create an object, optionally starting from one of its type's templates"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required("name", SyntaxShape::String, "Name of the new object")
+            .named(
+                "type",
+                SyntaxShape::String,
+                "Name of the object's type (can also accept a Type from pipeline)",
+                Some('t'),
+            )
+            .named(
+                "template",
+                SyntaxShape::String,
+                "Name or ID of a template of the type; its body and properties are copied",
+                None,
+            )
+            .named(
+                "body",
+                SyntaxShape::String,
+                "Markdown body; replaces the template's body",
+                Some('b'),
+            )
+            .named("space", SyntaxShape::String, "Name of the space", Some('s'))
+            .space_id_flag()
+            .input_output_types(vec![
+                (
+                    nu_protocol::Type::Nothing,
+                    nu_protocol::Type::Custom("AnytypeValue".into()),
+                ),
+                (
+                    nu_protocol::Type::Custom("AnytypeValue".into()),
+                    nu_protocol::Type::Custom("AnytypeValue".into()),
+                ),
+            ])
+            .category(Category::Custom("anytype".into()))
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let span = call.head;
+        let input = input.into_value(span)?;
+
+        let name: String = call.req(0)?;
+        let template: Option<String> = call.get_flag("template")?;
+        let body: Option<String> = call.get_flag("body")?;
+
+        let space_id = get_space_id(plugin, call, &input, span)?;
+        let type_id = get_type_id(plugin, call, &input, &space_id, span)?;
+
+        let client = plugin.client().map_err(|e| {
+            LabeledError::new(format!("Failed to get client: {}", e))
+                .with_label("Authentication required", span)
+        })?;
+
+        let response = match template {
+            Some(template) => {
+                let templates = plugin
+                    .run_async(client.list_templates(&space_id, &type_id))
+                    .map_err(|e| LabeledError::new(format!("Failed to list templates: {}", e)))?;
+                let found = templates
+                    .iter()
+                    .find(|t| {
+                        t.id == template
+                            || t.name
+                                .as_deref()
+                                .is_some_and(|name| name.eq_ignore_ascii_case(&template))
+                    })
+                    .ok_or_else(|| {
+                        LabeledError::new(format!("Template '{}' not found", template))
+                            .with_label("No template of this type has that name or ID", span)
+                    })?;
+
+                let overrides = TemplateOverrides {
+                    name: Some(name),
+                    body,
+                    properties: Vec::new(),
+                };
+                plugin
+                    .run_async(
+                        client
+                            .create_object_from_template(&space_id, &type_id, &found.id, overrides),
+                    )
+                    .map_err(|e| LabeledError::new(format!("Failed to create object: {}", e)))?
+            }
+            None => {
+                let type_data = plugin
+                    .run_async(client.get_type(&space_id, &type_id))
+                    .map_err(|e| LabeledError::new(format!("Failed to get type: {}", e)))?;
+                let request = CreateObjectRequest {
+                    body,
+                    ..CreateObjectRequest::new(type_data.key).name(name)
+                };
+                plugin
+                    .run_async(client.create_object(&space_id, request))
+                    .map_err(|e| LabeledError::new(format!("Failed to create object: {}", e)))?
+            }
+        };
+
+        let obj = response.object;
+        let type_key = obj
+            .object
+            .as_ref()
+            .map(ToString::to_string)
+            .unwrap_or_default();
+        let anytype_value: AnytypeValue = (obj, space_id, type_id, type_key).into();
+        Ok(PipelineData::Value(
+            Value::custom(Box::new(anytype_value), span),
+            None,
+        ))
+    }
+}
+
+/// Command: anytype object bookmark
+pub struct ObjectBookmark;
+
+impl PluginCommand for ObjectBookmark {
+    type Plugin = AnytypePlugin;
+
+    fn name(&self) -> &str {
+        "anytype object bookmark"
+    }
+
+    fn description(&self) -> &str {
+        "Create a bookmark object for a URL, named after the page's title"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required("url", SyntaxShape::String, "URL to bookmark")
+            .named(
+                "name",
+                SyntaxShape::String,
+                "Name of the bookmark; skips fetching the page title",
+                Some('n'),
+            )
+            .named("space", SyntaxShape::String, "Name of the space", Some('s'))
+            .space_id_flag()
+            .input_output_types(vec![(
+                nu_protocol::Type::Nothing,
+                nu_protocol::Type::Custom("AnytypeValue".into()),
+            )])
+            .category(Category::Custom("anytype".into()))
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let span = call.head;
+        let input = input.into_value(span)?;
+
+        let url: String = call.req(0)?;
+        let name: Option<String> = call.get_flag("name")?;
+
+        let space_id = get_space_id(plugin, call, &input, span)?;
+
+        let client = plugin.client().map_err(|e| {
+            LabeledError::new(format!("Failed to get client: {}", e))
+                .with_label("Authentication required", span)
+        })?;
+        let resolver = plugin.resolver().map_err(|e| {
+            LabeledError::new(format!("Failed to get resolver: {}", e))
+                .with_label("Authentication required", span)
+        })?;
+
+        // Without a name the page title is fetched, falling back to the URL
+        let response = match name {
+            Some(name) => plugin.run_async(
+                client.create_object(&space_id, CreateObjectRequest::bookmark(&url).name(name)),
+            ),
+            None => plugin.run_async(client.create_bookmark(&space_id, &url)),
+        }
+        .map_err(|e| LabeledError::new(format!("Failed to create bookmark: {}", e)))?;
+
+        let obj = response.object;
+        let type_key = obj
+            .object
+            .as_ref()
+            .map_or_else(|| "bookmark".to_string(), ToString::to_string);
+
+        // If resolution fails (e.g., for system types), use the type_key as fallback
+        let type_id = plugin
+            .run_async(resolver.resolve_type_by_key(&space_id, &type_key))
+            .unwrap_or_else(|_| type_key.clone());
+
+        let anytype_value: AnytypeValue = (obj, space_id, type_id, type_key).into();
+        Ok(PipelineData::Value(
+            Value::custom(Box::new(anytype_value), span),
+            None,
+        ))
+    }
+}
+
+/// Command: anytype object append
+pub struct ObjectAppend;
+
+impl PluginCommand for ObjectAppend {
+    type Plugin = AnytypePlugin;
+
+    fn name(&self) -> &str {
+        "anytype object append"
+    }
+
+    fn description(&self) -> &str {
+        "Append markdown to the end of an object's body"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required("name", SyntaxShape::String, "Name of the object")
+            .optional(
+                "text",
+                SyntaxShape::String,
+                "Markdown to append (can also be piped in)",
+            )
+            .named("space", SyntaxShape::String, "Name of the space", Some('s'))
+            .space_id_flag()
+            .input_output_types(vec![
+                (
+                    nu_protocol::Type::Nothing,
+                    nu_protocol::Type::Custom("AnytypeValue".into()),
+                ),
+                (
+                    nu_protocol::Type::String,
+                    nu_protocol::Type::Custom("AnytypeValue".into()),
+                ),
+            ])
+            .category(Category::Custom("anytype".into()))
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let span = call.head;
+        let input = input.into_value(span)?;
+
+        let name: String = call.req(0)?;
+
+        // Text comes from the argument, falling back to piped input
+        let text = match call.opt::<String>(1)? {
+            Some(text) => text,
+            None => input.coerce_string().map_err(|_| {
+                LabeledError::new("No text to append")
+                    .with_label("Pass the text as an argument or pipe it in", span)
+            })?,
+        };
+
+        let space_id = get_space_id(plugin, call, &input, span)?;
+
+        let resolver = plugin.resolver().map_err(|e| {
+            LabeledError::new(format!("Failed to get resolver: {}", e))
+                .with_label("Authentication required", span)
+        })?;
+
+        let object_id = plugin
+            .run_async(resolver.resolve_object_or_id(&space_id, &name))
+            .map_err(|e| {
+                LabeledError::new(format!(
+                    "Failed to resolve object '{}' in space '{}': {}",
+                    name, space_id, e
+                ))
+            })?;
+
+        let client = plugin.client().map_err(|e| {
+            LabeledError::new(format!("Failed to get client: {}", e))
+                .with_label("Authentication required", span)
+        })?;
+
+        let response = plugin
+            .run_async(client.append_markdown(&space_id, &object_id, &text))
+            .map_err(|e| LabeledError::new(format!("Failed to append to object: {}", e)))?;
+
+        let obj = response.object;
+        let type_key = obj
+            .object
+            .as_ref()
+            .ok_or_else(|| LabeledError::new(format!("Object {} missing type key", obj.id)))?
+            .to_string();
+
+        // If resolution fails (e.g., for system types), use the type_key as fallback
+        let type_id = plugin
+            .run_async(resolver.resolve_type_by_key(&space_id, &type_key))
+            .unwrap_or_else(|_| type_key.clone());
+
+        let anytype_value: AnytypeValue = (obj, space_id, type_id, type_key).into();
+        Ok(PipelineData::Value(
+            Value::custom(Box::new(anytype_value), span),
+            None,
+        ))
+    }
+}
+
+/// Command: anytype object unset-property
+pub struct ObjectUnsetProperty;
+
+impl PluginCommand for ObjectUnsetProperty {
+    type Plugin = AnytypePlugin;
+
+    fn name(&self) -> &str {
+        "anytype object unset-property"
+    }
+
+    fn description(&self) -> &str {
+        "Clear the value of a property on an object"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required("name", SyntaxShape::String, "Name of the object")
+            .required_named(
+                "property",
+                SyntaxShape::String,
+                "Key or name of the property to clear",
+                Some('p'),
+            )
+            .named("space", SyntaxShape::String, "Name of the space", Some('s'))
+            .space_id_flag()
+            .input_output_types(vec![(
+                nu_protocol::Type::Nothing,
+                nu_protocol::Type::Custom("AnytypeValue".into()),
+            )])
+            .category(Category::Custom("anytype".into()))
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let span = call.head;
+        let input = input.into_value(span)?;
+
+        let name: String = call.req(0)?;
+        let property: String = call.get_flag("property")?.ok_or_else(|| {
+            LabeledError::new("Missing property").with_label("Pass --property <name>", span)
+        })?;
+
+        let space_id = get_space_id(plugin, call, &input, span)?;
+
+        let resolver = plugin.resolver().map_err(|e| {
+            LabeledError::new(format!("Failed to get resolver: {}", e))
+                .with_label("Authentication required", span)
+        })?;
+
+        let object_id = plugin
+            .run_async(resolver.resolve_object_or_id(&space_id, &name))
+            .map_err(|e| {
+                LabeledError::new(format!(
+                    "Failed to resolve object '{}' in space '{}': {}",
+                    name, space_id, e
+                ))
+            })?;
+
+        let client = plugin.client().map_err(|e| {
+            LabeledError::new(format!("Failed to get client: {}", e))
+                .with_label("Authentication required", span)
+        })?;
+
+        let response = plugin
+            .run_async(client.unset_object_property(&space_id, &object_id, &property))
+            .map_err(|e| {
+                LabeledError::new(format!("Failed to clear property '{}': {}", property, e))
+            })?;
+
+        let obj = response.object;
+        let type_key = obj
+            .object
+            .as_ref()
+            .ok_or_else(|| LabeledError::new(format!("Object {} missing type key", obj.id)))?
+            .to_string();
+
+        // If resolution fails (e.g., for system types), use the type_key as fallback
+        let type_id = plugin
+            .run_async(resolver.resolve_type_by_key(&space_id, &type_key))
+            .unwrap_or_else(|_| type_key.clone());
+
+        let anytype_value: AnytypeValue = (obj, space_id, type_id, type_key).into();
+        Ok(PipelineData::Value(
+            Value::custom(Box::new(anytype_value), span),
+            None,
+        ))
+    }
+}
+
+/// Command: anytype object link
+pub struct ObjectLink;
+
+impl PluginCommand for ObjectLink {
+    type Plugin = AnytypePlugin;
+
+    fn name(&self) -> &str {
+        "anytype object link"
+    }
+
+    fn description(&self) -> &str {
+        "Link an object to another through a relation property, keeping existing links"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required("from", SyntaxShape::String, "Name of the object to update")
+            .required("to", SyntaxShape::String, "Name of the object to link to")
+            .required_named(
+                "property",
+                SyntaxShape::String,
+                "Key of the relation (objects-format) property",
+                Some('p'),
+            )
+            .named("space", SyntaxShape::String, "Name of the space", Some('s'))
+            .space_id_flag()
+            .input_output_types(vec![(
+                nu_protocol::Type::Nothing,
+                nu_protocol::Type::Custom("AnytypeValue".into()),
+            )])
+            .category(Category::Custom("anytype".into()))
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let span = call.head;
+        let input = input.into_value(span)?;
+
+        let from: String = call.req(0)?;
+        let to: String = call.req(1)?;
+        let property: String = call.get_flag("property")?.ok_or_else(|| {
+            LabeledError::new("Missing property").with_label("Pass --property <key>", span)
+        })?;
+
+        let space_id = get_space_id(plugin, call, &input, span)?;
+
+        let resolver = plugin.resolver().map_err(|e| {
+            LabeledError::new(format!("Failed to get resolver: {}", e))
+                .with_label("Authentication required", span)
+        })?;
+
+        let (from_id, to_id) = plugin
+            .run_async(async {
+                tokio::try_join!(
+                    resolver.resolve_object_or_id(&space_id, &from),
+                    resolver.resolve_object_or_id(&space_id, &to),
+                )
+            })
+            .map_err(|e| {
+                LabeledError::new(format!(
+                    "Failed to resolve objects in space '{}': {}",
+                    space_id, e
+                ))
+            })?;
+
+        let client = plugin.client().map_err(|e| {
+            LabeledError::new(format!("Failed to get client: {}", e))
+                .with_label("Authentication required", span)
+        })?;
+
+        let response = plugin
+            .run_async(client.add_object_relation(&space_id, &from_id, &property, &to_id))
+            .map_err(|e| LabeledError::new(format!("Failed to link objects: {}", e)))?;
+
+        let obj = response.object;
+        let type_key = obj
+            .object
+            .as_ref()
+            .ok_or_else(|| LabeledError::new(format!("Object {} missing type key", obj.id)))?
+            .to_string();
+
+        // If resolution fails (e.g., for system types), use the type_key as fallback
+        let type_id = plugin
+            .run_async(resolver.resolve_type_by_key(&space_id, &type_key))
+            .unwrap_or_else(|_| type_key.clone());
+
+        let anytype_value: AnytypeValue = (obj, space_id, type_id, type_key).into();
+        Ok(PipelineData::Value(
+            Value::custom(Box::new(anytype_value), span),
+            None,
+        ))
+    }
+}
+
+/// Command: anytype object diff
+pub struct ObjectDiff;
+
+impl PluginCommand for ObjectDiff {
+    type Plugin = AnytypePlugin;
+
+    fn name(&self) -> &str {
+        "anytype object diff"
+    }
+
+    fn description(&self) -> &str {
+        "Compare two objects: a unified diff of their bodies and the properties that differ"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required(
+                "old",
+                SyntaxShape::String,
+                "Name of the object to compare from",
+            )
+            .required(
+                "new",
+                SyntaxShape::String,
+                "Name of the object to compare to",
+            )
+            .named("space", SyntaxShape::String, "Name of the space", Some('s'))
+            .space_id_flag()
+            .input_output_types(vec![(
+                nu_protocol::Type::Nothing,
+                nu_protocol::Type::record(),
+            )])
+            .category(Category::Custom("anytype".into()))
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let span = call.head;
+        let input = input.into_value(span)?;
+
+        let old: String = call.req(0)?;
+        let new: String = call.req(1)?;
+
+        let space_id = get_space_id(plugin, call, &input, span)?;
+
+        let resolver = plugin.resolver().map_err(|e| {
+            LabeledError::new(format!("Failed to get resolver: {}", e))
+                .with_label("Authentication required", span)
+        })?;
+
+        let (old_id, new_id) = plugin
+            .run_async(async {
+                tokio::try_join!(
+                    resolver.resolve_object_or_id(&space_id, &old),
+                    resolver.resolve_object_or_id(&space_id, &new),
+                )
+            })
+            .map_err(|e| {
+                LabeledError::new(format!(
+                    "Failed to resolve objects in space '{}': {}",
+                    space_id, e
+                ))
+            })?;
+
+        let client = plugin.client().map_err(|e| {
+            LabeledError::new(format!("Failed to get client: {}", e))
+                .with_label("Authentication required", span)
+        })?;
+
+        let diff = plugin
+            .run_async(client.diff_objects(&space_id, &old_id, &new_id))
+            .map_err(|e| LabeledError::new(format!("Failed to compare objects: {}", e)))?;
+
+        let properties = diff
+            .properties
+            .iter()
+            .map(|change| property_change_value(change, span))
+            .collect();
+
+        let mut record = Record::new();
+        record.push("old", Value::string(old, span));
+        record.push("new", Value::string(new, span));
+        record.push("body", Value::string(diff.body, span));
+        record.push("properties", Value::list(properties, span));
+        Ok(PipelineData::Value(Value::record(record, span), None))
+    }
+}
+
+/// A row for one changed property, with `added`, `removed` or `changed` in its `change` column
+fn property_change_value(change: &PropertyChange, span: nu_protocol::Span) -> Value {
+    let (kind, old, new) = match change {
+        PropertyChange::Added { value, .. } => ("added", None, Some(value)),
+        PropertyChange::Removed { value, .. } => ("removed", Some(value), None),
+        PropertyChange::Changed { old, new, .. } => ("changed", Some(old), Some(new)),
+    };
+
+    let mut record = Record::new();
+    record.push("key", Value::string(change.key(), span));
+    record.push("change", Value::string(kind, span));
+    for (column, value) in [("old", old), ("new", new)] {
+        let value = value.map_or(Value::nothing(span), |v| Value::string(v.to_string(), span));
+        record.push(column, value);
+    }
+    Value::record(record, span)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_type_key_matches_object_field() {
+        let objects: Vec<Object> = serde_json::from_value(serde_json::json!([
+            { "id": "a", "object": "ot_page" },
+            { "id": "b", "object": "ot_task" },
+            { "id": "c" }
+        ]))
+        .unwrap();
+
+        let pages = with_type_key(objects.clone(), Some("ot_page"));
+        assert_eq!(pages.len(), 1);
+        assert_eq!(pages[0].id, "a");
+        assert_eq!(with_type_key(objects, None).len(), 3);
+    }
+}
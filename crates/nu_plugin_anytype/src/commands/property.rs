@@ -1,4 +1,8 @@
-use crate::{AnytypePlugin, commands::common::get_space_id, value::AnytypeValue};
+use crate::{
+    AnytypePlugin,
+    commands::common::{SignatureExt, get_space_id},
+    value::AnytypeValue,
+};
 use anytype_rs::{CreatePropertyRequest, PropertyFormat, UpdatePropertyRequest};
 use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
 use nu_protocol::{Category, LabeledError, PipelineData, Signature, SyntaxShape, Value};
@@ -25,6 +29,7 @@ impl PluginCommand for PropertyList {
                 "Name of the space (can also accept Space from pipeline)",
                 Some('s'),
             )
+            .space_id_flag()
             .input_output_types(vec![
                 (
                     nu_protocol::Type::Nothing,
@@ -104,6 +109,7 @@ impl PluginCommand for PropertyGet {
                 "Name of the space (can also accept Space from pipeline)",
                 Some('s'),
             )
+            .space_id_flag()
             .input_output_types(vec![
                 (
                     nu_protocol::Type::Nothing,
@@ -192,6 +198,7 @@ impl PluginCommand for PropertyCreate {
                 "Name of the space (can also accept Space from pipeline)",
                 Some('s'),
             )
+            .space_id_flag()
             .named(
                 "format",
                 SyntaxShape::String,
@@ -292,6 +299,7 @@ impl PluginCommand for PropertyUpdate {
                 "Name of the space (can also accept Space from pipeline)",
                 Some('s'),
             )
+            .space_id_flag()
             .named(
                 "new-name",
                 SyntaxShape::String,
@@ -418,6 +426,7 @@ impl PluginCommand for PropertyDelete {
                 "Name of the space (can also accept Space from pipeline)",
                 Some('s'),
             )
+            .space_id_flag()
             .input_output_types(vec![
                 (
                     nu_protocol::Type::Nothing,
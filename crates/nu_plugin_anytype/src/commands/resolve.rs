@@ -1,4 +1,7 @@
-use crate::{AnytypePlugin, commands::common::get_space_id};
+use crate::{
+    AnytypePlugin,
+    commands::common::{SignatureExt, get_space_id},
+};
 use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
 use nu_protocol::{Category, LabeledError, PipelineData, Record, Signature, SyntaxShape, Value};
 
@@ -72,6 +75,7 @@ impl PluginCommand for ResolveType {
                 "Name of the space (can also accept Space from pipeline)",
                 Some('s'),
             )
+            .space_id_flag()
             .input_output_types(vec![
                 (
                     nu_protocol::Type::Nothing,
@@ -155,6 +159,7 @@ impl PluginCommand for ResolveObject {
                 "Name of the space (can also accept Space from pipeline)",
                 Some('s'),
             )
+            .space_id_flag()
             .input_output_types(vec![
                 (
                     nu_protocol::Type::Nothing,
@@ -204,6 +209,90 @@ impl PluginCommand for ResolveObject {
     }
 }
 
+/// Command: anytype resolve all
+pub struct ResolveAll;
+
+impl PluginCommand for ResolveAll {
+    type Plugin = AnytypePlugin;
+
+    fn name(&self) -> &str {
+        "anytype resolve all"
+    }
+
+    fn description(&self) -> &str {
+        "List every type, object, property and tag name in a space with its ID"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .named(
+                "space",
+                SyntaxShape::String,
+                "Name of the space (can also accept Space from pipeline)",
+                Some('s'),
+            )
+            .space_id_flag()
+            .input_output_types(vec![
+                (
+                    nu_protocol::Type::Nothing,
+                    nu_protocol::Type::List(Box::new(nu_protocol::Type::Record(vec![].into()))),
+                ),
+                (
+                    nu_protocol::Type::Custom("AnytypeValue".into()),
+                    nu_protocol::Type::List(Box::new(nu_protocol::Type::Record(vec![].into()))),
+                ),
+            ])
+            .category(Category::Custom("anytype".into()))
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let span = call.head;
+        let input = input.into_value(span)?;
+
+        let space_id = get_space_id(plugin, call, &input, span)?;
+
+        let resolver = plugin.resolver().map_err(|e| {
+            LabeledError::new(format!("Failed to get resolver: {}", e))
+                .with_label("Authentication required", span)
+        })?;
+
+        plugin
+            .run_async(resolver.warm_space(&space_id))
+            .map_err(|e| {
+                LabeledError::new(format!(
+                    "Failed to load names in space '{}': {}",
+                    space_id, e
+                ))
+            })?;
+
+        let values = resolver
+            .space_entries(&space_id)
+            .into_iter()
+            .map(|entry| {
+                let mut record = Record::new();
+                record.push("kind", Value::string(entry.kind, span));
+                record.push("name", Value::string(entry.name, span));
+                record.push("id", Value::string(entry.id, span));
+                record.push(
+                    "property_id",
+                    entry
+                        .property_id
+                        .map_or_else(|| Value::nothing(span), |id| Value::string(id, span)),
+                );
+                Value::record(record, span)
+            })
+            .collect();
+
+        Ok(PipelineData::Value(Value::list(values, span), None))
+    }
+}
+
 /// Command: anytype cache clear
 pub struct CacheClear;
 
@@ -245,6 +334,45 @@ impl PluginCommand for CacheClear {
     }
 }
 
+/// Command: anytype cache flush
+pub struct CacheFlush;
+
+impl PluginCommand for CacheFlush {
+    type Plugin = AnytypePlugin;
+
+    fn name(&self) -> &str {
+        "anytype cache flush"
+    }
+
+    fn description(&self) -> &str {
+        "Flush the resolution cache, dropping expired entries"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name()).category(Category::Custom("anytype".into()))
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        _input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let span = call.head;
+
+        let removed = plugin.flush();
+
+        Ok(PipelineData::Value(
+            Value::string(
+                format!("Cache flushed ({} expired entries removed)", removed),
+                span,
+            ),
+            None,
+        ))
+    }
+}
+
 /// Command: anytype cache stats
 pub struct CacheStats;
 
@@ -1,4 +1,8 @@
-use crate::{AnytypePlugin, commands::common::get_space_id, value::AnytypeValue};
+use crate::{
+    AnytypePlugin,
+    commands::common::{SignatureExt, get_space_id},
+    value::AnytypeValue,
+};
 use anytype_rs::client::search::{
     SearchRequest, SearchSpaceRequest, Sort, SortDirection, SortProperty,
 };
@@ -28,6 +32,7 @@ impl PluginCommand for Search {
                 "Name of the space to search within (can also accept Space from pipeline)",
                 Some('s'),
             )
+            .space_id_flag()
             .named(
                 "limit",
                 SyntaxShape::Int,
@@ -52,6 +57,11 @@ impl PluginCommand for Search {
                 "Sort direction: asc or desc (default: desc)",
                 Some('d'),
             )
+            .switch(
+                "include-archived",
+                "Include archived objects, which are left out by default",
+                None,
+            )
             .input_output_types(vec![
                 (
                     nu_protocol::Type::Nothing,
@@ -88,6 +98,7 @@ impl PluginCommand for Search {
         // Get optional parameters
         let limit: Option<i64> = call.get_flag("limit")?;
         let offset: Option<i64> = call.get_flag("offset")?;
+        let include_archived = call.has_flag("include-archived")?;
         let sort_property: Option<String> = call.get_flag("sort")?;
         let sort_direction: Option<String> = call.get_flag("direction")?;
 
@@ -146,6 +157,7 @@ impl PluginCommand for Search {
                 limit: limit.map(|l| l as usize),
                 offset: offset.map(|o| o as usize),
                 sort,
+                include_archived,
             };
 
             plugin
@@ -159,6 +171,7 @@ impl PluginCommand for Search {
                 offset: offset.map(|o| o as usize),
                 sort,
                 space_id: None,
+                include_archived,
             };
 
             plugin
@@ -170,7 +183,7 @@ impl PluginCommand for Search {
         let mut values = Vec::new();
         for search_obj in search_objects {
             // Extract type_key from SearchObject.object field (global type key like "ot_page")
-            let type_key = search_obj.object.clone();
+            let type_key = search_obj.object.to_string();
 
             // Extract space_id (search results include this)
             let space_id = search_obj.space_id.clone();
@@ -188,8 +201,10 @@ impl PluginCommand for Search {
                 id: search_obj.id,
                 name: Some(search_obj.name),
                 space_id: Some(space_id.clone()),
-                object: Some(type_key.clone()),
+                object: Some(search_obj.object),
                 properties: search_obj.properties,
+                markdown: None,
+                snippet: Some(search_obj.snippet).filter(|s| !s.is_empty()),
             };
 
             // Use From<(Object, String, String, String)> for conversion
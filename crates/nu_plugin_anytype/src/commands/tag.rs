@@ -1,4 +1,8 @@
-use crate::{AnytypePlugin, commands::common::get_space_id, value::AnytypeValue};
+use crate::{
+    AnytypePlugin,
+    commands::common::{SignatureExt, get_space_ref},
+    value::AnytypeValue,
+};
 use anytype_rs::{Color, CreateTagRequest, UpdateTagRequest};
 use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
 use nu_protocol::{Category, LabeledError, PipelineData, Signature, SyntaxShape, Value};
@@ -26,6 +30,7 @@ impl PluginCommand for TagList {
                 "Name of the space (can also accept Property from pipeline)",
                 Some('s'),
             )
+            .space_id_flag()
             .input_output_types(vec![
                 (
                     nu_protocol::Type::Nothing,
@@ -115,6 +120,7 @@ impl PluginCommand for TagGet {
                 "Name of the space (can also accept Property from pipeline)",
                 Some('s'),
             )
+            .space_id_flag()
             .input_output_types(vec![
                 (
                     nu_protocol::Type::Nothing,
@@ -235,10 +241,11 @@ impl PluginCommand for TagCreate {
                 "Name of the space (can also accept Property from pipeline)",
                 Some('s'),
             )
+            .space_id_flag()
             .named(
                 "color",
                 SyntaxShape::String,
-                "Color for the tag (grey, yellow, orange, red, pink, purple, blue, ice, teal, lime)",
+                format!("Color for the tag ({})", Color::names()),
                 Some('c'),
             )
             .input_output_types(vec![
@@ -348,6 +355,7 @@ impl PluginCommand for TagUpdate {
                 "Name of the space (can also accept Property/Tag from pipeline)",
                 Some('s'),
             )
+            .space_id_flag()
             .named(
                 "new-name",
                 SyntaxShape::String,
@@ -357,7 +365,7 @@ impl PluginCommand for TagUpdate {
             .named(
                 "color",
                 SyntaxShape::String,
-                "Color for the tag (grey, yellow, orange, red, pink, purple, blue, ice, teal, lime)",
+                format!("Color for the tag ({})", Color::names()),
                 Some('c'),
             )
             .input_output_types(vec![
@@ -503,6 +511,7 @@ impl PluginCommand for TagDelete {
                 "Name of the space (can also accept Property/Tag from pipeline)",
                 Some('s'),
             )
+            .space_id_flag()
             .input_output_types(vec![
                 (nu_protocol::Type::Nothing, nu_protocol::Type::String),
                 (
@@ -599,8 +608,8 @@ fn get_property_context(
     property_name: &str,
     span: nu_protocol::Span,
 ) -> Result<(String, String), LabeledError> {
-    // Get space_id from multiple sources
-    let space_id = get_space_id(plugin, call, input, span)?;
+    // Find the space without resolving it yet
+    let space = get_space_ref(plugin, call, input, span)?;
 
     // Get resolver
     let resolver = plugin.resolver().map_err(|e| {
@@ -623,36 +632,36 @@ fn get_property_context(
         .with_label("Missing type context", span));
     };
 
-    // Resolve property name to ID within the type
-    let property_id = plugin
-        .run_async(resolver.resolve_property(&type_id, property_name))
-        .map_err(|e| {
-            LabeledError::new(format!(
-                "Failed to resolve property '{}' in type '{}': {}",
-                property_name, type_id, e
+    // The space and property lookups don't depend on each other, so run them together.
+    // Repeated invocations (e.g. piping many tags) are served from the resolver cache.
+    let (space_id, property_id) = plugin
+        .run_async(async {
+            Ok(tokio::join!(
+                space.resolve(&resolver),
+                resolver.resolve_property(&type_id, property_name),
             ))
-        })?;
+        })
+        .map_err(LabeledError::from)?;
+
+    let space_id = space_id.map_err(|e| space.resolve_error(e))?;
+    let property_id = property_id.map_err(|e| {
+        LabeledError::new(format!(
+            "Failed to resolve property '{}' in type '{}': {}",
+            property_name, type_id, e
+        ))
+    })?;
 
     Ok((space_id, property_id))
 }
 
 /// Helper function to parse color string
 fn parse_color(color_str: &str, span: nu_protocol::Span) -> Result<Color, LabeledError> {
-    match color_str.to_lowercase().as_str() {
-        "grey" => Ok(Color::Grey),
-        "yellow" => Ok(Color::Yellow),
-        "orange" => Ok(Color::Orange),
-        "red" => Ok(Color::Red),
-        "pink" => Ok(Color::Pink),
-        "purple" => Ok(Color::Purple),
-        "blue" => Ok(Color::Blue),
-        "ice" => Ok(Color::Ice),
-        "teal" => Ok(Color::Teal),
-        "lime" => Ok(Color::Lime),
-        _ => Err(LabeledError::new(format!(
-            "Invalid color: {}. Valid options: grey, yellow, orange, red, pink, purple, blue, ice, teal, lime",
-            color_str
+    Color::from_name(color_str).ok_or_else(|| {
+        LabeledError::new(format!(
+            "Invalid color: {}. Valid options: {}",
+            color_str,
+            Color::names()
         ))
-        .with_label("Invalid color", span)),
-    }
+        .with_label("Invalid color", span)
+    })
 }
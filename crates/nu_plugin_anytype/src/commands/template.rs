@@ -1,6 +1,6 @@
 use crate::{
     AnytypePlugin,
-    commands::common::{get_space_id, get_type_id},
+    commands::common::{SignatureExt, get_space_id, get_type_id},
     value::AnytypeValue,
 };
 use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
@@ -34,6 +34,7 @@ impl PluginCommand for TemplateList {
                 "Name of the space (can also accept Space or Type from pipeline)",
                 Some('s'),
             )
+            .space_id_flag()
             .input_output_types(vec![
                 (
                     nu_protocol::Type::Nothing,
@@ -1,4 +1,8 @@
-use crate::{AnytypePlugin, commands::common::get_space_id, value::AnytypeValue};
+use crate::{
+    AnytypePlugin,
+    commands::common::{SignatureExt, get_space_id, successful_spaces, wants_all_spaces},
+    value::AnytypeValue,
+};
 use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
 use nu_protocol::{Category, LabeledError, PipelineData, Signature, SyntaxShape, Value};
 
@@ -13,7 +17,7 @@ impl PluginCommand for TypeList {
     }
 
     fn description(&self) -> &str {
-        "List all types in a space"
+        "List all types in a space, or in every space with --space '*'"
     }
 
     fn signature(&self) -> Signature {
@@ -21,9 +25,15 @@ impl PluginCommand for TypeList {
             .named(
                 "space",
                 SyntaxShape::String,
-                "Name of the space (can also accept Space from pipeline)",
+                "Name of the space, or '*' for every space (can also accept Space from pipeline)",
                 Some('s'),
             )
+            .space_id_flag()
+            .switch(
+                "user-only",
+                "Hide Anytype's built-in types (page, note, task, ...)",
+                Some('u'),
+            )
             .input_output_types(vec![
                 (
                     nu_protocol::Type::Nothing,
@@ -51,15 +61,34 @@ impl PluginCommand for TypeList {
         let span = call.head;
         let input = input.into_value(span)?;
 
-        // Get space_id from multiple sources
-        let space_id = get_space_id(plugin, call, &input, span)?;
-
         // Get client
         let client = plugin.client().map_err(|e| {
             LabeledError::new(format!("Failed to get client: {}", e))
                 .with_label("Authentication required", span)
         })?;
 
+        let user_only = call.has_flag("user-only")?;
+
+        // Fan out across every space, tagging each type with its space name
+        if wants_all_spaces(call) {
+            let spaces = plugin
+                .run_async(client.list_types_in_all_spaces())
+                .map_err(|e| LabeledError::new(format!("Failed to list types: {}", e)))?;
+
+            let mut values = Vec::new();
+            for (space, types) in successful_spaces(spaces, "types")? {
+                for type_data in types.into_iter().filter(|t| !user_only || !t.is_system()) {
+                    let anytype_value: AnytypeValue = (type_data, space.id.clone()).into();
+                    let anytype_value = anytype_value.with_space_name(&space.name);
+                    values.push(Value::custom(Box::new(anytype_value), span));
+                }
+            }
+            return Ok(PipelineData::Value(Value::list(values, span), None));
+        }
+
+        // Get space_id from multiple sources
+        let space_id = get_space_id(plugin, call, &input, span)?;
+
         // List types from API
         let types = plugin
             .run_async(client.list_types(&space_id))
@@ -68,6 +97,7 @@ impl PluginCommand for TypeList {
         // Convert to AnytypeValue::Type with space_id context
         let values: Vec<Value> = types
             .into_iter()
+            .filter(|type_data| !user_only || !type_data.is_system())
             .map(|type_data| {
                 // Use From<(Type, String)> to convert with space_id context
                 let anytype_value: AnytypeValue = (type_data, space_id.clone()).into();
@@ -102,6 +132,7 @@ impl PluginCommand for TypeGet {
                 "Name of the space (can also accept Space from pipeline)",
                 Some('s'),
             )
+            .space_id_flag()
             .input_output_types(vec![
                 (
                     nu_protocol::Type::Nothing,
@@ -139,7 +170,7 @@ impl PluginCommand for TypeGet {
 
         // Resolve type name to ID within the space
         let type_id = plugin
-            .run_async(resolver.resolve_type(&space_id, &name))
+            .run_async(resolver.resolve_type_or_id(&space_id, &name))
             .map_err(|e| {
                 LabeledError::new(format!(
                     "Failed to resolve type '{}' in space '{}': {}",
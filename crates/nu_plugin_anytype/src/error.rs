@@ -18,13 +18,20 @@ pub fn convert_anytype_error(err: AnytypeError) -> ShellError {
             msg: format!("HTTP request failed: {}", source),
             span: Span::unknown(),
         },
-        AnytypeError::Api { message } => ShellError::GenericError {
+        AnytypeError::Api { message, .. } => ShellError::GenericError {
             error: "API error".to_string(),
             msg: message,
             span: None,
             help: Some("Check the Anytype API server status and logs".to_string()),
             inner: vec![],
         },
+        AnytypeError::NotFound { message } => ShellError::GenericError {
+            error: "Not found".to_string(),
+            msg: message,
+            span: None,
+            help: Some("Check the name or ID and the space it is looked up in".to_string()),
+            inner: vec![],
+        },
         AnytypeError::Serialization { source } => ShellError::GenericError {
             error: "Serialization error".to_string(),
             msg: format!("Failed to serialize/deserialize: {}", source),
@@ -39,6 +46,24 @@ pub fn convert_anytype_error(err: AnytypeError) -> ShellError {
             help: Some("The API returned an unexpected response format".to_string()),
             inner: vec![],
         },
+        AnytypeError::Config { message } => ShellError::GenericError {
+            error: "Configuration error".to_string(),
+            msg: message,
+            span: None,
+            help: Some("Check the values passed to the command".to_string()),
+            inner: vec![],
+        },
+        AnytypeError::Timeout { duration } => ShellError::NetworkFailure {
+            msg: format!(
+                "Anytype didn't respond within {}s — is the app running?",
+                duration.as_secs()
+            ),
+            span: Span::unknown(),
+        },
+        AnytypeError::Connection { message } => ShellError::NetworkFailure {
+            msg: message,
+            span: Span::unknown(),
+        },
     }
 }
 
@@ -65,6 +90,7 @@ mod tests {
     fn test_convert_api_error() {
         let err = AnytypeError::Api {
             message: "Not found".to_string(),
+            status: None,
         };
         let shell_err = convert_anytype_error(err);
         match shell_err {
@@ -75,6 +101,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_convert_not_found_error() {
+        let err = AnytypeError::NotFound {
+            message: "No Space found with name 'Work'".to_string(),
+        };
+        let shell_err = convert_anytype_error(err);
+        match shell_err {
+            ShellError::GenericError { error, msg, .. } => {
+                assert_eq!(error, "Not found");
+                assert_eq!(msg, "No Space found with name 'Work'");
+            }
+            _ => panic!("Expected GenericError"),
+        }
+    }
+
     #[test]
     fn test_convert_invalid_response_error() {
         let err = AnytypeError::InvalidResponse {
@@ -88,4 +129,47 @@ mod tests {
             _ => panic!("Expected GenericError"),
         }
     }
+
+    #[test]
+    fn test_convert_config_error() {
+        let err = AnytypeError::Config {
+            message: "Invalid type key 'My Type': key must not contain whitespace".to_string(),
+        };
+        let shell_err = convert_anytype_error(err);
+        match shell_err {
+            ShellError::GenericError { error, msg, .. } => {
+                assert_eq!(error, "Configuration error");
+                assert!(msg.contains("My Type"));
+            }
+            _ => panic!("Expected GenericError"),
+        }
+    }
+
+    #[test]
+    fn test_convert_timeout_error() {
+        let err = AnytypeError::Timeout {
+            duration: std::time::Duration::from_secs(30),
+        };
+        let shell_err = convert_anytype_error(err);
+        match shell_err {
+            ShellError::NetworkFailure { msg, .. } => {
+                assert!(msg.contains("30s"));
+            }
+            _ => panic!("Expected NetworkFailure"),
+        }
+    }
+
+    #[test]
+    fn test_convert_connection_error() {
+        let err = AnytypeError::Connection {
+            message: "Could not connect to Anytype at http://localhost:31009".to_string(),
+        };
+        let shell_err = convert_anytype_error(err);
+        match shell_err {
+            ShellError::NetworkFailure { msg, .. } => {
+                assert!(msg.contains("localhost:31009"));
+            }
+            _ => panic!("Expected NetworkFailure"),
+        }
+    }
 }
@@ -1,6 +1,15 @@
 use nu_plugin::{MsgPackSerializer, serve_plugin};
 use nu_plugin_anytype::AnytypePlugin;
+use tracing_subscriber::EnvFilter;
 
 fn main() {
+    // Stdout carries the plugin protocol, so logs go to stderr, and only when asked for
+    if let Ok(filter) = EnvFilter::try_from_default_env() {
+        tracing_subscriber::fmt()
+            .with_env_filter(filter)
+            .with_writer(std::io::stderr)
+            .init();
+    }
+
     serve_plugin(&AnytypePlugin::new(), MsgPackSerializer {})
 }
@@ -143,10 +143,29 @@ impl AnytypePlugin {
             .map(|s| s.to_string())
             .unwrap_or_else(|| defaults.app_name.clone());
 
+        let record_dir = config_value
+            .as_ref()
+            .and_then(|v| v.as_record().ok())
+            .and_then(|record| record.get("record_dir"))
+            .and_then(|v| v.as_str().ok())
+            .map(std::path::PathBuf::from)
+            .or(defaults.record_dir);
+
+        let max_concurrency = config_value
+            .as_ref()
+            .and_then(|v| v.as_record().ok())
+            .and_then(|record| record.get("max_concurrency"))
+            .and_then(|v| v.as_int().ok())
+            .map(|n| n.max(1) as usize)
+            .unwrap_or(defaults.max_concurrency);
+
         anytype_rs::ClientConfig {
             base_url,
             timeout_seconds,
             app_name,
+            record_dir,
+            max_concurrency,
+            ..defaults
         }
     }
 
@@ -207,6 +226,18 @@ impl AnytypePlugin {
         ))
     }
 
+    /// Flush the resolution cache, returning the number of expired entries dropped
+    ///
+    /// Unlike [`Self::resolver`], this never initializes the client: if nothing has been
+    /// resolved yet there is nothing to flush.
+    pub fn flush(&self) -> usize {
+        self.resolver
+            .read()
+            .ok()
+            .and_then(|resolver| resolver.as_ref().map(|r| r.flush_cache()))
+            .unwrap_or(0)
+    }
+
     /// Get client (initializing if needed)
     pub fn client(&self) -> Result<Arc<AnytypeClient>, ShellError> {
         {
@@ -243,6 +274,12 @@ impl Plugin for AnytypePlugin {
             Box::new(crate::commands::TypeGet),
             Box::new(crate::commands::ObjectList),
             Box::new(crate::commands::ObjectGet),
+            Box::new(crate::commands::ObjectCreate),
+            Box::new(crate::commands::ObjectAppend),
+            Box::new(crate::commands::ObjectUnsetProperty),
+            Box::new(crate::commands::ObjectLink),
+            Box::new(crate::commands::ObjectDiff),
+            Box::new(crate::commands::ObjectBookmark),
             Box::new(crate::commands::PropertyList),
             Box::new(crate::commands::PropertyGet),
             Box::new(crate::commands::PropertyCreate),
@@ -263,8 +300,10 @@ impl Plugin for AnytypePlugin {
             Box::new(crate::commands::ResolveSpace),
             Box::new(crate::commands::ResolveType),
             Box::new(crate::commands::ResolveObject),
+            Box::new(crate::commands::ResolveAll),
             Box::new(crate::commands::CacheClear),
             Box::new(crate::commands::CacheStats),
+            Box::new(crate::commands::CacheFlush),
             Box::new(crate::commands::ImportMarkdown),
         ]
     }
@@ -275,3 +314,11 @@ impl Default for AnytypePlugin {
         Self::new()
     }
 }
+
+impl Drop for AnytypePlugin {
+    fn drop(&mut self) {
+        // Flush on shutdown so cache state isn't lost when nushell exits; a panic here
+        // would abort the process, so it is caught and discarded
+        let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| self.flush()));
+    }
+}
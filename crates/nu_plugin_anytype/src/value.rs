@@ -1,6 +1,10 @@
-use anytype_rs::{Color, Icon, Type as AnytypeType};
-use nu_protocol::{CustomValue, Record, ShellError, Span, Value};
+use anytype_rs::{Color, Icon, Type as AnytypeType, TypeProperty};
+use nu_protocol::{
+    CustomValue, Record, ShellError, Span, Type, Value,
+    ast::{Comparison, Operator},
+};
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 
 /// Unified Custom Value for all Anytype entities
 ///
@@ -22,10 +26,12 @@ pub enum AnytypeValue {
         key: String,
         icon: Option<Icon>,
         layout: Option<String>,
-        /// Properties stored as JSON since TypeProperty doesn't derive Clone
-        properties: serde_json::Value,
+        properties: Vec<TypeProperty>,
         /// Context: parent space ID
         space_id: String,
+        /// Context: parent space name, set when results from several spaces are merged
+        #[serde(default)]
+        space_name: Option<String>,
     },
     Object {
         id: String,
@@ -41,6 +47,9 @@ pub enum AnytypeValue {
         type_id: String,
         /// Context: global type key for reference
         type_key: String,
+        /// Context: parent space name, set when results from several spaces are merged
+        #[serde(default)]
+        space_name: Option<String>,
     },
     Property {
         id: String,
@@ -167,6 +176,85 @@ impl AnytypeValue {
             _ => None,
         }
     }
+
+    /// Tag a type or object with its space's name, shown as a leading `space` column
+    ///
+    /// Used for results merged from several spaces, where the space ID alone is hard to read.
+    /// Other kinds are returned unchanged.
+    pub fn with_space_name(mut self, name: &str) -> Self {
+        if let Self::Type { space_name, .. } | Self::Object { space_name, .. } = &mut self {
+            *space_name = Some(name.to_string());
+        }
+        self
+    }
+
+    /// Every column of the record for this kind of entity, including optional ones
+    pub fn columns(&self) -> &'static [&'static str] {
+        match self {
+            Self::Space { .. } => &["id", "name", "description", "icon", "_type"],
+            Self::Type { .. } => &[
+                "space",
+                "id",
+                "name",
+                "key",
+                "space_id",
+                "layout",
+                "icon",
+                "properties",
+                "_type",
+            ],
+            Self::Object { .. } => &[
+                "space",
+                "id",
+                "name",
+                "snippet",
+                "markdown",
+                "space_id",
+                "type_id",
+                "type_key",
+                "properties",
+                "_type",
+            ],
+            Self::Property { .. } => &[
+                "id", "name", "key", "format", "space_id", "type_id", "_type",
+            ],
+            Self::Tag { .. } => &[
+                "id",
+                "name",
+                "key",
+                "color",
+                "space_id",
+                "property_id",
+                "_type",
+            ],
+            Self::List { .. } => &["id", "name", "space_id", "_type"],
+            Self::Template { .. } => &[
+                "id", "name", "snippet", "markdown", "space_id", "type_id", "_type",
+            ],
+            Self::Member { .. } => &["id", "name", "role", "status", "space_id", "_type"],
+        }
+    }
+
+    /// The list-typed column indexed by `$value.0`, for kinds that have one
+    fn list_column(&self) -> Option<&'static str> {
+        match self {
+            Self::Type { .. } | Self::Object { .. } => Some("properties"),
+            _ => None,
+        }
+    }
+
+    /// Whether two values refer to the same Anytype entity (same kind and ID)
+    pub fn same_entity(&self, other: &Self) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other) && self.id() == other.id()
+    }
+
+    /// Ordering used by nushell `sort`: display name (case-insensitive), then ID
+    pub fn sort_cmp(&self, other: &Self) -> Ordering {
+        self.name()
+            .to_lowercase()
+            .cmp(&other.name().to_lowercase())
+            .then_with(|| self.id().cmp(other.id()))
+    }
 }
 
 #[typetag::serde(name = "AnytypeValue")]
@@ -187,18 +275,57 @@ impl CustomValue for AnytypeValue {
         self
     }
 
+    /// `$value.0` indexes into the entity's list field: the properties of a type or object
+    fn follow_path_int(
+        &self,
+        _self_span: Span,
+        index: usize,
+        path_span: Span,
+    ) -> Result<Value, ShellError> {
+        let Some(column) = self.list_column() else {
+            return Err(ShellError::IncompatiblePathAccess {
+                type_name: self.type_name(),
+                span: path_span,
+            });
+        };
+        let items = match self.follow_path_string(path_span, column.to_string(), path_span)? {
+            Value::List { vals, .. } => vals,
+            _ => Vec::new(),
+        };
+        match items.len() {
+            0 => Err(ShellError::AccessEmptyContent { span: path_span }),
+            len if index >= len => Err(ShellError::AccessBeyondEnd {
+                max_idx: len - 1,
+                span: path_span,
+            }),
+            _ => Ok(items[index].clone()),
+        }
+    }
+
     fn follow_path_string(
         &self,
         _self_span: Span,
         column_name: String,
         path_span: Span,
     ) -> Result<Value, ShellError> {
-        // Convert to record and access the field
-        let record = self.to_base_value(path_span)?;
+        // Only build the requested column, so a cell path like `get id` stays cheap
+        let record = self.to_columns(Some(&[column_name.as_str()]), path_span);
         match record {
             Value::Record { val, .. } => {
                 val.get(&column_name)
                     .cloned()
+                    // Unnamed objects fall back to their display name so `sort-by name`
+                    // works on every value
+                    .or_else(|| {
+                        (column_name == "name").then(|| Value::string(self.name(), path_span))
+                    })
+                    // Optional fields that are not set are empty rather than missing, so a
+                    // cell path works on every value of the same kind
+                    .or_else(|| {
+                        self.columns()
+                            .contains(&column_name.as_str())
+                            .then(|| Value::nothing(path_span))
+                    })
                     .ok_or_else(|| ShellError::CantFindColumn {
                         col_name: column_name,
                         span: Some(path_span),
@@ -213,8 +340,80 @@ impl CustomValue for AnytypeValue {
         }
     }
 
+    fn partial_cmp(&self, other: &Value) -> Option<Ordering> {
+        let Value::Custom { val, .. } = other else {
+            return None;
+        };
+        let other = val.as_any().downcast_ref::<Self>()?;
+        Some(self.sort_cmp(other))
+    }
+
+    /// `==`/`!=` compare entity identity; a string on the right is compared with the ID.
+    /// Ordering operators use the same order as `sort`.
+    fn operation(
+        &self,
+        lhs_span: Span,
+        operator: Operator,
+        op: Span,
+        right: &Value,
+    ) -> Result<Value, ShellError> {
+        let span = op.merge(right.span());
+        let Operator::Comparison(comparison) = operator else {
+            return Err(ShellError::OperatorUnsupportedType {
+                op: operator,
+                unsupported: Type::Custom(self.type_name().into()),
+                op_span: op,
+                unsupported_span: lhs_span,
+                help: None,
+            });
+        };
+
+        let equal = match right {
+            Value::String { val, .. } => Some(self.id() == val),
+            Value::Custom { val, .. } => val
+                .as_any()
+                .downcast_ref::<Self>()
+                .map(|other| self.same_entity(other)),
+            _ => None,
+        };
+        let ordering = CustomValue::partial_cmp(self, right);
+
+        let result = match (comparison, equal, ordering) {
+            (Comparison::Equal, Some(equal), _) => equal,
+            (Comparison::NotEqual, Some(equal), _) => !equal,
+            (Comparison::LessThan, _, Some(ordering)) => ordering.is_lt(),
+            (Comparison::LessThanOrEqual, _, Some(ordering)) => ordering.is_le(),
+            (Comparison::GreaterThan, _, Some(ordering)) => ordering.is_gt(),
+            (Comparison::GreaterThanOrEqual, _, Some(ordering)) => ordering.is_ge(),
+            _ => {
+                return Err(ShellError::OperatorUnsupportedType {
+                    op: operator,
+                    unsupported: right.get_type(),
+                    op_span: op,
+                    unsupported_span: right.span(),
+                    help: None,
+                });
+            }
+        };
+
+        Ok(Value::bool(result, span))
+    }
+
     fn to_base_value(&self, span: Span) -> Result<Value, ShellError> {
-        let mut record = Record::new();
+        Ok(self.to_columns(None, span))
+    }
+}
+
+impl AnytypeValue {
+    /// The record for this value, limited to `columns` when given
+    ///
+    /// Columns are only built when wanted, so asking for `id` skips converting large fields
+    /// such as object properties and markdown. Columns this kind doesn't have are ignored.
+    pub fn to_columns(&self, columns: Option<&[&str]>, span: Span) -> Value {
+        let mut record = RecordBuilder {
+            record: Record::new(),
+            columns,
+        };
 
         match self {
             Self::Space {
@@ -223,37 +422,54 @@ impl CustomValue for AnytypeValue {
                 description,
                 icon,
             } => {
-                record.push("id", Value::string(id, span));
-                record.push("name", Value::string(name, span));
+                record.push("id", || Value::string(id, span));
+                record.push("name", || Value::string(name, span));
                 if let Some(desc) = description {
-                    record.push("description", Value::string(desc, span));
+                    record.push("description", || Value::string(desc, span));
                 }
                 if let Some(icon_val) = icon {
-                    record.push("icon", Value::string(icon_val.to_string(), span));
+                    record.push("icon", || Value::string(icon_val.to_string(), span));
                 }
-                record.push("_type", Value::string("space", span));
+                record.push("_type", || Value::string("space", span));
             }
             Self::Type {
                 id,
                 name,
                 key,
                 space_id,
+                space_name,
                 layout,
                 properties,
                 icon,
             } => {
-                record.push("id", Value::string(id, span));
-                record.push("name", Value::string(name, span));
-                record.push("key", Value::string(key, span));
-                record.push("space_id", Value::string(space_id, span));
+                if let Some(space_name) = space_name {
+                    record.push("space", || Value::string(space_name, span));
+                }
+                record.push("id", || Value::string(id, span));
+                record.push("name", || Value::string(name, span));
+                record.push("key", || Value::string(key, span));
+                record.push("space_id", || Value::string(space_id, span));
                 if let Some(layout_str) = layout {
-                    record.push("layout", Value::string(layout_str, span));
+                    record.push("layout", || Value::string(layout_str, span));
                 }
                 if let Some(icon_val) = icon {
-                    record.push("icon", Value::string(format!("{:?}", icon_val), span));
+                    record.push("icon", || Value::string(icon_val.display(), span));
                 }
-                record.push("properties", Value::string(properties.to_string(), span));
-                record.push("_type", Value::string("type", span));
+                record.push("properties", || {
+                    let properties = properties
+                        .iter()
+                        .map(|property| {
+                            let mut record = Record::new();
+                            record.push("id", Value::string(&property.id, span));
+                            record.push("key", Value::string(&property.key, span));
+                            record.push("name", Value::string(&property.name, span));
+                            record.push("format", Value::string(&property.format, span));
+                            Value::record(record, span)
+                        })
+                        .collect();
+                    Value::list(properties, span)
+                });
+                record.push("_type", || Value::string("type", span));
             }
             Self::Object {
                 id,
@@ -261,25 +477,29 @@ impl CustomValue for AnytypeValue {
                 space_id,
                 type_id,
                 type_key,
+                space_name,
                 markdown,
                 snippet,
                 properties,
             } => {
-                record.push("id", Value::string(id, span));
+                if let Some(space_name) = space_name {
+                    record.push("space", || Value::string(space_name, span));
+                }
+                record.push("id", || Value::string(id, span));
                 if let Some(n) = name {
-                    record.push("name", Value::string(n, span));
+                    record.push("name", || Value::string(n, span));
                 }
                 if let Some(s) = snippet {
-                    record.push("snippet", Value::string(s, span));
+                    record.push("snippet", || Value::string(s, span));
                 }
                 if let Some(md) = markdown {
-                    record.push("markdown", Value::string(md, span));
+                    record.push("markdown", || Value::string(md, span));
                 }
-                record.push("space_id", Value::string(space_id, span));
-                record.push("type_id", Value::string(type_id, span));
-                record.push("type_key", Value::string(type_key, span));
-                record.push("properties", Value::string(properties.to_string(), span));
-                record.push("_type", Value::string("object", span));
+                record.push("space_id", || Value::string(space_id, span));
+                record.push("type_id", || Value::string(type_id, span));
+                record.push("type_key", || Value::string(type_key, span));
+                record.push("properties", || json_to_value(properties, span));
+                record.push("_type", || Value::string("object", span));
             }
             Self::Property {
                 id,
@@ -289,13 +509,13 @@ impl CustomValue for AnytypeValue {
                 space_id,
                 type_id,
             } => {
-                record.push("id", Value::string(id, span));
-                record.push("name", Value::string(name, span));
-                record.push("key", Value::string(key, span));
-                record.push("format", Value::string(format, span));
-                record.push("space_id", Value::string(space_id, span));
-                record.push("type_id", Value::string(type_id, span));
-                record.push("_type", Value::string("property", span));
+                record.push("id", || Value::string(id, span));
+                record.push("name", || Value::string(name, span));
+                record.push("key", || Value::string(key, span));
+                record.push("format", || Value::string(format, span));
+                record.push("space_id", || Value::string(space_id, span));
+                record.push("type_id", || Value::string(type_id, span));
+                record.push("_type", || Value::string("property", span));
             }
             Self::Tag {
                 id,
@@ -305,21 +525,21 @@ impl CustomValue for AnytypeValue {
                 space_id,
                 property_id,
             } => {
-                record.push("id", Value::string(id, span));
-                record.push("name", Value::string(name, span));
-                record.push("key", Value::string(key, span));
+                record.push("id", || Value::string(id, span));
+                record.push("name", || Value::string(name, span));
+                record.push("key", || Value::string(key, span));
                 if let Some(c) = color {
-                    record.push("color", Value::string(c.to_string(), span));
+                    record.push("color", || Value::string(c.to_string(), span));
                 }
-                record.push("space_id", Value::string(space_id, span));
-                record.push("property_id", Value::string(property_id, span));
-                record.push("_type", Value::string("tag", span));
+                record.push("space_id", || Value::string(space_id, span));
+                record.push("property_id", || Value::string(property_id, span));
+                record.push("_type", || Value::string("tag", span));
             }
             Self::List { id, name, space_id } => {
-                record.push("id", Value::string(id, span));
-                record.push("name", Value::string(name, span));
-                record.push("space_id", Value::string(space_id, span));
-                record.push("_type", Value::string("list", span));
+                record.push("id", || Value::string(id, span));
+                record.push("name", || Value::string(name, span));
+                record.push("space_id", || Value::string(space_id, span));
+                record.push("_type", || Value::string("list", span));
             }
             Self::Template {
                 id,
@@ -330,19 +550,19 @@ impl CustomValue for AnytypeValue {
                 snippet,
                 ..
             } => {
-                record.push("id", Value::string(id, span));
+                record.push("id", || Value::string(id, span));
                 if let Some(n) = name {
-                    record.push("name", Value::string(n, span));
+                    record.push("name", || Value::string(n, span));
                 }
                 if let Some(s) = snippet {
-                    record.push("snippet", Value::string(s, span));
+                    record.push("snippet", || Value::string(s, span));
                 }
                 if let Some(md) = markdown {
-                    record.push("markdown", Value::string(md, span));
+                    record.push("markdown", || Value::string(md, span));
                 }
-                record.push("space_id", Value::string(space_id, span));
-                record.push("type_id", Value::string(type_id, span));
-                record.push("_type", Value::string("template", span));
+                record.push("space_id", || Value::string(space_id, span));
+                record.push("type_id", || Value::string(type_id, span));
+                record.push("_type", || Value::string("template", span));
             }
             Self::Member {
                 id,
@@ -351,18 +571,57 @@ impl CustomValue for AnytypeValue {
                 status,
                 space_id,
             } => {
-                record.push("id", Value::string(id, span));
+                record.push("id", || Value::string(id, span));
                 if let Some(n) = name {
-                    record.push("name", Value::string(n, span));
+                    record.push("name", || Value::string(n, span));
                 }
-                record.push("role", Value::string(role, span));
-                record.push("status", Value::string(status, span));
-                record.push("space_id", Value::string(space_id, span));
-                record.push("_type", Value::string("member", span));
+                record.push("role", || Value::string(role, span));
+                record.push("status", || Value::string(status, span));
+                record.push("space_id", || Value::string(space_id, span));
+                record.push("_type", || Value::string("member", span));
             }
         }
 
-        Ok(Value::record(record, span))
+        Value::record(record.record, span)
+    }
+}
+
+/// A record under construction that keeps only the wanted columns
+struct RecordBuilder<'a> {
+    record: Record,
+    /// Columns to keep; all of them when `None`
+    columns: Option<&'a [&'a str]>,
+}
+
+impl RecordBuilder<'_> {
+    /// Add a column, building its value only if the column is wanted
+    fn push(&mut self, column: &str, value: impl FnOnce() -> Value) {
+        if self.columns.is_none_or(|columns| columns.contains(&column)) {
+            self.record.push(column, value());
+        }
+    }
+}
+
+/// Convert JSON from the API into a structured nushell value
+fn json_to_value(json: &serde_json::Value, span: Span) -> Value {
+    match json {
+        serde_json::Value::Null => Value::nothing(span),
+        serde_json::Value::Bool(b) => Value::bool(*b, span),
+        serde_json::Value::Number(n) => match n.as_i64() {
+            Some(i) => Value::int(i, span),
+            None => Value::float(n.as_f64().unwrap_or_default(), span),
+        },
+        serde_json::Value::String(s) => Value::string(s, span),
+        serde_json::Value::Array(items) => Value::list(
+            items.iter().map(|item| json_to_value(item, span)).collect(),
+            span,
+        ),
+        serde_json::Value::Object(map) => Value::record(
+            map.iter()
+                .map(|(key, value)| (key.clone(), json_to_value(value, span)))
+                .collect(),
+            span,
+        ),
     }
 }
 
@@ -387,9 +646,9 @@ impl From<(AnytypeType, String)> for AnytypeValue {
             key: type_data.key,
             icon: Some(type_data.icon),
             layout: type_data.layout,
-            properties: serde_json::to_value(&type_data.properties)
-                .unwrap_or(serde_json::json!([])),
+            properties: type_data.properties,
             space_id,
+            space_name: None,
         }
     }
 }
@@ -404,11 +663,12 @@ impl From<(anytype_rs::Object, String, String, String)> for AnytypeValue {
             id: obj.id,
             name: obj.name,
             properties: obj.properties,
-            markdown: None, // May be populated from separate API call
-            snippet: None,  // May be populated from API response
+            markdown: obj.markdown,
+            snippet: obj.snippet,
             space_id,
             type_id,
             type_key,
+            space_name: None,
         }
     }
 }
@@ -507,6 +767,7 @@ mod tests {
             space_id: "sp_123".to_string(),
             type_id: "ot_789".to_string(),
             type_key: "ot_task".to_string(),
+            space_name: None,
         };
 
         assert_eq!(object.id(), "obj_456");
@@ -528,6 +789,7 @@ mod tests {
             space_id: "sp_123".to_string(),
             type_id: "ot_789".to_string(),
             type_key: "ot_note".to_string(),
+            space_name: None,
         };
 
         assert_eq!(object_no_name.name(), "A preview snippet");
@@ -541,6 +803,7 @@ mod tests {
             space_id: "sp_123".to_string(),
             type_id: "ot_789".to_string(),
             type_key: "ot_note".to_string(),
+            space_name: None,
         };
 
         assert_eq!(object_no_name_no_snippet.name(), "obj_456");
@@ -562,4 +825,189 @@ mod tests {
         assert_eq!(tag.property_id(), Some("prop_888"));
         assert_eq!(tag.name(), "Important");
     }
+
+    fn object(id: &str, name: Option<&str>) -> AnytypeValue {
+        AnytypeValue::Object {
+            id: id.to_string(),
+            name: name.map(str::to_string),
+            properties: serde_json::json!({}),
+            markdown: None,
+            snippet: None,
+            space_id: "sp_123".to_string(),
+            type_id: "ot_789".to_string(),
+            type_key: "ot_task".to_string(),
+            space_name: None,
+        }
+    }
+
+    #[test]
+    fn test_partial_cmp_orders_by_name_then_id() {
+        let alpha = object("obj_2", Some("alpha"));
+        let beta = object("obj_1", Some("Beta"));
+        let beta_again = object("obj_3", Some("beta"));
+
+        let custom =
+            |value: &AnytypeValue| Value::custom(Box::new(value.clone()), Span::test_data());
+        assert_eq!(
+            CustomValue::partial_cmp(&alpha, &custom(&beta)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            CustomValue::partial_cmp(&beta_again, &custom(&beta)),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            CustomValue::partial_cmp(&alpha, &Value::test_string("alpha")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_equality_compares_identity() {
+        let span = Span::test_data();
+        let original = object("obj_1", Some("Task"));
+        let renamed = object("obj_1", Some("Renamed"));
+        let other = object("obj_2", Some("Task"));
+        let equal = |lhs: &AnytypeValue, rhs: Value| {
+            lhs.operation(span, Operator::Comparison(Comparison::Equal), span, &rhs)
+                .unwrap()
+                .as_bool()
+                .unwrap()
+        };
+
+        assert!(equal(&original, Value::custom(Box::new(renamed), span)));
+        assert!(!equal(&original, Value::custom(Box::new(other), span)));
+        assert!(equal(&original, Value::test_string("obj_1")));
+    }
+
+    #[test]
+    fn test_follow_name_falls_back_to_display_name() {
+        let span = Span::test_data();
+        let unnamed = object("obj_1", None);
+
+        let name = unnamed
+            .follow_path_string(span, "name".to_string(), span)
+            .unwrap();
+        assert_eq!(name.as_str().unwrap(), "obj_1");
+    }
+
+    #[test]
+    fn test_type_properties_are_records() {
+        let span = Span::test_data();
+        let type_value = AnytypeValue::Type {
+            id: "ot_123".to_string(),
+            name: "Task".to_string(),
+            key: "task".to_string(),
+            icon: None,
+            layout: None,
+            properties: vec![TypeProperty {
+                format: "select".to_string(),
+                id: "prop_1".to_string(),
+                key: "status".to_string(),
+                name: "Status".to_string(),
+                object: "property".into(),
+            }],
+            space_id: "sp_123".to_string(),
+            space_name: None,
+        };
+
+        let record = type_value.to_base_value(span).unwrap();
+        let properties = record.get_data_by_key("properties").unwrap();
+        let first = &properties.as_list().unwrap()[0];
+        assert_eq!(
+            first.get_data_by_key("format").unwrap().as_str().unwrap(),
+            "select"
+        );
+    }
+
+    #[test]
+    fn test_space_name_leads_the_record() {
+        let span = Span::test_data();
+        let tagged = object("obj_1", Some("Task")).with_space_name("Work");
+
+        let record = tagged.to_base_value(span).unwrap().into_record().unwrap();
+        assert_eq!(record.columns().next().unwrap(), "space");
+        assert_eq!(record.get("space").unwrap().as_str().unwrap(), "Work");
+
+        let plain = object("obj_1", Some("Task")).to_base_value(span).unwrap();
+        assert!(plain.get_data_by_key("space").is_none());
+    }
+
+    #[test]
+    fn test_unset_optional_field_is_nothing() {
+        let span = Span::test_data();
+        let object = object("obj_1", Some("Task"));
+
+        let markdown = object
+            .follow_path_string(span, "markdown".to_string(), span)
+            .unwrap();
+        assert!(markdown.is_nothing());
+        assert!(
+            object
+                .follow_path_string(span, "no_such_field".to_string(), span)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_follow_index_into_object_properties() {
+        let span = Span::test_data();
+        let AnytypeValue::Object {
+            id,
+            name,
+            markdown,
+            snippet,
+            space_id,
+            type_id,
+            type_key,
+            ..
+        } = object("obj_1", Some("Task"))
+        else {
+            unreachable!()
+        };
+        let object = AnytypeValue::Object {
+            id,
+            name,
+            properties: serde_json::json!([
+                { "key": "done", "format": "checkbox", "checkbox": true },
+                { "key": "estimate", "format": "number", "number": 3 }
+            ]),
+            markdown,
+            snippet,
+            space_id,
+            type_id,
+            type_key,
+            space_name: None,
+        };
+
+        let second = object.follow_path_int(span, 1, span).unwrap();
+        assert_eq!(
+            second.get_data_by_key("number").unwrap().as_int().unwrap(),
+            3
+        );
+        assert!(object.follow_path_int(span, 2, span).is_err());
+
+        let list = AnytypeValue::List {
+            id: "list_1".to_string(),
+            name: "Reading".to_string(),
+            space_id: "sp_123".to_string(),
+        };
+        assert!(list.follow_path_int(span, 0, span).is_err());
+    }
+
+    #[test]
+    fn test_to_columns_builds_only_requested_columns() {
+        let span = Span::test_data();
+        let object = object("obj_1", Some("Task"));
+
+        let record = object.to_columns(Some(&["id", "name", "no_such_field"]), span);
+        let record = record.as_record().unwrap();
+        assert_eq!(record.columns().collect::<Vec<_>>(), vec!["id", "name"]);
+
+        // Without a hint every column is built, as for `to_base_value`
+        assert_eq!(
+            object.to_columns(None, span),
+            object.to_base_value(span).unwrap()
+        );
+    }
 }
@@ -64,6 +64,16 @@ fn test_cache_stats_requires_auth() -> Result<(), ShellError> {
     Ok(())
 }
 
+#[test]
+fn test_cache_flush_without_credentials() -> Result<(), ShellError> {
+    let pipeline = create_plugin_test()?.eval("anytype cache flush")?;
+    let value = pipeline.into_value(Span::test_data())?;
+
+    // Nothing has been resolved, so there is nothing to flush
+    assert!(value.as_str()?.contains("0 expired entries"));
+    Ok(())
+}
+
 // ============================================================================
 // Resolve Commands Tests (without authentication)
 // ============================================================================
@@ -489,6 +499,7 @@ fn test_custom_value_object_with_context() {
         space_id: "sp_123".to_string(),
         type_id: "ty_789".to_string(),
         type_key: "ot_task".to_string(),
+        space_name: None,
     };
 
     assert_eq!(object.id(), "obj_456");
@@ -512,6 +523,7 @@ fn test_custom_value_name_fallback() {
         space_id: "sp_123".to_string(),
         type_id: "ty_789".to_string(),
         type_key: "ot_note".to_string(),
+        space_name: None,
     };
 
     assert_eq!(object.name(), "A preview snippet");
@@ -526,6 +538,7 @@ fn test_custom_value_name_fallback() {
         space_id: "sp_123".to_string(),
         type_id: "ty_789".to_string(),
         type_key: "ot_note".to_string(),
+        space_name: None,
     };
 
     assert_eq!(object_no_name.name(), "obj_456");